@@ -2,6 +2,7 @@ use std::convert::{AsRef, TryInto};
 use std::process::abort;
 use std::sync::Arc;
 
+use anyhow::*;
 use hashbrown::HashMap;
 
 use cranelift_entity::EntityRef;
@@ -13,7 +14,9 @@ use libeir_ir::{
 };
 
 use liblumen_alloc::atom;
-use liblumen_alloc::erts::exception::{Exception, RuntimeException, SystemException};
+use liblumen_alloc::erts::exception::{
+    self, ArcError, Exception, RuntimeException, SystemException,
+};
 use liblumen_alloc::erts::process::code;
 use liblumen_alloc::erts::process::gc::RootSet;
 use liblumen_alloc::erts::process::{Process, ProcessFlags};
@@ -39,6 +42,8 @@ pub enum OpResult {
     Block(Block),
     Term(Term),
     TermYield(Term),
+    /// The op has no escape continuation to raise `exception` through, so the process exits
+    Exit(RuntimeException),
 }
 
 trait TermCollection {
@@ -137,6 +142,32 @@ where
     }
 }
 
+/// In a guard, anything other than `true` is false.
+fn guard_is_true(term: Term) -> bool {
+    term == atom!("true")
+}
+
+/// Outside a guard, anything other than a boolean is a `badarg`.
+fn term_try_into_bool(term: Term) -> std::result::Result<bool, RuntimeException> {
+    let result: std::result::Result<bool, _> = term.try_into();
+
+    result
+        .with_context(|| format!("{} is not a boolean", term))
+        .map_err(|error| exception::badarg(None, ArcError::new(error)))
+}
+
+/// Guard sequences are combined with these, so a logic op is a guard when it is the condition of
+/// a strict `if_bool`.
+fn is_logic_op(fun: &ErlangFunction, value: Value) -> bool {
+    match fun.fun.value_kind(value) {
+        ValueKind::PrimOp(prim) => match fun.fun.primop_kind(prim) {
+            PrimOpKind::LogicOp(_) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 /// Sets up the current stack frame of `proc` to call `closure` with `args`.
 fn call_closure(proc: &Arc<Process>, mut closure: Term, args: &mut [Term]) {
     try_gc(proc, &mut (&mut closure, args), &mut |(
@@ -335,6 +366,7 @@ impl CallExecutor {
                 }
                 OpResult::Term(t) => break call_closure(proc, t, &mut exec.next_args),
                 OpResult::TermYield(t) => break call_closure(proc, t, &mut exec.next_args),
+                OpResult::Exit(exception) => break proc.exception(exception),
             }
         }
     }
@@ -375,7 +407,7 @@ impl CallExecutor {
                     .zip(values.as_slice(&fun.fun.cons().const_pool).iter())
                 {
                     map.insert(
-                        self.make_const_term(proc, fun, *k)?,
+                        MapKey(self.make_const_term(proc, fun, *k)?),
                         self.make_const_term(proc, fun, *v)?,
                     );
                 }
@@ -460,21 +492,29 @@ impl CallExecutor {
                         let res = proc.cons(head, tail)?;
                         Ok(res)
                     }
+                    // Outside a guard, the first non-boolean is the result, so that it is not
+                    // mistaken for `false`.  Guards are evaluated with `make_guard_term` instead.
                     PrimOpKind::LogicOp(LogicOp::And) => {
                         let mut acc = true;
                         for read in reads.iter() {
-                            let term = self.make_term(proc, fun, *read).unwrap();
-                            let res: bool = term.try_into().ok().unwrap();
-                            acc = acc & res;
+                            let term = self.make_term(proc, fun, *read)?;
+                            let res: std::result::Result<bool, _> = term.try_into();
+                            match res {
+                                Ok(res) => acc = acc & res,
+                                Err(_) => return Ok(term),
+                            }
                         }
                         Ok(acc.into())
                     }
                     PrimOpKind::LogicOp(LogicOp::Or) => {
                         let mut acc = false;
                         for read in reads.iter() {
-                            let term = self.make_term(proc, fun, *read).unwrap();
-                            let res: bool = term.try_into().ok().unwrap();
-                            acc = acc | res;
+                            let term = self.make_term(proc, fun, *read)?;
+                            let res: std::result::Result<bool, _> = term.try_into();
+                            match res {
+                                Ok(res) => acc = acc | res,
+                                Err(_) => return Ok(term),
+                            }
                         }
                         Ok(acc.into())
                     }
@@ -502,6 +542,40 @@ impl CallExecutor {
         }
     }
 
+    /// Like `make_term`, but evaluates logic ops as guards, where anything other than `true` is
+    /// false, so that after a guard that isn't a boolean, the rest of the guard sequence is still
+    /// tried.
+    fn make_guard_term(
+        &self,
+        proc: &Arc<Process>,
+        fun: &ErlangFunction,
+        value: Value,
+    ) -> Result<Term, SystemException> {
+        if let ValueKind::PrimOp(prim) = fun.fun.value_kind(value) {
+            match fun.fun.primop_kind(prim) {
+                PrimOpKind::LogicOp(LogicOp::And) => {
+                    let mut acc = true;
+                    for read in fun.fun.primop_reads(prim).iter() {
+                        let term = self.make_guard_term(proc, fun, *read)?;
+                        acc = acc & guard_is_true(term);
+                    }
+                    return Ok(acc.into());
+                }
+                PrimOpKind::LogicOp(LogicOp::Or) => {
+                    let mut acc = false;
+                    for read in fun.fun.primop_reads(prim).iter() {
+                        let term = self.make_guard_term(proc, fun, *read)?;
+                        acc = acc | guard_is_true(term);
+                    }
+                    return Ok(acc.into());
+                }
+                _ => (),
+            }
+        }
+
+        self.make_term(proc, fun, value)
+    }
+
     fn val_call(
         &mut self,
         proc: &Arc<Process>,
@@ -593,12 +667,22 @@ impl CallExecutor {
                         Err(_) => 2,
                     }
                 } else if reads.len() == 3 {
-                    let bool_term = self.make_term(proc, fun, reads[2]).unwrap();
-                    let b: std::result::Result<bool, _> = bool_term.try_into();
-                    match b {
-                        Ok(true) => 0,
-                        Ok(false) => 1,
-                        Err(_) => unreachable!(),
+                    // Without a branch for non-booleans, a guard is false unless it is `true`,
+                    // but anything else has to be a boolean.
+                    if is_logic_op(fun, reads[2]) {
+                        let bool_term = self.make_guard_term(proc, fun, reads[2])?;
+                        if guard_is_true(bool_term) {
+                            0
+                        } else {
+                            1
+                        }
+                    } else {
+                        let bool_term = self.make_term(proc, fun, reads[2])?;
+                        match term_try_into_bool(bool_term) {
+                            Ok(true) => 0,
+                            Ok(false) => 1,
+                            Err(exception) => return Ok(OpResult::Exit(exception)),
+                        }
                     }
                 } else {
                     unreachable!()
@@ -609,7 +693,7 @@ impl CallExecutor {
             OpKind::Match { branches } => self::r#match::match_op(self, proc, fun, branches, block),
             OpKind::MapPut { action } => {
                 let map_term: Boxed<Map> = self.make_term(proc, fun, reads[2])?.try_into().unwrap();
-                let hashmap_ref: &HashMap<Term, Term> = map_term.as_ref().as_ref();
+                let hashmap_ref: &HashMap<MapKey, Term> = map_term.as_ref().as_ref();
                 let mut hashmap = hashmap_ref.clone();
 
                 let mut idx = 3;
@@ -620,13 +704,13 @@ impl CallExecutor {
 
                     match action {
                         MapPutUpdate::Put => {
-                            hashmap.insert(key, val);
+                            hashmap.insert(MapKey(key), val);
                         }
                         MapPutUpdate::Update => {
-                            if hashmap.contains_key(&key) {
+                            if hashmap.contains_key(&MapKey(key)) {
                                 unimplemented!()
                             }
-                            hashmap.insert(key, val);
+                            hashmap.insert(MapKey(key), val);
                         }
                     }
                 }
@@ -35,6 +35,29 @@ pub fn make_erlang() -> NativeModule {
     native.add_simple(Atom::try_from_str("=:=").unwrap(), 2, |_proc, args| {
         Ok(erlang::are_exactly_equal_2::native(args[0], args[1]))
     });
+    native.add_simple(Atom::try_from_str("/=").unwrap(), 2, |_proc, args| {
+        Ok(erlang::are_not_equal_after_conversion_2::native(
+            args[0], args[1],
+        ))
+    });
+    native.add_simple(Atom::try_from_str("=/=").unwrap(), 2, |_proc, args| {
+        Ok(erlang::are_exactly_not_equal_2::native(args[0], args[1]))
+    });
+
+    // Like any other call, a `badarg` from these in a guard goes to the guard's failure
+    // continuation, so the guard is false instead of the exception escaping.
+    native.add_simple(Atom::try_from_str("and").unwrap(), 2, |_proc, args| {
+        erlang::and_2::native(args[0], args[1])
+    });
+    native.add_simple(Atom::try_from_str("or").unwrap(), 2, |_proc, args| {
+        erlang::or_2::native(args[0], args[1])
+    });
+    native.add_simple(Atom::try_from_str("xor").unwrap(), 2, |_proc, args| {
+        erlang::xor_2::native(args[0], args[1])
+    });
+    native.add_simple(Atom::try_from_str("not").unwrap(), 1, |_proc, args| {
+        erlang::not_1::native(args[0])
+    });
 
     native.add_simple(Atom::try_from_str("spawn_opt").unwrap(), 4, |proc, args| {
         match args[3].decode().unwrap() {
@@ -164,7 +187,7 @@ pub fn make_erlang() -> NativeModule {
         |_proc, args| Ok(erlang::is_bitstring_1::native(args[0])),
     );
     native.add_simple(Atom::try_from_str("is_float").unwrap(), 1, |_proc, args| {
-        Ok(erlang::is_bitstring_1::native(args[0]))
+        Ok(erlang::is_float_1::native(args[0]))
     });
 
     native.add_simple(
@@ -303,3 +303,110 @@ run(N) -> this_proc(N, 0).
     println!("{:?}", res.result);
     //assert!(res.result == Ok(100));
 }
+
+#[test]
+fn guard_with_and_of_comparison() {
+    &*VM;
+
+    let arc_scheduler = Scheduler::current();
+    let init_arc_process = arc_scheduler.spawn_init(0).unwrap();
+
+    liblumen_otp::erlang::apply_3::export();
+
+    let module = Atom::try_from_str("guard_with_and_of_comparison").unwrap();
+    let function = Atom::try_from_str("run").unwrap();
+
+    let eir_mod = compile(
+        "
+-module(guard_with_and_of_comparison).
+
+positive(X) when is_integer(X) and (X > 0) -> yes;
+positive(_) -> no.
+
+run() -> {positive(1), positive(0), positive(a), positive(1.5)}.
+",
+    );
+
+    VM.modules.write().unwrap().register_erlang_module(eir_mod);
+
+    let res = crate::call_result::call_run_erlang(init_arc_process.clone(), module, function, &[]);
+
+    let yes = Atom::str_to_term("yes");
+    let no = Atom::str_to_term("no");
+    let expected = init_arc_process
+        .tuple_from_slice(&[yes, no, no, no])
+        .unwrap();
+    assert!(res.result == Ok(expected));
+}
+
+#[test]
+fn guard_with_exception_fails_guard() {
+    &*VM;
+
+    let arc_scheduler = Scheduler::current();
+    let init_arc_process = arc_scheduler.spawn_init(0).unwrap();
+
+    liblumen_otp::erlang::apply_3::export();
+
+    let module = Atom::try_from_str("guard_with_exception_fails_guard").unwrap();
+    let function = Atom::try_from_str("run").unwrap();
+
+    let eir_mod = compile(
+        "
+-module(guard_with_exception_fails_guard).
+
+strict(X) when X and true -> yes;
+strict(_) -> no.
+
+increment_positive(X) when X + 1 > 1 -> yes;
+increment_positive(_) -> no.
+
+run() -> {strict(true), strict(1), increment_positive(1), increment_positive(a)}.
+",
+    );
+
+    VM.modules.write().unwrap().register_erlang_module(eir_mod);
+
+    let res = crate::call_result::call_run_erlang(init_arc_process.clone(), module, function, &[]);
+
+    let yes = Atom::str_to_term("yes");
+    let no = Atom::str_to_term("no");
+    let expected = init_arc_process
+        .tuple_from_slice(&[yes, no, yes, no])
+        .unwrap();
+    assert!(res.result == Ok(expected));
+}
+
+#[test]
+fn guard_sequence_with_non_boolean_guard_tries_next_guard() {
+    &*VM;
+
+    let arc_scheduler = Scheduler::current();
+    let init_arc_process = arc_scheduler.spawn_init(0).unwrap();
+
+    liblumen_otp::erlang::apply_3::export();
+
+    let module =
+        Atom::try_from_str("guard_sequence_with_non_boolean_guard_tries_next_guard").unwrap();
+    let function = Atom::try_from_str("run").unwrap();
+
+    let eir_mod = compile(
+        "
+-module(guard_sequence_with_non_boolean_guard_tries_next_guard).
+
+either(X, Y) when X; Y -> yes;
+either(_, _) -> no.
+
+run() -> {either(1, true), either(1, 2), either(false, true)}.
+",
+    );
+
+    VM.modules.write().unwrap().register_erlang_module(eir_mod);
+
+    let res = crate::call_result::call_run_erlang(init_arc_process.clone(), module, function, &[]);
+
+    let yes = Atom::str_to_term("yes");
+    let no = Atom::str_to_term("no");
+    let expected = init_arc_process.tuple_from_slice(&[yes, no, yes]).unwrap();
+    assert!(res.result == Ok(expected));
+}
@@ -26,15 +26,18 @@ pub trait CloneToProcess: Debug {
     /// Returns boxed copy of this value, performing any heap allocations
     /// using the process heap of `process`, possibly using heap fragments if
     /// there is not enough space for the cloned value
-    fn clone_to_process(&self, process: &Process) -> Term {
+    ///
+    /// If there is not enough space on the heap and a heap fragment cannot be
+    /// allocated either, `Err(Alloc)` is returned
+    fn clone_to_process(&self, process: &Process) -> AllocResult<Term> {
         let mut heap = process.acquire_heap();
         match self.clone_to_heap(&mut heap) {
-            Ok(term) => term,
+            Ok(term) => Ok(term),
             Err(_) => {
                 drop(heap);
-                let (term, mut frag) = self.clone_to_fragment().unwrap();
+                let (term, mut frag) = self.clone_to_fragment()?;
                 process.attach_fragment(unsafe { frag.as_mut() });
-                term
+                Ok(term)
             }
         }
     }
@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use crate::erts::exception::AllocResult;
 use crate::erts::term::prelude::Term;
 use crate::erts::Process;
 
@@ -23,21 +24,23 @@ pub enum Cow {
     Owned(Term),
 }
 impl Cow {
-    pub fn clone_to_process(&self, process: &mut Process) -> Self {
+    pub fn clone_to_process(&self, process: &mut Process) -> AllocResult<Self> {
         match *self {
-            Self::Borrowed(b) => Self::Borrowed(b),
-            Self::Owned(o) => Self::Owned(o.clone_to_process(process)),
+            Self::Borrowed(b) => Ok(Self::Borrowed(b)),
+            Self::Owned(o) => o.clone_to_process(process).map(Self::Owned),
         }
     }
 
-    pub fn clone_from(&mut self, source: &Self, process: &mut Process) {
+    pub fn clone_from(&mut self, source: &Self, process: &mut Process) -> AllocResult<()> {
         if let Self::Owned(ref mut dest) = *self {
             if let Self::Owned(ref o) = *source {
-                *dest = o.clone_to_process(process);
-                return;
+                *dest = o.clone_to_process(process)?;
+                return Ok(());
             }
         }
 
-        *self = source.clone_to_process(process);
+        *self = source.clone_to_process(process)?;
+
+        Ok(())
     }
 }
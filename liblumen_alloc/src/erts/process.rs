@@ -1,4 +1,5 @@
 pub mod alloc;
+mod binary_builder;
 pub mod code;
 //pub mod ffi;
 mod flags;
@@ -37,14 +38,15 @@ use crate::erts::term::prelude::*;
 
 use super::*;
 
-use self::alloc::VirtualAllocator;
 use self::alloc::{Heap, HeapAlloc, TermAlloc};
 use self::alloc::{StackAlloc, StackPrimitives};
+use self::alloc::{VirtualAllocator, VirtualHeap};
 use self::code::stack;
 use self::code::stack::frame::{Frame, Placement};
 use self::code::Code;
 use self::gc::{GcError, RootSet};
 
+pub use self::binary_builder::BinaryBuilder;
 pub use self::flags::*;
 pub use self::heap::ProcessHeap;
 pub use self::mailbox::*;
@@ -117,6 +119,9 @@ pub struct Process {
     /// exceeds `MAX_REDUCTIONS_PER_RUN`.
     run_reductions: AtomicU16,
     pub total_reductions: AtomicU64,
+    /// The number of `suspend_process/1` calls not yet matched by `resume_process/1`.  The process
+    /// is not run while this is non-zero.
+    suspend_count: AtomicUsize,
     code_stack: Mutex<code::stack::Stack>,
     pub status: RwLock<Status>,
     pub registered_name: RwLock<Option<Atom>>,
@@ -181,6 +186,7 @@ impl Process {
             initial_module_function_arity,
             run_reductions: Default::default(),
             total_reductions: Default::default(),
+            suspend_count: AtomicUsize::new(0),
             registered_name: Default::default(),
             linked_pid_set: Default::default(),
             monitor_by_reference: Default::default(),
@@ -541,6 +547,20 @@ impl Process {
         self.acquire_heap().binary_from_bytes(bytes)
     }
 
+    /// Starts a binary on this process that bytes and binaries can be appended to before it is
+    /// finished.
+    pub fn binary_builder(&self) -> BinaryBuilder {
+        BinaryBuilder::new(self)
+    }
+
+    /// `write` is called while the heap is locked, so it must not allocate on this process.
+    pub fn binary_from_fn<F>(&self, len: usize, write: F) -> AllocResult<Term>
+    where
+        F: FnOnce(&mut [u8]),
+    {
+        self.acquire_heap().binary_from_fn(len, write)
+    }
+
     pub fn binary_from_str(&self, s: &str) -> AllocResult<Term> {
         self.acquire_heap().binary_from_str(s)
     }
@@ -663,7 +683,7 @@ impl Process {
         }
     }
 
-    pub fn map_from_hash_map(&self, hash_map: HashMap<Term, Term>) -> AllocResult<Term> {
+    pub fn map_from_hash_map(&self, hash_map: HashMap<MapKey, Term>) -> AllocResult<Term> {
         self.acquire_heap()
             .map_from_hash_map(hash_map)
             .map(|map| map.into())
@@ -932,6 +952,26 @@ impl Process {
         self.flags.are_set(ProcessFlags::NeedFullSweep)
     }
 
+    /// The size in words of the young generation, where new terms are allocated
+    pub fn heap_size(&self) -> usize {
+        self.heap.lock().heap_size()
+    }
+
+    /// The size in words of the young and old generations and any heap fragments
+    pub fn total_heap_size(&self) -> usize {
+        let heap_size = self.heap.lock().total_heap_size();
+
+        heap_size + self.off_heap_size()
+    }
+
+    /// The size in bytes of the process itself, its heaps, and the off-heap binaries on its virtual
+    /// binary heap
+    pub fn memory(&self) -> usize {
+        let virtual_heap_used = self.heap.lock().virtual_heap_used();
+
+        mem::size_of::<Self>() + self.total_heap_size() * mem::size_of::<Term>() + virtual_heap_used
+    }
+
     /// Inserts roots from the process into the given root set.
     /// This includes all process dictionary entries.
     #[inline]
@@ -1015,6 +1055,33 @@ impl Process {
         self.run_reductions.fetch_add(1, Ordering::SeqCst);
     }
 
+    /// Charges `reductions` at once, so that native functions whose work is proportional to the
+    /// size of their input, such as sorting, are preempted as soon as they return instead of
+    /// counting as a single reduction.  Saturates instead of overflowing.
+    pub fn reduce_by(&self, reductions: usize) {
+        let increment = reductions.min(Reductions::max_value() as usize) as Reductions;
+        let mut current = self.run_reductions.load(Ordering::SeqCst);
+
+        loop {
+            let new = current.saturating_add(increment);
+
+            match self.run_reductions.compare_exchange(
+                current,
+                new,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => break,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// The number of reductions in the current `run`
+    pub fn run_reductions(&self) -> Reductions {
+        self.run_reductions.load(Ordering::SeqCst)
+    }
+
     pub fn is_reduced(&self) -> bool {
         MAX_REDUCTIONS_PER_RUN <= self.run_reductions.load(Ordering::SeqCst)
     }
@@ -1045,11 +1112,10 @@ impl Process {
     }
 
     fn stop_running(&self) {
-        self.total_reductions.fetch_add(
-            self.run_reductions.load(Ordering::SeqCst) as u64,
-            Ordering::SeqCst,
-        );
-        self.run_reductions.store(0, Ordering::SeqCst);
+        let run_reductions = self.run_reductions.swap(0, Ordering::SeqCst) as u64;
+        self.total_reductions
+            .fetch_add(run_reductions, Ordering::SeqCst);
+        TOTAL_REDUCTIONS.fetch_add(run_reductions, Ordering::SeqCst);
 
         let mut writable_status = self.status.write();
 
@@ -1073,6 +1139,38 @@ impl Process {
         self.exit(atom!("normal"), source);
     }
 
+    /// Suspends the process until a matching `resume`.  Suspends nest, so the returned count of
+    /// outstanding suspends must drop back to `0` before the process can run again.
+    pub fn suspend(&self) -> usize {
+        self.suspend_count.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Undoes one `suspend`, returning the count of outstanding suspends, or `None` if the process
+    /// was not suspended.
+    pub fn resume(&self) -> Option<usize> {
+        let mut current = self.suspend_count.load(Ordering::SeqCst);
+
+        loop {
+            if current == 0 {
+                break None;
+            }
+
+            match self.suspend_count.compare_exchange(
+                current,
+                current - 1,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => break Some(current - 1),
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        0 < self.suspend_count.load(Ordering::SeqCst)
+    }
+
     pub fn is_exiting(&self) -> bool {
         if let Status::Exiting(_) = *self.status.read() {
             true
@@ -1267,6 +1365,15 @@ unsafe impl Sync for Process {}
 
 type Reductions = u16;
 
+/// The reductions of all processes in their completed `run`s
+static TOTAL_REDUCTIONS: AtomicU64 = AtomicU64::new(0);
+
+/// The reductions of all processes since the VM started, not counting the current `run` of any
+/// running processes.
+pub fn total_reductions() -> u64 {
+    TOTAL_REDUCTIONS.load(Ordering::SeqCst)
+}
+
 // [BEAM statuses](https://github.com/erlang/otp/blob/551d03fe8232a66daf1c9a106194aa38ef660ef6/erts/emulator/beam/erl_process.c#L8944-L8972)
 #[derive(Debug, PartialEq)]
 pub enum Status {
@@ -1283,4 +1390,4 @@ impl Default for Status {
 }
 
 #[cfg(test)]
-mod test;
+pub(in crate::erts) mod test;
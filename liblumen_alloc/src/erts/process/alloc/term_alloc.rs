@@ -29,13 +29,13 @@ use super::{Heap, VirtualAllocator};
 pub trait TermAlloc: Heap {
     /// Constructs a binary from the given byte slice, and associated with the given process
     ///
-    /// For inputs greater than 64 bytes in size, the resulting binary data is allocated
+    /// For inputs greater than `heap_bin_limit()` bytes in size, the resulting binary data is allocated
     /// on the global shared heap, and reference counted (a `ProcBin`), the header to that
     /// binary is allocated on the process heap, and the data is placed in the processes'
     /// virtual binary heap, and a boxed term is returned which can then be placed on the stack,
     /// or as part of a larger structure if desired.
     ///
-    /// For inputs less than or equal to 64 bytes, both the header and data are allocated
+    /// For inputs less than or equal to `heap_bin_limit()` bytes, both the header and data are allocated
     /// on the process heap, and a boxed term is returned as described above.
     ///
     /// NOTE: If allocation fails for some reason, `Err(Alloc)` is returned, this usually
@@ -47,8 +47,8 @@ pub trait TermAlloc: Heap {
     {
         let len = bytes.len();
 
-        // Allocate ProcBins for sizes greater than 64 bytes
-        if len > 64 {
+        // Allocate ProcBins for sizes greater than the heap binary limit
+        if len > heap_bin_limit() {
             match self.procbin_from_bytes(bytes) {
                 Err(error) => Err(error),
                 Ok(bin_ptr) => {
@@ -63,6 +63,36 @@ pub trait TermAlloc: Heap {
         }
     }
 
+    /// Like `binary_from_bytes`, but the `len` bytes are written in place by `write`, so that
+    /// large binaries don't need to be built in a separate buffer and then copied.  The bytes
+    /// are zeroed before `write` is called.
+    fn binary_from_fn<F>(&mut self, len: usize, write: F) -> AllocResult<Term>
+    where
+        Self: VirtualAllocator<ProcBin>,
+        F: FnOnce(&mut [u8]),
+    {
+        if len > heap_bin_limit() {
+            // Allocates on global heap
+            let bin = ProcBin::from_fn(len, Encoding::Raw, write)?;
+
+            let bin_ptr = unsafe {
+                // Allocates space on the process heap for the header
+                let ptr = self.alloc_layout(Layout::new::<ProcBin>())?.as_ptr() as *mut ProcBin;
+                // Write the header to the process heap
+                ptr.write(bin);
+
+                Boxed::new_unchecked(ptr)
+            };
+
+            // Add the binary to the process's virtual binary heap
+            self.virtual_alloc(bin_ptr);
+
+            Ok(bin_ptr.into())
+        } else {
+            HeapBin::from_fn(self, len, Encoding::Raw, write).map(|nn| nn.into())
+        }
+    }
+
     /// Either returns a `&[u8]` to the pre-existing bytes in the heap binary, process binary, or
     /// aligned subbinary or creates a new aligned binary and returns the bytes from that new
     /// binary.
@@ -101,13 +131,13 @@ pub trait TermAlloc: Heap {
 
     /// Constructs a binary from the given string, and associated with the given process
     ///
-    /// For inputs greater than 64 bytes in size, the resulting binary data is allocated
+    /// For inputs greater than `heap_bin_limit()` bytes in size, the resulting binary data is allocated
     /// on the global shared heap, and reference counted (a `ProcBin`), the header to that
     /// binary is allocated on the process heap, and the data is placed in the processes'
     /// virtual binary heap, and a boxed term is returned which can then be placed on the stack,
     /// or as part of a larger structure if desired.
     ///
-    /// For inputs less than or equal to 64 bytes, both the header and data are allocated
+    /// For inputs less than or equal to `heap_bin_limit()` bytes, both the header and data are allocated
     /// on the process heap, and a boxed term is returned as described above.
     ///
     /// NOTE: If allocation fails for some reason, `Err(Alloc)` is returned, this usually
@@ -118,8 +148,8 @@ pub trait TermAlloc: Heap {
         Self: VirtualAllocator<ProcBin>,
     {
         let len = s.len();
-        // Allocate ProcBins for sizes greater than 64 bytes
-        if len > HeapBin::MAX_SIZE {
+        // Allocate ProcBins for sizes greater than the heap binary limit
+        if len > heap_bin_limit() {
             match self.procbin_from_str(s) {
                 Err(error) => Err(error),
                 Ok(bin_ptr) => {
@@ -262,7 +292,7 @@ pub trait TermAlloc: Heap {
     }
 
     /// Constructs a map and associated with the given process.
-    fn map_from_hash_map(&mut self, hash_map: HashMap<Term, Term>) -> AllocResult<Boxed<Map>>
+    fn map_from_hash_map(&mut self, hash_map: HashMap<MapKey, Term>) -> AllocResult<Boxed<Map>>
     where
         Self: Sized,
     {
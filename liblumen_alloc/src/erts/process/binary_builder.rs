@@ -0,0 +1,117 @@
+use alloc::vec::Vec;
+
+use crate::erts::exception::AllocResult;
+use crate::erts::term::prelude::*;
+
+use super::Process;
+
+/// Builds a binary for `process` by appending bytes and binaries, for runtime code that assembles
+/// a binary piece by piece instead of from one slice.
+///
+/// The bytes are buffered off the process heap, which grows geometrically, so appending is
+/// amortized constant time per byte.  `finish` copies them once into a heap binary or, above
+/// `heap_bin_limit()` bytes, a `ProcBin`, like `Process::binary_from_bytes`.
+pub struct BinaryBuilder<'process> {
+    process: &'process Process,
+    bytes: Vec<u8>,
+}
+
+impl<'process> BinaryBuilder<'process> {
+    pub fn new(process: &'process Process) -> Self {
+        Self::with_capacity(process, 0)
+    }
+
+    /// Reserves `capacity` bytes up front, for when the final size is known or can be estimated
+    pub fn with_capacity(process: &'process Process, capacity: usize) -> Self {
+        Self {
+            process,
+            bytes: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn append_byte(&mut self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
+    pub fn append_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Appends the bytes of `binary`, which can be any binary, but not a bitstring with a partial
+    /// byte
+    pub fn append_binary(&mut self, binary: Term) -> Result<(), BytesFromBinaryError> {
+        let bytes = self.process.bytes_from_binary(binary)?;
+        self.bytes.extend_from_slice(bytes);
+
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn finish(self) -> AllocResult<Term> {
+        self.process.binary_from_bytes(&self.bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::erts::process::test::process;
+
+    #[test]
+    fn with_byte_and_binary_appends_finishes_with_bytes_in_order() {
+        let process = process();
+        let heap_binary = process.binary_from_bytes(&[3, 4]).unwrap();
+        let proc_bin_bytes: Vec<u8> = (0..100).collect();
+        let proc_bin = process.binary_from_bytes(&proc_bin_bytes).unwrap();
+
+        let mut binary_builder = process.binary_builder();
+        binary_builder.append_byte(1);
+        binary_builder.append_bytes(&[2]);
+        binary_builder.append_binary(heap_binary).unwrap();
+        binary_builder.append_byte(5);
+        binary_builder.append_binary(proc_bin).unwrap();
+
+        let mut expected_bytes = vec![1, 2, 3, 4, 5];
+        expected_bytes.extend_from_slice(&proc_bin_bytes);
+
+        assert_eq!(binary_builder.len(), expected_bytes.len());
+
+        let binary = binary_builder.finish().unwrap();
+
+        assert!(binary.is_boxed_procbin());
+        assert_eq!(
+            process.bytes_from_binary(binary).unwrap(),
+            &expected_bytes[..]
+        );
+    }
+
+    #[test]
+    fn with_bytes_up_to_heap_bin_limit_finishes_with_heap_binary() {
+        let process = process();
+
+        let mut binary_builder = process.binary_builder();
+        binary_builder.append_bytes(&[0; 8]);
+
+        let binary = binary_builder.finish().unwrap();
+
+        assert!(binary.is_boxed_heapbin());
+        assert_eq!(process.bytes_from_binary(binary).unwrap(), &[0; 8]);
+    }
+
+    #[test]
+    fn with_non_binary_append_binary_errors() {
+        let process = process();
+        let mut binary_builder = process.binary_builder();
+
+        assert!(binary_builder.append_binary(Term::NIL).is_err());
+        assert!(binary_builder.is_empty());
+    }
+}
@@ -40,6 +40,12 @@ impl ProcessHeap {
         self.heap.should_collect(gc_threshold)
     }
 
+    /// The size of the young and old generations in words
+    #[inline]
+    pub fn total_heap_size(&self) -> usize {
+        self.heap.young_generation().heap_size() + self.heap.old_generation().heap_size()
+    }
+
     #[cfg(test)]
     pub(super) fn heap(&self) -> &SemispaceProcessHeap {
         &self.heap
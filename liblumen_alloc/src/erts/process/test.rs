@@ -68,7 +68,7 @@ mod integer {
     }
 }
 
-pub(super) fn process() -> Process {
+pub(in crate::erts) fn process() -> Process {
     let init = atom_from_str!("init");
     let initial_module_function_arity = Arc::new(ModuleFunctionArity {
         module: init,
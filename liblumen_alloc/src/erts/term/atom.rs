@@ -59,6 +59,12 @@ pub fn dump_atoms() {
     table.dump();
 }
 
+/// The approximate number of bytes used by the atom table for the names and their lookup entries
+pub fn atom_table_size() -> usize {
+    let table = ATOMS.read();
+    table.size()
+}
+
 /// An interned string, represented in memory as a integer ID.
 ///
 /// This struct is simply a transparent wrapper around the ID.
@@ -388,6 +394,16 @@ impl AtomTable {
         Ok(id)
     }
 
+    fn size(&self) -> usize {
+        // Each atom has an entry in both `ids` and `names`
+        let entry_size = 2 * (mem::size_of::<&'static str>() + mem::size_of::<usize>());
+
+        self.names
+            .values()
+            .map(|name| name.len() + entry_size)
+            .sum()
+    }
+
     fn dump(&self) {
         for (id, name) in self.names.iter() {
             println!("atom(id = {}, value = '{}')", *id, name);
@@ -10,14 +10,25 @@ mod process;
 mod sub;
 
 use core::fmt;
+use core::slice;
 use core::str::Utf8Error;
 
+use alloc::boxed::Box;
+
 use thiserror::Error;
 
 use crate::erts::exception::Alloc;
 use crate::erts::string::Encoding;
 
-use super::prelude::Boxed;
+use super::prelude::{Boxed, HeapBin};
+
+/// The largest number of bytes stored in a `HeapBin` on the process heap.  Anything bigger is
+/// stored in a reference-counted `ProcBin`, so every place that builds a binary must use this
+/// to pick the representation.
+#[inline]
+pub fn heap_bin_limit() -> usize {
+    HeapBin::MAX_SIZE
+}
 
 // This module provides a limited set of exported types/traits for convenience
 pub mod prelude {
@@ -31,10 +42,12 @@ pub mod prelude {
     pub use super::{Binary, Bitstring, IndexByte, MaybePartialByte};
     // Expose the type for binary flags
     pub use super::BinaryFlags;
+    // Expose the size that decides between heap binaries and reference-counted binaries
+    pub use super::heap_bin_limit;
     // Expose the concrete binary types
     pub use super::heap::HeapBin;
     pub use super::literal::BinaryLiteral;
-    pub use super::match_context::MatchContext;
+    pub use super::match_context::{Endianness, MatchContext};
     pub use super::process::ProcBin;
     pub use super::sub::SubBinary;
     // Expose the error types
@@ -69,6 +82,22 @@ pub trait Bitstring {
     /// safe. For bitstrings specifically, you should use one of the iterators
     /// supplied by the `iter` module.
     unsafe fn as_byte_ptr(&self) -> *mut u8;
+
+    /// Iterator of all the bits, most-significant bit first, with `1` as `true` and `0` as
+    /// `false`, including any in a final partial byte.
+    ///
+    /// By default, the bits are those of the `full_byte_len` bytes starting at `as_byte_ptr`, so
+    /// bitstrings with a bit offset or partial byte must override this.
+    fn bit_iter(&self) -> Box<dyn Iterator<Item = bool> + '_> {
+        let bytes = unsafe { slice::from_raw_parts(self.as_byte_ptr(), self.full_byte_len()) };
+
+        Box::new(
+            bytes
+                .iter()
+                .flat_map(|byte| iter::BitsIter::new(*byte))
+                .map(|bit| bit == 1),
+        )
+    }
 }
 
 impl<T: ?Sized + Bitstring> Bitstring for Boxed<T> {
@@ -81,6 +110,11 @@ impl<T: ?Sized + Bitstring> Bitstring for Boxed<T> {
     default unsafe fn as_byte_ptr(&self) -> *mut u8 {
         self.as_ref().as_byte_ptr()
     }
+
+    #[inline]
+    default fn bit_iter(&self) -> Box<dyn Iterator<Item = bool> + '_> {
+        self.as_ref().bit_iter()
+    }
 }
 
 /// This trait provides common behavior for all binary types which represent a collection of bytes
@@ -339,3 +373,42 @@ impl From<BytesFromBinaryError> for StrFromBinaryError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::erts::process::alloc::TermAlloc;
+    use crate::erts::term::prelude::*;
+    use crate::erts::testing::RegionHeap;
+
+    #[test]
+    fn heap_bin_limit_bytes_is_heap_binary() {
+        let mut heap = RegionHeap::default();
+        let bytes = vec![0; heap_bin_limit()];
+
+        let binary = heap.binary_from_bytes(&bytes).unwrap();
+
+        assert!(binary.is_boxed_heapbin());
+    }
+
+    #[test]
+    fn heap_bin_limit_plus_one_bytes_is_process_binary() {
+        let mut heap = RegionHeap::default();
+        let bytes = vec![0; heap_bin_limit() + 1];
+
+        let binary = heap.binary_from_bytes(&bytes).unwrap();
+
+        assert!(binary.is_boxed_procbin());
+    }
+
+    #[test]
+    fn heap_bin_limit_applies_to_str() {
+        let mut heap = RegionHeap::default();
+        let under: String = core::iter::repeat('a').take(heap_bin_limit()).collect();
+        let over: String = core::iter::repeat('a').take(heap_bin_limit() + 1).collect();
+
+        assert!(heap.binary_from_str(&under).unwrap().is_boxed_heapbin());
+        assert!(heap.binary_from_str(&over).unwrap().is_boxed_procbin());
+    }
+}
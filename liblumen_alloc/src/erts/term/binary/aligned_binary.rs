@@ -3,6 +3,9 @@ use core::fmt;
 use core::hash;
 use core::str;
 
+use alloc::borrow::Cow;
+use alloc::string::String;
+
 use anyhow::*;
 
 use crate::erts::term::prelude::Boxed;
@@ -26,6 +29,15 @@ pub trait AlignedBinary: Binary {
         );
         unsafe { str::from_utf8_unchecked(self.as_bytes()) }
     }
+
+    /// Converts this binary to a `str` regardless of encoding, replacing invalid UTF-8 sequences
+    /// with `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// When the binary is valid UTF-8, the `str` borrows from `self` without copying.
+    #[inline]
+    fn as_str_lossy(&self) -> Cow<str> {
+        String::from_utf8_lossy(self.as_bytes())
+    }
 }
 
 impl<T: ?Sized + AlignedBinary> AlignedBinary for Boxed<T> {
@@ -252,3 +264,40 @@ macro_rules! partial_eq_aligned_binary_aligned_binary {
 partial_eq_aligned_binary_aligned_binary!(HeapBin for ProcBin);
 partial_eq_aligned_binary_aligned_binary!(HeapBin for BinaryLiteral);
 partial_eq_aligned_binary_aligned_binary!(ProcBin for BinaryLiteral);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::erts::process::alloc::TermAlloc;
+    use crate::erts::string::Encoding;
+    use crate::erts::testing::RegionHeap;
+
+    #[test]
+    fn as_str_lossy_with_valid_utf8_borrows_str() {
+        let mut heap = RegionHeap::default();
+        let heap_bin = heap.heapbin_from_str("héllo").unwrap();
+        let proc_bin = ProcBin::from_str("wörld").unwrap();
+
+        assert_eq!(heap_bin.as_str_lossy(), Cow::Borrowed("héllo"));
+        assert_eq!(proc_bin.as_str_lossy(), Cow::Borrowed("wörld"));
+    }
+
+    #[test]
+    fn as_str_lossy_with_invalid_utf8_replaces_with_replacement_character() {
+        let mut heap = RegionHeap::default();
+        // latin1 `é` is not valid UTF-8
+        let heap_bin = heap.heapbin_from_bytes(&[b'c', b'a', b'f', 0xE9]).unwrap();
+        let proc_bin = ProcBin::from_slice(&[0xFF, b'o', b'k'], Encoding::Raw).unwrap();
+
+        let heap_str = heap_bin.as_str_lossy();
+        let proc_str = proc_bin.as_str_lossy();
+
+        assert_eq!(heap_str, "caf\u{FFFD}");
+        assert!(match heap_str {
+            Cow::Owned(_) => true,
+            Cow::Borrowed(_) => false,
+        });
+        assert_eq!(proc_str, "\u{FFFD}ok");
+    }
+}
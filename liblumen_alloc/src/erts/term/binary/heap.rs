@@ -59,6 +59,37 @@ impl HeapBin {
         }
     }
 
+    /// Creates a new `HeapBin` of `len` bytes that are written in place by `write`, so that they
+    /// don't need to be built in a separate buffer first.  The bytes are zeroed before `write`
+    /// is called.
+    pub fn from_fn<A, F>(
+        heap: &mut A,
+        len: usize,
+        encoding: Encoding,
+        write: F,
+    ) -> AllocResult<Boxed<Self>>
+    where
+        A: ?Sized + HeapAlloc,
+        F: FnOnce(&mut [u8]),
+    {
+        let (layout, flags_offset, data_offset) = Self::layout_for_len(len);
+
+        unsafe {
+            match heap.alloc_layout(layout) {
+                Ok(non_null) => {
+                    let dst = non_null.as_ptr() as *mut u8;
+                    Self::write_header_and_flags(dst, len, encoding, flags_offset);
+                    let data_ptr = dst.add(data_offset);
+                    ptr::write_bytes(data_ptr, 0, len);
+                    write(slice::from_raw_parts_mut(data_ptr, len));
+
+                    Ok(Self::from_raw_parts(dst, len))
+                }
+                Err(_) => Err(alloc!()),
+            }
+        }
+    }
+
     // This function handles the low-level parts of creating a `HeapBin` at the given pointer
     #[inline]
     unsafe fn copy_slice_to_internal(
@@ -69,24 +100,38 @@ impl HeapBin {
         data_offset: usize,
     ) -> Boxed<Self> {
         let len = s.len();
-        // Write header
+        Self::write_header_and_flags(dst, len, encoding, flags_offset);
+        let data_ptr = dst.add(data_offset);
+        ptr::copy_nonoverlapping(s.as_ptr(), data_ptr, len);
+
+        Self::from_raw_parts(dst, len)
+    }
+
+    unsafe fn write_header_and_flags(
+        dst: *mut u8,
+        len: usize,
+        encoding: Encoding,
+        flags_offset: usize,
+    ) {
         let arity = erts::to_word_size(len + mem::size_of::<BinaryFlags>());
         let header = Header::from_arity(arity);
         ptr::write(dst as *mut Header<HeapBin>, header);
         let flags_ptr = dst.offset(flags_offset as isize) as *mut BinaryFlags;
         let flags = BinaryFlags::new(encoding).set_size(len);
         ptr::write(flags_ptr, flags);
-        let data_ptr = dst.add(data_offset);
-        ptr::copy_nonoverlapping(s.as_ptr(), data_ptr, len);
-
-        Self::from_raw_parts(dst, len)
     }
 
     fn layout_for(s: &[u8]) -> (Layout, usize, usize) {
+        Self::layout_for_len(s.len())
+    }
+
+    fn layout_for_len(len: usize) -> (Layout, usize, usize) {
         let (base_layout, flags_offset) = Layout::new::<Header<HeapBin>>()
             .extend(Layout::new::<BinaryFlags>())
             .unwrap();
-        let (unpadded_layout, data_offset) = base_layout.extend(Layout::for_value(s)).unwrap();
+        let (unpadded_layout, data_offset) = base_layout
+            .extend(Layout::array::<u8>(len).unwrap())
+            .unwrap();
         // We pad to alignment so that the Layout produced here
         // matches that returned by `Layout::for_value` on the
         // final `HeapBin`
@@ -5,6 +5,9 @@ use core::ptr;
 use core::slice;
 
 use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use num_bigint::BigInt;
 
 use liblumen_core::util::pointer::distance_absolute;
 
@@ -13,7 +16,7 @@ use crate::erts::exception::AllocResult;
 use crate::erts::process::alloc::TermAlloc;
 use crate::erts::term::prelude::*;
 
-use super::prelude::{byte_offset, num_bytes};
+use super::prelude::{bit_offset, byte_offset, num_bytes};
 
 /// Represents a binary being matched
 ///
@@ -81,6 +84,14 @@ impl MatchBuffer {
     }
 }
 
+/// The byte order of an integer segment, such as the `big` in `<<A:16/big>>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    Big,
+    Little,
+    Native,
+}
+
 /// Used in match contexts
 ///
 /// See `ErlBinMatchState` and `ErlBinMatchBuffer` in `erl_bits.h`
@@ -243,6 +254,152 @@ impl MatchContext {
         }
     }
 }
+impl MatchContext {
+    /// The number of bits left to match
+    #[inline]
+    pub fn bits_remaining(&self) -> usize {
+        self.buffer.bit_len - self.buffer.bit_offset
+    }
+
+    /// Matches `<<Bytes:len/binary>>`, copying the bytes out of the binary.  The bytes don't need
+    /// to be aligned.
+    ///
+    /// Returns `None` without advancing if fewer than `len` bytes remain.
+    pub fn match_bytes(&mut self, len: usize) -> Option<Vec<u8>> {
+        if self.bits_remaining() < len * 8 {
+            return None;
+        }
+
+        let bytes = (0..len)
+            .map(|index| self.byte_at(self.buffer.bit_offset + index * 8))
+            .collect();
+        self.buffer.bit_offset += len * 8;
+
+        Some(bytes)
+    }
+
+    /// Matches `<<Integer:bit_len>>` with the given signedness and endianness.
+    ///
+    /// Like BEAM, when `bit_len` is not a multiple of 8, little endian puts the full bytes first
+    /// and the remaining most significant bits last.
+    ///
+    /// Returns `None` without advancing if fewer than `bit_len` bits remain.
+    pub fn match_integer(
+        &mut self,
+        bit_len: usize,
+        signed: bool,
+        endianness: Endianness,
+    ) -> Option<Integer> {
+        if self.bits_remaining() < bit_len {
+            return None;
+        }
+
+        let start = self.buffer.bit_offset;
+        let full_byte_len = byte_offset(bit_len);
+        let partial_byte_bit_len = bit_offset(bit_len);
+
+        let big_endian_bytes: Vec<u8> = (0..full_byte_len)
+            .map(|index| self.byte_at(start + index * 8))
+            .collect();
+        let partial_byte = (0..partial_byte_bit_len).fold(0_u8, |acc, index| {
+            (acc << 1) | self.bit_at(start + full_byte_len * 8 + index)
+        });
+
+        let little = match endianness {
+            Endianness::Big => false,
+            Endianness::Little => true,
+            Endianness::Native => cfg!(target_endian = "little"),
+        };
+
+        let integer = if bit_len <= 64 {
+            // Fits in a `u64`, so only wider fields need a `BigInt`
+            let unsigned = if little {
+                // The partial byte has the most significant bits, then the full bytes from last to
+                // first.
+                big_endian_bytes
+                    .iter()
+                    .rev()
+                    .fold(partial_byte as u64, |acc, byte| (acc << 8) | (*byte as u64))
+            } else {
+                let full_bytes = big_endian_bytes
+                    .iter()
+                    .fold(0_u64, |acc, byte| (acc << 8) | (*byte as u64));
+
+                (full_bytes << partial_byte_bit_len) | (partial_byte as u64)
+            };
+
+            if signed && 0 < bit_len {
+                // Shifts the sign bit to the top and back to sign-extend it
+                let unused_bit_len = 64 - bit_len;
+
+                (((unsigned << unused_bit_len) as i64) >> unused_bit_len).into()
+            } else {
+                unsigned.into()
+            }
+        } else {
+            let unsigned = if little {
+                big_endian_bytes
+                    .iter()
+                    .rev()
+                    .fold(BigInt::from(partial_byte), |acc, byte| {
+                        (acc << 8) | BigInt::from(*byte)
+                    })
+            } else {
+                let full_bytes = big_endian_bytes.iter().fold(BigInt::from(0), |acc, byte| {
+                    (acc << 8) | BigInt::from(*byte)
+                });
+
+                (full_bytes << partial_byte_bit_len) | BigInt::from(partial_byte)
+            };
+
+            let big_int = if signed && (&unsigned >> (bit_len - 1)) == BigInt::from(1) {
+                unsigned - (BigInt::from(1) << bit_len)
+            } else {
+                unsigned
+            };
+
+            big_int.into()
+        };
+
+        self.buffer.bit_offset += bit_len;
+
+        Some(integer)
+    }
+
+    /// Matches `<<Binary:bit_len/bitstring>>` as a `SubBinary` of the original binary, so the
+    /// bits are not copied.  Use `bits_remaining` as the `bit_len` to match the tail, like
+    /// `Rest/bitstring`.
+    ///
+    /// Returns `None` without advancing if fewer than `bit_len` bits remain.
+    pub fn match_binary(&mut self, bit_len: usize) -> Option<SubBinary> {
+        if self.bits_remaining() < bit_len {
+            None
+        } else {
+            Some(SubBinary::from_match(self, bit_len))
+        }
+    }
+
+    fn bit_at(&self, bit_position: usize) -> u8 {
+        let byte = unsafe { *self.buffer.base.add(byte_offset(bit_position)) };
+
+        (byte >> (7 - bit_offset(bit_position))) & 0b1
+    }
+
+    /// The 8 bits starting at `bit_position`, which may span two bytes when unaligned
+    fn byte_at(&self, bit_position: usize) -> u8 {
+        let index = byte_offset(bit_position);
+        let shift = bit_offset(bit_position);
+        let high = unsafe { *self.buffer.base.add(index) };
+
+        if shift == 0 {
+            high
+        } else {
+            let low = unsafe { *self.buffer.base.add(index + 1) };
+
+            (high << shift) | (low >> (8 - shift))
+        }
+    }
+}
 impl TryFrom<TypedTerm> for Boxed<MatchContext> {
     type Error = TypeError;
 
@@ -262,6 +419,15 @@ impl Bitstring for MatchContext {
     unsafe fn as_byte_ptr(&self) -> *mut u8 {
         self.buffer.base
     }
+
+    fn bit_iter(&self) -> Box<dyn Iterator<Item = bool> + '_> {
+        Box::new(
+            self.full_byte_iter()
+                .flat_map(BitsIter::new)
+                .chain(self.partial_byte_bit_iter())
+                .map(|bit| bit == 1),
+        )
+    }
 }
 
 impl CloneToProcess for MatchContext {
@@ -370,3 +536,106 @@ impl MaybePartialByte for MatchContext {
         (self.buffer.bit_len + (8 - 1)) / 8
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::erts::testing::RegionHeap;
+
+    #[test]
+    fn match_integer_8_then_integer_16_then_binary_tail() {
+        let mut heap = RegionHeap::default();
+        let binary = heap.binary_from_bytes(&[1, 2, 3, 4, 5]).unwrap();
+        let mut match_context = MatchContext::new(binary);
+
+        assert_eq!(
+            match_context.match_integer(8, false, Endianness::Big),
+            Some(1.into())
+        );
+        assert_eq!(
+            match_context.match_integer(16, false, Endianness::Big),
+            Some(0x0203.into())
+        );
+
+        let rest = match_context
+            .match_binary(match_context.bits_remaining())
+            .unwrap();
+
+        assert_eq!(rest.full_byte_iter().collect::<Vec<u8>>(), vec![4, 5]);
+        assert_eq!(match_context.bits_remaining(), 0);
+    }
+
+    #[test]
+    fn match_integer_with_signed_little_endian() {
+        let mut heap = RegionHeap::default();
+        let binary = heap.binary_from_bytes(&[0xFE, 0xFF, 0x02, 0x01]).unwrap();
+        let mut match_context = MatchContext::new(binary);
+
+        assert_eq!(
+            match_context.match_integer(16, true, Endianness::Little),
+            Some((-2).into())
+        );
+        assert_eq!(
+            match_context.match_integer(16, true, Endianness::Little),
+            Some(0x0102.into())
+        );
+    }
+
+    #[test]
+    fn match_integer_with_64_and_72_bits() {
+        let mut heap = RegionHeap::default();
+        let binary = heap
+            .binary_from_bytes(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x01, 0x00])
+            .unwrap();
+        let mut match_context = MatchContext::new(binary);
+
+        assert_eq!(
+            match_context.match_integer(64, true, Endianness::Big),
+            Some((-2_i64).into())
+        );
+
+        let mut match_context = MatchContext::new(binary);
+
+        assert_eq!(
+            match_context.match_integer(64, false, Endianness::Big),
+            Some(0xFFFF_FFFF_FFFF_FFFE_u64.into())
+        );
+
+        let mut match_context = MatchContext::new(binary);
+
+        assert_eq!(
+            match_context.match_integer(72, false, Endianness::Big),
+            Some(0xFF_FFFF_FFFF_FFFF_FE01_u128.into())
+        );
+    }
+
+    #[test]
+    fn match_bytes_after_unaligned_integer() {
+        let mut heap = RegionHeap::default();
+        let binary = heap.binary_from_bytes(&[0b1010_1010, 0b1010_1010]).unwrap();
+        let mut match_context = MatchContext::new(binary);
+
+        assert_eq!(
+            match_context.match_integer(1, false, Endianness::Big),
+            Some(1.into())
+        );
+        assert_eq!(match_context.match_bytes(1), Some(vec![0b0101_0101]));
+        assert_eq!(match_context.bits_remaining(), 7);
+    }
+
+    #[test]
+    fn match_without_enough_bits_does_not_advance() {
+        let mut heap = RegionHeap::default();
+        let binary = heap.binary_from_bytes(&[1, 2]).unwrap();
+        let mut match_context = MatchContext::new(binary);
+
+        assert_eq!(
+            match_context.match_integer(17, false, Endianness::Big),
+            None
+        );
+        assert_eq!(match_context.match_bytes(3), None);
+        assert!(match_context.match_binary(17).is_none());
+        assert_eq!(match_context.bits_remaining(), 16);
+    }
+}
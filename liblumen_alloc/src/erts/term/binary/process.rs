@@ -1,6 +1,7 @@
 use core::alloc::Layout;
 use core::convert::TryFrom;
 use core::fmt::{self, Debug};
+use core::hash::{BuildHasher, Hash, Hasher};
 use core::iter;
 use core::ptr::{self, NonNull};
 use core::slice;
@@ -10,13 +11,35 @@ use core::sync::atomic::{self, AtomicUsize};
 use intrusive_collections::LinkedListLink;
 use liblumen_core::offset_of;
 
+use anyhow::*;
+use hashbrown::hash_map::DefaultHashBuilder;
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::Mutex;
+
 use crate::borrow::CloneToProcess;
-use crate::erts::exception::AllocResult;
-use crate::erts::process::alloc::TermAlloc;
+use crate::erts::exception::{AllocResult, InternalResult};
+use crate::erts::process::alloc::{TermAlloc, VirtualAllocator};
 use crate::erts::process::Process;
 use crate::erts::string::Encoding;
 use crate::erts::term::prelude::*;
 
+#[cfg(not(test))]
+use liblumen_core::sys::alloc as sys_alloc;
+
+// Checks that procbins are freed with the same layout they were allocated with
+#[cfg(test)]
+use self::tests::checking_alloc as sys_alloc;
+
+lazy_static! {
+    /// The procbins created by `ProcBin::from_slice_interned` used by the runtime system
+    static ref INTERNED: Mutex<InternedTable> = Default::default();
+}
+
+/// The bytes of all `ProcBinInner` allocations that have not been freed
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
 /// This is the header written alongside all procbin binaries in the heap,
 /// it owns the refcount and the raw binary data
 ///
@@ -55,6 +78,24 @@ impl ProcBinInner {
         &self.data
     }
 
+    /// Produces the layout of the allocation for this struct with `len` bytes of data.  This
+    /// must be used both when allocating and freeing, so that they agree.
+    ///
+    /// Returns the layout + the offset of the flags field + the offset of the data field
+    #[inline]
+    fn layout(len: usize) -> (Layout, usize, usize) {
+        let (base_layout, flags_offset) = Self::base_layout();
+        let (unpadded_layout, data_offset) = base_layout
+            .extend(Layout::array::<u8>(len).unwrap())
+            .unwrap();
+        // We pad to alignment so that the Layout produced here
+        // matches that returned by `Layout::for_value` on the
+        // final `ProcBinInner`
+        let layout = unpadded_layout.pad_to_align();
+
+        (layout, flags_offset, data_offset)
+    }
+
     /// Produces the base layout for this struct, before the
     /// dynamically sized data is factored in.
     ///
@@ -118,6 +159,12 @@ impl ProcBin {
         offset_of!(ProcBin, inner)
     }
 
+    /// The bytes allocated for the data of all procbins, shared by any number of references, that
+    /// have not been freed yet
+    pub fn allocated_bytes() -> usize {
+        ALLOCATED_BYTES.load(atomic::Ordering::Acquire)
+    }
+
     /// Creates a new procbin from a str slice, by copying it to the heap
     pub fn from_str(s: &str) -> AllocResult<Self> {
         let encoding = Encoding::from_str(s);
@@ -125,20 +172,31 @@ impl ProcBin {
         Self::from_slice(s.as_bytes(), encoding)
     }
 
+    /// Creates a new procbin from a str slice that is recorded as UTF-8, by copying it to the heap
+    ///
+    /// Unlike `from_str`, the encoding is not re-detected, so `as_str` can later return the `str`
+    /// without rescanning the bytes.
+    pub fn from_utf8_str(s: &str) -> AllocResult<Self> {
+        Self::from_slice(s.as_bytes(), Encoding::Utf8)
+    }
+
     /// Creates a new procbin from a raw byte slice, by copying it to the heap
     pub fn from_slice(s: &[u8], encoding: Encoding) -> AllocResult<Self> {
-        use liblumen_core::sys::alloc as sys_alloc;
+        Self::from_fn(s.len(), encoding, |data| data.copy_from_slice(s))
+    }
 
-        let (base_layout, flags_offset) = ProcBinInner::base_layout();
-        let (unpadded_layout, data_offset) = base_layout.extend(Layout::for_value(s)).unwrap();
-        // We pad to alignment so that the Layout produced here
-        // matches that returned by `Layout::for_value` on the
-        // final `ProcBinInner`
-        let layout = unpadded_layout.pad_to_align();
+    /// Creates a new procbin of `len` bytes that are written in place by `write`, so that they
+    /// don't need to be built in a separate buffer first.  The bytes are zeroed before `write`
+    /// is called.
+    pub fn from_fn<F>(len: usize, encoding: Encoding, write: F) -> AllocResult<Self>
+    where
+        F: FnOnce(&mut [u8]),
+    {
+        let (layout, flags_offset, data_offset) = ProcBinInner::layout(len);
 
         unsafe {
             let (non_null, _) = sys_alloc::alloc(layout)?;
-            let len = s.len();
+            ALLOCATED_BYTES.fetch_add(layout.size(), atomic::Ordering::AcqRel);
 
             let ptr: *mut u8 = non_null.as_ptr();
             ptr::write(ptr as *mut AtomicUsize, AtomicUsize::new(1));
@@ -146,7 +204,8 @@ impl ProcBin {
             let flags = BinaryFlags::new(encoding).set_size(len);
             ptr::write(flags_ptr, flags);
             let data_ptr = ptr.offset(data_offset as isize);
-            ptr::copy_nonoverlapping(s.as_ptr(), data_ptr, len);
+            ptr::write_bytes(data_ptr, 0, len);
+            write(slice::from_raw_parts_mut(data_ptr, len));
 
             let inner = ProcBinInner::from_raw_parts(ptr, len);
             Ok(Self {
@@ -157,22 +216,81 @@ impl ProcBin {
         }
     }
 
+    /// Like `from_slice`, but if a procbin with identical bytes and encoding was already created
+    /// with this function, a clone of that procbin sharing its data is returned instead of
+    /// copying `s` to the heap again.
+    ///
+    /// Interned procbins are never freed, so this should only be used for data that is created
+    /// repeatedly, such as literals.
+    pub fn from_slice_interned(s: &[u8], encoding: Encoding) -> AllocResult<Self> {
+        INTERNED.lock().get_or_insert(s, encoding)
+    }
+
+    /// Returns the data as a `str` only if the flags record it as UTF-8, such as when created with
+    /// `from_utf8_str`, so no validation is done.
+    ///
+    /// Unlike `AlignedBinary::as_str`, this does not panic for other encodings, including latin1.
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        if self.is_utf8() {
+            Some(unsafe { str::from_utf8_unchecked(self.as_bytes()) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a subbinary of the `len` bytes starting at `offset` that aliases this procbin's data
+    /// instead of copying it.
+    ///
+    /// The subbinary's original is a clone of this procbin's header written to `heap`, so the
+    /// refcount is bumped and the data stays alive even after this handle is dropped.
+    pub fn subslice<A>(
+        &self,
+        heap: &mut A,
+        offset: usize,
+        len: usize,
+    ) -> InternalResult<Boxed<SubBinary>>
+    where
+        A: ?Sized + TermAlloc + VirtualAllocator<Self>,
+    {
+        let full_byte_len = self.full_byte_len();
+
+        match offset.checked_add(len) {
+            Some(end) if end <= full_byte_len => {
+                let original = unsafe {
+                    // Allocate before cloning, so that a failed allocation can't leave the
+                    // refcount bumped
+                    let ptr = heap.alloc_layout(Layout::new::<Self>())?.as_ptr() as *mut Self;
+                    ptr.write(self.clone());
+
+                    Boxed::new_unchecked(ptr)
+                };
+                heap.virtual_alloc(original);
+
+                heap.subbinary_from_original(original.into(), offset, 0, len, 0)
+                    .map_err(From::from)
+            }
+            _ => Err(anyhow!(
+                "offset ({}) and len ({}) exceed procbin byte len ({})",
+                offset,
+                len,
+                full_byte_len
+            )
+            .into()),
+        }
+    }
+
     #[inline]
     fn inner(&self) -> &ProcBinInner {
         unsafe { self.inner.as_ref() }
     }
 
-    // Non-inlined part of `drop`.
+    // Non-inlined part of `drop`, which is only called once the refcount has reached zero.
     #[inline(never)]
     unsafe fn drop_slow(&self) {
-        use liblumen_core::sys::alloc as sys_alloc;
-
-        if self.inner().refc.fetch_sub(1, atomic::Ordering::Release) == 1 {
-            atomic::fence(atomic::Ordering::Acquire);
-            let inner = self.inner.as_ref();
-            let layout = Layout::for_value(&inner);
-            sys_alloc::free(inner as *const _ as *mut u8, layout);
-        }
+        let (layout, _, _) = ProcBinInner::layout(self.inner().data.len());
+        sys_alloc::free(self.inner.as_ptr() as *mut u8, layout);
+        ALLOCATED_BYTES.fetch_sub(layout.size(), atomic::Ordering::AcqRel);
     }
 
     #[inline]
@@ -180,6 +298,39 @@ impl ProcBin {
         self.inner().as_bytes().iter().copied()
     }
 }
+/// Interned procbins are bucketed by the hash of their data, but found by exact comparison, so
+/// that colliding hashes can't return the wrong data
+#[derive(Default)]
+struct InternedTable {
+    hash_builder: DefaultHashBuilder,
+    procbins_by_hash: HashMap<u64, Vec<ProcBin>>,
+}
+impl InternedTable {
+    fn get_or_insert(&mut self, s: &[u8], encoding: Encoding) -> AllocResult<ProcBin> {
+        let mut hasher = self.hash_builder.build_hasher();
+        s.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let procbins = self.procbins_by_hash.entry(hash).or_insert_with(Vec::new);
+
+        match procbins
+            .iter()
+            .find(|procbin| procbin.encoding() == encoding && procbin.as_bytes() == s)
+        {
+            Some(procbin) => Ok(procbin.clone()),
+            None => {
+                let procbin = ProcBin::from_slice(s, encoding)?;
+                let clone = procbin.clone();
+                procbins.push(procbin);
+
+                Ok(clone)
+            }
+        }
+    }
+}
+// The procbin data is shared between processes and its refcount is atomic
+unsafe impl Send for InternedTable {}
+
 impl Bitstring for ProcBin {
     #[inline]
     fn full_byte_len(&self) -> usize {
@@ -217,16 +368,39 @@ impl Clone for ProcBin {
 }
 
 impl CloneToProcess for ProcBin {
-    fn clone_to_process(&self, process: &Process) -> Term {
+    fn clone_to_process(&self, process: &Process) -> AllocResult<Term> {
+        // A `ProcBin` owned by `process` was already pushed on to its virtual heap when it was
+        // written, so cloning it again would only add a redundant reference and entry
+        if process.is_owner(self as *const Self) {
+            let boxed: Boxed<Self> = self.into();
+
+            return Ok(boxed.into());
+        }
+
         let mut heap = process.acquire_heap();
-        let boxed = self.clone_to_heap(&mut heap).unwrap();
+        let boxed = match self.clone_to_heap(&mut heap) {
+            Ok(term) => {
+                // `virtual_alloc` needs to acquire the heap too
+                drop(heap);
+
+                term
+            }
+            Err(_) => {
+                drop(heap);
+                // `clone_to_heap` only bumps the refcount once the clone is written, so if a heap
+                // fragment can't be allocated either, the refcount is left unchanged
+                let (term, mut frag) = self.clone_to_fragment()?;
+                process.attach_fragment(unsafe { frag.as_mut() });
+
+                term
+            }
+        };
         let ptr: *mut Self = boxed.dyn_cast();
-        self.inner().refc.fetch_add(1, atomic::Ordering::AcqRel);
         // Reify a reference to the newly written clone, and push it
         // on to the process virtual heap
         let clone = unsafe { &*ptr };
         process.virtual_alloc(clone);
-        boxed
+        Ok(boxed)
     }
 
     fn clone_to_heap<A>(&self, heap: &mut A) -> AllocResult<Term>
@@ -237,15 +411,10 @@ impl CloneToProcess for ProcBin {
             // Allocate space for the header
             let layout = Layout::new::<Self>();
             let ptr = heap.alloc_layout(layout)?.as_ptr() as *mut Self;
-            // Write the binary header with an empty link
-            ptr::write(
-                ptr,
-                Self {
-                    header: self.header.clone(),
-                    inner: self.inner,
-                    link: LinkedListLink::new(),
-                },
-            );
+            // Write the binary header with an empty link.  The written header is another
+            // reference to `inner`, so the refcount is bumped, but only now that allocation
+            // succeeded, so that a failed allocation leaves the refcount unchanged
+            ptr::write(ptr, self.clone());
             // Reify result term
             Ok(ptr.into())
         }
@@ -333,3 +502,311 @@ impl TryFrom<TypedTerm> for Boxed<ProcBin> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::convert::TryInto;
+    use core::mem;
+
+    use liblumen_core::sys::sysconf::MIN_ALIGN;
+
+    use crate::erts::process::alloc::VirtualHeap;
+    use crate::erts::process::test::process;
+    use crate::erts::testing::RegionHeap;
+
+    /// `sys::alloc`, but remembering the layout of each allocation, so that `free` can assert that
+    /// it is passed the same layout
+    pub(super) mod checking_alloc {
+        use core::alloc::{AllocErr, Layout};
+        use core::cell::RefCell;
+        use core::ptr::NonNull;
+
+        use hashbrown::HashMap;
+        use lazy_static::lazy_static;
+
+        use liblumen_core::locks::Mutex;
+        use liblumen_core::sys::alloc as sys_alloc;
+
+        lazy_static! {
+            static ref LAYOUT_BY_ADDRESS: Mutex<HashMap<usize, Layout>> = Default::default();
+        }
+
+        std::thread_local! {
+            static LAST_FREED: RefCell<Option<(usize, Layout)>> = RefCell::new(None);
+        }
+
+        pub unsafe fn alloc(layout: Layout) -> Result<(NonNull<u8>, usize), AllocErr> {
+            let (non_null, size) = sys_alloc::alloc(layout)?;
+            LAYOUT_BY_ADDRESS
+                .lock()
+                .insert(non_null.as_ptr() as usize, layout);
+
+            Ok((non_null, size))
+        }
+
+        pub unsafe fn free(ptr: *mut u8, layout: Layout) {
+            let address = ptr as usize;
+            let allocated_layout = LAYOUT_BY_ADDRESS.lock().remove(&address);
+
+            assert_eq!(
+                allocated_layout,
+                Some(layout),
+                "{:p} is not freed with the layout it was allocated with",
+                ptr
+            );
+
+            LAST_FREED.with(|last_freed| *last_freed.borrow_mut() = Some((address, layout)));
+            sys_alloc::free(ptr, layout)
+        }
+
+        /// The layout that the allocation at `address` was allocated with, if it is not freed
+        pub fn allocated_layout(address: usize) -> Option<Layout> {
+            LAYOUT_BY_ADDRESS.lock().get(&address).copied()
+        }
+
+        /// The address and layout of the last allocation freed by this thread
+        pub fn last_freed() -> Option<(usize, Layout)> {
+            LAST_FREED.with(|last_freed| *last_freed.borrow())
+        }
+    }
+
+    #[test]
+    fn from_utf8_str_as_str_returns_str() {
+        let bin = ProcBin::from_utf8_str("héllo wörld").unwrap();
+
+        assert!(bin.is_utf8());
+        assert_eq!(bin.as_str(), Some("héllo wörld"));
+    }
+
+    #[test]
+    fn from_utf8_str_with_ascii_records_utf8() {
+        let bin = ProcBin::from_utf8_str("hello world").unwrap();
+
+        assert!(bin.is_utf8());
+        assert_eq!(bin.as_str(), Some("hello world"));
+    }
+
+    #[test]
+    fn from_str_with_latin1_as_str_returns_none() {
+        let bin = ProcBin::from_str("hello world").unwrap();
+
+        assert!(bin.is_latin1());
+        assert_eq!(bin.as_str(), None);
+    }
+
+    #[test]
+    fn from_fn_writes_bytes_in_place() {
+        let bin = ProcBin::from_fn(100, Encoding::Raw, |data| {
+            for (index, byte) in data.iter_mut().enumerate() {
+                *byte = index as u8;
+            }
+        })
+        .unwrap();
+        let bytes: Vec<u8> = (0..100).collect();
+
+        assert_eq!(bin.as_bytes(), &bytes[..]);
+    }
+
+    #[test]
+    fn from_fn_zeroes_bytes_before_write() {
+        let bin = ProcBin::from_fn(100, Encoding::Raw, |_| {}).unwrap();
+
+        assert_eq!(bin.as_bytes(), &[0; 100][..]);
+    }
+
+    #[test]
+    fn subslice_keeps_data_alive_after_original_is_dropped() {
+        let mut heap = RegionHeap::default();
+        let bytes: Vec<u8> = (0..100).collect();
+        let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+
+        let subbinary = bin.subslice(&mut heap, 10, 5).unwrap();
+
+        assert_eq!(bin.inner().refc.load(atomic::Ordering::SeqCst), 2);
+
+        drop(bin);
+
+        let subbinary_bytes: Vec<u8> = subbinary.full_byte_iter().collect();
+
+        assert_eq!(subbinary_bytes, vec![10, 11, 12, 13, 14]);
+    }
+
+    #[test]
+    fn subslice_out_of_range_errors() {
+        let mut heap = RegionHeap::default();
+        let bytes: Vec<u8> = (0..100).collect();
+        let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+
+        assert!(bin.subslice(&mut heap, 98, 5).is_err());
+        assert!(bin.subslice(&mut heap, usize::max_value(), 1).is_err());
+        assert_eq!(bin.inner().refc.load(atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn clone_to_process_counts_written_clone() {
+        let process = process();
+        let bytes: Vec<u8> = (0..100).collect();
+        let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+
+        let clone_term = bin.clone_to_process(&process).unwrap();
+
+        assert_eq!(bin.inner().refc.load(atomic::Ordering::SeqCst), 2);
+
+        let clone: Boxed<ProcBin> = clone_term.decode().unwrap().try_into().unwrap();
+
+        assert_eq!(clone.as_bytes(), bin.as_bytes());
+    }
+
+    #[test]
+    fn clone_to_process_of_owned_binary_returns_same_term() {
+        let process = process();
+        let bytes: Vec<u8> = (0..100).collect();
+        let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+        let owned_term = bin.clone_to_process(&process).unwrap();
+        let owned: Boxed<ProcBin> = owned_term.decode().unwrap().try_into().unwrap();
+        let virtual_heap_used = process.acquire_heap().virtual_heap_used();
+
+        let clone_term = owned.as_ref().clone_to_process(&process).unwrap();
+        let clone: Boxed<ProcBin> = clone_term.decode().unwrap().try_into().unwrap();
+
+        assert_eq!(clone.as_ptr(), owned.as_ptr());
+        assert_eq!(bin.inner().refc.load(atomic::Ordering::SeqCst), 2);
+        assert_eq!(
+            process.acquire_heap().virtual_heap_used(),
+            virtual_heap_used
+        );
+    }
+
+    #[test]
+    fn clone_to_heap_failure_leaves_refcount_unchanged() {
+        let layout = Layout::from_size_align(mem::size_of::<Term>(), MIN_ALIGN).unwrap();
+        let mut heap = RegionHeap::new(layout);
+        let bytes: Vec<u8> = (0..100).collect();
+        let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+
+        assert!(bin.clone_to_heap(&mut heap).is_err());
+        assert!(bin.subslice(&mut heap, 0, 1).is_err());
+        assert_eq!(bin.inner().refc.load(atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn from_slice_interned_with_same_bytes_shares_inner() {
+        let bytes: Vec<u8> = (0..100).map(|i: u8| i.wrapping_mul(2)).collect();
+
+        let first = ProcBin::from_slice_interned(&bytes, Encoding::Raw).unwrap();
+        let second = ProcBin::from_slice_interned(&bytes, Encoding::Raw).unwrap();
+
+        assert_eq!(first.inner, second.inner);
+        assert_eq!(second.as_bytes(), &bytes[..]);
+    }
+
+    #[test]
+    fn from_slice_interned_with_different_bytes_does_not_share_inner() {
+        let bytes: Vec<u8> = (0..100).map(|i: u8| i.wrapping_mul(3)).collect();
+        let mut other_bytes = bytes.clone();
+        other_bytes[99] += 1;
+
+        let first = ProcBin::from_slice_interned(&bytes, Encoding::Raw).unwrap();
+        let second = ProcBin::from_slice_interned(&other_bytes, Encoding::Raw).unwrap();
+
+        assert_ne!(first.inner, second.inner);
+        assert_eq!(second.as_bytes(), &other_bytes[..]);
+    }
+
+    #[test]
+    fn layout_matches_layout_for_value_of_inner() {
+        for len in 0..=256 {
+            let bytes = vec![0xAA; len];
+            let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+            let (layout, _, _) = ProcBinInner::layout(len);
+
+            assert_eq!(layout, Layout::for_value(bin.inner()));
+        }
+    }
+
+    #[test]
+    fn dropping_procbins_of_many_lengths_frees_with_allocated_layout() {
+        for len in 0..=1024 {
+            let bytes: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+            let clone = bin.clone();
+            let address = bin.inner.as_ptr() as usize;
+            let layout = checking_alloc::allocated_layout(address).unwrap();
+
+            drop(bin);
+
+            assert_eq!(checking_alloc::allocated_layout(address), Some(layout));
+
+            // `checking_alloc::free` panics if it is passed a different layout
+            drop(clone);
+
+            assert_eq!(checking_alloc::last_freed(), Some((address, layout)));
+        }
+    }
+
+    #[test]
+    fn dropping_clones_frees_only_after_last_reference() {
+        for len in (0..=4096).step_by(61) {
+            let bytes: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+            let clone = bin.clone();
+
+            drop(bin);
+
+            assert_eq!(clone.inner().refc.load(atomic::Ordering::SeqCst), 1);
+            assert_eq!(clone.as_bytes(), &bytes[..]);
+        }
+    }
+
+    #[test]
+    fn clone_to_heap_then_dropping_clone_restores_refcount() {
+        let mut heap = RegionHeap::default();
+        let bytes: Vec<u8> = (0..100).collect();
+        let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+        let refc = || bin.inner().refc.load(atomic::Ordering::SeqCst);
+        let original_refc = refc();
+
+        let clone_term = bin.clone_to_heap(&mut heap).unwrap();
+
+        assert_eq!(refc(), original_refc + 1);
+
+        let clone: Boxed<ProcBin> = clone_term.decode().unwrap().try_into().unwrap();
+        unsafe { ptr::drop_in_place(clone.as_ptr()) };
+
+        assert_eq!(refc(), original_refc);
+    }
+
+    #[test]
+    fn clone_to_process_then_dropping_process_restores_refcount() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let bin = ProcBin::from_slice(&bytes, Encoding::Raw).unwrap();
+        let refc = || bin.inner().refc.load(atomic::Ordering::SeqCst);
+        let original_refc = refc();
+        let process = process();
+
+        bin.clone_to_process(&process).unwrap();
+
+        assert_eq!(refc(), original_refc + 1);
+
+        // Dropping the process frees its virtual heap, which drops the clone
+        drop(process);
+
+        assert_eq!(refc(), original_refc);
+    }
+
+    #[test]
+    fn allocated_bytes_includes_live_procbins() {
+        let len = 1024 * 1024;
+        let before = ProcBin::allocated_bytes();
+        let bin = ProcBin::from_slice(&vec![0; len], Encoding::Raw).unwrap();
+
+        // other tests concurrently allocate and free small procbins
+        assert!(before + len / 2 <= ProcBin::allocated_bytes());
+
+        drop(bin);
+
+        assert!(ProcBin::allocated_bytes() + len / 2 <= before + len);
+    }
+}
@@ -53,9 +53,13 @@ impl SubBinary {
     /// See erts_bs_get_binary_2 in erl_bits.c:460
     #[inline]
     pub fn from_match(ctx: &mut MatchContext, bit_len: usize) -> Self {
-        assert!(ctx.buffer.bit_len - ctx.buffer.bit_offset < bit_len);
+        assert!(bit_len <= ctx.buffer.bit_len - ctx.buffer.bit_offset);
 
-        let original = ctx.buffer.original;
+        // The match offsets of a subbinary are relative to the binary it is a slice of
+        let original = match ctx.buffer.original.decode().unwrap() {
+            TypedTerm::SubBinary(subbinary) => subbinary.original(),
+            _ => ctx.buffer.original,
+        };
         let subbinary_byte_offset = byte_offset(ctx.buffer.bit_offset);
         let subbinary_bit_offset = bit_offset(ctx.buffer.bit_offset) as u8;
         let full_byte_len = byte_offset(bit_len);
@@ -120,7 +124,7 @@ impl SubBinary {
         if self.is_binary()
             && self.is_aligned()
             && !self.writable
-            && self.full_byte_len <= HeapBin::MAX_SIZE
+            && self.full_byte_len <= heap_bin_limit()
         {
             Ok(unsafe { self.to_raw_parts() })
         } else {
@@ -238,6 +242,15 @@ impl Bitstring for SubBinary {
             t => panic!("invalid term, expected binary but got {:?}", t),
         }
     }
+
+    fn bit_iter(&self) -> Box<dyn Iterator<Item = bool> + '_> {
+        Box::new(
+            self.full_byte_iter()
+                .flat_map(BitsIter::new)
+                .chain(self.partial_byte_bit_iter())
+                .map(|bit| bit == 1),
+        )
+    }
 }
 
 impl CloneToProcess for SubBinary {
@@ -388,3 +401,61 @@ impl TryFrom<TypedTerm> for Boxed<SubBinary> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::erts::testing::RegionHeap;
+
+    #[test]
+    fn bit_iter_respects_bit_offset_and_partial_byte() {
+        let mut heap = RegionHeap::default();
+        let original = heap
+            .heapbin_from_bytes(&[0b1010_1100, 0b0101_1111, 0b1111_1111])
+            .unwrap();
+        // 12 bits starting 2 bits into the first byte
+        let subbinary = heap
+            .subbinary_from_original(original.into(), 0, 2, 1, 4)
+            .unwrap();
+
+        let bits: Vec<bool> = subbinary.bit_iter().collect();
+
+        assert_eq!(
+            bits,
+            vec![true, false, true, true, false, false, false, true, false, true, true, true]
+        );
+    }
+
+    #[test]
+    fn bit_iter_through_bitstring_bound_respects_bit_offset_and_partial_byte() {
+        fn bits<T: Bitstring>(bitstring: &T) -> Vec<bool> {
+            bitstring.bit_iter().collect()
+        }
+
+        let mut heap = RegionHeap::default();
+        let original = heap
+            .heapbin_from_bytes(&[0b0111_1111, 0b1000_0000])
+            .unwrap();
+        // 3 bits starting 7 bits into the first byte
+        let subbinary = heap
+            .subbinary_from_original(original.into(), 0, 7, 0, 3)
+            .unwrap();
+
+        assert_eq!(bits(subbinary.as_ref()), vec![true, true, false]);
+        assert_eq!(bits(&subbinary), vec![true, true, false]);
+    }
+
+    #[test]
+    fn bit_iter_of_aligned_binary_yields_all_bits() {
+        let mut heap = RegionHeap::default();
+        let heap_bin = heap.heapbin_from_bytes(&[0b1000_0001]).unwrap();
+
+        let bits: Vec<bool> = heap_bin.bit_iter().collect();
+
+        assert_eq!(
+            bits,
+            vec![true, false, false, false, false, false, false, true]
+        );
+    }
+}
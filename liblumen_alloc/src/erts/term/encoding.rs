@@ -244,7 +244,7 @@ impl<T: ?Sized> Debug for Header<T> {
 }
 const_assert_eq!(mem::size_of::<Header<usize>>(), mem::size_of::<usize>());
 impl Header<Map> {
-    pub fn from_map(map: &HashMap<Term, Term>) -> Self {
+    pub fn from_map(map: &HashMap<MapKey, Term>) -> Self {
         // NOTE: This size only accounts for the HashMap header, not the values
         let layout = Layout::for_value(map);
         let map_size = layout.size();
@@ -648,9 +648,9 @@ pub trait Encoded: Repr + Copy {
 }
 
 impl CloneToProcess for Term {
-    fn clone_to_process(&self, process: &crate::erts::process::Process) -> Term {
+    fn clone_to_process(&self, process: &crate::erts::process::Process) -> AllocResult<Term> {
         if self.is_immediate() || self.is_literal() {
-            *self
+            Ok(*self)
         } else if self.is_boxed() || self.is_non_empty_list() {
             let tt = self.decode().unwrap();
             tt.clone_to_process(process)
@@ -44,8 +44,8 @@ impl Float {
 
 impl CloneToProcess for Float {
     #[inline]
-    fn clone_to_process(&self, _process: &Process) -> Term {
-        self.encode().unwrap()
+    fn clone_to_process(&self, _process: &Process) -> AllocResult<Term> {
+        Ok(self.encode().unwrap())
     }
 
     #[inline]
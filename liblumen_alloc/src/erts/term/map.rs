@@ -2,46 +2,88 @@ use core::alloc::Layout;
 use core::cmp;
 use core::convert::{TryFrom, TryInto};
 use core::fmt::{self, Debug, Display, Write};
-use core::hash::{Hash, Hasher};
+use core::hash::{BuildHasher, Hash, Hasher};
 use core::mem;
 use core::ptr;
 
 use alloc::vec::Vec;
 
 use anyhow::*;
+use hashbrown::hash_map::DefaultHashBuilder;
 use hashbrown::HashMap;
+use lazy_static::lazy_static;
 
 use crate::erts::exception::{AllocResult, InternalResult};
 use crate::erts::process::alloc::TermAlloc;
 
 use super::prelude::*;
 
+lazy_static! {
+    /// The hasher of every `Map` on a heap, so that the key hashes of different maps are
+    /// comparable.
+    static ref HASH_BUILDER: DefaultHashBuilder = DefaultHashBuilder::new();
+}
+
+/// A key of a `Map`.  Unlike `Term`, keys are compared with exact equality (`=:=`), so that `1`
+/// and `1.0` are different keys, like in OTP.  Exactly equal terms have the same type, so they
+/// hash the same.
+#[derive(Clone, Copy, Debug, Hash)]
+#[repr(transparent)]
+pub struct MapKey(pub Term);
+
+impl Display for MapKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Eq for MapKey {}
+
+impl From<Term> for MapKey {
+    fn from(term: Term) -> Self {
+        Self(term)
+    }
+}
+
+impl PartialEq for MapKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0
+            .decode()
+            .unwrap()
+            .exact_eq(&other.0.decode().unwrap())
+    }
+}
+
 #[derive(Clone)]
 #[repr(C)]
 pub struct Map {
     header: Header<Map>,
-    value: HashMap<Term, Term>,
+    value: HashMap<MapKey, Term>,
+    /// Computed when the `Map` is constructed, as mutators return a new `HashMap` for a new `Map`
+    /// and `iter_mut` only changes values.
+    key_hash: u64,
 }
 
 impl Map {
-    pub(in crate::erts) fn from_hash_map(value: HashMap<Term, Term>) -> Self {
+    pub(in crate::erts) fn from_hash_map(value: HashMap<MapKey, Term>) -> Self {
         Self {
             header: Header::from_map(&value),
+            key_hash: key_hash(&value),
             value,
         }
     }
 
     pub(in crate::erts) fn from_slice(slice: &[(Term, Term)]) -> Self {
-        let mut value: HashMap<Term, Term> = HashMap::with_capacity(slice.len());
+        let mut value: HashMap<MapKey, Term> = HashMap::with_capacity(slice.len());
 
         for (entry_key, entry_value) in slice {
-            value.insert(*entry_key, *entry_value);
+            value.insert(MapKey(*entry_key), *entry_value);
         }
 
         Self::from_hash_map(value)
     }
 
-    pub fn from_list(list: Term) -> InternalResult<HashMap<Term, Term>> {
+    pub fn from_list(list: Term) -> InternalResult<HashMap<MapKey, Term>> {
         match list.decode()? {
             TypedTerm::Nil => Ok(HashMap::new()),
             TypedTerm::List(cons_ptr) => {
@@ -56,7 +98,7 @@ impl Map {
                             })?;
 
                             if tuple.len() == 2 {
-                                map.insert(tuple[0], tuple[1]);
+                                map.insert(MapKey(tuple[0]), tuple[1]);
                             } else {
                                 return Err(anyhow!(
                                     "element ({}) of list ({}) is not a 2-arity tuple",
@@ -83,13 +125,13 @@ impl Map {
     }
 
     pub fn get(&self, key: Term) -> Option<Term> {
-        self.value.get(&key).copied()
+        self.value.get(&MapKey(key)).copied()
     }
 
-    pub fn take(&self, key: Term) -> Option<(Term, HashMap<Term, Term>)> {
+    pub fn take(&self, key: Term) -> Option<(Term, HashMap<MapKey, Term>)> {
         if self.is_key(key) {
             let mut map = self.value.clone();
-            let value = map.remove(&key).unwrap();
+            let value = map.remove(&MapKey(key)).unwrap();
 
             Some((value, map))
         } else {
@@ -98,78 +140,194 @@ impl Map {
     }
 
     pub fn is_key(&self, key: Term) -> bool {
-        self.value.contains_key(&key)
+        self.value.contains_key(&MapKey(key))
     }
 
+    /// The keys in sorted order, so that the order is reproducible.
     pub fn keys(&self) -> Vec<Term> {
-        self.value.keys().into_iter().copied().collect()
+        self.sorted_keys()
     }
 
+    /// The values in the same order as their keys in `keys`.
     pub fn values(&self) -> Vec<Term> {
-        self.value.values().into_iter().copied().collect()
+        self.iter_sorted().map(|(_, value)| value).collect()
     }
 
     pub fn len(&self) -> usize {
         self.value.len()
     }
 
-    pub fn remove(&self, key: Term) -> Option<HashMap<Term, Term>> {
+    pub fn remove(&self, key: Term) -> Option<HashMap<MapKey, Term>> {
         if self.is_key(key) {
             let mut map = self.value.clone();
-            map.remove(&key);
+            map.remove(&MapKey(key));
             Some(map)
         } else {
             None
         }
     }
 
-    pub fn update(&self, key: Term, value: Term) -> Option<HashMap<Term, Term>> {
+    pub fn update(&self, key: Term, value: Term) -> Option<HashMap<MapKey, Term>> {
         if self.is_key(key) {
             let mut map = self.value.clone();
-            map.insert(key, value);
+            map.insert(MapKey(key), value);
             Some(map)
         } else {
             None
         }
     }
 
-    pub fn put(&self, key: Term, value: Term) -> Option<HashMap<Term, Term>> {
-        if self.get(key).map_or(false, |val| val == value) {
+    pub fn put(&self, key: Term, value: Term) -> Option<HashMap<MapKey, Term>> {
+        // Only an exactly equal value is unchanged, so `1.0` replaces `1`
+        if self.get(key).map_or(false, |val| {
+            val.decode().unwrap().exact_eq(&value.decode().unwrap())
+        }) {
             None
         } else {
             let mut map = self.value.clone();
-            map.insert(key, value);
+            map.insert(MapKey(key), value);
             Some(map)
         }
     }
 
-    pub fn iter(&self) -> hashbrown::hash_map::Iter<Term, Term> {
-        self.value.iter()
+    /// Returns the union of `self` and `other`, with the value from `other` when both have the
+    /// same key.  Only the larger map is cloned, and only the smaller map's entries are inserted.
+    pub(in crate::erts) fn merge(&self, other: &Map) -> HashMap<MapKey, Term> {
+        if other.len() <= self.len() {
+            let mut map = self.value.clone();
+
+            for (key, value) in other.value.iter() {
+                map.insert(*key, *value);
+            }
+
+            map
+        } else {
+            let mut map = other.value.clone();
+
+            for (key, value) in self.value.iter() {
+                map.entry(*key).or_insert(*value);
+            }
+
+            map
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Term, &Term)> {
+        self.value.iter().map(|(MapKey(key), value)| (key, value))
     }
 
-    pub fn iter_mut(&mut self) -> hashbrown::hash_map::IterMut<Term, Term> {
-        self.value.iter_mut()
+    /// Iterates the entries in key order, unlike `iter`, whose order depends on the hashing, so
+    /// that anything serialized from the entries, such as `term_to_binary`, is reproducible.
+    pub fn iter_sorted(&self) -> impl DoubleEndedIterator<Item = (Term, Term)> + '_ {
+        self.sorted_keys()
+            .into_iter()
+            .map(move |key| (key, self.value[&MapKey(key)]))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&Term, &mut Term)> {
+        self.value
+            .iter_mut()
+            .map(|(MapKey(key), value)| (key, value))
     }
 
     // Private
 
     fn sorted_keys(&self) -> Vec<Term> {
         let mut key_vec: Vec<Term> = Vec::new();
-        key_vec.extend(self.value.keys());
-        key_vec.sort_unstable_by(|key1, key2| key1.cmp(&key2));
+        key_vec.extend(self.value.keys().map(|MapKey(key)| *key));
+        key_vec.sort_unstable_by(key_cmp);
 
         key_vec
     }
 }
 
-impl AsRef<HashMap<Term, Term>> for Boxed<Map> {
-    fn as_ref(&self) -> &HashMap<Term, Term> {
+/// A hash of only the keys, as values are compared with `==`, which is `true` for numbers like `1`
+/// and `1.0` that hash differently.  The key hashes are summed instead of hashed in sorted order,
+/// because keys like `{1}` and `{1.0}` are neither ordered by `key_cmp` nor the same key.
+fn key_hash(value: &HashMap<MapKey, Term>) -> u64 {
+    value.keys().fold(0_u64, |acc, key| {
+        let mut hasher = HASH_BUILDER.build_hasher();
+        key.hash(&mut hasher);
+
+        acc.wrapping_add(hasher.finish())
+    })
+}
+
+/// Term order, except that an integer is less than a float of the same value, so that keys like
+/// `1` and `1.0`, which are `==`, but different keys, still have a total order.
+fn key_cmp(key1: &Term, key2: &Term) -> cmp::Ordering {
+    match key1.cmp(key2) {
+        cmp::Ordering::Equal => match (key1.decode(), key2.decode()) {
+            (Ok(TypedTerm::SmallInteger(_)), Ok(TypedTerm::Float(_)))
+            | (Ok(TypedTerm::BigInteger(_)), Ok(TypedTerm::Float(_))) => cmp::Ordering::Less,
+            (Ok(TypedTerm::Float(_)), Ok(TypedTerm::SmallInteger(_)))
+            | (Ok(TypedTerm::Float(_)), Ok(TypedTerm::BigInteger(_))) => cmp::Ordering::Greater,
+            _ => cmp::Ordering::Equal,
+        },
+        ordering => ordering,
+    }
+}
+
+fn key_vec_cmp(key_vec1: &[Term], key_vec2: &[Term]) -> cmp::Ordering {
+    for (key1, key2) in key_vec1.iter().zip(key_vec2.iter()) {
+        match key_cmp(key1, key2) {
+            cmp::Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+
+    key_vec1.len().cmp(&key_vec2.len())
+}
+
+/// Accumulates entries for a `Map`, so that building a map in a loop only copies the entries to
+/// the heap once in `build` instead of cloning the whole map for each entry like `Map::put`.
+///
+/// Like `maps:from_list/1`, when a key is inserted more than once, the last value wins.
+#[derive(Debug, Default)]
+pub struct MapBuilder {
+    value: HashMap<MapKey, Term>,
+}
+
+impl MapBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            value: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the value previously inserted for `key`, if any.
+    pub fn insert(&mut self, key: Term, value: Term) -> Option<Term> {
+        self.value.insert(MapKey(key), value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    pub fn build<A>(self, heap: &mut A) -> AllocResult<Boxed<Map>>
+    where
+        A: TermAlloc,
+    {
+        heap.map_from_hash_map(self.value)
+    }
+}
+
+impl AsRef<HashMap<MapKey, Term>> for Boxed<Map> {
+    fn as_ref(&self) -> &HashMap<MapKey, Term> {
         &self.as_ref().value
     }
 }
 
-impl AsRef<HashMap<Term, Term>> for Map {
-    fn as_ref(&self) -> &HashMap<Term, Term> {
+impl AsRef<HashMap<MapKey, Term>> for Map {
+    fn as_ref(&self) -> &HashMap<MapKey, Term> {
         &self.value
     }
 }
@@ -183,18 +341,22 @@ impl crate::borrow::CloneToProcess for Map {
         let ptr = unsafe { heap.alloc_layout(layout)?.as_ptr() };
 
         let self_value = &self.value;
-        let mut heap_value = HashMap::with_capacity(self_value.len());
+        let mut heap_value =
+            HashMap::with_capacity_and_hasher(self_value.len(), HASH_BUILDER.clone());
 
-        for (entry_key, entry_value) in self_value {
+        for (MapKey(entry_key), entry_value) in self_value {
             let heap_entry_key = entry_key.clone_to_heap(heap)?;
             let heap_entry_value = entry_value.clone_to_heap(heap)?;
-            heap_value.insert(heap_entry_key, heap_entry_value);
+
+            heap_value.insert(MapKey(heap_entry_key), heap_entry_value);
         }
 
         // Clone to ensure `value` remains valid if caller is dropped
         let heap_self = Self {
             header: self.header.clone(),
             value: heap_value,
+            // the cloned keys hash the same as the originals
+            key_hash: self.key_hash,
         };
 
         let size = mem::size_of_val(self);
@@ -244,7 +406,7 @@ impl Eq for Map {}
 impl Hash for Map {
     fn hash<H: Hasher>(&self, state: &mut H) {
         for key in self.sorted_keys() {
-            let value = self.value[&key];
+            let value = self.value[&MapKey(key)];
 
             key.hash(state);
             value.hash(state);
@@ -253,8 +415,9 @@ impl Hash for Map {
 }
 
 impl PartialEq for Map {
+    /// Maps with different sizes or key hashes are unequal without comparing their entries.
     fn eq(&self, other: &Map) -> bool {
-        self.value.eq(&other.value)
+        self.len() == other.len() && self.key_hash == other.key_hash && self.value.eq(&other.value)
     }
 }
 impl<T> PartialEq<Boxed<T>> for Map
@@ -292,17 +455,19 @@ impl Ord for Map {
                 let self_key_vec = self.sorted_keys();
                 let other_key_vec = other.sorted_keys();
 
-                match self_key_vec.cmp(&other_key_vec) {
+                match key_vec_cmp(&self_key_vec, &other_key_vec) {
                     cmp::Ordering::Equal => {
                         let self_value = &self.value;
                         let other_value = &other.value;
                         let mut final_ordering = cmp::Ordering::Equal;
 
                         for key in self_key_vec {
+                            let map_key = MapKey(key);
+
                             match self_value
-                                .get(&key)
+                                .get(&map_key)
                                 .unwrap()
-                                .cmp(other_value.get(&key).unwrap())
+                                .cmp(other_value.get(&map_key).unwrap())
                             {
                                 cmp::Ordering::Equal => continue,
                                 ordering => {
@@ -333,3 +498,304 @@ impl TryFrom<TypedTerm> for Boxed<Map> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::erts::testing::RegionHeap;
+
+    mod merge {
+        use super::*;
+
+        #[test]
+        fn matches_naive_merge() {
+            // Fixed LCG, so the "random" pairs are the same every run
+            let mut seed: u32 = 0x2545_f491;
+            let mut next = |bound: u32| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+
+                (seed >> 16) % bound
+            };
+
+            for _ in 0..20 {
+                let len1 = next(16);
+                let len2 = next(16);
+                let mut slice1 = Vec::new();
+                let mut slice2 = Vec::new();
+
+                for _ in 0..len1 {
+                    slice1.push((fixnum!(next(24) as isize), fixnum!(next(1_000) as isize)));
+                }
+
+                for _ in 0..len2 {
+                    slice2.push((fixnum!(next(24) as isize), fixnum!(next(1_000) as isize)));
+                }
+
+                let map1 = Map::from_slice(&slice1);
+                let map2 = Map::from_slice(&slice2);
+
+                let mut naive: HashMap<MapKey, Term> = HashMap::new();
+
+                for (key, value) in map1.iter().chain(map2.iter()) {
+                    naive.insert(MapKey(*key), *value);
+                }
+
+                assert_eq!(map1.merge(&map2), naive);
+            }
+        }
+
+        #[test]
+        fn with_smaller_second_map_second_value_wins() {
+            let first = Map::from_slice(&[
+                (fixnum!(1), fixnum!(10)),
+                (fixnum!(2), fixnum!(20)),
+                (fixnum!(3), fixnum!(30)),
+            ]);
+            let second = Map::from_slice(&[(fixnum!(2), fixnum!(200))]);
+
+            let merged = first.merge(&second);
+
+            assert_eq!(merged.len(), 3);
+            assert_eq!(merged[&MapKey(fixnum!(2))], fixnum!(200));
+        }
+
+        #[test]
+        fn with_larger_second_map_second_value_wins() {
+            let first = Map::from_slice(&[(fixnum!(2), fixnum!(20))]);
+            let second = Map::from_slice(&[
+                (fixnum!(1), fixnum!(100)),
+                (fixnum!(2), fixnum!(200)),
+                (fixnum!(3), fixnum!(300)),
+            ]);
+
+            let merged = first.merge(&second);
+
+            assert_eq!(merged.len(), 3);
+            assert_eq!(merged[&MapKey(fixnum!(2))], fixnum!(200));
+        }
+    }
+
+    mod cmp {
+        use super::*;
+
+        #[test]
+        fn with_integer_key_is_less_than_equal_float_key() {
+            let mut heap = RegionHeap::default();
+            let float_one: Term = heap.float(1.0).map(|f| f.into()).unwrap();
+            let value = fixnum!(0);
+
+            let integer_key_map = Map::from_slice(&[(fixnum!(1), value)]);
+            let float_key_map = Map::from_slice(&[(float_one, value)]);
+
+            assert_eq!(integer_key_map.cmp(&float_key_map), cmp::Ordering::Less);
+            assert_eq!(float_key_map.cmp(&integer_key_map), cmp::Ordering::Greater);
+            assert!(integer_key_map < float_key_map);
+        }
+
+        #[test]
+        fn with_integer_and_float_keys_sorts_integer_first() {
+            let mut heap = RegionHeap::default();
+            let float_one: Term = heap.float(1.0).map(|f| f.into()).unwrap();
+
+            let map = Map::from_slice(&[(float_one, fixnum!(2)), (fixnum!(1), fixnum!(1))]);
+            let sorted_vec: Vec<(Term, Term)> = map.iter_sorted().collect();
+
+            assert_eq!(
+                sorted_vec,
+                vec![(fixnum!(1), fixnum!(1)), (float_one, fixnum!(2))]
+            );
+        }
+
+        #[test]
+        fn with_same_keys_compares_values_in_key_order() {
+            let map = Map::from_slice(&[(fixnum!(1), fixnum!(1)), (fixnum!(2), fixnum!(3))]);
+            let greater_map =
+                Map::from_slice(&[(fixnum!(1), fixnum!(2)), (fixnum!(2), fixnum!(0))]);
+
+            assert_eq!(map.cmp(&greater_map), cmp::Ordering::Less);
+        }
+    }
+
+    mod eq {
+        use super::*;
+
+        #[test]
+        fn with_same_entries_in_different_insertion_orders_is_equal() {
+            let map = Map::from_slice(&[
+                (fixnum!(1), fixnum!(10)),
+                (fixnum!(2), fixnum!(20)),
+                (fixnum!(3), fixnum!(30)),
+            ]);
+            let reordered_map = Map::from_slice(&[
+                (fixnum!(3), fixnum!(30)),
+                (fixnum!(1), fixnum!(10)),
+                (fixnum!(2), fixnum!(20)),
+            ]);
+
+            assert_eq!(map, reordered_map);
+        }
+
+        #[test]
+        fn with_one_different_value_is_not_equal() {
+            let map = Map::from_slice(&[
+                (fixnum!(1), fixnum!(10)),
+                (fixnum!(2), fixnum!(20)),
+                (fixnum!(3), fixnum!(30)),
+            ]);
+            let different_map = Map::from_slice(&[
+                (fixnum!(3), fixnum!(30)),
+                (fixnum!(1), fixnum!(10)),
+                (fixnum!(2), fixnum!(21)),
+            ]);
+
+            assert_ne!(map, different_map);
+        }
+
+        #[test]
+        fn with_many_same_entries_in_different_insertion_orders_is_equal() {
+            let entry_vec: Vec<(Term, Term)> = (0..1_000_isize)
+                .map(|i| (fixnum!(i), fixnum!(-i)))
+                .collect();
+            let reversed_entry_vec: Vec<(Term, Term)> = entry_vec.iter().rev().copied().collect();
+
+            let map = Map::from_slice(&entry_vec);
+            let reversed_map = Map::from_slice(&reversed_entry_vec);
+
+            assert_eq!(map, reversed_map);
+        }
+
+        #[test]
+        fn with_one_different_key_is_not_equal() {
+            let map = Map::from_slice(&[(fixnum!(1), fixnum!(10)), (fixnum!(2), fixnum!(20))]);
+            let different_map =
+                Map::from_slice(&[(fixnum!(1), fixnum!(10)), (fixnum!(3), fixnum!(20))]);
+
+            assert_ne!(map, different_map);
+        }
+
+        #[test]
+        fn with_integer_and_float_values_of_same_number_is_equal() {
+            let mut heap = RegionHeap::default();
+            let float_one: Term = heap.float(1.0).map(|f| f.into()).unwrap();
+
+            let integer_value_map = Map::from_slice(&[(fixnum!(0), fixnum!(1))]);
+            let float_value_map = Map::from_slice(&[(fixnum!(0), float_one)]);
+
+            assert_eq!(integer_value_map, float_value_map);
+        }
+
+        #[bench]
+        fn bench_with_many_entries_and_one_different_key(bencher: &mut test::Bencher) {
+            let entry_vec: Vec<(Term, Term)> = (0..10_000_isize)
+                .map(|i| (fixnum!(i), fixnum!(i)))
+                .collect();
+            let mut different_entry_vec = entry_vec.clone();
+            different_entry_vec[9_999] = (fixnum!(10_000), fixnum!(9_999));
+
+            let map = Map::from_slice(&entry_vec);
+            let different_map = Map::from_slice(&different_entry_vec);
+
+            bencher.iter(|| map == different_map)
+        }
+    }
+
+    mod map_key {
+        use super::*;
+
+        #[test]
+        fn with_integer_and_float_keys_of_same_number_are_different_keys() {
+            let mut heap = RegionHeap::default();
+            let float_one: Term = heap.float(1.0).map(|f| f.into()).unwrap();
+
+            let map = heap
+                .map_from_slice(&[(fixnum!(1), fixnum!(10)), (float_one, fixnum!(20))])
+                .unwrap();
+
+            assert_eq!(map.len(), 2);
+            assert_eq!(map.get(fixnum!(1)), Some(fixnum!(10)));
+            assert_eq!(map.get(float_one), Some(fixnum!(20)));
+        }
+    }
+
+    mod iter_sorted {
+        use super::*;
+
+        #[test]
+        fn with_different_insertion_orders_is_identical() {
+            let entry_vec: Vec<(Term, Term)> = (0..32_isize)
+                .map(|i| (fixnum!((i * 7) % 32), fixnum!(i)))
+                .collect();
+            let reversed_entry_vec: Vec<(Term, Term)> = entry_vec.iter().rev().copied().collect();
+
+            let map = Map::from_slice(&entry_vec);
+            let reversed_map = Map::from_slice(&reversed_entry_vec);
+
+            let sorted_vec: Vec<(Term, Term)> = map.iter_sorted().collect();
+            let reversed_sorted_vec: Vec<(Term, Term)> = reversed_map.iter_sorted().collect();
+
+            assert_eq!(sorted_vec, reversed_sorted_vec);
+            assert_eq!(sorted_vec.len(), 32);
+            assert!(sorted_vec
+                .windows(2)
+                .all(|window| window[0].0 < window[1].0));
+        }
+    }
+
+    mod put {
+        use super::*;
+
+        #[test]
+        fn with_equal_but_not_exactly_equal_value_replaces_value() {
+            let mut heap = RegionHeap::default();
+            let float_one: Term = heap.float(1.0).map(|f| f.into()).unwrap();
+
+            let map = Map::from_slice(&[(fixnum!(0), fixnum!(1))]);
+            let hash_map = map.put(fixnum!(0), float_one).unwrap();
+
+            assert!(hash_map[&MapKey(fixnum!(0))]
+                .decode()
+                .unwrap()
+                .exact_eq(&float_one.decode().unwrap()));
+            assert!(map.put(fixnum!(0), fixnum!(1)).is_none());
+        }
+    }
+
+    mod map_builder {
+        use super::*;
+
+        #[test]
+        fn with_duplicate_keys_last_value_wins() {
+            let mut heap = RegionHeap::default();
+            let mut builder = MapBuilder::with_capacity(100);
+
+            for i in 0..100_isize {
+                builder.insert(fixnum!(i % 60), fixnum!(i));
+            }
+
+            assert_eq!(builder.len(), 60);
+
+            let map = builder.build(&mut heap).unwrap();
+
+            assert_eq!(map.len(), 60);
+
+            for key in 0..60_isize {
+                let last_value = if key < 40 { key + 60 } else { key };
+
+                assert_eq!(map.get(fixnum!(key)), Some(fixnum!(last_value)));
+            }
+        }
+
+        #[test]
+        fn without_entries_builds_empty_map() {
+            let mut heap = RegionHeap::default();
+            let builder = MapBuilder::new();
+
+            assert!(builder.is_empty());
+
+            let map = builder.build(&mut heap).unwrap();
+
+            assert_eq!(map.len(), 0);
+        }
+    }
+}
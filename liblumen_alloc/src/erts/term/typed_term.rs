@@ -647,15 +647,15 @@ impl PartialOrd<TypedTerm> for TypedTerm {
 }
 
 impl CloneToProcess for TypedTerm {
-    fn clone_to_process(&self, process: &Process) -> Term {
+    fn clone_to_process(&self, process: &Process) -> AllocResult<Term> {
         use TypedTerm::*;
         // Immediates are just copied and returned, all other terms
         // are expected to require allocation, so we delegate to those types
         match self {
-            &Atom(term) => term.encode().unwrap(),
-            &Pid(term) => term.encode().unwrap(),
-            &Port(term) => term.encode().unwrap(),
-            &SmallInteger(term) => term.encode().unwrap(),
+            &Atom(term) => Ok(term.encode().unwrap()),
+            &Pid(term) => Ok(term.encode().unwrap()),
+            &Port(term) => Ok(term.encode().unwrap()),
+            &SmallInteger(term) => Ok(term.encode().unwrap()),
             &Float(ref term) => term.clone_to_process(process),
             &List(term_ptr) => term_ptr.as_ref().clone_to_process(process),
             &Map(term_ptr) => term_ptr.as_ref().clone_to_process(process),
@@ -672,7 +672,7 @@ impl CloneToProcess for TypedTerm {
             &Tuple(term_ptr) => term_ptr.as_ref().clone_to_process(process),
             &Closure(term_ptr) => term_ptr.as_ref().clone_to_process(process),
             &HeapBinary(term_ptr) => term_ptr.as_ref().clone_to_process(process),
-            &Nil => Term::NIL,
+            &Nil => Ok(Term::NIL),
         }
     }
 
@@ -13,10 +13,15 @@
 #![feature(slice_index_methods)]
 // Support backtraces in errors
 #![feature(backtrace)]
+// for benchmarks in tests
+#![feature(test)]
 
 #[cfg_attr(not(test), macro_use)]
 extern crate alloc;
 
+#[cfg(test)]
+extern crate test;
+
 #[cfg(target_arch = "wasm32")]
 extern crate wasm_bindgen_test;
 
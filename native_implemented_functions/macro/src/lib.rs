@@ -391,6 +391,10 @@ impl Parse for FunctionArity {
                 ident.to_string()
             } else if let Ok(_) = input.parse::<Token![self]>() {
                 "self".to_string()
+            } else if let Ok(_) = input.parse::<Token![in]>() {
+                "in".to_string()
+            } else if let Ok(_) = input.parse::<Token![yield]>() {
+                "yield".to_string()
             } else if let Ok(_) = input.parse::<Token![*]>() {
                 "*".to_string()
             } else if let Ok(_) = input.parse::<Token![+]>() {
@@ -0,0 +1,93 @@
+//! Mirrors [array](http://erlang.org/doc/man/array.html) module
+//!
+//! Arrays are `{array, Size, Fixed, Default, Elements}`, where `Elements` is a sparse map from
+//! index to value that only has the elements that are not `Default`.  Fixed arrays cannot grow,
+//! while dynamic arrays grow when an element past their size is set.
+
+pub mod get_2;
+pub mod new_0;
+pub mod new_1;
+pub mod set_3;
+pub mod size_1;
+pub mod to_list_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("array").unwrap()
+}
+
+// Private
+
+struct Array {
+    size: usize,
+    fixed: bool,
+    default: Term,
+    elements: Boxed<Map>,
+}
+
+impl Array {
+    fn try_from_term(name: &str, array: Term) -> exception::Result<Self> {
+        let option = match array.decode()? {
+            TypedTerm::Tuple(tuple) if tuple.len() == 5 && tuple[0] == atom!("array") => {
+                let size: Option<usize> = tuple[1].try_into().ok();
+                let fixed: Option<bool> = tuple[2].try_into().ok();
+                let elements: Option<Boxed<Map>> = tuple[4].try_into().ok();
+
+                match (size, fixed, elements) {
+                    (Some(size), Some(fixed), Some(elements)) => Some(Self {
+                        size,
+                        fixed,
+                        default: tuple[3],
+                        elements,
+                    }),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        option
+            .with_context(|| format!("{} ({}) is not an array", name, array))
+            .map_err(From::from)
+    }
+
+    /// The element at `index`, which is `default` past `size` or when it has not been set
+    fn get(&self, process: &Process, index: usize) -> exception::Result<Term> {
+        let key = process.integer(index)?;
+
+        Ok(self.elements.get(key).unwrap_or(self.default))
+    }
+}
+
+fn array_to_term(
+    process: &Process,
+    size: usize,
+    fixed: bool,
+    default: Term,
+    elements: Term,
+) -> exception::Result<Term> {
+    process
+        .tuple_from_slice(&[
+            atom!("array"),
+            process.integer(size)?,
+            fixed.into(),
+            default,
+            elements,
+        ])
+        .map_err(From::from)
+}
+
+fn index_try_into_usize(index: Term) -> exception::Result<usize> {
+    index
+        .try_into()
+        .with_context(|| format!("index ({}) is not a non-negative integer", index))
+        .map_err(From::from)
+}
@@ -0,0 +1,35 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::array::{index_try_into_usize, Array};
+
+/// The element at `index`.  Past the size of a dynamic array, elements are the default.
+#[native_implemented_function(get/2)]
+pub fn native(process: &Process, index: Term, array: Term) -> exception::Result<Term> {
+    let index_usize = index_try_into_usize(index)?;
+    let array_struct = Array::try_from_term("array", array)?;
+
+    if array_struct.fixed && array_struct.size <= index_usize {
+        return Err(anyhow!(
+            "index ({}) is not less than the size ({}) of fixed array ({})",
+            index,
+            array_struct.size,
+            array
+        )
+        .into());
+    }
+
+    array_struct.get(process, index_usize)
+}
@@ -0,0 +1,45 @@
+use liblumen_alloc::atom;
+
+use crate::array::get_2::native;
+use crate::array::{new_0, new_1, set_3};
+use crate::test::with_process;
+
+#[test]
+fn with_set_index_returns_value() {
+    with_process(|process| {
+        let index = process.integer(1).unwrap();
+        let array = new_0::native(process).unwrap();
+        let array = set_3::native(process, index, atom!("value"), array).unwrap();
+
+        assert_eq!(native(process, index, array), Ok(atom!("value")));
+    });
+}
+
+#[test]
+fn with_index_past_size_of_fixed_array_errors_badarg() {
+    with_process(|process| {
+        let array = new_1::native(process, process.integer(2).unwrap()).unwrap();
+        let index = process.integer(2).unwrap();
+
+        assert_badarg!(
+            native(process, index, array),
+            format!(
+                "index ({}) is not less than the size (2) of fixed array ({})",
+                index, array
+            )
+        );
+    });
+}
+
+#[test]
+fn with_negative_index_errors_badarg() {
+    with_process(|process| {
+        let array = new_0::native(process).unwrap();
+        let index = process.integer(-1).unwrap();
+
+        assert_badarg!(
+            native(process, index, array),
+            format!("index ({}) is not a non-negative integer", index)
+        );
+    });
+}
@@ -0,0 +1,23 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::array::array_to_term;
+
+/// An empty dynamic array whose elements default to `undefined`.
+#[native_implemented_function(new/0)]
+pub fn native(process: &Process) -> exception::Result<Term> {
+    let elements = process.map_from_slice(&[])?;
+
+    array_to_term(process, 0, false, atom!("undefined"), elements)
+}
@@ -0,0 +1,21 @@
+use liblumen_alloc::atom;
+
+use crate::array::new_0::native;
+use crate::array::{get_2, size_1};
+use crate::test::with_process;
+
+#[test]
+fn returns_empty_dynamic_array_with_undefined_default() {
+    with_process(|process| {
+        let array = native(process).unwrap();
+
+        assert_eq!(
+            size_1::native(process, array),
+            Ok(process.integer(0).unwrap())
+        );
+        assert_eq!(
+            get_2::native(process, process.integer(10).unwrap(), array),
+            Ok(atom!("undefined"))
+        );
+    });
+}
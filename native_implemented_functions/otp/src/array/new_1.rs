@@ -0,0 +1,95 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::array::array_to_term;
+
+/// A new array configured by `options`, which is one option or a list of them:
+///
+/// * `N` or `{size, N}` - the array has `N` elements and is fixed, unless `{fixed, false}` is
+///   also given
+/// * `fixed` or `{fixed, true}` - the array cannot grow
+/// * `{fixed, false}` - the array grows when an element past its size is set
+/// * `{default, Value}` - elements that have not been set are `Value` instead of `undefined`
+#[native_implemented_function(new/1)]
+pub fn native(process: &Process, options: Term) -> exception::Result<Term> {
+    let option_vec = match options.decode()? {
+        TypedTerm::Nil => Vec::new(),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<std::result::Result<Vec<Term>, _>>()
+            .map_err(|_| anyhow!("options ({}) is not a proper list", options))?,
+        _ => vec![options],
+    };
+
+    let mut size = 0;
+    let mut fixed = None;
+    let mut default = atom!("undefined");
+
+    for option in option_vec {
+        match option.decode()? {
+            TypedTerm::SmallInteger(_) => {
+                size = size_try_from(option, option)?;
+                fixed = fixed.or(Some(true));
+            }
+            TypedTerm::Atom(atom) if atom.name() == "fixed" => fixed = Some(true),
+            TypedTerm::Tuple(tuple) if tuple.len() == 2 => match tuple[0].decode()? {
+                TypedTerm::Atom(atom) => match atom.name() {
+                    "default" => default = tuple[1],
+                    "fixed" => {
+                        let fixed_bool: bool = tuple[1]
+                            .try_into()
+                            .with_context(|| unsupported_option(option))?;
+
+                        fixed = Some(fixed_bool);
+                    }
+                    "size" => {
+                        size = size_try_from(option, tuple[1])?;
+                        fixed = fixed.or(Some(true));
+                    }
+                    _ => return Err(anyhow!(unsupported_option(option)).into()),
+                },
+                _ => return Err(anyhow!(unsupported_option(option)).into()),
+            },
+            _ => return Err(anyhow!(unsupported_option(option)).into()),
+        }
+    }
+
+    let elements = process.map_from_slice(&[])?;
+
+    array_to_term(process, size, fixed.unwrap_or(false), default, elements)
+}
+
+// Private
+
+fn size_try_from(option: Term, size: Term) -> exception::Result<usize> {
+    size.try_into()
+        .with_context(|| {
+            format!(
+                "option ({}) size ({}) is not a non-negative integer",
+                option, size
+            )
+        })
+        .map_err(From::from)
+}
+
+fn unsupported_option(option: Term) -> String {
+    format!(
+        "option ({}) is not N, fixed, {{fixed, Bool}}, {{size, N}}, or {{default, Value}}",
+        option
+    )
+}
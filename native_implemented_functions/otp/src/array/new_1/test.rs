@@ -0,0 +1,69 @@
+use liblumen_alloc::atom;
+
+use crate::array::new_1::native;
+use crate::array::{get_2, set_3, to_list_1};
+use crate::test::with_process;
+
+#[test]
+fn with_size_and_default_returns_fixed_array_of_default() {
+    with_process(|process| {
+        let default = process.integer(0).unwrap();
+        let options = process
+            .list_from_slice(&[
+                process.integer(3).unwrap(),
+                process
+                    .tuple_from_slice(&[atom!("default"), default])
+                    .unwrap(),
+            ])
+            .unwrap();
+        let array = native(process, options).unwrap();
+
+        assert_eq!(
+            to_list_1::native(process, array),
+            Ok(process
+                .list_from_slice(&[default, default, default])
+                .unwrap())
+        );
+        assert_badarg!(
+            set_3::native(process, process.integer(3).unwrap(), atom!("value"), array),
+            "is not less than the size (3) of fixed array"
+        );
+    });
+}
+
+#[test]
+fn with_size_and_fixed_false_returns_dynamic_array() {
+    with_process(|process| {
+        let options = process
+            .list_from_slice(&[
+                process
+                    .tuple_from_slice(&[atom!("size"), process.integer(2).unwrap()])
+                    .unwrap(),
+                process
+                    .tuple_from_slice(&[atom!("fixed"), false.into()])
+                    .unwrap(),
+            ])
+            .unwrap();
+        let array = native(process, options).unwrap();
+
+        assert_eq!(
+            get_2::native(process, process.integer(5).unwrap(), array),
+            Ok(atom!("undefined"))
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process(|process| {
+        let option = atom!("sparse");
+
+        assert_badarg!(
+            native(process, option),
+            format!(
+                "option ({}) is not N, fixed, {{fixed, Bool}}, {{size, N}}, or {{default, Value}}",
+                option
+            )
+        );
+    });
+}
@@ -0,0 +1,58 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::array::{array_to_term, index_try_into_usize, Array};
+
+/// Sets the element at `index` to `value`.  Setting past the size of a dynamic array grows it
+/// to `index + 1` elements, where the elements in between are the default.
+#[native_implemented_function(set/3)]
+pub fn native(process: &Process, index: Term, value: Term, array: Term) -> exception::Result<Term> {
+    let index_usize = index_try_into_usize(index)?;
+    let array_struct = Array::try_from_term("array", array)?;
+
+    let size = if index_usize < array_struct.size {
+        array_struct.size
+    } else if array_struct.fixed {
+        return Err(anyhow!(
+            "index ({}) is not less than the size ({}) of fixed array ({})",
+            index,
+            array_struct.size,
+            array
+        )
+        .into());
+    } else {
+        index_usize + 1
+    };
+
+    let key = process.integer(index_usize)?;
+    // Elements that are the default are not stored, so that the elements stay sparse
+    let changed_elements = if value.decode()?.exact_eq(&array_struct.default.decode()?) {
+        array_struct.elements.remove(key)
+    } else {
+        array_struct.elements.put(key, value)
+    };
+    let elements = match changed_elements {
+        Some(hash_map) => process.map_from_hash_map(hash_map)?,
+        None => array_struct.elements.into(),
+    };
+
+    array_to_term(
+        process,
+        size,
+        array_struct.fixed,
+        array_struct.default,
+        elements,
+    )
+}
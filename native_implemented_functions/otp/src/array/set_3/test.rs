@@ -0,0 +1,84 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::array::set_3::native;
+use crate::array::{get_2, new_0, size_1, to_list_1};
+use crate::test::with_process;
+
+#[test]
+fn with_index_past_size_of_dynamic_array_extends_with_default() {
+    with_process(|process| {
+        let array = new_0::native(process).unwrap();
+        let array = native(process, process.integer(2).unwrap(), atom!("c"), array).unwrap();
+
+        assert_eq!(
+            size_1::native(process, array),
+            Ok(process.integer(3).unwrap())
+        );
+        assert_eq!(
+            to_list_1::native(process, array),
+            Ok(process
+                .list_from_slice(&[atom!("undefined"), atom!("undefined"), atom!("c")])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_default_value_keeps_size() {
+    with_process(|process| {
+        let array = new_0::native(process).unwrap();
+        let array = native(process, process.integer(1).unwrap(), atom!("b"), array).unwrap();
+        let array = native(
+            process,
+            process.integer(1).unwrap(),
+            atom!("undefined"),
+            array,
+        )
+        .unwrap();
+
+        assert_eq!(
+            to_list_1::native(process, array),
+            Ok(process
+                .list_from_slice(&[atom!("undefined"), atom!("undefined")])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_equal_but_not_exactly_equal_value_replaces_element() {
+    with_process(|process| {
+        let float = process.float(1.0).unwrap();
+        let array = new_0::native(process).unwrap();
+        let array = native(
+            process,
+            process.integer(0).unwrap(),
+            process.integer(1).unwrap(),
+            array,
+        )
+        .unwrap();
+        let array = native(process, process.integer(0).unwrap(), float, array).unwrap();
+
+        let element = get_2::native(process, process.integer(0).unwrap(), array).unwrap();
+
+        assert!(
+            element.decode().unwrap().exact_eq(&float.decode().unwrap()),
+            "element ({}) is not exactly equal to {}",
+            element,
+            float
+        );
+    });
+}
+
+#[test]
+fn without_array_errors_badarg() {
+    with_process(|process| {
+        let array = process.tuple_from_slice(&[atom!("array")]).unwrap();
+
+        assert_badarg!(
+            native(process, process.integer(0).unwrap(), atom!("value"), array),
+            format!("array ({}) is not an array", array)
+        );
+    });
+}
@@ -0,0 +1,21 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::array::Array;
+
+#[native_implemented_function(size/1)]
+pub fn native(process: &Process, array: Term) -> exception::Result<Term> {
+    let array_struct = Array::try_from_term("array", array)?;
+
+    process.integer(array_struct.size).map_err(From::from)
+}
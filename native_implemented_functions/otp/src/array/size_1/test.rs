@@ -0,0 +1,13 @@
+use crate::array::new_1;
+use crate::array::size_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_fixed_array_returns_size() {
+    with_process(|process| {
+        let size = process.integer(4).unwrap();
+        let array = new_1::native(process, size).unwrap();
+
+        assert_eq!(native(process, array), Ok(size));
+    });
+}
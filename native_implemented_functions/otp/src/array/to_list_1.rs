@@ -0,0 +1,27 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::array::Array;
+
+/// The elements of `array` in index order, including those that are the default.
+#[native_implemented_function(to_list/1)]
+pub fn native(process: &Process, array: Term) -> exception::Result<Term> {
+    let array_struct = Array::try_from_term("array", array)?;
+    let mut element_vec = Vec::with_capacity(array_struct.size);
+
+    for index in 0..array_struct.size {
+        element_vec.push(array_struct.get(process, index)?);
+    }
+
+    process.list_from_slice(&element_vec).map_err(From::from)
+}
@@ -0,0 +1,19 @@
+use liblumen_alloc::atom;
+
+use crate::array::to_list_1::native;
+use crate::array::{new_0, set_3};
+use crate::test::with_process;
+
+#[test]
+fn returns_elements_in_index_order() {
+    with_process(|process| {
+        let array = new_0::native(process).unwrap();
+        let array = set_3::native(process, process.integer(1).unwrap(), atom!("b"), array).unwrap();
+        let array = set_3::native(process, process.integer(0).unwrap(), atom!("a"), array).unwrap();
+
+        assert_eq!(
+            native(process, array),
+            Ok(process.list_from_slice(&[atom!("a"), atom!("b")]).unwrap())
+        );
+    });
+}
@@ -0,0 +1,131 @@
+//! Mirrors [base64](http://erlang.org/doc/man/base64.html) module
+
+pub mod decode_1;
+pub mod encode_1;
+pub mod mime_decode_1;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("base64").unwrap()
+}
+
+// Private
+
+/// The standard alphabet from RFC 4648 Section 4
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PADDING: u8 = b'=';
+
+/// Decodes `bytes`, which must only contain the alphabet, with `=` padding to a multiple of 4
+/// bytes.
+fn decode(data: Term, bytes: &[u8]) -> exception::Result<Vec<u8>> {
+    if bytes.len() % 4 != 0 {
+        return Err(anyhow!(
+            "data ({}) length ({}) is not a multiple of 4",
+            data,
+            bytes.len()
+        )
+        .into());
+    }
+
+    let padding_len = bytes
+        .iter()
+        .rev()
+        .take_while(|byte| **byte == PADDING)
+        .count();
+
+    if 2 < padding_len {
+        return Err(anyhow!(
+            "data ({}) has more than 2 padding (=) bytes ({})",
+            data,
+            padding_len
+        )
+        .into());
+    }
+
+    let mut sextets = Vec::with_capacity(bytes.len() - padding_len);
+
+    for (index, byte) in bytes[..bytes.len() - padding_len].iter().enumerate() {
+        match sextet(*byte) {
+            Some(sextet) => sextets.push(sextet),
+            None => {
+                return Err(anyhow!(
+                    "data ({}) byte ({}) at index ({}) is not in the base64 alphabet",
+                    data,
+                    byte,
+                    index
+                )
+                .into())
+            }
+        }
+    }
+
+    Ok(sextets_to_bytes(&sextets))
+}
+
+fn encode(bytes: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity((bytes.len() + 2) / 3 * 4);
+
+    for chunk in bytes.chunks(3) {
+        let word = chunk.iter().enumerate().fold(0_u32, |acc, (index, byte)| {
+            acc | ((*byte as u32) << (16 - 8 * index))
+        });
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[((word >> (18 - 6 * index)) & 0x3F) as usize]);
+            } else {
+                encoded.push(PADDING);
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Decodes the alphabet bytes in `bytes` up to the first `=`, skipping whitespace and any other
+/// bytes not in the alphabet.
+fn mime_decode(bytes: &[u8]) -> Vec<u8> {
+    let sextets: Vec<u8> = bytes
+        .iter()
+        .take_while(|byte| **byte != PADDING)
+        .filter_map(|byte| sextet(*byte))
+        .collect();
+
+    sextets_to_bytes(&sextets)
+}
+
+fn sextet(byte: u8) -> Option<u8> {
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Every 4 sextets are 3 bytes.  A final 3 sextets are 2 bytes and 2 sextets are 1 byte, while a
+/// single sextet does not have enough bits for a byte.
+fn sextets_to_bytes(sextets: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(sextets.len() * 3 / 4);
+
+    for chunk in sextets.chunks(4) {
+        let word = chunk
+            .iter()
+            .enumerate()
+            .fold(0_u32, |acc, (index, sextet)| {
+                acc | ((*sextet as u32) << (18 - 6 * index))
+            });
+
+        for index in 0..(chunk.len() * 6 / 8) {
+            bytes.push((word >> (16 - 8 * index)) as u8);
+        }
+    }
+
+    bytes
+}
@@ -0,0 +1,25 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::base64::decode;
+use crate::erlang::iolist_or_binary;
+
+/// Decodes `data`, a binary or iodata, that only contains the standard base64 alphabet with `=`
+/// padding to a multiple of 4 bytes.  Use `mime_decode/1` to skip other bytes.
+#[native_implemented_function(decode/1)]
+pub fn native(process: &Process, data: Term) -> exception::Result<Term> {
+    let bytes = iolist_or_binary::to_byte_vec("data", data)?;
+    let decoded = decode(data, &bytes)?;
+
+    process.binary_from_bytes(&decoded).map_err(From::from)
+}
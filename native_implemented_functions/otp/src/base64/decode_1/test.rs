@@ -0,0 +1,76 @@
+use crate::base64::decode_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_rfc_4648_vectors_returns_decoded_binary() {
+    with_process(|process| {
+        for (encoded, data) in &[
+            ("", ""),
+            ("Zg==", "f"),
+            ("Zm8=", "fo"),
+            ("Zm9v", "foo"),
+            ("Zm9vYg==", "foob"),
+            ("Zm9vYmE=", "fooba"),
+            ("Zm9vYmFy", "foobar"),
+        ] {
+            assert_eq!(
+                native(process, process.binary_from_str(encoded).unwrap()),
+                Ok(process.binary_from_str(data).unwrap())
+            );
+        }
+    });
+}
+
+#[test]
+fn with_length_not_multiple_of_4_errors_badarg() {
+    with_process(|process| {
+        let data = process.binary_from_str("Zm9").unwrap();
+
+        assert_badarg!(
+            native(process, data),
+            format!("data ({}) length (3) is not a multiple of 4", data)
+        );
+    });
+}
+
+#[test]
+fn with_three_padding_bytes_errors_badarg() {
+    with_process(|process| {
+        let data = process.binary_from_str("Z===").unwrap();
+
+        assert_badarg!(
+            native(process, data),
+            format!("data ({}) has more than 2 padding (=) bytes (3)", data)
+        );
+    });
+}
+
+#[test]
+fn with_byte_not_in_alphabet_errors_badarg() {
+    with_process(|process| {
+        let data = process.binary_from_str("Zm 9").unwrap();
+
+        assert_badarg!(
+            native(process, data),
+            format!(
+                "data ({}) byte (32) at index (2) is not in the base64 alphabet",
+                data
+            )
+        );
+    });
+}
+
+#[test]
+fn with_padding_before_end_errors_badarg() {
+    with_process(|process| {
+        let data = process.binary_from_str("Zg==Zm8=").unwrap();
+
+        assert_badarg!(
+            native(process, data),
+            format!(
+                "data ({}) byte (61) at index (2) is not in the base64 alphabet",
+                data
+            )
+        );
+    });
+}
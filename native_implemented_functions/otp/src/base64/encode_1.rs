@@ -0,0 +1,26 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::base64::encode;
+use crate::erlang::iolist_or_binary;
+
+/// Encodes the bytes of `data`, a binary or iodata, with the standard base64 alphabet and `=`
+/// padding.
+#[native_implemented_function(encode/1)]
+pub fn native(process: &Process, data: Term) -> exception::Result<Term> {
+    let bytes = iolist_or_binary::to_byte_vec("data", data)?;
+
+    process
+        .binary_from_bytes(&encode(&bytes))
+        .map_err(From::from)
+}
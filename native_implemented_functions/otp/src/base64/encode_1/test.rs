@@ -0,0 +1,61 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use crate::base64::encode_1::native;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn with_rfc_4648_vectors_returns_encoded_binary() {
+    with_process(|process| {
+        for (data, encoded) in &[
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(
+                native(process, process.binary_from_str(data).unwrap()),
+                Ok(process.binary_from_str(encoded).unwrap())
+            );
+        }
+    });
+}
+
+#[test]
+fn with_charlist_returns_encoded_binary() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.charlist_from_str("foobar").unwrap()),
+            Ok(process.binary_from_str("Zm9vYmFy").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_binary_decode_returns_binary() {
+    run!(
+        |arc_process| {
+            (Just(arc_process.clone()), strategy::byte_vec()).prop_flat_map(
+                |(arc_process, byte_vec)| {
+                    (
+                        Just(arc_process.clone()),
+                        strategy::term::binary::containing_bytes(byte_vec, arc_process.clone()),
+                    )
+                },
+            )
+        },
+        |(arc_process, data)| {
+            let encoded = native(&arc_process, data).unwrap();
+
+            prop_assert_eq!(
+                crate::base64::decode_1::native(&arc_process, encoded),
+                Ok(data)
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,26 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::base64::mime_decode;
+use crate::erlang::iolist_or_binary;
+
+/// Like `decode/1`, but whitespace, such as MIME line breaks, and any other bytes outside the
+/// alphabet are skipped, and decoding stops at the first `=`.
+#[native_implemented_function(mime_decode/1)]
+pub fn native(process: &Process, data: Term) -> exception::Result<Term> {
+    let bytes = iolist_or_binary::to_byte_vec("data", data)?;
+
+    process
+        .binary_from_bytes(&mime_decode(&bytes))
+        .map_err(From::from)
+}
@@ -0,0 +1,26 @@
+use crate::base64::mime_decode_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_whitespace_and_stray_bytes_returns_decoded_binary() {
+    with_process(|process| {
+        let data = process.binary_from_str("Zm9v\r\nYm!Fy").unwrap();
+
+        assert_eq!(
+            native(process, data),
+            Ok(process.binary_from_str("foobar").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_bytes_after_padding_ignores_them() {
+    with_process(|process| {
+        let data = process.binary_from_str("Zm8=\nZm9v").unwrap();
+
+        assert_eq!(
+            native(process, data),
+            Ok(process.binary_from_str("fo").unwrap())
+        );
+    });
+}
@@ -1,3 +1,15 @@
+//! Mirrors [binary](http://erlang.org/doc/man/binary.html) module
+
+pub mod decode_hex_1;
+pub mod decode_unsigned_1;
+pub mod decode_unsigned_2;
+pub mod encode_hex_1;
+pub mod longest_common_prefix_1;
+pub mod part_2;
+pub mod part_3;
+pub mod split_2;
+pub mod split_3;
+
 use std::backtrace::Backtrace;
 use std::convert::TryInto;
 use std::ops::Range;
@@ -9,6 +21,10 @@ use liblumen_alloc::erts::exception::{self, ArcError, Exception, InternalExcepti
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::Process;
 
+fn module() -> Atom {
+    Atom::try_from_str("binary").unwrap()
+}
+
 pub struct PartRange {
     pub byte_offset: usize,
     pub byte_len: usize,
@@ -189,7 +205,7 @@ pub enum PartRangeError {
         available_byte_count: usize,
         backtrace: Backtrace,
     },
-    #[error("end ({end}) is less than or equal to 0")]
+    #[error("end ({end}) is less than 0")]
     EndNegativeLength { end: isize, backtrace: Backtrace },
 }
 
@@ -198,3 +214,212 @@ impl From<PartRangeError> for Exception {
         InternalException::from(ArcError::from_err(err)).into()
     }
 }
+
+// Private
+
+/// Options to `split`
+struct SplitOptions {
+    global: bool,
+    /// The bytes of the subject that can be matched.  Parts still cover the whole subject.
+    scope: Option<PartRange>,
+    trim: Trim,
+}
+
+impl Default for SplitOptions {
+    fn default() -> Self {
+        Self {
+            global: false,
+            scope: None,
+            trim: Trim::None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Trim {
+    None,
+    /// Remove empty parts at the end
+    Trailing,
+    /// Remove all empty parts
+    All,
+}
+
+/// Splits `subject` where `pattern` matches.  The parts are subbinaries of the original binary,
+/// so no bytes are copied, even for large `ProcBin`s.
+fn split(
+    process: &Process,
+    subject: Term,
+    pattern: Term,
+    options: SplitOptions,
+) -> exception::Result<Term> {
+    let (original, byte_offset, bit_offset) = match subject.decode()? {
+        TypedTerm::HeapBinary(_) | TypedTerm::ProcBin(_) | TypedTerm::BinaryLiteral(_) => {
+            (subject, 0, 0)
+        }
+        TypedTerm::SubBinary(subbinary) if subbinary.is_binary() => (
+            subbinary.original(),
+            subbinary.byte_offset(),
+            subbinary.bit_offset(),
+        ),
+        _ => {
+            return Err(TypeError)
+                .context(format!("subject ({}) is not a binary", subject))
+                .map_err(From::from)
+        }
+    };
+    let bytes = process
+        .bytes_from_binary(subject)
+        .with_context(|| format!("subject ({}) is not a binary", subject))?;
+    let patterns = patterns_try_from(process, pattern)?;
+    let scope: Range<usize> = match options.scope {
+        Some(part_range) => part_range.into(),
+        None => 0..bytes.len(),
+    };
+
+    if bytes.len() < scope.end {
+        return Err(anyhow!(
+            "scope ({:?}) exceeds byte size ({}) of subject ({})",
+            scope,
+            bytes.len(),
+            subject
+        )
+        .into());
+    }
+
+    let matches = split_matches(bytes, &patterns, scope, options.global);
+
+    if matches.is_empty() {
+        return process.list_from_slice(&[subject]).map_err(From::from);
+    }
+
+    let mut parts = Vec::with_capacity(matches.len() + 1);
+    let mut part_start = 0;
+
+    for match_range in matches {
+        parts.push(part_start..match_range.start);
+        part_start = match_range.end;
+    }
+
+    parts.push(part_start..bytes.len());
+
+    match options.trim {
+        Trim::None => (),
+        Trim::Trailing => {
+            while parts.last().map_or(false, |part| part.start == part.end) {
+                parts.pop();
+            }
+        }
+        Trim::All => parts.retain(|part| part.start < part.end),
+    }
+
+    let mut part_vec = Vec::with_capacity(parts.len());
+
+    for part in parts {
+        part_vec.push(process.subbinary_from_original(
+            original,
+            byte_offset + part.start,
+            bit_offset,
+            part.end - part.start,
+            0,
+        )?);
+    }
+
+    process.list_from_slice(&part_vec).map_err(From::from)
+}
+
+/// The ranges of `bytes` in `scope` that `patterns` match, only the first one unless `global`.
+///
+/// Single byte patterns, like splitting lines on `\n`, scan for the byte directly instead of
+/// trying every pattern at every offset.
+fn split_matches(
+    bytes: &[u8],
+    patterns: &[&[u8]],
+    scope: Range<usize>,
+    global: bool,
+) -> Vec<Range<usize>> {
+    match patterns {
+        [pattern] if pattern.len() == 1 => byte_matches(bytes, pattern[0], scope, global),
+        _ => longest_matches(bytes, patterns, scope, global),
+    }
+}
+
+fn byte_matches(bytes: &[u8], byte: u8, scope: Range<usize>, global: bool) -> Vec<Range<usize>> {
+    let mut matches = Vec::new();
+    let mut offset = scope.start;
+
+    while let Some(index) = bytes[offset..scope.end].iter().position(|b| *b == byte) {
+        let start = offset + index;
+        matches.push(start..start + 1);
+        offset = start + 1;
+
+        if !global {
+            break;
+        }
+    }
+
+    matches
+}
+
+/// Like `binary:matches/2`, at each offset the longest of the patterns that match there is used,
+/// and matches do not overlap.
+fn longest_matches(
+    bytes: &[u8],
+    patterns: &[&[u8]],
+    scope: Range<usize>,
+    global: bool,
+) -> Vec<Range<usize>> {
+    let mut matches = Vec::new();
+    let mut offset = scope.start;
+
+    while offset < scope.end {
+        let scoped = &bytes[offset..scope.end];
+        let longest_len = patterns
+            .iter()
+            .filter(|pattern| scoped.starts_with(pattern))
+            .map(|pattern| pattern.len())
+            .max();
+
+        match longest_len {
+            Some(len) => {
+                matches.push(offset..offset + len);
+                offset += len;
+
+                if !global {
+                    break;
+                }
+            }
+            None => offset += 1,
+        }
+    }
+
+    matches
+}
+
+fn patterns_try_from(process: &Process, pattern: Term) -> exception::Result<Vec<&[u8]>> {
+    let pattern_terms = match pattern.decode()? {
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<std::result::Result<Vec<Term>, _>>()
+            .map_err(|_| anyhow!("pattern ({}) is not a proper list", pattern))?,
+        _ => vec![pattern],
+    };
+
+    let mut patterns = Vec::with_capacity(pattern_terms.len());
+
+    for pattern_term in pattern_terms {
+        let bytes = process
+            .bytes_from_binary(pattern_term)
+            .ok()
+            .filter(|bytes| !bytes.is_empty())
+            .with_context(|| {
+                format!(
+                    "pattern ({}) is not a non-empty binary or a non-empty list of them",
+                    pattern
+                )
+            })?;
+
+        patterns.push(bytes);
+    }
+
+    Ok(patterns)
+}
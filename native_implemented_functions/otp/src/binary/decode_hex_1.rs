@@ -0,0 +1,53 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+/// Reverses `binary:encode_hex/1`.  Both uppercase and lowercase hexadecimal digits are accepted.
+#[native_implemented_function(decode_hex/1)]
+pub fn native(process: &Process, bin: Term) -> exception::Result<Term> {
+    let hex = process
+        .bytes_from_binary(bin)
+        .with_context(|| format!("bin ({}) is not a binary", bin))?;
+
+    if hex.len() % 2 != 0 {
+        return Err(anyhow!("bin ({}) has an odd number of bytes ({})", bin, hex.len()).into());
+    }
+
+    let mut bytes = Vec::with_capacity(hex.len() / 2);
+
+    for pair in hex.chunks(2) {
+        let high = digit_value(bin, pair[0])?;
+        let low = digit_value(bin, pair[1])?;
+
+        bytes.push((high << 4) | low);
+    }
+
+    process.binary_from_bytes(&bytes).map_err(From::from)
+}
+
+// Private
+
+fn digit_value(bin: Term, digit: u8) -> exception::Result<u8> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        _ => Err(anyhow!(
+            "bin ({}) contains a byte ({}) that is not a hexadecimal digit",
+            bin,
+            digit
+        )
+        .into()),
+    }
+}
@@ -0,0 +1,47 @@
+use crate::binary::decode_hex_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_uppercase_or_lowercase_hex_returns_bytes() {
+    with_process(|process| {
+        let expected = process
+            .binary_from_bytes(&[0x00, 0x1f, 0xab, 0xff])
+            .unwrap();
+
+        assert_eq!(
+            native(process, process.binary_from_str("001FABFF").unwrap()),
+            Ok(expected)
+        );
+        assert_eq!(
+            native(process, process.binary_from_str("001fabff").unwrap()),
+            Ok(expected)
+        );
+    });
+}
+
+#[test]
+fn with_odd_length_errors_badarg() {
+    with_process(|process| {
+        let bin = process.binary_from_str("ABC").unwrap();
+
+        assert_badarg!(
+            native(process, bin),
+            format!("bin ({}) has an odd number of bytes (3)", bin)
+        );
+    });
+}
+
+#[test]
+fn with_non_hex_byte_errors_badarg() {
+    with_process(|process| {
+        let bin = process.binary_from_str("G0").unwrap();
+
+        assert_badarg!(
+            native(process, bin),
+            format!(
+                "bin ({}) contains a byte (71) that is not a hexadecimal digit",
+                bin
+            )
+        );
+    });
+}
@@ -0,0 +1,14 @@
+use crate::binary::decode_unsigned_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_bytes_decodes_big_endian() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&[0x01, 0x02]).unwrap();
+
+        assert_eq!(
+            native(process, subject),
+            Ok(process.integer(0x0102).unwrap())
+        );
+    });
+}
@@ -0,0 +1,79 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+use std::mem;
+
+use anyhow::*;
+use num_bigint::{BigInt, Sign};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+/// Converts the bytes of `subject` to the unsigned integer they encode in `endianness`, which is
+/// `big` or `little`.
+///
+/// Leading zeros are skipped, so when the significant bytes fit in a `u64`, the value is
+/// accumulated without a `BigInt`, and it is only a bignum if it is too large for a small integer.
+#[native_implemented_function(decode_unsigned/2)]
+pub fn native(process: &Process, subject: Term, endianness: Term) -> exception::Result<Term> {
+    let bytes = process
+        .bytes_from_binary(subject)
+        .with_context(|| format!("subject ({}) is not a binary", subject))?;
+    let big_endian = big_endian_try_from(endianness)?;
+
+    // leading zeros are at the start for big and at the end for little endian
+    let significant = if big_endian {
+        let start = bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(bytes.len());
+
+        &bytes[start..]
+    } else {
+        let end = bytes
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |index| index + 1);
+
+        &bytes[..end]
+    };
+
+    let integer = if significant.len() <= mem::size_of::<u64>() {
+        let shift_in = |acc: u64, byte: &u8| (acc << 8) | (*byte as u64);
+        let u = if big_endian {
+            significant.iter().fold(0, shift_in)
+        } else {
+            significant.iter().rev().fold(0, shift_in)
+        };
+
+        process.integer(u)?
+    } else if big_endian {
+        process.integer(BigInt::from_bytes_be(Sign::Plus, significant))?
+    } else {
+        process.integer(BigInt::from_bytes_le(Sign::Plus, significant))?
+    };
+
+    Ok(integer)
+}
+
+// Private
+
+fn big_endian_try_from(endianness: Term) -> exception::Result<bool> {
+    let endianness_atom: Atom = endianness
+        .try_into()
+        .with_context(|| format!("endianness ({}) is not big or little", endianness))?;
+
+    match endianness_atom.name() {
+        "big" => Ok(true),
+        "little" => Ok(false),
+        _ => Err(anyhow!("endianness ({}) is not big or little", endianness).into()),
+    }
+}
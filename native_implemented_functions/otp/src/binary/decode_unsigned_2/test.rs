@@ -0,0 +1,82 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use num_bigint::BigInt;
+
+use crate::binary::decode_unsigned_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_two_bytes_returns_small_integer() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&[0xAB, 0xCD]).unwrap();
+        let decoded = native(process, subject, atom!("big")).unwrap();
+
+        assert!(decoded.is_smallint());
+        assert_eq!(decoded, process.integer(0xABCD).unwrap());
+    });
+}
+
+#[test]
+fn with_sixteen_bytes_returns_big_integer() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&[0xFF; 16]).unwrap();
+        let decoded = native(process, subject, atom!("big")).unwrap();
+
+        assert!(decoded.is_boxed_bigint());
+        assert_eq!(
+            decoded,
+            process.integer(BigInt::from(u128::max_value())).unwrap()
+        );
+    });
+}
+
+#[test]
+fn with_leading_zeros_returns_small_integer() {
+    with_process(|process| {
+        let mut bytes = vec![0; 16];
+        bytes[15] = 7;
+        let subject = process.binary_from_bytes(&bytes).unwrap();
+        let decoded = native(process, subject, atom!("big")).unwrap();
+
+        assert!(decoded.is_smallint());
+        assert_eq!(decoded, process.integer(7).unwrap());
+    });
+}
+
+#[test]
+fn with_little_decodes_least_significant_byte_first() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&[0x01, 0x02, 0x00]).unwrap();
+
+        assert_eq!(
+            native(process, subject, atom!("little")),
+            Ok(process.integer(0x0201).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_empty_binary_returns_zero() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&[]).unwrap();
+
+        assert_eq!(
+            native(process, subject, atom!("big")),
+            Ok(process.integer(0).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_invalid_endianness_errors_badarg() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&[1]).unwrap();
+        let endianness = atom!("native");
+
+        assert_badarg!(
+            native(process, subject, endianness),
+            format!("endianness ({}) is not big or little", endianness)
+        );
+    });
+}
@@ -0,0 +1,35 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+/// Each byte of `bin` as two uppercase hexadecimal digits, so the returned binary is twice as
+/// long as `bin`.
+#[native_implemented_function(encode_hex/1)]
+pub fn native(process: &Process, bin: Term) -> exception::Result<Term> {
+    let bytes = process
+        .bytes_from_binary(bin)
+        .with_context(|| format!("bin ({}) is not a binary", bin))?;
+    let mut hex = Vec::with_capacity(bytes.len() * 2);
+
+    for byte in bytes {
+        hex.push(DIGITS[(byte >> 4) as usize]);
+        hex.push(DIGITS[(byte & 0xF) as usize]);
+    }
+
+    process.binary_from_bytes(&hex).map_err(From::from)
+}
+
+// Private
+
+const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
@@ -0,0 +1,71 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use crate::binary::encode_hex_1::native;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_binary_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_binary(arc_process.clone()),
+            )
+        },
+        |(arc_process, bin)| {
+            prop_assert_badarg!(
+                native(&arc_process, bin),
+                format!("bin ({}) is not a binary", bin)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_binary_returns_uppercase_hex_twice_as_long() {
+    with_process(|process| {
+        let bin = process
+            .binary_from_bytes(&[0x00, 0x1f, 0xab, 0xff])
+            .unwrap();
+
+        assert_eq!(
+            native(process, bin),
+            Ok(process.binary_from_str("001FABFF").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_binary_decode_hex_returns_binary() {
+    run!(
+        |arc_process| {
+            (Just(arc_process.clone()), strategy::byte_vec()).prop_flat_map(
+                |(arc_process, byte_vec)| {
+                    (
+                        Just(arc_process.clone()),
+                        strategy::term::binary::containing_bytes(byte_vec, arc_process.clone()),
+                    )
+                },
+            )
+        },
+        |(arc_process, bin)| {
+            let hex = native(&arc_process, bin).unwrap();
+
+            prop_assert_eq!(
+                crate::erlang::byte_size_1::native(&arc_process, hex),
+                Ok(arc_process
+                    .integer(arc_process.bytes_from_binary(bin).unwrap().len() * 2)
+                    .unwrap())
+            );
+            prop_assert_eq!(
+                crate::binary::decode_hex_1::native(&arc_process, hex),
+                Ok(bin)
+            );
+
+            Ok(())
+        },
+    );
+}
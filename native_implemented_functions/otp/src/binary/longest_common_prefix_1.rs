@@ -0,0 +1,94 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::mem;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+/// The length in bytes of the longest prefix that all of `binaries` have in common.
+#[native_implemented_function(longest_common_prefix/1)]
+pub fn native(process: &Process, binaries: Term) -> exception::Result<Term> {
+    let cons = match binaries.decode()? {
+        TypedTerm::List(cons) => cons,
+        TypedTerm::Nil => {
+            return Err(anyhow!("binaries ({}) is empty", binaries).into());
+        }
+        _ => {
+            return Err(TypeError)
+                .context(format!("binaries ({}) is not a list", binaries))
+                .map_err(From::from)
+        }
+    };
+
+    let mut prefix: Option<&[u8]> = None;
+
+    for result in cons.into_iter() {
+        let binary = result
+            .map_err(|_| ImproperListError)
+            .with_context(|| format!("binaries ({}) is not a proper list", binaries))?;
+        let bytes = process.bytes_from_binary(binary).with_context(|| {
+            format!(
+                "binaries ({}) element ({}) is not a binary",
+                binaries, binary
+            )
+        })?;
+
+        prefix = Some(match prefix {
+            Some(prefix) => &prefix[..common_prefix_len(prefix, bytes)],
+            None => bytes,
+        });
+    }
+
+    process
+        .integer(prefix.map_or(0, |prefix| prefix.len()))
+        .map_err(From::from)
+}
+
+// Private
+
+/// Compares a `usize` at a time, so long shared prefixes take a fraction of the comparisons of a
+/// byte loop, and then finds the first differing byte from the bits that differ in the word.  The
+/// bytes after the last whole word are compared one at a time.
+fn common_prefix_len(left: &[u8], right: &[u8]) -> usize {
+    const WORD_LEN: usize = mem::size_of::<usize>();
+
+    let len = left.len().min(right.len());
+    let (left, right) = (&left[..len], &right[..len]);
+    let mut offset = 0;
+
+    for (left_chunk, right_chunk) in left
+        .chunks_exact(WORD_LEN)
+        .zip(right.chunks_exact(WORD_LEN))
+    {
+        let mut left_word = [0; WORD_LEN];
+        left_word.copy_from_slice(left_chunk);
+        let mut right_word = [0; WORD_LEN];
+        right_word.copy_from_slice(right_chunk);
+
+        // little endian, so that the first byte in memory is the least significant
+        let differing_bits = usize::from_le_bytes(left_word) ^ usize::from_le_bytes(right_word);
+
+        if differing_bits != 0 {
+            return offset + (differing_bits.trailing_zeros() as usize) / 8;
+        }
+
+        offset += WORD_LEN;
+    }
+
+    offset
+        + left[offset..]
+            .iter()
+            .zip(&right[offset..])
+            .take_while(|(left_byte, right_byte)| left_byte == right_byte)
+            .count()
+}
@@ -0,0 +1,130 @@
+use libtest::Bencher;
+
+use proptest::collection::vec;
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::longest_common_prefix_1::{common_prefix_len, native};
+use crate::test::with_process;
+
+#[test]
+fn with_binaries_returns_length_of_common_prefix() {
+    with_process(|process| {
+        let binaries = binaries(
+            process,
+            &["abcdefghijklmnopq", "abcdefghijklmnopz", "abcdefghij"],
+        );
+
+        assert_eq!(native(process, binaries), Ok(process.integer(10).unwrap()));
+    });
+}
+
+#[test]
+fn with_one_binary_returns_its_length() {
+    with_process(|process| {
+        let binaries = binaries(process, &["abc"]);
+
+        assert_eq!(native(process, binaries), Ok(process.integer(3).unwrap()));
+    });
+}
+
+#[test]
+fn with_empty_binary_returns_zero() {
+    with_process(|process| {
+        let binaries = binaries(process, &["abc", ""]);
+
+        assert_eq!(native(process, binaries), Ok(process.integer(0).unwrap()));
+    });
+}
+
+#[test]
+fn with_empty_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(native(process, Term::NIL), "binaries ([]) is empty");
+    });
+}
+
+#[test]
+fn with_non_binary_element_errors_badarg() {
+    with_process(|process| {
+        let element = Atom::str_to_term("abc");
+        let binaries = process
+            .list_from_slice(&[process.binary_from_str("abc").unwrap(), element])
+            .unwrap();
+
+        assert_badarg!(
+            native(process, binaries),
+            format!("element ({}) is not a binary", element)
+        );
+    });
+}
+
+#[test]
+fn common_prefix_len_is_the_same_as_byte_loop() {
+    run!(
+        |_| {
+            vec(0_u8..=2, 0..40).prop_flat_map(|left| {
+                let len = left.len();
+
+                (Just(left), 0..=len, vec(0_u8..=2, 0..40))
+            })
+        },
+        |(left, shared_len, suffix)| {
+            // share a prefix of any length, so the divergence is at every offset in a word and in
+            // the unaligned end
+            let mut right = left[..shared_len].to_vec();
+            right.extend_from_slice(&suffix);
+
+            prop_assert_eq!(
+                common_prefix_len(&left, &right),
+                byte_loop_common_prefix_len(&left, &right)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[bench]
+fn bench_common_prefix_len_of_megabyte_binaries(bencher: &mut Bencher) {
+    let (left, right) = megabyte_byte_vecs();
+
+    bencher.iter(|| common_prefix_len(&left, &right))
+}
+
+#[bench]
+fn bench_byte_loop_common_prefix_len_of_megabyte_binaries(bencher: &mut Bencher) {
+    let (left, right) = megabyte_byte_vecs();
+
+    bencher.iter(|| byte_loop_common_prefix_len(&left, &right))
+}
+
+fn binaries(process: &Process, strs: &[&str]) -> Term {
+    let binary_vec: Vec<Term> = strs
+        .iter()
+        .map(|s| process.binary_from_str(s).unwrap())
+        .collect();
+
+    process.list_from_slice(&binary_vec).unwrap()
+}
+
+fn byte_loop_common_prefix_len(left: &[u8], right: &[u8]) -> usize {
+    left.iter()
+        .zip(right)
+        .take_while(|(left_byte, right_byte)| left_byte == right_byte)
+        .count()
+}
+
+/// Megabyte-scale byte vectors that only differ near the end and in length
+fn megabyte_byte_vecs() -> (Vec<u8>, Vec<u8>) {
+    let left: Vec<u8> = (0..(1 << 20)).map(|i| (i % 251) as u8).collect();
+    let mut right = left.clone();
+    right.truncate(left.len() - 3);
+    let last = right.len() - 5;
+    right[last] ^= 0xFF;
+
+    (left, right)
+}
@@ -0,0 +1,21 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang;
+
+/// `binary:part/2` is the same as `erlang:binary_part/2`, including a negative `Length` in
+/// `{Start, Length}` taking the bytes before `Start`.
+#[native_implemented_function(part/2)]
+pub fn native(process: &Process, subject: Term, pos_len: Term) -> exception::Result<Term> {
+    erlang::binary_part_2::native(process, subject, pos_len)
+}
@@ -0,0 +1,34 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::part_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_negative_length_returns_bytes_before_start() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&[1, 2, 3, 4, 5]).unwrap();
+        let pos_len = process
+            .tuple_from_slice(&[process.integer(5).unwrap(), process.integer(-2).unwrap()])
+            .unwrap();
+
+        assert_eq!(
+            native(process, subject, pos_len),
+            Ok(process.binary_from_bytes(&[4, 5]).unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_2_arity_tuple_errors_badarg() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&[1, 2, 3, 4, 5]).unwrap();
+        let pos_len = process
+            .tuple_from_slice(&[process.integer(0).unwrap()])
+            .unwrap();
+
+        assert_badarg!(
+            native(process, subject, pos_len),
+            format!("start_length ({}) is a tuple, but not 2-arity", pos_len)
+        );
+    });
+}
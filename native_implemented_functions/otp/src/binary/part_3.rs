@@ -0,0 +1,21 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang;
+
+/// `binary:part/3` is the same as `erlang:binary_part/3`, including a negative `len` taking the
+/// bytes before `pos`.
+#[native_implemented_function(part/3)]
+pub fn native(process: &Process, subject: Term, pos: Term, len: Term) -> exception::Result<Term> {
+    erlang::binary_part_3::native(process, subject, pos, len)
+}
@@ -0,0 +1,135 @@
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::part_3::native;
+use crate::test::with_process;
+
+const SUBJECT: [u8; 5] = [1, 2, 3, 4, 5];
+
+#[test]
+fn with_zero_length_at_start_returns_empty_binary() {
+    assert_part(0, 0, &[]);
+}
+
+#[test]
+fn with_full_length_returns_subject() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&SUBJECT).unwrap();
+
+        assert_eq!(
+            native(process, subject, integer(process, 0), integer(process, 5)),
+            Ok(subject)
+        );
+    });
+}
+
+#[test]
+fn with_zero_length_at_end_returns_empty_binary() {
+    assert_part(5, 0, &[]);
+}
+
+#[test]
+fn with_negative_length_from_end_to_start_returns_subject() {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&SUBJECT).unwrap();
+
+        assert_eq!(
+            native(process, subject, integer(process, 5), integer(process, -5)),
+            Ok(subject)
+        );
+    });
+}
+
+#[test]
+fn with_negative_length_from_end_returns_bytes_before_end() {
+    assert_part(5, -2, &[4, 5]);
+}
+
+#[test]
+fn with_negative_length_to_start_returns_bytes_before_start() {
+    assert_part(2, -2, &[1, 2]);
+}
+
+#[test]
+fn with_positive_length_in_middle_returns_bytes_after_start() {
+    assert_part(1, 3, &[2, 3, 4]);
+}
+
+#[test]
+fn with_binary_literal_returns_bytes() {
+    with_process(|process| {
+        let subject = process.binary_from_str("hello").unwrap();
+        let bytes = b"hello";
+        let binary_literal =
+            BinaryLiteral::from_raw_bytes(bytes.as_ptr() as *mut u8, bytes.len(), None);
+        let boxed: Boxed<BinaryLiteral> =
+            unsafe { Boxed::new_unchecked(&binary_literal as *const _ as *mut _) };
+        let literal: Term = boxed.into();
+
+        assert_eq!(
+            native(process, literal, integer(process, 5), integer(process, -5)),
+            Ok(subject)
+        );
+        assert_eq!(
+            native(process, literal, integer(process, 1), integer(process, 3)),
+            Ok(process.binary_from_str("ell").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_negative_length_past_start_errors_badarg() {
+    assert_part_badarg(2, -3, "end (-1) is less than 0");
+    assert_part_badarg(0, -1, "end (-1) is less than 0");
+}
+
+#[test]
+fn with_positive_length_past_end_errors_badarg() {
+    assert_part_badarg(4, 2, "end (6) exceeds available_byte_count (5)");
+}
+
+#[test]
+fn with_start_past_end_errors_badarg() {
+    assert_part_badarg(6, 0, "start (6) exceeds available_byte_count (5)");
+}
+
+#[test]
+fn with_negative_start_errors_badarg() {
+    assert_part_badarg(-1, 1, "a non-negative integer");
+}
+
+fn assert_part(start: isize, length: isize, expected: &[u8]) {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&SUBJECT).unwrap();
+
+        assert_eq!(
+            native(
+                process,
+                subject,
+                integer(process, start),
+                integer(process, length)
+            ),
+            Ok(process.binary_from_bytes(expected).unwrap())
+        );
+    });
+}
+
+fn assert_part_badarg(start: isize, length: isize, substring: &str) {
+    with_process(|process| {
+        let subject = process.binary_from_bytes(&SUBJECT).unwrap();
+
+        assert_badarg!(
+            native(
+                process,
+                subject,
+                integer(process, start),
+                integer(process, length)
+            ),
+            substring
+        );
+    });
+}
+
+fn integer(process: &Process, i: isize) -> Term {
+    process.integer(i).unwrap()
+}
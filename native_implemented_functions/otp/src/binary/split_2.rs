@@ -0,0 +1,21 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::binary::{split, SplitOptions};
+
+/// Splits `subject` into the part before and after the first match of `pattern`, which is a
+/// binary or a list of binaries.  Returns `[subject]` when `pattern` does not match.
+#[native_implemented_function(split/2)]
+pub fn native(process: &Process, subject: Term, pattern: Term) -> exception::Result<Term> {
+    split(process, subject, pattern, SplitOptions::default())
+}
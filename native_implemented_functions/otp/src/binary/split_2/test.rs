@@ -0,0 +1,113 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::split_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_binary_pattern_splits_at_first_match() {
+    with_process(|process| {
+        let subject = process.binary_from_str("a,b,c").unwrap();
+        let pattern = process.binary_from_str(",").unwrap();
+
+        assert_eq!(
+            native(process, subject, pattern),
+            Ok(process
+                .list_from_slice(&[
+                    process.binary_from_str("a").unwrap(),
+                    process.binary_from_str("b,c").unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_match_returns_list_of_subject() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abc").unwrap();
+        let pattern = process.binary_from_str(",").unwrap();
+
+        assert_eq!(
+            native(process, subject, pattern),
+            Ok(process.list_from_slice(&[subject]).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_list_of_patterns_matches_longest_at_same_offset() {
+    with_process(|process| {
+        let subject = process.binary_from_str("a\r\nb").unwrap();
+        let pattern = process
+            .list_from_slice(&[
+                process.binary_from_str("\r").unwrap(),
+                process.binary_from_str("\r\n").unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(process, subject, pattern),
+            Ok(process
+                .list_from_slice(&[
+                    process.binary_from_str("a").unwrap(),
+                    process.binary_from_str("b").unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_procbin_parts_are_subbinaries_of_subject() {
+    with_process(|process| {
+        let line = "x".repeat(100);
+        let subject = process
+            .binary_from_str(&format!("{}\n{}", line, line))
+            .unwrap();
+        let pattern = process.binary_from_str("\n").unwrap();
+
+        assert!(subject.is_boxed_procbin());
+
+        let parts = native(process, subject, pattern).unwrap();
+        let cons: Boxed<Cons> = parts.try_into().unwrap();
+
+        for result in cons.into_iter() {
+            let part = result.unwrap();
+            let subbinary: Boxed<SubBinary> = part.try_into().unwrap();
+
+            assert_eq!(subbinary.original(), subject);
+            assert_eq!(part, process.binary_from_str(&line).unwrap());
+        }
+    });
+}
+
+#[test]
+fn with_empty_pattern_errors_badarg() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abc").unwrap();
+        let pattern = process.binary_from_str("").unwrap();
+
+        assert_badarg!(
+            native(process, subject, pattern),
+            format!(
+                "pattern ({}) is not a non-empty binary or a non-empty list of them",
+                pattern
+            )
+        );
+    });
+}
+
+#[test]
+fn without_binary_subject_errors_badarg() {
+    with_process(|process| {
+        let subject = Atom::str_to_term("abc");
+        let pattern = process.binary_from_str(",").unwrap();
+
+        assert_badarg!(
+            native(process, subject, pattern),
+            format!("subject ({}) is not a binary", subject)
+        );
+    });
+}
@@ -0,0 +1,94 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::binary::{split, start_length_to_part_range, PartRange, SplitOptions, Trim};
+
+/// Like `split/2`, but `options` can contain
+///
+/// * `global` - split at every match instead of just the first
+/// * `trim` - remove empty parts at the end
+/// * `trim_all` - remove all empty parts
+/// * `{scope, {Start, Length}}` - only match in that part of `subject`
+#[native_implemented_function(split/3)]
+pub fn native(
+    process: &Process,
+    subject: Term,
+    pattern: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let split_options = options_try_from(options)?;
+
+    split(process, subject, pattern, split_options)
+}
+
+// Private
+
+fn options_try_from(options: Term) -> exception::Result<SplitOptions> {
+    let mut split_options = SplitOptions::default();
+    let option_vec = match options.decode()? {
+        TypedTerm::Nil => Vec::new(),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<std::result::Result<Vec<Term>, _>>()
+            .map_err(|_| anyhow!("options ({}) is not a proper list", options))?,
+        _ => {
+            return Err(TypeError)
+                .context(format!("options ({}) is not a list", options))
+                .map_err(From::from)
+        }
+    };
+
+    for option in option_vec {
+        match option.decode()? {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "global" => split_options.global = true,
+                "trim" => split_options.trim = Trim::Trailing,
+                "trim_all" => split_options.trim = Trim::All,
+                _ => return Err(option_error(option)),
+            },
+            TypedTerm::Tuple(tuple) if tuple.len() == 2 && tuple[0] == atom!("scope") => {
+                split_options.scope =
+                    Some(scope_try_from(tuple[1]).ok_or_else(|| option_error(option))?);
+            }
+            _ => return Err(option_error(option)),
+        }
+    }
+
+    Ok(split_options)
+}
+
+fn option_error(option: Term) -> exception::Exception {
+    anyhow!(
+        "option ({}) is not global, trim, trim_all, or {{scope, {{Start, Length}}}}",
+        option
+    )
+    .into()
+}
+
+fn scope_try_from(scope: Term) -> Option<PartRange> {
+    match scope.decode().ok()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+            let start: usize = tuple[0].try_into().ok()?;
+            let length: isize = tuple[1].try_into().ok()?;
+
+            // Exceeding the subject is checked with the subject's size when splitting
+            start_length_to_part_range(start, length, std::usize::MAX).ok()
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,157 @@
+use libtest::Bencher;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::split_3::native;
+use crate::binary::{byte_matches, longest_matches, split_matches};
+use crate::test::with_process;
+
+#[test]
+fn with_global_splits_at_every_match() {
+    with_process(|process| {
+        let subject = process.binary_from_str("a\nb\n\nc\n").unwrap();
+        let pattern = process.binary_from_str("\n").unwrap();
+
+        assert_eq!(
+            native(process, subject, pattern, options(process, &["global"])),
+            Ok(binaries(process, &["a", "b", "", "c", ""]))
+        );
+    });
+}
+
+#[test]
+fn with_trim_removes_trailing_empty_parts() {
+    with_process(|process| {
+        let subject = process.binary_from_str("a\nb\n\nc\n\n").unwrap();
+        let pattern = process.binary_from_str("\n").unwrap();
+
+        assert_eq!(
+            native(
+                process,
+                subject,
+                pattern,
+                options(process, &["global", "trim"])
+            ),
+            Ok(binaries(process, &["a", "b", "", "c"]))
+        );
+    });
+}
+
+#[test]
+fn with_trim_all_removes_all_empty_parts() {
+    with_process(|process| {
+        let subject = process.binary_from_str("\na\nb\n\nc\n").unwrap();
+        let pattern = process.binary_from_str("\n").unwrap();
+
+        assert_eq!(
+            native(
+                process,
+                subject,
+                pattern,
+                options(process, &["global", "trim_all"])
+            ),
+            Ok(binaries(process, &["a", "b", "c"]))
+        );
+    });
+}
+
+#[test]
+fn with_scope_only_matches_in_scope() {
+    with_process(|process| {
+        let subject = process.binary_from_str("a,b,c,d").unwrap();
+        let pattern = process.binary_from_str(",").unwrap();
+        let scope = process
+            .tuple_from_slice(&[
+                Atom::str_to_term("scope"),
+                process
+                    .tuple_from_slice(&[process.integer(2).unwrap(), process.integer(3).unwrap()])
+                    .unwrap(),
+            ])
+            .unwrap();
+        let options = process
+            .list_from_slice(&[Atom::str_to_term("global"), scope])
+            .unwrap();
+
+        assert_eq!(
+            native(process, subject, pattern, options),
+            Ok(binaries(process, &["a,b", "c,d"]))
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process(|process| {
+        let subject = process.binary_from_str("a,b").unwrap();
+        let pattern = process.binary_from_str(",").unwrap();
+        let option = Atom::str_to_term("everywhere");
+
+        assert_badarg!(
+            native(
+                process,
+                subject,
+                pattern,
+                process.list_from_slice(&[option]).unwrap()
+            ),
+            format!(
+                "option ({}) is not global, trim, trim_all, or {{scope, {{Start, Length}}}}",
+                option
+            )
+        );
+    });
+}
+
+#[test]
+fn byte_matches_are_the_same_as_longest_matches() {
+    let bytes = lines();
+
+    for global in &[false, true] {
+        assert_eq!(
+            byte_matches(&bytes, b'\n', 0..bytes.len(), *global),
+            longest_matches(&bytes, &[&b"\n"[..]], 0..bytes.len(), *global)
+        );
+    }
+}
+
+#[bench]
+fn bench_split_lines_with_byte_fast_path(bencher: &mut Bencher) {
+    let bytes = lines();
+
+    bencher.iter(|| split_matches(&bytes, &[&b"\n"[..]], 0..bytes.len(), true))
+}
+
+#[bench]
+fn bench_split_lines_with_longest_matches(bencher: &mut Bencher) {
+    let bytes = lines();
+
+    bencher.iter(|| longest_matches(&bytes, &[&b"\n"[..]], 0..bytes.len(), true))
+}
+
+fn binaries(process: &Process, strs: &[&str]) -> Term {
+    let binary_vec: Vec<Term> = strs
+        .iter()
+        .map(|s| process.binary_from_str(s).unwrap())
+        .collect();
+
+    process.list_from_slice(&binary_vec).unwrap()
+}
+
+/// A multi-megabyte log-like binary
+fn lines() -> Vec<u8> {
+    let mut bytes = Vec::new();
+
+    for i in 0..65_536 {
+        bytes.extend_from_slice(
+            format!("{:08} lumen log line of about sixty bytes long\n", i).as_bytes(),
+        );
+    }
+
+    bytes
+}
+
+fn options(process: &Process, names: &[&str]) -> Term {
+    let option_vec: Vec<Term> = names.iter().map(|name| Atom::str_to_term(name)).collect();
+
+    process.list_from_slice(&option_vec).unwrap()
+}
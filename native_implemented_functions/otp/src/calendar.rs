@@ -0,0 +1,11 @@
+//! Mirrors [calendar](http://erlang.org/doc/man/calendar.html) module
+
+pub mod day_of_the_week_3;
+mod gregorian;
+pub mod is_leap_year_1;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("calendar").unwrap()
+}
@@ -0,0 +1,55 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+use num_bigint::{BigInt, Sign};
+use num_traits::ToPrimitive;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use lumen_rt_core::context::*;
+
+use crate::calendar::gregorian::{date_to_gregorian_days, days_in_month};
+
+/// `day_of_the_week/3`
+///
+/// Returns `1` for Monday through `7` for Sunday.
+#[native_implemented_function(day_of_the_week/3)]
+pub fn native(process: &Process, year: Term, month: Term, day: Term) -> exception::Result<Term> {
+    let year_big_int: BigInt = year
+        .try_into()
+        .ok()
+        .filter(|year_big_int: &BigInt| year_big_int.sign() != Sign::Minus)
+        .with_context(|| term_is_not_non_negative_integer("year", year))?;
+    let month_u8: u8 = month
+        .try_into()
+        .ok()
+        .filter(|month_u8| 1 <= *month_u8 && *month_u8 <= 12)
+        .with_context(|| term_is_not_type("month", month, "an integer in 1-12"))?;
+    let day_u8: u8 = day
+        .try_into()
+        .ok()
+        .filter(|day_u8| 1 <= *day_u8 && *day_u8 <= days_in_month(&year_big_int, month_u8))
+        .with_context(|| {
+            format!(
+                "day ({}) is not a day in month ({}) of year ({})",
+                day, month, year
+            )
+        })?;
+
+    let days = date_to_gregorian_days(&year_big_int, month_u8, day_u8);
+    // `{0, 1, 1}` was a Saturday
+    let day_of_the_week = ((days + 5_u32) % 7_u32 + 1_u32).to_u8().unwrap();
+
+    process.integer(day_of_the_week).map_err(From::from)
+}
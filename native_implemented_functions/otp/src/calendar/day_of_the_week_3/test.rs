@@ -0,0 +1,81 @@
+use crate::calendar::day_of_the_week_3::native;
+use crate::test::with_process;
+
+#[test]
+fn with_known_dates_returns_day_of_the_week() {
+    with_process(|process| {
+        // (year, month, day, day of the week)
+        let known_dates = [
+            (0, 1, 1, 6),
+            (1900, 2, 28, 3),
+            (1900, 3, 1, 4),
+            (1970, 1, 1, 4),
+            (2000, 1, 1, 6),
+            (2000, 2, 29, 2),
+            (2000, 3, 1, 3),
+            (2020, 1, 19, 7),
+            (2020, 1, 20, 1),
+        ];
+
+        for (year, month, day, day_of_the_week) in known_dates.iter() {
+            assert_eq!(
+                native(
+                    process,
+                    process.integer(*year).unwrap(),
+                    process.integer(*month).unwrap(),
+                    process.integer(*day).unwrap()
+                ),
+                Ok(process.integer(*day_of_the_week).unwrap()),
+                "{}-{}-{}",
+                year,
+                month,
+                day
+            );
+        }
+    });
+}
+
+#[test]
+fn with_february_29_in_non_leap_year_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            native(
+                process,
+                process.integer(1900).unwrap(),
+                process.integer(2).unwrap(),
+                process.integer(29).unwrap()
+            ),
+            "day (29) is not a day in month (2) of year (1900)"
+        );
+    });
+}
+
+#[test]
+fn without_month_in_range_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            native(
+                process,
+                process.integer(2000).unwrap(),
+                process.integer(13).unwrap(),
+                process.integer(1).unwrap()
+            ),
+            "month (13) is not an integer in 1-12"
+        );
+    });
+}
+
+#[test]
+fn with_negative_year_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            native(
+                process,
+                process.integer(-1).unwrap(),
+                process.integer(1).unwrap(),
+                process.integer(1).unwrap()
+            ),
+            "year (-1) is not a non-negative integer"
+        );
+    });
+}
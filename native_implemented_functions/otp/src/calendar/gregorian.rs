@@ -0,0 +1,46 @@
+use num_bigint::BigInt;
+
+use num_traits::Zero;
+
+/// Days from `{0, 1, 1}` to `{year, month, day}`, like `calendar:date_to_gregorian_days/3`.
+/// `year` must be non-negative and `{year, month, day}` must be a valid date.
+pub fn date_to_gregorian_days(year: &BigInt, month: u8, day: u8) -> BigInt {
+    let days_before_year = if year.is_zero() {
+        BigInt::zero()
+    } else {
+        let previous_year: BigInt = year - 1_u32;
+
+        // Year 0 is a leap year, so it adds the extra `1`
+        year * 365_u32 + &previous_year / 4_u32 - &previous_year / 100_u32
+            + &previous_year / 400_u32
+            + 1_u32
+    };
+    let days_before_month = DAYS_BEFORE_MONTH[(month - 1) as usize]
+        + if (2 < month) && is_leap_year(year) {
+            1
+        } else {
+            0
+        };
+
+    days_before_year + days_before_month + (day as u32) - 1_u32
+}
+
+pub fn days_in_month(year: &BigInt, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => unreachable!("month ({}) is not in 1-12", month),
+    }
+}
+
+pub fn is_leap_year(year: &BigInt) -> bool {
+    let is_divisible_by = |divisor: u32| (year % divisor).is_zero();
+
+    is_divisible_by(4) && (!is_divisible_by(100) || is_divisible_by(400))
+}
+
+// Private
+
+const DAYS_BEFORE_MONTH: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
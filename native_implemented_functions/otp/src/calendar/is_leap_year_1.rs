@@ -0,0 +1,31 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+use num_bigint::{BigInt, Sign};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use lumen_rt_core::context::*;
+
+use crate::calendar::gregorian::is_leap_year;
+
+#[native_implemented_function(is_leap_year/1)]
+pub fn native(year: Term) -> exception::Result<Term> {
+    let year_big_int: BigInt = year
+        .try_into()
+        .ok()
+        .filter(|year_big_int: &BigInt| year_big_int.sign() != Sign::Minus)
+        .with_context(|| term_is_not_non_negative_integer("year", year))?;
+
+    Ok(is_leap_year(&year_big_int).into())
+}
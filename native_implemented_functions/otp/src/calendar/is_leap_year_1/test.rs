@@ -0,0 +1,56 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::calendar::is_leap_year_1::native;
+use crate::test::with_process;
+
+#[test]
+fn without_integer_errors_badarg() {
+    assert_badarg!(
+        native(Atom::str_to_term("year")),
+        "year (:'year') is not a non-negative integer"
+    );
+}
+
+#[test]
+fn with_negative_year_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            native(process.integer(-4).unwrap()),
+            "year (-4) is not a non-negative integer"
+        );
+    });
+}
+
+#[test]
+fn with_century_not_divisible_by_400_returns_false() {
+    with_process(|process| {
+        assert_eq!(native(process.integer(1900).unwrap()), Ok(false.into()));
+        assert_eq!(native(process.integer(2100).unwrap()), Ok(false.into()));
+    });
+}
+
+#[test]
+fn with_century_divisible_by_400_returns_true() {
+    with_process(|process| {
+        assert_eq!(native(process.integer(1600).unwrap()), Ok(true.into()));
+        assert_eq!(native(process.integer(2000).unwrap()), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_non_century_returns_whether_divisible_by_4() {
+    with_process(|process| {
+        assert_eq!(native(process.integer(2004).unwrap()), Ok(true.into()));
+        assert_eq!(native(process.integer(2019).unwrap()), Ok(false.into()));
+    });
+}
+
+#[test]
+fn with_big_integer_divisible_by_400_returns_true() {
+    with_process(|process| {
+        let year = process.integer(400_i128 << 64).unwrap();
+
+        assert!(year.is_boxed_bigint());
+        assert_eq!(native(year), Ok(true.into()));
+    });
+}
@@ -0,0 +1,57 @@
+//! Mirrors [dict](http://erlang.org/doc/man/dict.html) module
+//!
+//! Dicts are `{dict, Map}`, so, like in OTP, keys are compared with exact equality (`=:=`), as map
+//! keys are.  Like the record that OTP uses, the tuple is opaque, so that a dict is not mistaken
+//! for a map.
+
+pub mod erase_2;
+pub mod fetch_2;
+pub mod find_2;
+pub mod new_0;
+pub mod size_1;
+pub mod store_3;
+pub mod to_list_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+use hashbrown::HashMap;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("dict").unwrap()
+}
+
+// Private
+
+fn dict_from_hash_map(
+    process: &Process,
+    hash_map: HashMap<MapKey, Term>,
+) -> exception::Result<Term> {
+    let map = process.map_from_hash_map(hash_map)?;
+
+    dict_from_map(process, map)
+}
+
+fn dict_from_map(process: &Process, map: Term) -> exception::Result<Term> {
+    process
+        .tuple_from_slice(&[atom!("dict"), map])
+        .map_err(From::from)
+}
+
+fn dict_try_into_map(name: &str, dict: Term) -> exception::Result<Boxed<Map>> {
+    let option = match dict.decode()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 && tuple[0] == atom!("dict") => {
+            tuple[1].try_into().ok()
+        }
+        _ => None,
+    };
+
+    option
+        .with_context(|| format!("{} ({}) is not a dict", name, dict))
+        .map_err(From::from)
+}
@@ -0,0 +1,28 @@
+use liblumen_alloc::atom;
+
+use crate::dict::erase_2::native;
+use crate::dict::{find_2, new_0, store_3};
+use crate::test::with_process;
+
+#[test]
+fn with_key_removes_it() {
+    with_process(|process| {
+        let dict = new_0::native(process).unwrap();
+        let dict = store_3::native(process, atom!("key"), atom!("value"), dict).unwrap();
+        let dict = native(process, atom!("key"), dict).unwrap();
+
+        assert_eq!(
+            find_2::native(process, atom!("key"), dict),
+            Ok(atom!("error"))
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_dict() {
+    with_process(|process| {
+        let dict1 = new_0::native(process).unwrap();
+
+        assert_eq!(native(process, atom!("key"), dict1), Ok(dict1));
+    });
+}
@@ -0,0 +1,26 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::dict::dict_try_into_map;
+
+/// Returns the value for `key`, which must be in `dict`.
+#[native_implemented_function(fetch/2)]
+pub fn native(key: Term, dict: Term) -> exception::Result<Term> {
+    let boxed_map = dict_try_into_map("dict", dict)?;
+
+    boxed_map
+        .get(key)
+        .with_context(|| format!("key ({}) is not in dict ({})", key, dict))
+        .map_err(From::from)
+}
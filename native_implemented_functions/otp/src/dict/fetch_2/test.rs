@@ -0,0 +1,28 @@
+use liblumen_alloc::atom;
+
+use crate::dict::fetch_2::native;
+use crate::dict::{new_0, store_3};
+use crate::test::with_process;
+
+#[test]
+fn with_key_returns_value() {
+    with_process(|process| {
+        let dict = new_0::native(process).unwrap();
+        let dict = store_3::native(process, atom!("key"), atom!("value"), dict).unwrap();
+
+        assert_eq!(native(atom!("key"), dict), Ok(atom!("value")));
+    });
+}
+
+#[test]
+fn without_key_errors_badarg() {
+    with_process(|process| {
+        let dict = new_0::native(process).unwrap();
+        let key = atom!("key");
+
+        assert_badarg!(
+            native(key, dict),
+            format!("key ({}) is not in dict ({})", key, dict)
+        );
+    });
+}
@@ -0,0 +1,28 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::dict::dict_try_into_map;
+
+/// Returns `{ok, Value}` for `key` or `error` if `dict` does not have `key`.
+#[native_implemented_function(find/2)]
+pub fn native(process: &Process, key: Term, dict: Term) -> exception::Result<Term> {
+    let boxed_map = dict_try_into_map("dict", dict)?;
+
+    match boxed_map.get(key) {
+        Some(value) => process
+            .tuple_from_slice(&[atom!("ok"), value])
+            .map_err(From::from),
+        None => Ok(atom!("error")),
+    }
+}
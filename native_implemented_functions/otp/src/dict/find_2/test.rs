@@ -0,0 +1,29 @@
+use liblumen_alloc::atom;
+
+use crate::dict::find_2::native;
+use crate::dict::{new_0, store_3};
+use crate::test::with_process;
+
+#[test]
+fn with_key_returns_ok_value() {
+    with_process(|process| {
+        let dict = new_0::native(process).unwrap();
+        let dict = store_3::native(process, atom!("key"), atom!("value"), dict).unwrap();
+
+        assert_eq!(
+            native(process, atom!("key"), dict),
+            Ok(process
+                .tuple_from_slice(&[atom!("ok"), atom!("value")])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_error() {
+    with_process(|process| {
+        let dict = new_0::native(process).unwrap();
+
+        assert_eq!(native(process, atom!("key"), dict), Ok(atom!("error")));
+    });
+}
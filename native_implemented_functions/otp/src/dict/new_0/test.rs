@@ -0,0 +1,15 @@
+use crate::dict::new_0::native;
+use crate::test::with_process;
+
+#[test]
+fn returns_empty_dict() {
+    with_process(|process| {
+        let dict = native(process).unwrap();
+
+        assert!(dict.is_boxed_tuple());
+        assert_eq!(
+            crate::dict::size_1::native(process, dict),
+            Ok(process.integer(0).unwrap())
+        );
+    });
+}
@@ -0,0 +1,21 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::dict::dict_try_into_map;
+
+#[native_implemented_function(size/1)]
+pub fn native(process: &Process, dict: Term) -> exception::Result<Term> {
+    let boxed_map = dict_try_into_map("dict", dict)?;
+
+    process.integer(boxed_map.len()).map_err(From::from)
+}
@@ -0,0 +1,16 @@
+use liblumen_alloc::atom;
+
+use crate::dict::size_1::native;
+use crate::dict::{new_0, store_3};
+use crate::test::with_process;
+
+#[test]
+fn returns_number_of_keys() {
+    with_process(|process| {
+        let dict = new_0::native(process).unwrap();
+        let dict = store_3::native(process, atom!("a"), atom!("value"), dict).unwrap();
+        let dict = store_3::native(process, atom!("b"), atom!("value"), dict).unwrap();
+
+        assert_eq!(native(process, dict), Ok(process.integer(2).unwrap()));
+    });
+}
@@ -0,0 +1,25 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::dict::{dict_from_hash_map, dict_try_into_map};
+
+/// Stores `value` for `key`, replacing any value that `key` already has.
+#[native_implemented_function(store/3)]
+pub fn native(process: &Process, key: Term, value: Term, dict1: Term) -> exception::Result<Term> {
+    let boxed_map = dict_try_into_map("dict1", dict1)?;
+
+    match boxed_map.put(key, value) {
+        Some(hash_map) => dict_from_hash_map(process, hash_map),
+        None => Ok(dict1),
+    }
+}
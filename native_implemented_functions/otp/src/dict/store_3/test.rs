@@ -0,0 +1,75 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::dict::store_3::native;
+use crate::dict::{fetch_2, new_0, size_1};
+use crate::test::with_process;
+
+#[test]
+fn with_existing_key_replaces_value() {
+    with_process(|process| {
+        let dict = new_0::native(process).unwrap();
+        let dict = native(process, atom!("key"), process.integer(1).unwrap(), dict).unwrap();
+        let dict = native(process, atom!("key"), process.integer(2).unwrap(), dict).unwrap();
+
+        assert_eq!(
+            fetch_2::native(atom!("key"), dict),
+            Ok(process.integer(2).unwrap())
+        );
+        assert_eq!(
+            size_1::native(process, dict),
+            Ok(process.integer(1).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_existing_key_and_equal_but_not_exactly_equal_value_replaces_value() {
+    with_process(|process| {
+        let float = process.float(1.0).unwrap();
+        let dict = new_0::native(process).unwrap();
+        let dict = native(process, atom!("key"), process.integer(1).unwrap(), dict).unwrap();
+        let dict = native(process, atom!("key"), float, dict).unwrap();
+
+        let value = fetch_2::native(atom!("key"), dict).unwrap();
+
+        assert!(
+            value.decode().unwrap().exact_eq(&float.decode().unwrap()),
+            "value ({}) is not exactly equal to {}",
+            value,
+            float
+        );
+    });
+}
+
+#[test]
+fn with_equal_but_not_exactly_equal_key_stores_different_key() {
+    with_process(|process| {
+        let float_key = process.float(1.0).unwrap();
+        let dict = new_0::native(process).unwrap();
+        let dict = native(process, process.integer(1).unwrap(), atom!("integer"), dict).unwrap();
+        let dict = native(process, float_key, atom!("float"), dict).unwrap();
+
+        assert_eq!(
+            fetch_2::native(process.integer(1).unwrap(), dict),
+            Ok(atom!("integer"))
+        );
+        assert_eq!(fetch_2::native(float_key, dict), Ok(atom!("float")));
+        assert_eq!(
+            size_1::native(process, dict),
+            Ok(process.integer(2).unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_dict_errors_badarg() {
+    with_process(|process| {
+        let dict1 = process.map_from_slice(&[]).unwrap();
+
+        assert_badarg!(
+            native(process, atom!("key"), atom!("value"), dict1),
+            format!("dict1 ({}) is not a dict", dict1)
+        );
+    });
+}
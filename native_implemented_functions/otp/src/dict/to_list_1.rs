@@ -0,0 +1,27 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::dict::dict_try_into_map;
+
+/// The `{Key, Value}` entries of `dict` in key order.
+#[native_implemented_function(to_list/1)]
+pub fn native(process: &Process, dict: Term) -> exception::Result<Term> {
+    let boxed_map = dict_try_into_map("dict", dict)?;
+    let mut entry_vec: Vec<Term> = Vec::with_capacity(boxed_map.len());
+
+    for (key, value) in boxed_map.iter_sorted() {
+        entry_vec.push(process.tuple_from_slice(&[key, value])?);
+    }
+
+    process.list_from_slice(&entry_vec).map_err(From::from)
+}
@@ -0,0 +1,28 @@
+use liblumen_alloc::atom;
+
+use crate::dict::to_list_1::native;
+use crate::dict::{new_0, store_3};
+use crate::test::with_process;
+
+#[test]
+fn returns_key_value_tuples_in_key_order() {
+    with_process(|process| {
+        let dict = new_0::native(process).unwrap();
+        let dict = store_3::native(process, atom!("b"), process.integer(2).unwrap(), dict).unwrap();
+        let dict = store_3::native(process, atom!("a"), process.integer(1).unwrap(), dict).unwrap();
+
+        assert_eq!(
+            native(process, dict),
+            Ok(process
+                .list_from_slice(&[
+                    process
+                        .tuple_from_slice(&[atom!("a"), process.integer(1).unwrap()])
+                        .unwrap(),
+                    process
+                        .tuple_from_slice(&[atom!("b"), process.integer(2).unwrap()])
+                        .unwrap(),
+                ])
+                .unwrap())
+        );
+    });
+}
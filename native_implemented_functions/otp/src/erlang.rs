@@ -41,9 +41,11 @@ mod charlist_to_string;
 pub mod concatenate_2;
 pub mod convert_time_unit_3;
 pub mod date_0;
+mod datetime_tuple;
 pub mod delete_element_2;
 pub mod demonitor_1;
 pub mod demonitor_2;
+pub mod display_string_1;
 pub mod div_2;
 pub mod divide_2;
 pub mod element_2;
@@ -52,6 +54,8 @@ pub mod erase_1;
 pub mod error_1;
 pub mod error_2;
 pub mod exit_1;
+pub mod external_size_1;
+pub mod external_size_2;
 pub mod float_1;
 pub mod float_to_binary_1;
 pub mod float_to_binary_2;
@@ -59,7 +63,10 @@ pub mod float_to_list_1;
 pub mod float_to_list_2;
 mod float_to_string;
 pub mod floor_1;
+pub mod fun_to_list_1;
 pub mod function_exported_3;
+pub mod garbage_collect_0;
+pub mod garbage_collect_1;
 pub mod get_0;
 pub mod get_1;
 pub mod get_keys_0;
@@ -67,6 +74,7 @@ pub mod get_keys_1;
 pub mod get_stacktrace_0;
 pub mod group_leader_0;
 pub mod group_leader_2;
+pub mod hash_2;
 pub mod hd_1;
 pub mod insert_element_3;
 pub mod integer_to_binary_1;
@@ -74,7 +82,7 @@ pub mod integer_to_binary_2;
 pub mod integer_to_list_1;
 pub mod integer_to_list_2;
 mod integer_to_string;
-mod iolist_or_binary;
+pub(crate) mod iolist_or_binary;
 pub mod iolist_size_1;
 pub mod iolist_to_binary_1;
 pub mod iolist_to_iovec_1;
@@ -111,15 +119,21 @@ pub mod list_to_float_1;
 pub mod list_to_integer_1;
 pub mod list_to_integer_2;
 pub mod list_to_pid_1;
-mod list_to_string;
+pub(crate) mod list_to_string;
 pub mod list_to_tuple_1;
 pub mod localtime_0;
+pub mod localtime_to_universaltime_1;
+pub mod localtime_to_universaltime_2;
+pub mod make_fun_3;
 pub mod make_ref_0;
 pub mod make_tuple_2;
 pub mod make_tuple_3;
 pub mod map_get_2;
 pub mod map_size_1;
 pub mod max_2;
+mod memory;
+pub mod memory_0;
+pub mod memory_1;
 pub mod min_2;
 pub mod monitor_2;
 pub mod monotonic_time_0;
@@ -142,6 +156,7 @@ pub mod read_timer_2;
 pub mod register_2;
 pub mod registered_0;
 pub mod rem_2;
+pub mod resume_process_1;
 pub mod round_1;
 pub mod self_0;
 pub mod send_2;
@@ -163,14 +178,18 @@ pub mod spawn_opt_4;
 pub mod split_binary_2;
 pub mod start_timer_3;
 pub mod start_timer_4;
+pub mod statistics_1;
 mod string_to_float;
 mod string_to_integer;
 pub mod subtract_2;
 pub mod subtract_list_2;
+pub mod suspend_process_1;
+pub mod system_info_1;
 pub mod system_time_0;
 pub mod system_time_1;
 mod term_to_binary;
 pub mod term_to_binary_1;
+pub mod term_to_json_1;
 pub mod throw_1;
 pub mod time_0;
 pub mod time_offset_0;
@@ -184,10 +203,12 @@ mod unique_integer;
 pub mod unique_integer_0;
 pub mod unique_integer_1;
 pub mod universaltime_0;
+pub mod universaltime_to_localtime_1;
 pub mod unlink_1;
 pub mod unregister_1;
 pub mod whereis_1;
 pub mod xor_2;
+pub mod yield_0;
 
 use std::convert::TryInto;
 use std::sync::Arc;
@@ -196,8 +217,10 @@ use anyhow::*;
 
 use liblumen_alloc::atom;
 use liblumen_alloc::erts::exception::{self, InternalResult};
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::gc::GcError;
+use liblumen_alloc::erts::process::{Process, ProcessFlags};
 use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::exit;
 
 use lumen_rt_core::context::*;
 use lumen_rt_core::time::{monotonic, Milliseconds};
@@ -254,6 +277,24 @@ fn cancel_timer(
     Ok(term)
 }
 
+/// Collects the heap of `process` immediately with a full sweep.
+///
+/// Live terms are moved, so only `process` itself can call this and only terms reachable from its
+/// stack or dictionary remain valid afterward.
+fn garbage_collect(process: &Process) -> exception::Result<()> {
+    process.set_flags(ProcessFlags::NeedFullSweep);
+
+    match process.garbage_collect(0, &mut []) {
+        Ok(reductions) => {
+            process.reduce_by(reductions);
+
+            Ok(())
+        }
+        Err(GcError::Alloc(alloc)) => Err(alloc.into()),
+        Err(gc_error) => Err(exit!(atom!("killed"), anyhow!(gc_error).into()).into()),
+    }
+}
+
 fn is_record(term: Term, record_tag: Term, size: Option<Term>) -> exception::Result<Term> {
     match term.decode()? {
         TypedTerm::Tuple(tuple) => {
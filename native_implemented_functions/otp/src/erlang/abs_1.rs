@@ -53,7 +53,7 @@ pub fn native(process: &Process, number: Term) -> exception::Result<Term> {
                 Ordering::Less => {
                     let positive_f = f.abs();
 
-                    process.float(positive_f).unwrap()
+                    process.float(positive_f)?
                 }
                 _ => number,
             };
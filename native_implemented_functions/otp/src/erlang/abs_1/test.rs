@@ -4,7 +4,7 @@ use proptest::strategy::Just;
 use liblumen_alloc::erts::term::prelude::Term;
 
 use crate::erlang::abs_1::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_number_errors_badarg() {
@@ -34,3 +34,23 @@ fn with_number_returns_non_negative() {
         },
     );
 }
+
+#[test]
+fn with_negative_small_integer_returns_positive_small_integer() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.integer(-5).unwrap()),
+            Ok(process.integer(5).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_negative_float_returns_positive_float() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.float(-2.5).unwrap()),
+            Ok(process.float(2.5).unwrap())
+        );
+    });
+}
@@ -65,3 +65,21 @@ where
         },
     );
 }
+
+#[test]
+fn with_float_operand_returns_float() {
+    with_process(|process| {
+        let one = process.integer(1).unwrap();
+        let two_float = process.float(2.0).unwrap();
+
+        let sum = native(process, one, two_float).unwrap();
+
+        assert!(sum.is_float());
+        assert_eq!(sum, process.float(3.0).unwrap());
+
+        let big_integer = process.integer(SmallInteger::MAX_VALUE + 1).unwrap();
+
+        assert!(native(process, big_integer, two_float).unwrap().is_float());
+        assert!(native(process, two_float, big_integer).unwrap().is_float());
+    });
+}
@@ -112,3 +112,35 @@ where
         f(augend, &process)
     })
 }
+
+#[test]
+fn at_small_integer_boundaries_promotes_to_big_integer_and_back() {
+    with_process(|process| {
+        let max = process.integer(SmallInteger::MAX_VALUE).unwrap();
+        let min = process.integer(SmallInteger::MIN_VALUE).unwrap();
+        let one = process.integer(1).unwrap();
+        let negative_one = process.integer(-1).unwrap();
+
+        let above_max = native(&process, max, one).unwrap();
+
+        assert!(above_max.is_boxed_bigint());
+        assert_eq!(
+            above_max,
+            process.integer(SmallInteger::MAX_VALUE + 1).unwrap()
+        );
+        assert_eq!(native(&process, above_max, negative_one), Ok(max));
+        assert!(native(&process, above_max, negative_one)
+            .unwrap()
+            .is_smallint());
+
+        let below_min = native(&process, min, negative_one).unwrap();
+
+        assert!(below_min.is_boxed_bigint());
+        assert_eq!(
+            below_min,
+            process.integer(SmallInteger::MIN_VALUE - 1).unwrap()
+        );
+        assert_eq!(native(&process, below_min, one), Ok(min));
+        assert!(native(&process, below_min, one).unwrap().is_smallint());
+    })
+}
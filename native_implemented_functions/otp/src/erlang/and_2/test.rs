@@ -5,9 +5,21 @@ use proptest::prop_assert_eq;
 use proptest::test_runner::{Config, TestRunner};
 
 use crate::erlang::and_2::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_boolean_left_errors_badarg() {
     crate::test::without_boolean_left_errors_badarg(file!(), native);
 }
+
+#[test]
+fn with_integer_left_and_true_right_errors_badarg() {
+    with_process(|process| {
+        let left_boolean = process.integer(1).unwrap();
+
+        assert_badarg!(
+            native(left_boolean, true.into()),
+            format!("left_boolean ({}) is not a boolean", left_boolean)
+        );
+    });
+}
@@ -40,8 +40,8 @@ fn run_until_ready(function: Term, arguments: Term) -> Ready {
     lumen_rt_full::future::run_until_ready(
         Default::default(),
         |child_process| {
-            let child_function = function.clone_to_process(child_process);
-            let child_arguments = arguments.clone_to_process(child_process);
+            let child_function = function.clone_to_process(child_process)?;
+            let child_arguments = arguments.clone_to_process(child_process)?;
 
             place_frame_with_arguments(
                 child_process,
@@ -2,6 +2,8 @@ use super::*;
 
 use proptest::strategy::Strategy;
 
+use crate::test::with_process;
+
 #[test]
 fn without_small_integer_or_float_returns_false() {
     run!(
@@ -126,3 +128,14 @@ fn with_different_value_float_right_returns_false() {
         },
     );
 }
+
+#[test]
+fn with_one_and_one_point_zero_returns_true() {
+    with_process(|process| {
+        let left = process.integer(1).unwrap();
+        let right = process.float(1.0).unwrap();
+
+        assert_eq!(native(left, right), true.into());
+        assert_eq!(native(right, left), true.into());
+    });
+}
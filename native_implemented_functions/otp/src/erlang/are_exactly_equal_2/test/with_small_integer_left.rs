@@ -2,6 +2,8 @@ use super::*;
 
 use proptest::strategy::Strategy;
 
+use crate::test::with_process;
+
 #[test]
 fn without_small_integer_returns_false() {
     run!(
@@ -69,3 +71,14 @@ fn with_different_small_integer_right_returns_false() {
         },
     );
 }
+
+#[test]
+fn with_one_and_one_point_zero_returns_false() {
+    with_process(|process| {
+        let left = process.integer(1).unwrap();
+        let right = process.float(1.0).unwrap();
+
+        assert_eq!(native(left, right), false.into());
+        assert_eq!(native(right, left), false.into());
+    });
+}
@@ -31,36 +31,27 @@ pub fn native(
     let length_isize = term_try_into_isize!(length)?;
 
     match binary.decode().unwrap() {
-        TypedTerm::HeapBinary(heap_binary) => {
-            let available_byte_count = heap_binary.full_byte_len();
-            let PartRange {
-                byte_offset,
-                byte_len,
-            } = start_length_to_part_range(start_usize, length_isize, available_byte_count)?;
-
-            if (byte_offset == 0) && (byte_len == available_byte_count) {
-                Ok(binary)
-            } else {
-                process
-                    .subbinary_from_original(binary, byte_offset, 0, byte_len, 0)
-                    .map_err(|error| error.into())
-            }
-        }
-        TypedTerm::ProcBin(process_binary) => {
-            let available_byte_count = process_binary.full_byte_len();
-            let PartRange {
-                byte_offset,
-                byte_len,
-            } = start_length_to_part_range(start_usize, length_isize, available_byte_count)?;
-
-            if (byte_offset == 0) && (byte_len == available_byte_count) {
-                Ok(binary)
-            } else {
-                process
-                    .subbinary_from_original(binary, byte_offset, 0, byte_len, 0)
-                    .map_err(|error| error.into())
-            }
-        }
+        TypedTerm::HeapBinary(heap_binary) => original_part(
+            process,
+            binary,
+            heap_binary.full_byte_len(),
+            start_usize,
+            length_isize,
+        ),
+        TypedTerm::ProcBin(process_binary) => original_part(
+            process,
+            binary,
+            process_binary.full_byte_len(),
+            start_usize,
+            length_isize,
+        ),
+        TypedTerm::BinaryLiteral(binary_literal) => original_part(
+            process,
+            binary,
+            binary_literal.full_byte_len(),
+            start_usize,
+            length_isize,
+        ),
         TypedTerm::SubBinary(subbinary) => {
             let PartRange {
                 byte_offset,
@@ -93,3 +84,28 @@ pub fn native(
             .map_err(From::from),
     }
 }
+
+// Private
+
+/// Part of a binary that is the original of any subbinary, so the subbinary can point directly at
+/// `binary`.
+fn original_part(
+    process: &Process,
+    binary: Term,
+    available_byte_count: usize,
+    start: usize,
+    length: isize,
+) -> exception::Result<Term> {
+    let PartRange {
+        byte_offset,
+        byte_len,
+    } = start_length_to_part_range(start, length, available_byte_count)?;
+
+    if (byte_offset == 0) && (byte_len == available_byte_count) {
+        Ok(binary)
+    } else {
+        process
+            .subbinary_from_original(binary, byte_offset, 0, byte_len, 0)
+            .map_err(|error| error.into())
+    }
+}
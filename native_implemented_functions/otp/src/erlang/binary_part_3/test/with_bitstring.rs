@@ -88,7 +88,7 @@ fn with_start_less_than_size_with_negative_length_past_start_errors_badarg() {
         |(arc_process, binary, start, length, end)| {
             prop_assert_badarg!(
                 native(&arc_process, binary, start, length),
-                format!("end ({}) is less than or equal to 0", end)
+                format!("end ({}) is less than 0", end)
             );
 
             Ok(())
@@ -4,6 +4,7 @@ use proptest::strategy::{Just, Strategy};
 
 use crate::erlang::binary_to_float_1::native;
 use crate::test::strategy;
+use crate::test::with_process;
 
 #[test]
 fn without_binary_errors_badarg() {
@@ -27,7 +28,10 @@ fn with_binary_with_integer_errors_badarg() {
         |(arc_process, binary)| {
             prop_assert_badarg!(
                 native(&arc_process, binary),
-                format!("binary ({}) does not contain decimal point", binary)
+                format!(
+                    "binary ({}) does not contain decimal point or exponent",
+                    binary
+                )
             );
 
             Ok(())
@@ -93,3 +97,36 @@ fn with_binary_with_greater_than_max_f64_errors_badarg() {
         },
     );
 }
+
+#[test]
+fn with_binary_with_decimal_point_returns_float() {
+    with_process(|process| {
+        let binary = process.binary_from_str("3.0").unwrap();
+
+        assert_eq!(native(process, binary), Ok(process.float(3.0).unwrap()));
+    });
+}
+
+#[test]
+fn with_binary_without_decimal_point_or_exponent_errors_badarg() {
+    with_process(|process| {
+        let binary = process.binary_from_str("3").unwrap();
+
+        assert_badarg!(
+            native(process, binary),
+            format!(
+                "binary ({}) does not contain decimal point or exponent",
+                binary
+            )
+        );
+    });
+}
+
+#[test]
+fn with_binary_with_exponent_without_decimal_point_returns_float() {
+    with_process(|process| {
+        let binary = process.binary_from_str("3e2").unwrap();
+
+        assert_eq!(native(process, binary), Ok(process.float(300.0).unwrap()));
+    });
+}
@@ -4,7 +4,8 @@ use proptest::strategy::{Just, Strategy};
 use liblumen_alloc::erts::term::prelude::Term;
 
 use crate::erlang::binary_to_list_1::native;
-use crate::test::strategy;
+use crate::erlang::list_to_binary_1;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_binary_errors_badarg() {
@@ -75,3 +76,32 @@ fn with_binary_returns_list_of_bytes() {
         },
     );
 }
+
+#[test]
+fn with_bitstring_with_partial_byte_errors_badarg() {
+    with_process(|process| {
+        let bitstring = bitstring!(1, 2, 0b101 :: 3, &process);
+
+        assert_badarg!(
+            native(process, bitstring),
+            format!("binary ({})", bitstring)
+        );
+    });
+}
+
+#[test]
+fn with_binary_round_trips_through_list_to_binary() {
+    with_process(|process| {
+        let binary = process.binary_from_bytes(&[1, 2, 3]).unwrap();
+        let list = process
+            .list_from_slice(&[
+                process.integer(1).unwrap(),
+                process.integer(2).unwrap(),
+                process.integer(3).unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(native(process, binary), Ok(list));
+        assert_eq!(list_to_binary_1::native(process, list), Ok(binary));
+    });
+}
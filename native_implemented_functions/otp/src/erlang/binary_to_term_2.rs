@@ -46,6 +46,9 @@ pub fn native(process: &Process, binary: Term, options: Term) -> exception::Resu
     let options: Options = options.try_into()?;
 
     match binary.decode()? {
+        TypedTerm::BinaryLiteral(binary_literal) => {
+            versioned_tagged_bytes_try_into_term(process, &options, binary_literal.as_bytes())
+        }
         TypedTerm::HeapBinary(heap_binary) => {
             versioned_tagged_bytes_try_into_term(process, &options, heap_binary.as_bytes())
         }
@@ -59,3 +59,50 @@ fn with_used_with_binary_returns_how_many_bytes_were_consumed_along_with_term()
         process.cons(Atom::str_to_term("used"), Term::NIL).unwrap()
     }
 }
+
+#[test]
+fn with_used_with_two_terms_decodes_second_from_remaining_bytes() {
+    // <<131,100,0,5,"hello",131,97,42>>
+    let byte_vec = vec![131, 100, 0, 5, 104, 101, 108, 108, 111, 131, 97, 42];
+
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::binary::containing_bytes(byte_vec.clone(), arc_process.clone()),
+            )
+        },
+        |(arc_process, binary)| {
+            let options = arc_process
+                .cons(Atom::str_to_term("used"), Term::NIL)
+                .unwrap();
+
+            let first_tuple = native(&arc_process, binary, options).unwrap();
+            let first_term =
+                erlang::element_2::native(arc_process.integer(1).unwrap(), first_tuple).unwrap();
+            let first_used =
+                erlang::element_2::native(arc_process.integer(2).unwrap(), first_tuple).unwrap();
+
+            prop_assert_eq!(first_term, Atom::str_to_term("hello"));
+            prop_assert_eq!(first_used, arc_process.integer(9).unwrap());
+
+            let split_binary_tuple =
+                erlang::split_binary_2::native(&arc_process, binary, first_used).unwrap();
+            let rest =
+                erlang::element_2::native(arc_process.integer(2).unwrap(), split_binary_tuple)
+                    .unwrap();
+
+            prop_assert_eq!(
+                native(&arc_process, rest, options),
+                Ok(arc_process
+                    .tuple_from_slice(&[
+                        arc_process.integer(42).unwrap(),
+                        arc_process.integer(3).unwrap()
+                    ])
+                    .unwrap())
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -1,12 +1,13 @@
 use std::convert::TryInto;
 
-use proptest::strategy::Just;
+use proptest::strategy::{Just, Strategy, ValueTree};
+use proptest::test_runner::{Config, TestRunner};
 use proptest::{prop_assert, prop_assert_eq};
 
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::erlang::bit_size_1::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process, with_process_arc};
 
 #[test]
 fn without_bitstring_errors_badarg() {
@@ -71,3 +72,43 @@ fn with_subbinary_is_eight_times_byte_count_plus_bit_count() {
         },
     );
 }
+
+#[test]
+fn with_subbinary_with_seven_trailing_bits_is_seven() {
+    with_process(|process| {
+        let bitstring = bitstring!(0b1010101 :: 7, &process);
+
+        assert_eq!(native(process, bitstring), Ok(process.integer(7).unwrap()));
+    });
+}
+
+#[test]
+fn subbinary_strategy_covers_unaligned_and_partial_byte_cases() {
+    with_process_arc(|arc_process| {
+        let mut runner = TestRunner::new(Config::with_source_file(file!()));
+        let subbinary_strategy = strategy::term::binary::sub(arc_process.clone());
+        let subbinary_vec: Vec<Boxed<SubBinary>> = (0..64)
+            .map(|_| {
+                subbinary_strategy
+                    .new_tree(&mut runner)
+                    .unwrap()
+                    .current()
+                    .try_into()
+                    .unwrap()
+            })
+            .collect();
+
+        assert!(subbinary_vec
+            .iter()
+            .any(|subbinary| 0 < subbinary.partial_byte_bit_len()));
+        assert!(subbinary_vec
+            .iter()
+            .any(|subbinary| subbinary.partial_byte_bit_len() == 0));
+        assert!(subbinary_vec
+            .iter()
+            .any(|subbinary| 0 < subbinary.bit_offset()));
+        assert!(subbinary_vec
+            .iter()
+            .any(|subbinary| subbinary.bit_offset() == 0));
+    });
+}
@@ -54,6 +54,15 @@ fn with_small_integer_returns_small_integer() {
     );
 }
 
+#[test]
+fn with_zero_returns_negative_one() {
+    with_process(|process| {
+        let integer = process.integer(0).unwrap();
+
+        assert_eq!(native(&process, integer), Ok(process.integer(-1).unwrap()))
+    });
+}
+
 #[test]
 fn with_small_integer_inverts_bits() {
     with_process(|process| {
@@ -15,10 +15,12 @@ use native_implemented_function::native_implemented_function;
 
 #[native_implemented_function(byte_size/1)]
 pub fn native(process: &Process, bitstring: Term) -> exception::Result<Term> {
-    let option_total_byte_len = match bitstring.decode().unwrap() {
+    let option_total_byte_len = match bitstring.decode()? {
+        TypedTerm::BinaryLiteral(binary_literal) => Some(binary_literal.total_byte_len()),
         TypedTerm::HeapBinary(heap_binary) => Some(heap_binary.total_byte_len()),
         TypedTerm::ProcBin(process_binary) => Some(process_binary.total_byte_len()),
         TypedTerm::SubBinary(subbinary) => Some(subbinary.total_byte_len()),
+        TypedTerm::MatchContext(match_context) => Some(match_context.total_byte_len()),
         _ => None,
     };
 
@@ -2,7 +2,7 @@ use proptest::prop_assert_eq;
 use proptest::strategy::{Just, Strategy};
 
 use crate::erlang::byte_size_1::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_bitstring_errors_badarg() {
@@ -92,3 +92,12 @@ fn with_subbinary_with_bit_count_is_byte_count_plus_one() {
         },
     );
 }
+
+#[test]
+fn with_subbinary_with_seven_trailing_bits_rounds_up_to_one_byte() {
+    with_process(|process| {
+        let bitstring = bitstring!(0b1010101 :: 7, &process);
+
+        assert_eq!(native(process, bitstring), Ok(process.integer(1).unwrap()));
+    });
+}
@@ -1,6 +1,7 @@
 use proptest::prop_assert;
 
 use crate::erlang::ceil_1::native;
+use crate::test::with_process;
 
 #[test]
 fn without_number_errors_badarg() {
@@ -20,3 +21,13 @@ fn with_float_round_up_to_next_integer() {
         Ok(())
     })
 }
+
+#[test]
+fn with_negative_two_and_a_half_rounds_up() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.float(-2.5).unwrap()),
+            Ok(process.integer(-2).unwrap())
+        );
+    });
+}
@@ -64,6 +64,32 @@ fn without_list_right_returns_improper_list_with_right_as_tail() {
     );
 }
 
+#[test]
+fn with_integer_right_returns_improper_list_with_integer_as_tail() {
+    with_process_arc(|arc_process| {
+        let left = arc_process
+            .list_from_slice(&[
+                arc_process.integer(1).unwrap(),
+                arc_process.integer(2).unwrap(),
+            ])
+            .unwrap();
+        let right = arc_process.integer(3).unwrap();
+
+        assert_eq!(
+            native(&arc_process, left, right),
+            Ok(arc_process
+                .improper_list_from_slice(
+                    &[
+                        arc_process.integer(1).unwrap(),
+                        arc_process.integer(2).unwrap()
+                    ],
+                    right
+                )
+                .unwrap())
+        );
+    });
+}
+
 #[test]
 fn with_improper_list_right_returns_improper_list_with_right_as_tail() {
     run!(
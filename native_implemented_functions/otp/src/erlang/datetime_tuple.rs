@@ -0,0 +1,68 @@
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Converts `[Year, Month, Day, Hour, Minute, Second]` to `{{Year, Month, Day}, {Hour, Minute,
+/// Second}}`.
+pub fn datetime_to_term(process: &Process, datetime: [usize; 6]) -> exception::Result<Term> {
+    let date_tuple = process.tuple_from_slice(&[
+        process.integer(datetime[0])?,
+        process.integer(datetime[1])?,
+        process.integer(datetime[2])?,
+    ])?;
+    let time_tuple = process.tuple_from_slice(&[
+        process.integer(datetime[3])?,
+        process.integer(datetime[4])?,
+        process.integer(datetime[5])?,
+    ])?;
+
+    process
+        .tuple_from_slice(&[date_tuple, time_tuple])
+        .map_err(|error| error.into())
+}
+
+/// Converts `{{Year, Month, Day}, {Hour, Minute, Second}}` to `[Year, Month, Day, Hour, Minute,
+/// Second]`.  Only the shape is checked, so the fields may still not be a valid date and time.
+pub fn term_try_into_datetime(name: &str, term: Term) -> exception::Result<[usize; 6]> {
+    try_into_datetime(term)
+        .with_context(|| {
+            format!(
+                "{} ({}) is not a {{{{year, month, day}}, {{hour, minute, second}}}} tuple",
+                name, term
+            )
+        })
+        .map_err(From::from)
+}
+
+// Private
+
+fn try_into_datetime(term: Term) -> Option<[usize; 6]> {
+    let tuple: Boxed<Tuple> = term.try_into().ok()?;
+
+    match tuple.elements() {
+        [date, time] => {
+            let [year, month, day] = try_into_triple(*date)?;
+            let [hour, minute, second] = try_into_triple(*time)?;
+
+            Some([year, month, day, hour, minute, second])
+        }
+        _ => None,
+    }
+}
+
+fn try_into_triple(term: Term) -> Option<[usize; 3]> {
+    let tuple: Boxed<Tuple> = term.try_into().ok()?;
+
+    match tuple.elements() {
+        [first, second, third] => Some([
+            (*first).try_into().ok()?,
+            (*second).try_into().ok()?,
+            (*third).try_into().ok()?,
+        ]),
+        _ => None,
+    }
+}
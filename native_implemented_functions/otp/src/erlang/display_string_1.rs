@@ -0,0 +1,49 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_full::binary_to_string::binary_to_string;
+use lumen_rt_full::system;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang::list_to_string::list_to_string;
+
+/// Writes `string`, a charlist or UTF-8 binary, to standard output as text, the same as `~s` in
+/// `io:format/2`, instead of as a term like `display/1`.
+#[native_implemented_function(display_string/1)]
+pub fn native(string: Term) -> exception::Result<Term> {
+    let s = string_to_string(string)?;
+
+    // NOT A DEBUGGING LOG
+    system::io::print(&s);
+
+    Ok(true.into())
+}
+
+// Private
+
+fn string_to_string(string: Term) -> exception::Result<String> {
+    match string.decode()? {
+        TypedTerm::Nil | TypedTerm::List(_) => list_to_string(string),
+        TypedTerm::HeapBinary(_)
+        | TypedTerm::ProcBin(_)
+        | TypedTerm::BinaryLiteral(_)
+        | TypedTerm::SubBinary(_)
+            if string.is_binary() =>
+        {
+            binary_to_string(string)
+        }
+        _ => Err(TypeError)
+            .context(format!("string ({}) is not a charlist or binary", string))
+            .map_err(From::from),
+    }
+}
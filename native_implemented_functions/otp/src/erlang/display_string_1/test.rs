@@ -0,0 +1,56 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::display_string_1::native;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn with_charlist_returns_true() {
+    with_process(|process| {
+        let string = process.charlist_from_str("hello").unwrap();
+
+        assert_eq!(native(string), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_binary_returns_true() {
+    with_process(|process| {
+        let string = process.binary_from_str("hello").unwrap();
+
+        assert_eq!(native(string), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_charlist_with_non_character_errors_badarg() {
+    with_process(|process| {
+        let string = process
+            .list_from_slice(&[process.integer(-1).unwrap()])
+            .unwrap();
+
+        assert_badarg!(native(string), "must be a unicode scalar value");
+    });
+}
+
+#[test]
+fn with_tuple_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::tuple(arc_process.clone()),
+            )
+        },
+        |(_, string)| {
+            prop_assert_badarg!(
+                native(string),
+                format!("string ({}) is not a charlist or binary", string)
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -25,3 +25,31 @@ fn with_integer_dividend_without_integer_divisor_errors_badarith() {
 fn with_integer_dividend_with_zero_divisor_errors_badarith() {
     crate::test::with_integer_dividend_with_zero_divisor_errors_badarith(file!(), native);
 }
+
+#[test]
+fn with_negative_dividend_truncates_toward_zero() {
+    with_process(|process| {
+        assert_eq!(
+            native(
+                process,
+                process.integer(-7).unwrap(),
+                process.integer(2).unwrap()
+            ),
+            Ok(process.integer(-3).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_negative_big_integer_dividend_truncates_toward_zero() {
+    with_process(|process| {
+        let dividend = process.integer(SmallInteger::MIN_VALUE - 1).unwrap();
+
+        assert!(dividend.is_boxed_bigint());
+
+        assert_eq!(
+            native(process, dividend, process.integer(2).unwrap()),
+            Ok(process.integer(SmallInteger::MIN_VALUE / 2).unwrap())
+        );
+    });
+}
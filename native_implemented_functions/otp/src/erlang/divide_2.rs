@@ -8,23 +8,20 @@ mod test;
 use std::convert::TryInto;
 
 use anyhow::*;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 
 use liblumen_alloc::erts::exception::{self, *};
 use liblumen_alloc::erts::process::Process;
-use liblumen_alloc::erts::term::prelude::Term;
+use liblumen_alloc::erts::term::prelude::*;
 
 use native_implemented_function::native_implemented_function;
 
-/// `//2` infix operator.  Unlike `+/2`, `-/2` and `*/2` always promotes to `float` returns the
-/// `float`.
+/// `/` infix operator.  Always returns a float, even when both operands are integers.
 #[native_implemented_function(/ /2)]
 pub fn native(process: &Process, dividend: Term, divisor: Term) -> exception::Result<Term> {
-    let dividend_f64: f64 = dividend.try_into().map_err(|_| {
-        badarith(anyhow!("dividend ({}) cannot be promoted to a float", dividend).into())
-    })?;
-    let divisor_f64: f64 = divisor.try_into().map_err(|_| {
-        badarith(anyhow!("divisor ({}) cannot be promoted to a float", divisor).into())
-    })?;
+    let dividend_f64 = number_to_f64("dividend", dividend)?;
+    let divisor_f64 = number_to_f64("divisor", divisor)?;
 
     if divisor_f64 == 0.0 {
         Err(badarith(anyhow!("divisor ({}) cannot be zero", divisor).into()).into())
@@ -35,3 +32,22 @@ pub fn native(process: &Process, dividend: Term, divisor: Term) -> exception::Re
         Ok(quotient_term)
     }
 }
+
+// Private
+
+fn number_to_f64(name: &'static str, number: Term) -> exception::Result<f64> {
+    let option_f64 = match number.decode()? {
+        TypedTerm::BigInteger(big_integer) => {
+            let big_int: &BigInt = big_integer.as_ref().into();
+
+            // Rounds to the nearest `f64` once instead of at every byte and is `None` when
+            // `big_int` is too big to be a finite `f64`.
+            big_int.to_f64()
+        }
+        _ => number.try_into().ok(),
+    };
+
+    option_f64.ok_or_else(|| {
+        badarith(anyhow!("{} ({}) cannot be promoted to a float", name, number).into()).into()
+    })
+}
@@ -5,6 +5,8 @@ use std::sync::Arc;
 use proptest::strategy::{BoxedStrategy, Just, Strategy};
 use proptest::{prop_assert, prop_oneof};
 
+use num_bigint::BigInt;
+
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
@@ -126,3 +128,62 @@ fn zero(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     ]
     .boxed()
 }
+
+#[test]
+fn with_integers_returns_float() {
+    with_process(|process| {
+        assert_eq!(
+            native(
+                process,
+                process.integer(7).unwrap(),
+                process.integer(2).unwrap()
+            ),
+            Ok(process.float(3.5).unwrap())
+        );
+        assert_eq!(
+            native(
+                process,
+                process.integer(-7).unwrap(),
+                process.integer(2).unwrap()
+            ),
+            Ok(process.float(-3.5).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_integer_zero_divisor_errors_badarith() {
+    with_process(|process| {
+        let divisor = process.integer(0).unwrap();
+
+        assert_badarith!(
+            native(process, process.integer(1).unwrap(), divisor),
+            format!("divisor ({}) cannot be zero", divisor)
+        );
+    });
+}
+
+#[test]
+fn with_big_integer_dividend_with_float_divisor_returns_float() {
+    with_process(|process| {
+        let dividend = process.integer(BigInt::from(1) << 70).unwrap();
+
+        assert!(dividend.is_boxed_bigint());
+        assert_eq!(
+            native(process, dividend, process.float(2.0).unwrap()),
+            Ok(process.float(2_f64.powi(69)).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_big_integer_too_big_for_float_errors_badarith() {
+    with_process(|process| {
+        let dividend = process.integer(BigInt::from(1) << 1100).unwrap();
+
+        assert_badarith!(
+            native(process, dividend, process.integer(1).unwrap()),
+            format!("dividend ({}) cannot be promoted to a float", dividend)
+        );
+    });
+}
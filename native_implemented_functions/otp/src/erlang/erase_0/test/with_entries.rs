@@ -52,3 +52,23 @@ fn with_heap_available_erases_dictionary_and_returns_entries_as_list() {
         Atom::str_to_term("undefined")
     );
 }
+
+#[test]
+fn with_entries_leaves_dictionary_empty() {
+    with_process(|process| {
+        process
+            .put(Atom::str_to_term("first"), process.integer(1).unwrap())
+            .unwrap();
+        process
+            .put(
+                process.charlist_from_str("second").unwrap(),
+                process.binary_from_str("2").unwrap(),
+            )
+            .unwrap();
+
+        assert_ne!(native(process), Ok(Term::NIL));
+
+        assert_eq!(crate::erlang::get_0::native(process), Ok(Term::NIL));
+        assert_eq!(native(process), Ok(Term::NIL));
+    });
+}
@@ -4,7 +4,9 @@ use proptest::strategy::Just;
 use liblumen_alloc::erts::term::prelude::Atom;
 
 use crate::erlang::erase_1::native;
+use crate::erlang::get_1;
 use crate::test::strategy;
+use crate::test::with_process;
 
 #[test]
 fn without_key_returns_undefined() {
@@ -49,3 +51,25 @@ fn with_key_returns_value_and_removes_key_from_dictionary() {
         },
     );
 }
+
+#[test]
+fn with_key_returns_prior_value_and_leaves_other_keys() {
+    with_process(|process| {
+        let erased_key = Atom::str_to_term("erased");
+        let erased_value = process.charlist_from_str("erased value").unwrap();
+        let kept_key = Atom::str_to_term("kept");
+        let kept_value = process.charlist_from_str("kept value").unwrap();
+
+        process.put(erased_key, erased_value).unwrap();
+        process.put(kept_key, kept_value).unwrap();
+
+        assert_eq!(native(process, erased_key), erased_value);
+        assert_eq!(
+            get_1::native(process, erased_key),
+            Atom::str_to_term("undefined")
+        );
+        assert_eq!(native(process, erased_key), Atom::str_to_term("undefined"));
+
+        assert_eq!(get_1::native(process, kept_key), kept_value);
+    });
+}
@@ -0,0 +1,23 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang::term_to_binary::external_size;
+
+/// The number of bytes in `term_to_binary(term)`, without allocating the binary, so that callers
+/// can size buffers up front.
+#[native_implemented_function(external_size/1)]
+pub fn native(process: &Process, term: Term) -> exception::Result<Term> {
+    let size = external_size(process, term, &Default::default());
+
+    process.integer(size).map_err(From::from)
+}
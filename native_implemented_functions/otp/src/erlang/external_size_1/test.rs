@@ -0,0 +1,48 @@
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::external_size_1::native;
+use crate::test::with_process;
+
+#[test]
+fn is_byte_size_of_term_to_binary() {
+    with_process(|process| {
+        for term in terms(process) {
+            let binary = crate::erlang::term_to_binary_1::native(process, term).unwrap();
+
+            assert_eq!(
+                native(process, term),
+                crate::erlang::byte_size_1::native(process, binary),
+                "external_size({})",
+                term
+            );
+        }
+    });
+}
+
+fn terms(process: &Process) -> Vec<Term> {
+    let big_integer = process.integer(std::i64::MAX).unwrap();
+    let tuple = process
+        .tuple_from_slice(&[Atom::str_to_term("ok"), process.integer(1).unwrap()])
+        .unwrap();
+    let string = process.charlist_from_str("hello").unwrap();
+
+    vec![
+        Atom::str_to_term("atom"),
+        process.integer(0).unwrap(),
+        process.integer(-1_000_000).unwrap(),
+        big_integer,
+        process.float(1.5).unwrap(),
+        Term::NIL,
+        string,
+        process
+            .list_from_slice(&[tuple, process.integer(1_000).unwrap()])
+            .unwrap(),
+        tuple,
+        process
+            .map_from_slice(&[(Atom::str_to_term("key"), string)])
+            .unwrap(),
+        process.binary_from_bytes(&[1, 2, 3]).unwrap(),
+        process.binary_from_bytes(&[0; 100]).unwrap(),
+    ]
+}
@@ -0,0 +1,35 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang::term_to_binary::{external_size, Options};
+
+/// The number of bytes in `term_to_binary(term, options)`, without allocating the binary.
+///
+/// Compression is not implemented, so with `compressed` the size is the uncompressed size, which
+/// is an upper bound for the compressed size.
+#[native_implemented_function(external_size/2)]
+pub fn native(process: &Process, term: Term, options: Term) -> exception::Result<Term> {
+    let options_options: Options = options.try_into().map_err(|_| {
+        anyhow!(
+            "options ({}) is not a proper list of compressed, {{compressed, 0..9}}, or {{minor_version, 0..2}}",
+            options
+        )
+    })?;
+    let size = external_size(process, term, &options_options);
+
+    process.integer(size).map_err(From::from)
+}
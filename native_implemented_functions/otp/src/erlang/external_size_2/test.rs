@@ -0,0 +1,59 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::external_size_2::native;
+use crate::test::with_process;
+
+#[test]
+fn without_options_is_external_size_1() {
+    with_process(|process| {
+        let term = process
+            .list_from_slice(&[Atom::str_to_term("a"), process.integer(257).unwrap()])
+            .unwrap();
+
+        assert_eq!(
+            native(process, term, Term::NIL),
+            crate::erlang::external_size_1::native(process, term)
+        );
+    });
+}
+
+#[test]
+fn with_compressed_is_uncompressed_upper_bound() {
+    with_process(|process| {
+        let term = process.binary_from_bytes(&[0; 100]).unwrap();
+        let options = process
+            .list_from_slice(&[
+                Atom::str_to_term("compressed"),
+                process
+                    .tuple_from_slice(&[
+                        Atom::str_to_term("minor_version"),
+                        process.integer(2).unwrap(),
+                    ])
+                    .unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(process, term, options),
+            crate::erlang::external_size_1::native(process, term)
+        );
+    });
+}
+
+#[test]
+fn with_invalid_option_errors_badarg() {
+    with_process(|process| {
+        let term = Atom::str_to_term("term");
+        let options = process
+            .list_from_slice(&[Atom::str_to_term("unknown")])
+            .unwrap();
+
+        assert_badarg!(
+            native(process, term, options),
+            format!(
+                "options ({}) is not a proper list of compressed, {{compressed, 0..9}}, or {{minor_version, 0..2}}",
+                options
+            )
+        );
+    });
+}
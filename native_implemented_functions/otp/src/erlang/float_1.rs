@@ -9,7 +9,7 @@ use std::convert::TryInto;
 
 use anyhow::*;
 
-use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::exception::{self, *};
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
@@ -24,6 +24,11 @@ pub fn native(process: &Process, number: Term) -> exception::Result<Term> {
             .try_into()
             .with_context(|| term_is_not_number!(number))?;
 
-        process.float(f).map_err(From::from)
+        // Big integers past `f64::MAX` convert to infinity, which Erlang does not support
+        if f.is_finite() {
+            process.float(f).map_err(From::from)
+        } else {
+            Err(badarith(anyhow!("number ({}) is too large to be a float", number).into()).into())
+        }
     }
 }
@@ -1,8 +1,15 @@
+use num_bigint::BigInt;
+
+use num_traits::Num;
+
 use proptest::prop_assert_eq;
 use proptest::strategy::{Just, Strategy};
 
+use liblumen_alloc::erts::term::prelude::Encoded;
+
 use crate::erlang::float_1::native;
 use crate::test::strategy;
+use crate::test::with_process;
 
 #[test]
 fn without_number_errors_badarg() {
@@ -49,3 +56,26 @@ fn with_float_returns_same_float() {
         },
     );
 }
+
+#[test]
+fn with_small_integer_returns_float() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.integer(3).unwrap()),
+            Ok(process.float(3.0).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_big_integer_greater_than_max_f64_errors_badarith() {
+    with_process(|process| {
+        // 2^1024 is the first power of two past `f64::MAX`
+        let big_int =
+            <BigInt as Num>::from_str_radix(&format!("1{}", "0".repeat(1024)), 2).unwrap();
+        let number = process.integer(big_int).unwrap();
+
+        assert!(number.is_boxed_bigint());
+        assert_badarith!(native(process, number));
+    });
+}
@@ -11,7 +11,7 @@ use liblumen_alloc::erts::term::prelude::{Encoded, Float};
 
 use crate::erlang::floor_1::native;
 use crate::test::strategy;
-use crate::test::with_process_arc;
+use crate::test::{with_process, with_process_arc};
 
 #[test]
 fn without_number_errors_badarg() {
@@ -55,3 +55,13 @@ fn with_float_rounds_down_to_previous_integer() {
             .unwrap();
     });
 }
+
+#[test]
+fn with_negative_two_and_a_half_rounds_down() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.float(-2.5).unwrap()),
+            Ok(process.integer(-3).unwrap())
+        );
+    });
+}
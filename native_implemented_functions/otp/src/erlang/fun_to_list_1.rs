@@ -0,0 +1,40 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::closure::Definition;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+/// Returns the printed form of `fun` as a charlist.  Anonymous funs are
+/// `#Fun<Module.Index.OldUnique>` and exported funs are `fun Module:Function/Arity`, like OTP.
+#[native_implemented_function(fun_to_list/1)]
+pub fn native(process: &Process, fun: Term) -> exception::Result<Term> {
+    let closure: Boxed<Closure> = fun
+        .try_into()
+        .with_context(|| format!("fun ({}) is not a function", fun))?;
+
+    let string = match closure.definition() {
+        Definition::Export { function } => format!(
+            "fun {}:{}/{}",
+            closure.module().name(),
+            function.name(),
+            closure.arity()
+        ),
+        Definition::Anonymous {
+            index, old_unique, ..
+        } => format!("#Fun<{}.{}.{}>", closure.module().name(), index, old_unique),
+    };
+
+    process.charlist_from_str(&string).map_err(From::from)
+}
@@ -0,0 +1,92 @@
+use proptest::prop_assert;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::fun_to_list_1::native;
+use crate::erlang::list_to_string::list_to_string;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_function(arc_process.clone()),
+            )
+        },
+        |(arc_process, fun)| {
+            prop_assert_badarg!(
+                native(&arc_process, fun),
+                format!("fun ({}) is not a function", fun)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_anonymous_function_returns_charlist_starting_with_fun() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::function::anonymous(arc_process.clone()),
+            )
+        },
+        |(arc_process, fun)| {
+            let list = native(&arc_process, fun).unwrap();
+            let string = list_to_string(list).unwrap();
+
+            prop_assert!(string.starts_with("#Fun<"));
+            prop_assert!(string.ends_with('>'));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_anonymous_function_returns_module_index_and_old_unique() {
+    with_process(|process| {
+        let fun = process
+            .anonymous_closure_with_env_from_slice(
+                Atom::try_from_str("erl_eval").unwrap(),
+                20,
+                128620087,
+                [0; 16],
+                0,
+                None,
+                process.pid().into(),
+                &[],
+            )
+            .unwrap();
+
+        assert_eq!(
+            native(process, fun),
+            Ok(process
+                .charlist_from_str("#Fun<erl_eval.20.128620087>")
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_export_function_returns_module_function_arity() {
+    with_process(|process| {
+        let fun = strategy::term::export_closure(
+            process,
+            Atom::try_from_str("lists").unwrap(),
+            Atom::try_from_str("reverse").unwrap(),
+            1,
+        );
+
+        assert_eq!(
+            native(process, fun),
+            Ok(process.charlist_from_str("fun lists:reverse/1").unwrap())
+        );
+    });
+}
@@ -0,0 +1,46 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::garbage_collect_0::native;
+use crate::test::with_process;
+
+#[test]
+fn returns_true() {
+    with_process(|process| {
+        assert_eq!(native(process), Ok(true.into()));
+    });
+}
+
+#[test]
+fn keeps_terms_in_dictionary_and_on_stack() {
+    with_process(|process| {
+        let key = atom!("key");
+        process.put(key, live_term(process)).unwrap();
+        process.stack_push(live_term(process)).unwrap();
+
+        // garbage that is not reachable from any root
+        for i in 0..100 {
+            process
+                .list_from_slice(&[process.integer(i).unwrap()])
+                .unwrap();
+        }
+
+        assert_eq!(native(process), Ok(true.into()));
+
+        assert_eq!(process.get_value_from_key(key), live_term(process));
+        assert_eq!(process.stack_peek(1), Some(live_term(process)));
+    });
+}
+
+fn live_term(process: &Process) -> Term {
+    process
+        .tuple_from_slice(&[
+            process
+                .list_from_slice(&[process.integer(1).unwrap(), process.float(2.0).unwrap()])
+                .unwrap(),
+            process.binary_from_str("binary").unwrap(),
+            process.integer(std::isize::MAX).unwrap(),
+        ])
+        .unwrap()
+}
@@ -0,0 +1,38 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{Process, ProcessFlags};
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_core::registry::pid_to_process;
+use native_implemented_function::native_implemented_function;
+
+/// Garbage collects the process `pid`.
+///
+/// The calling process is collected immediately like `garbage_collect/0`.  Any other process may
+/// be running, so it is collected by its scheduler before it next runs.  Returns `false` if
+/// `pid` is not alive.
+#[native_implemented_function(garbage_collect/1)]
+pub fn native(process: &Process, pid: Term) -> exception::Result<Term> {
+    if pid == process.pid_term() {
+        super::garbage_collect(process)?;
+
+        Ok(true.into())
+    } else {
+        let pid_pid = term_try_into_local_pid!(pid)?;
+
+        match pid_to_process(&pid_pid) {
+            Some(arc_process) if !arc_process.is_exiting() => {
+                arc_process.set_flags(ProcessFlags::ForceGC | ProcessFlags::NeedFullSweep);
+
+                Ok(true.into())
+            }
+            _ => Ok(false.into()),
+        }
+    }
+}
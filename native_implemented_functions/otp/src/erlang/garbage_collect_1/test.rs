@@ -0,0 +1,73 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::{Process, ProcessFlags};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::garbage_collect_1::native;
+use crate::test::{self, strategy, with_process, with_process_arc};
+
+#[test]
+fn without_pid_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_pid(arc_process.clone()),
+            )
+        },
+        |(arc_process, pid)| {
+            prop_assert_is_not_local_pid!(native(&arc_process, pid), pid);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_self_collects_immediately_and_keeps_live_terms() {
+    with_process(|process| {
+        let key = atom!("key");
+        process.put(key, live_term(process)).unwrap();
+
+        assert_eq!(native(process, process.pid_term()), Ok(true.into()));
+
+        assert_eq!(process.get_value_from_key(key), live_term(process));
+        assert!(!process.are_flags_set(ProcessFlags::ForceGC));
+    });
+}
+
+#[test]
+fn with_other_process_forces_collection_before_it_next_runs() {
+    with_process_arc(|arc_process| {
+        let other_arc_process = test::process::child(&arc_process);
+
+        assert_eq!(
+            native(&arc_process, other_arc_process.pid_term()),
+            Ok(true.into())
+        );
+
+        assert!(other_arc_process.are_flags_set(ProcessFlags::ForceGC));
+        assert!(!arc_process.are_flags_set(ProcessFlags::ForceGC));
+    });
+}
+
+#[test]
+fn without_process_returns_false() {
+    with_process_arc(|arc_process| {
+        let pid = Pid::next_term();
+
+        assert_eq!(native(&arc_process, pid), Ok(false.into()));
+    });
+}
+
+fn live_term(process: &Process) -> Term {
+    process
+        .tuple_from_slice(&[
+            process
+                .list_from_slice(&[process.integer(1).unwrap()])
+                .unwrap(),
+            process.binary_from_str("binary").unwrap(),
+        ])
+        .unwrap()
+}
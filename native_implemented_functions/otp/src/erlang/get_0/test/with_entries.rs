@@ -58,3 +58,55 @@ fn with_heap_available_returns_entries_as_list() {
 
     assert_eq!(boxed_cons.tail, Term::NIL);
 }
+
+#[test]
+fn with_multiple_entries_returns_all_entries() {
+    with_process(|process| {
+        let entries = [
+            (
+                Atom::str_to_term("atom"),
+                process.charlist_from_str("charlist").unwrap(),
+            ),
+            (
+                process.integer(1).unwrap(),
+                process
+                    .tuple_from_slice(&[Atom::str_to_term("tuple"), process.float(1.0).unwrap()])
+                    .unwrap(),
+            ),
+            (
+                process.binary_from_str("binary").unwrap(),
+                process.integer(isize::max_value() as i128 + 1).unwrap(),
+            ),
+        ];
+
+        for (key, value) in entries.iter() {
+            assert_eq!(
+                crate::erlang::put_2::native(process, *key, *value),
+                Ok(Atom::str_to_term("undefined"))
+            );
+        }
+
+        for (key, value) in entries.iter() {
+            assert_eq!(crate::erlang::get_1::native(process, *key), *value);
+        }
+
+        let list = native(process).unwrap();
+        let boxed_cons: Boxed<Cons> = list.try_into().unwrap();
+        let mut actual_entry_vec: Vec<(Term, Term)> = boxed_cons
+            .into_iter()
+            .map(|result| {
+                let entry_tuple: Boxed<Tuple> = result.unwrap().try_into().unwrap();
+
+                assert_eq!(entry_tuple.len(), 2);
+
+                (entry_tuple[0], entry_tuple[1])
+            })
+            .collect();
+        actual_entry_vec.sort();
+
+        let mut expected_entry_vec = entries.to_vec();
+        expected_entry_vec.sort();
+
+        assert_eq!(actual_entry_vec, expected_entry_vec);
+    });
+}
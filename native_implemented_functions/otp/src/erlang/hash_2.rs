@@ -0,0 +1,179 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+use num_bigint::{BigInt, Sign};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+/// `hash/2`
+///
+/// Deprecated in OTP in favor of `phash2/2`, but still functional for old code that calls it.
+/// Returns a hash of `term` in `1..=range`.  The hash only depends on the value of `term`, so it
+/// is the same across processes and runs, but it is not the same value that `phash2/2` returns.
+///
+/// Like in OTP, `range` is in `1..=2^27`, which is smaller than the `1..=2^32` of `phash2/2`.
+#[native_implemented_function(hash/2)]
+pub fn native(process: &Process, term: Term, range: Term) -> exception::Result<Term> {
+    let range_u64: u64 = range
+        .try_into()
+        .ok()
+        .filter(|range_u64| 1 <= *range_u64 && *range_u64 <= MAX_RANGE)
+        .with_context(|| format!("range ({}) is not an integer in 1..=2^27", range))?;
+
+    let mut hash = 0;
+    write_hash(&mut hash, term)?;
+
+    let hash_in_range = (hash as u64) % range_u64 + 1;
+
+    process.integer(hash_in_range).map_err(From::from)
+}
+
+// Private
+
+const MAX_RANGE: u64 = 1 << 27;
+
+// Primes inherited from the legacy `make_broken_hash` in BEAM
+const FUNNY_NUMBER1: u32 = 268440163;
+const FUNNY_NUMBER2: u32 = 268439161;
+const FUNNY_NUMBER3: u32 = 268435459;
+const FUNNY_NUMBER4: u32 = 268436141;
+const FUNNY_NUMBER5: u32 = 268438633;
+const FUNNY_NUMBER6: u32 = 268437017;
+const FUNNY_NUMBER7: u32 = 268438039;
+const FUNNY_NUMBER8: u32 = 268437511;
+const FUNNY_NUMBER9: u32 = 268439627;
+const FUNNY_NUMBER10: u32 = 268440479;
+
+/// The atom table hash used by BEAM, so that atoms hash by name instead of by atom index.
+fn hashpjw(name: &str) -> u32 {
+    name.bytes().fold(0_u32, |h, byte| {
+        let h = (h << 4).wrapping_add(byte as u32);
+        let g = h & 0xf000_0000;
+
+        if g != 0 {
+            (h ^ (g >> 24)) ^ g
+        } else {
+            h
+        }
+    })
+}
+
+fn step(hash: &mut u32, prime: u32, value: u32) {
+    *hash = hash.wrapping_mul(prime).wrapping_add(value);
+}
+
+fn step_bytes<I: Iterator<Item = u8>>(hash: &mut u32, prime: u32, byte_iter: I) -> u32 {
+    let mut len = 0;
+
+    for byte in byte_iter {
+        step(hash, prime, byte as u32);
+        len += 1;
+    }
+
+    len
+}
+
+fn write_hash(hash: &mut u32, term: Term) -> exception::Result<()> {
+    match term.decode()? {
+        TypedTerm::Nil => step(hash, FUNNY_NUMBER3, 1),
+        TypedTerm::Atom(atom) => step(hash, FUNNY_NUMBER1, hashpjw(atom.name())),
+        TypedTerm::SmallInteger(small_integer) => {
+            let i: isize = small_integer.into();
+            let magnitude = (i as i64).wrapping_abs() as u64;
+
+            step_bytes(hash, FUNNY_NUMBER2, magnitude.to_le_bytes().iter().copied());
+            *hash = hash.wrapping_mul(if i < 0 { FUNNY_NUMBER4 } else { FUNNY_NUMBER3 });
+        }
+        TypedTerm::BigInteger(big_integer) => {
+            let big_int: &BigInt = big_integer.as_ref().into();
+            let (sign, magnitude_bytes) = big_int.to_bytes_le();
+
+            step_bytes(hash, FUNNY_NUMBER2, magnitude_bytes.into_iter());
+            *hash = hash.wrapping_mul(if sign == Sign::Minus {
+                FUNNY_NUMBER4
+            } else {
+                FUNNY_NUMBER3
+            });
+        }
+        TypedTerm::Float(float) => {
+            let f: f64 = float.into();
+            let bits = f.to_bits();
+
+            step(hash, FUNNY_NUMBER6, (bits as u32) ^ ((bits >> 32) as u32));
+        }
+        TypedTerm::HeapBinary(heap_binary) => {
+            let len = step_bytes(hash, FUNNY_NUMBER1, heap_binary.full_byte_iter());
+            step(hash, FUNNY_NUMBER4, len);
+        }
+        TypedTerm::ProcBin(process_binary) => {
+            let len = step_bytes(hash, FUNNY_NUMBER1, process_binary.full_byte_iter());
+            step(hash, FUNNY_NUMBER4, len);
+        }
+        TypedTerm::BinaryLiteral(binary_literal) => {
+            let len = step_bytes(hash, FUNNY_NUMBER1, binary_literal.full_byte_iter());
+            step(hash, FUNNY_NUMBER4, len);
+        }
+        TypedTerm::SubBinary(subbinary) => {
+            let len = step_bytes(hash, FUNNY_NUMBER1, subbinary.full_byte_iter());
+            let partial_byte_bit_len = subbinary.partial_byte_bit_len() as u32;
+
+            if 0 < partial_byte_bit_len {
+                let partial_byte = subbinary
+                    .partial_byte_bit_iter()
+                    .fold(0_u32, |acc, bit| (acc << 1) | (bit as u32));
+
+                step(hash, FUNNY_NUMBER1, partial_byte);
+                step(hash, FUNNY_NUMBER7, partial_byte_bit_len);
+            }
+
+            step(hash, FUNNY_NUMBER4, len);
+        }
+        TypedTerm::List(cons) => {
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => {
+                        write_hash(hash, element)?;
+                        *hash = hash.wrapping_mul(FUNNY_NUMBER8);
+                    }
+                    Err(ImproperList { tail }) => write_hash(hash, tail)?,
+                }
+            }
+        }
+        TypedTerm::Tuple(tuple) => {
+            for element in tuple.iter() {
+                write_hash(hash, *element)?;
+            }
+
+            step(hash, FUNNY_NUMBER9, tuple.len() as u32);
+        }
+        TypedTerm::Map(map) => {
+            for (key, value) in map.iter_sorted() {
+                write_hash(hash, key)?;
+                write_hash(hash, value)?;
+            }
+
+            step(hash, FUNNY_NUMBER10, map.len() as u32);
+        }
+        TypedTerm::Pid(pid) => {
+            step(hash, FUNNY_NUMBER5, pid.number() as u32);
+            step(hash, FUNNY_NUMBER5, pid.serial() as u32);
+        }
+        // References, closures, ports and external pids only need to be deterministic
+        _ => {
+            step_bytes(hash, FUNNY_NUMBER10, term.to_string().bytes());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::convert::TryInto;
+
+use proptest::prop_assert;
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::hash_2::native;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_range_in_one_to_two_to_the_twenty_seventh_errors_badarg() {
+    with_process(|process| {
+        let term = Atom::str_to_term("term");
+
+        assert_badarg!(
+            native(process, term, process.integer(0).unwrap()),
+            "range (0) is not an integer in 1..=2^27"
+        );
+        assert_badarg!(
+            native(process, term, process.integer(-1).unwrap()),
+            "range (-1) is not an integer in 1..=2^27"
+        );
+        assert_badarg!(
+            native(process, term, process.integer((1_u64 << 27) + 1).unwrap()),
+            "range (134217729) is not an integer in 1..=2^27"
+        );
+        assert_badarg!(
+            native(process, term, Atom::str_to_term("range")),
+            "is not an integer in 1..=2^27"
+        );
+    });
+}
+
+#[test]
+fn with_range_one_returns_one() {
+    with_process(|process| {
+        let range = process.integer(1).unwrap();
+
+        assert_eq!(
+            native(process, Atom::str_to_term("term"), range),
+            Ok(process.integer(1).unwrap())
+        );
+        assert_eq!(
+            native(process, process.integer(-12).unwrap(), range),
+            Ok(process.integer(1).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_range_two_to_the_twenty_seventh_returns_integer_in_one_to_range() {
+    with_process(|process| {
+        let range_u64 = 1_u64 << 27;
+        let range = process.integer(range_u64).unwrap();
+
+        for term in &[
+            Atom::str_to_term("term"),
+            process.integer(-12).unwrap(),
+            process.binary_from_str("binary").unwrap(),
+        ] {
+            let hash_u64: u64 = native(process, *term, range).unwrap().try_into().unwrap();
+
+            assert!(1 <= hash_u64 && hash_u64 <= range_u64);
+        }
+    });
+}
+
+#[test]
+fn with_positive_range_returns_integer_in_one_to_range() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                1..=1_000_u32,
+            )
+        },
+        |(arc_process, term, range_u32)| {
+            let hash = native(
+                &arc_process,
+                term,
+                arc_process.integer(range_u32 as u64).unwrap(),
+            )
+            .unwrap();
+            let hash_u32: u32 = hash.try_into().unwrap();
+
+            prop_assert!(1 <= hash_u32 && hash_u32 <= range_u32);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_same_value_in_different_processes_returns_same_hash() {
+    let range_u32 = 1 << 27;
+
+    let hash_in_process = |process: &Process| {
+        let term = process
+            .tuple_from_slice(&[
+                Atom::str_to_term("key"),
+                process.integer(-12).unwrap(),
+                process.float(1.5).unwrap(),
+                process.charlist_from_str("value").unwrap(),
+                process.binary_from_str("binary").unwrap(),
+            ])
+            .unwrap();
+
+        let hash = native(process, term, process.integer(range_u32 as u64).unwrap()).unwrap();
+        let hash_u32: u32 = hash.try_into().unwrap();
+
+        hash_u32
+    };
+
+    let mut first_hash = 0;
+    with_process(|process| first_hash = hash_in_process(process));
+    let mut second_hash = 0;
+    with_process(|process| second_hash = hash_in_process(process));
+
+    assert_eq!(first_hash, second_hash);
+    assert!(1 <= first_hash && first_hash <= range_u32);
+}
+
+#[test]
+fn with_different_values_returns_different_hashes() {
+    with_process(|process| {
+        let range = process.integer(1 << 27).unwrap();
+
+        assert_ne!(
+            native(process, Atom::str_to_term("a"), range),
+            native(process, Atom::str_to_term("b"), range)
+        );
+        assert_ne!(
+            native(process, process.integer(1).unwrap(), range),
+            native(process, process.integer(-1).unwrap(), range)
+        );
+    });
+}
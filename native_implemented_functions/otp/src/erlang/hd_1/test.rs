@@ -4,7 +4,7 @@ use proptest::strategy::Just;
 use liblumen_alloc::erts::term::prelude::Term;
 
 use crate::erlang::hd_1::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_list_errors_badarg() {
@@ -44,3 +44,14 @@ fn with_list_returns_head() {
         },
     );
 }
+
+#[test]
+fn with_improper_list_returns_head() {
+    with_process(|process| {
+        let list = process
+            .cons(process.integer(1).unwrap(), process.integer(2).unwrap())
+            .unwrap();
+
+        assert_eq!(native(list), Ok(process.integer(1).unwrap()));
+    });
+}
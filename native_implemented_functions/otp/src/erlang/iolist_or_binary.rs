@@ -3,7 +3,7 @@ use std::convert::TryInto;
 use anyhow::*;
 
 use liblumen_alloc::erts::exception;
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::{BinaryBuilder, Process};
 use liblumen_alloc::erts::term::prelude::*;
 
 use lumen_rt_core::context::{r#type, term_is_not_type};
@@ -48,7 +48,174 @@ pub fn native(
 }
 
 pub fn to_binary(process: &Process, name: &'static str, value: Term) -> exception::Result<Term> {
+    let mut binary_builder = process.binary_builder();
+    write_to(&mut binary_builder, name, value)?;
+
+    binary_builder.finish().map_err(From::from)
+}
+
+/// The bytes of `value` concatenated in order, like `to_binary`, but without allocating a binary.
+pub fn to_byte_vec(name: &'static str, value: Term) -> exception::Result<Vec<u8>> {
     let mut byte_vec: Vec<u8> = Vec::new();
+    write_to(&mut byte_vec, name, value)?;
+
+    Ok(byte_vec)
+}
+
+/// Flattens `value` into a list of binaries without concatenating it all into one binary.
+///
+/// Bytes and binaries no bigger than `heap_bin_limit()` are coalesced into a single new binary,
+/// so there are never two small binaries in a row, while bigger binaries are kept as is to
+/// avoid copying them.
+pub fn to_iovec(process: &Process, name: &'static str, value: Term) -> exception::Result<Term> {
+    let mut iovec = IoVec {
+        process,
+        binary_vec: Vec::new(),
+        byte_vec: Vec::new(),
+    };
+    write_to(&mut iovec, name, value)?;
+    iovec.push_byte_vec()?;
+
+    process
+        .list_from_slice(&iovec.binary_vec)
+        .map_err(From::from)
+}
+
+// Private
+
+/// Where `write_to` writes the parts of an iolist or binary, so that the iolist only needs to be
+/// traversed in one place, whether its bytes are concatenated (`Vec<u8>` and `BinaryBuilder`) or
+/// not (`IoVec`).
+trait Sink {
+    fn push_byte(&mut self, byte: u8);
+
+    /// `bytes` are those of `binary`
+    fn push_binary(&mut self, binary: Term, bytes: &[u8]) -> exception::Result<()>;
+
+    /// Like `push_binary`, but the `len` bytes of `binary` aren't aligned, so they can only be
+    /// iterated
+    fn push_unaligned_binary<I: Iterator<Item = u8>>(
+        &mut self,
+        binary: Term,
+        len: usize,
+        byte_iter: I,
+    ) -> exception::Result<()>;
+}
+
+impl Sink for Vec<u8> {
+    fn push_byte(&mut self, byte: u8) {
+        self.push(byte);
+    }
+
+    fn push_binary(&mut self, _binary: Term, bytes: &[u8]) -> exception::Result<()> {
+        self.extend_from_slice(bytes);
+
+        Ok(())
+    }
+
+    fn push_unaligned_binary<I: Iterator<Item = u8>>(
+        &mut self,
+        _binary: Term,
+        _len: usize,
+        byte_iter: I,
+    ) -> exception::Result<()> {
+        self.extend(byte_iter);
+
+        Ok(())
+    }
+}
+
+impl<'a> Sink for BinaryBuilder<'a> {
+    fn push_byte(&mut self, byte: u8) {
+        self.append_byte(byte);
+    }
+
+    fn push_binary(&mut self, _binary: Term, bytes: &[u8]) -> exception::Result<()> {
+        self.append_bytes(bytes);
+
+        Ok(())
+    }
+
+    fn push_unaligned_binary<I: Iterator<Item = u8>>(
+        &mut self,
+        _binary: Term,
+        _len: usize,
+        byte_iter: I,
+    ) -> exception::Result<()> {
+        for byte in byte_iter {
+            self.append_byte(byte);
+        }
+
+        Ok(())
+    }
+}
+
+struct IoVec<'a> {
+    process: &'a Process,
+    binary_vec: Vec<Term>,
+    /// Bytes and small binaries that are not pushed onto `binary_vec` yet
+    byte_vec: Vec<u8>,
+}
+
+impl<'a> IoVec<'a> {
+    fn push_byte_vec(&mut self) -> exception::Result<()> {
+        if !self.byte_vec.is_empty() {
+            self.binary_vec
+                .push(self.process.binary_from_bytes(&self.byte_vec)?);
+            self.byte_vec.clear();
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Sink for IoVec<'a> {
+    fn push_byte(&mut self, byte: u8) {
+        self.byte_vec.push(byte);
+    }
+
+    fn push_binary(&mut self, binary: Term, bytes: &[u8]) -> exception::Result<()> {
+        if bytes.len() <= heap_bin_limit() {
+            self.byte_vec.extend_from_slice(bytes);
+        } else {
+            self.push_byte_vec()?;
+            self.binary_vec.push(binary);
+        }
+
+        Ok(())
+    }
+
+    fn push_unaligned_binary<I: Iterator<Item = u8>>(
+        &mut self,
+        _binary: Term,
+        len: usize,
+        byte_iter: I,
+    ) -> exception::Result<()> {
+        if len <= heap_bin_limit() {
+            self.byte_vec.extend(byte_iter);
+        } else {
+            self.push_byte_vec()?;
+
+            // the binary can't be kept as is, because the bytes of binaries in an iovec are
+            // aligned
+            let aligned_byte_vec: Vec<u8> = byte_iter.collect();
+            self.binary_vec
+                .push(self.process.binary_from_bytes(&aligned_byte_vec)?);
+        }
+
+        Ok(())
+    }
+}
+
+fn element_context(name: &'static str, value: Term, element: Term) -> String {
+    format!(
+        "{} ({}) element ({}) is not a byte, binary, or nested iolist",
+        name, value, element
+    )
+}
+
+/// Writes the bytes and binaries of `value` to `sink` in order
+fn write_to<S: Sink>(sink: &mut S, name: &'static str, value: Term) -> exception::Result<()> {
     let mut stack: Vec<Term> = vec![value];
 
     while let Some(top) = stack.pop() {
@@ -58,7 +225,7 @@ pub fn to_binary(process: &Process, name: &'static str, value: Term) -> exceptio
                     .try_into()
                     .with_context(|| element_context(name, value, top))?;
 
-                byte_vec.push(top_byte);
+                sink.push_byte(top_byte);
             }
             TypedTerm::Nil => (),
             TypedTerm::List(boxed_cons) => {
@@ -84,14 +251,18 @@ pub fn to_binary(process: &Process, name: &'static str, value: Term) -> exceptio
                 stack.push(boxed_cons.head);
             }
             TypedTerm::HeapBinary(heap_binary) => {
-                byte_vec.extend_from_slice(heap_binary.as_bytes());
+                sink.push_binary(top, heap_binary.as_bytes())?;
             }
             TypedTerm::SubBinary(subbinary) => {
                 if subbinary.is_binary() {
                     if subbinary.is_aligned() {
-                        byte_vec.extend(unsafe { subbinary.as_bytes_unchecked() });
+                        sink.push_binary(top, unsafe { subbinary.as_bytes_unchecked() })?;
                     } else {
-                        byte_vec.extend(subbinary.full_byte_iter());
+                        sink.push_unaligned_binary(
+                            top,
+                            subbinary.full_byte_len(),
+                            subbinary.full_byte_iter(),
+                        )?;
                     }
                 } else {
                     return Err(NotABinary)
@@ -100,7 +271,10 @@ pub fn to_binary(process: &Process, name: &'static str, value: Term) -> exceptio
                 }
             }
             TypedTerm::ProcBin(procbin) => {
-                byte_vec.extend_from_slice(procbin.as_bytes());
+                sink.push_binary(top, procbin.as_bytes())?;
+            }
+            TypedTerm::BinaryLiteral(binary_literal) => {
+                sink.push_binary(top, binary_literal.as_bytes())?;
             }
             _ => {
                 return Err(TypeError)
@@ -110,12 +284,5 @@ pub fn to_binary(process: &Process, name: &'static str, value: Term) -> exceptio
         }
     }
 
-    Ok(process.binary_from_bytes(byte_vec.as_slice()).unwrap())
-}
-
-fn element_context(name: &'static str, value: Term, element: Term) -> String {
-    format!(
-        "{} ({}) element ({}) is not a byte, binary, or nested iolist",
-        name, value, element
-    )
+    Ok(())
 }
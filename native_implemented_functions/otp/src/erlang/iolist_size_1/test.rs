@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use proptest::strategy::{BoxedStrategy, Just, Strategy};
-use proptest::{prop_assert, prop_oneof};
+use proptest::{prop_assert, prop_assert_eq, prop_oneof};
 
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
@@ -32,6 +32,23 @@ fn without_list_or_bitstring_errors_badarg() {
     );
 }
 
+#[test]
+fn with_iodata_returns_byte_size_of_iolist_to_binary() {
+    run!(
+        |arc_process| (Just(arc_process.clone()), iodata(arc_process)),
+        |(arc_process, iodata)| {
+            let binary = crate::erlang::iolist_to_binary_1::native(&arc_process, iodata).unwrap();
+
+            prop_assert_eq!(
+                native(&arc_process, iodata),
+                crate::erlang::byte_size_1::native(&arc_process, binary)
+            );
+
+            Ok(())
+        }
+    );
+}
+
 #[test]
 fn with_iolist_or_binary_returns_non_negative_integer() {
     run!(
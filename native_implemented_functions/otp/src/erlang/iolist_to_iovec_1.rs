@@ -13,7 +13,9 @@ use native_implemented_function::native_implemented_function;
 
 use crate::erlang::iolist_or_binary;
 
-/// Returns a binary that is made from the integers and binaries given in iolist
+/// Returns a list of binaries that is made from the integers and binaries given in iolist.
+/// Unlike `iolist_to_binary/1`, binaries bigger than the heap binary limit are not copied, so
+/// the iovec can be used for scatter/gather I/O.
 #[native_implemented_function(iolist_to_iovec/1)]
 pub fn native(process: &Process, iolist_or_binary: Term) -> exception::Result<Term> {
     iolist_or_binary::native(process, iolist_or_binary, iolist_or_binary_to_iovec)
@@ -23,7 +25,5 @@ pub fn iolist_or_binary_to_iovec(
     process: &Process,
     iolist_or_binary: Term,
 ) -> exception::Result<Term> {
-    let binary = iolist_or_binary::to_binary(process, "iolist_or_binary", iolist_or_binary)?;
-
-    process.list_from_slice(&[binary]).map_err(From::from)
+    iolist_or_binary::to_iovec(process, "iolist_or_binary", iolist_or_binary)
 }
@@ -64,6 +64,84 @@ fn is_idempotent() {
     )
 }
 
+#[test]
+fn concatenated_iovec_is_iolist_to_binary() {
+    run!(
+        |arc_process| { (Just(arc_process.clone()), is_iolist_or_binary(arc_process)) },
+        |(arc_process, iolist_or_binary)| {
+            let iovec = native(&arc_process, iolist_or_binary).unwrap();
+
+            prop_assert_eq!(
+                crate::erlang::iolist_to_binary_1::native(&arc_process, iovec),
+                crate::erlang::iolist_to_binary_1::native(&arc_process, iolist_or_binary)
+            );
+
+            Ok(())
+        }
+    )
+}
+
+#[test]
+fn with_bytes_and_small_binaries_coalesces_into_one_binary() {
+    with_process(|process| {
+        let iolist = process
+            .list_from_slice(&[
+                process.integer(1).unwrap(),
+                process.integer(2).unwrap(),
+                process.binary_from_bytes(&[3]).unwrap(),
+                process
+                    .list_from_slice(&[process.integer(4).unwrap()])
+                    .unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(process, iolist),
+            Ok(process
+                .list_from_slice(&[process.binary_from_bytes(&[1, 2, 3, 4]).unwrap()])
+                .unwrap())
+        )
+    });
+}
+
+#[test]
+fn with_procbin_between_bytes_keeps_procbin() {
+    with_process(|process| {
+        let bytes = [7; 65];
+        let procbin = process.binary_from_bytes(&bytes).unwrap();
+
+        assert!(procbin.is_boxed_procbin());
+
+        let iolist = process
+            .list_from_slice(&[
+                process.integer(1).unwrap(),
+                procbin,
+                process.integer(2).unwrap(),
+            ])
+            .unwrap();
+
+        let iovec = native(process, iolist).unwrap();
+        let iovec_cons: Boxed<Cons> = iovec.try_into().unwrap();
+        let iovec_vec: Vec<Term> = iovec_cons
+            .into_iter()
+            .map(|result| result.unwrap())
+            .collect();
+
+        assert_eq!(iovec_vec.len(), 3);
+        assert_eq!(iovec_vec[0], process.binary_from_bytes(&[1]).unwrap());
+        assert!(iovec_vec[1].is_boxed_procbin());
+        assert_eq!(iovec_vec[1], procbin);
+        assert_eq!(iovec_vec[2], process.binary_from_bytes(&[2]).unwrap());
+    });
+}
+
+#[test]
+fn with_empty_list_returns_empty_list() {
+    with_process(|process| {
+        assert_eq!(native(process, Term::NIL), Ok(Term::NIL));
+    });
+}
+
 #[test]
 fn with_binary_returns_binary_in_list() {
     with_process(|process| {
@@ -3,10 +3,13 @@ mod test;
 
 use liblumen_alloc::erts::term::prelude::Term;
 
+use lumen_rt_core::distribution::nodes::node;
+
 use native_implemented_function::native_implemented_function;
 
-/// Distribution is not supported at this time.  Always returns `false`.
+/// Returns `true` if the node is named, so it can be part of a distributed system.  A standalone
+/// node is `nonode@nohost`, so it is not alive.
 #[native_implemented_function(is_alive/0)]
 pub fn native() -> Term {
-    false.into()
+    node::is_alive().into()
 }
@@ -1,6 +1,15 @@
+use liblumen_alloc::erts::term::prelude::Atom;
+
 use crate::erlang::is_alive_0::native;
+use crate::erlang::node_0;
 
 #[test]
 fn returns_false() {
     assert_eq!(native(), false.into())
 }
+
+#[test]
+fn with_standalone_node_returns_false() {
+    assert_eq!(node_0::native(), Atom::str_to_term("nonode@nohost"));
+    assert_eq!(native(), false.into());
+}
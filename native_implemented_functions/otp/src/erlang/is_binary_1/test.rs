@@ -3,7 +3,7 @@ use proptest::test_runner::{Config, TestRunner};
 
 use crate::erlang::is_binary_1::native;
 use crate::test::strategy;
-use crate::test::with_process_arc;
+use crate::test::{with_process, with_process_arc};
 
 #[test]
 fn without_binary_returns_false() {
@@ -29,3 +29,31 @@ fn with_binary_returns_true() {
             .unwrap();
     });
 }
+
+#[test]
+fn with_subbinary_with_partial_byte_returns_false() {
+    with_process(|process| {
+        let original = process
+            .binary_from_bytes(&[0b1111_1111, 0b1111_0000])
+            .unwrap();
+        let bitstring = process
+            .subbinary_from_original(original, 0, 0, 1, 4)
+            .unwrap();
+
+        assert_eq!(native(bitstring), false.into());
+    });
+}
+
+#[test]
+fn with_subbinary_without_partial_byte_returns_true() {
+    with_process(|process| {
+        let original = process
+            .binary_from_bytes(&[0b1111_1111, 0b1111_0000])
+            .unwrap();
+        let binary = process
+            .subbinary_from_original(original, 0, 0, 2, 0)
+            .unwrap();
+
+        assert_eq!(native(binary), true.into());
+    });
+}
@@ -1,7 +1,10 @@
 use proptest::prop_assert_eq;
 
+use liblumen_alloc::erts::term::prelude::*;
+
 use crate::erlang::is_float_1::native;
 use crate::test::strategy;
+use crate::test::with_process;
 
 #[test]
 fn without_float_returns_false() {
@@ -26,3 +29,17 @@ fn with_float_returns_true() {
         },
     );
 }
+
+#[test]
+fn with_small_integer_big_integer_float_or_atom() {
+    with_process(|process| {
+        let big_integer = process.integer(isize::max_value() as i128 + 1).unwrap();
+
+        assert!(big_integer.is_boxed_bigint());
+
+        assert_eq!(native(process.integer(3).unwrap()), false.into());
+        assert_eq!(native(big_integer), false.into());
+        assert_eq!(native(process.float(3.0).unwrap()), true.into());
+        assert_eq!(native(Atom::str_to_term("three")), false.into());
+    });
+}
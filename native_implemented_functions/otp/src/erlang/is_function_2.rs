@@ -5,6 +5,8 @@
 #[cfg(all(not(target_arch = "wasm32"), test))]
 mod test;
 
+use num_bigint::{BigInt, Sign};
+
 use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::term::prelude::*;
 
@@ -14,7 +16,31 @@ use lumen_rt_core::context::*;
 
 #[native_implemented_function(is_function/2)]
 fn native(term: Term, arity: Term) -> exception::Result<Term> {
-    let arity_arity = term_try_into_arity(arity)?;
+    match term_try_into_arity(arity) {
+        Ok(arity_u8) => Ok(term.decode()?.is_function_with_arity(arity_u8).into()),
+        // Closures can't have an arity past `u8`, but any non-negative arity is still valid to ask
+        // about.
+        Err(_) if is_non_negative_integer(arity)? => Ok(false.into()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+// Private
+
+fn is_non_negative_integer(term: Term) -> exception::Result<bool> {
+    let is_non_negative_integer = match term.decode()? {
+        TypedTerm::SmallInteger(small_integer) => {
+            let i: isize = small_integer.into();
+
+            0 <= i
+        }
+        TypedTerm::BigInteger(big_integer) => {
+            let big_int: &BigInt = big_integer.as_ref().into();
+
+            big_int.sign() != Sign::Minus
+        }
+        _ => false,
+    };
 
-    Ok(term.decode()?.is_function_with_arity(arity_arity).into())
+    Ok(is_non_negative_integer)
 }
@@ -2,6 +2,10 @@ use super::*;
 
 use proptest::strategy::Just;
 
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::with_process;
+
 #[test]
 fn without_function_arity_returns_false() {
     run!(
@@ -55,3 +59,73 @@ fn with_function_arity_returns_true() {
         },
     );
 }
+
+#[test]
+fn with_one_arity_function_matches_only_one_arity() {
+    with_process(|process| {
+        let function = strategy::term::export_closure(
+            process,
+            Atom::try_from_str("module").unwrap(),
+            Atom::try_from_str("function").unwrap(),
+            1,
+        );
+
+        assert_eq!(
+            native(function, process.integer(1).unwrap()),
+            Ok(true.into())
+        );
+        assert_eq!(
+            native(function, process.integer(0).unwrap()),
+            Ok(false.into())
+        );
+        assert_eq!(
+            native(function, process.integer(2).unwrap()),
+            Ok(false.into())
+        );
+    });
+}
+
+#[test]
+fn with_two_arity_function_matches_two_arity_but_not_one_arity() {
+    with_process(|process| {
+        let function = strategy::term::export_closure(
+            process,
+            Atom::try_from_str("module").unwrap(),
+            Atom::try_from_str("function").unwrap(),
+            2,
+        );
+
+        assert_eq!(
+            native(function, process.integer(2).unwrap()),
+            Ok(true.into())
+        );
+        assert_eq!(
+            native(function, process.integer(1).unwrap()),
+            Ok(false.into())
+        );
+    });
+}
+
+#[test]
+fn with_arity_greater_than_max_function_arity_returns_false() {
+    with_process(|process| {
+        let function = strategy::term::export_closure(
+            process,
+            Atom::try_from_str("module").unwrap(),
+            Atom::try_from_str("function").unwrap(),
+            255,
+        );
+
+        assert_eq!(
+            native(function, process.integer(256).unwrap()),
+            Ok(false.into())
+        );
+        assert_eq!(
+            native(
+                function,
+                process.integer(isize::max_value() as i128 + 1).unwrap()
+            ),
+            Ok(false.into())
+        );
+    });
+}
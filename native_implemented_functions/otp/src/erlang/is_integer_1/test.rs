@@ -1,8 +1,11 @@
 use proptest::prop_assert_eq;
 use proptest::test_runner::{Config, TestRunner};
 
+use liblumen_alloc::erts::term::prelude::*;
+
 use crate::erlang::is_integer_1::native;
 use crate::test::strategy;
+use crate::test::with_process;
 use crate::test::with_process_arc;
 
 #[test]
@@ -29,3 +32,17 @@ fn with_integer_returns_true() {
             .unwrap();
     });
 }
+
+#[test]
+fn with_small_integer_big_integer_float_or_atom() {
+    with_process(|process| {
+        let big_integer = process.integer(isize::max_value() as i128 + 1).unwrap();
+
+        assert!(big_integer.is_boxed_bigint());
+
+        assert_eq!(native(process.integer(3).unwrap()), true.into());
+        assert_eq!(native(big_integer), true.into());
+        assert_eq!(native(process.float(3.0).unwrap()), false.into());
+        assert_eq!(native(Atom::str_to_term("three")), false.into());
+    });
+}
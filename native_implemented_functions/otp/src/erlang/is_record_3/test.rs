@@ -4,7 +4,7 @@ use proptest::strategy::{Just, Strategy};
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::erlang::is_record_3::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_tuple_returns_false() {
@@ -210,3 +210,26 @@ fn with_non_empty_tuple_with_record_tag_with_size_returns_true() {
         },
     );
 }
+
+#[test]
+fn with_tagged_tuple_with_matching_size_returns_true_and_other_size_returns_false() {
+    with_process(|process| {
+        let record_tag = Atom::str_to_term("tag");
+        let tuple = process
+            .tuple_from_slice(&[
+                record_tag,
+                process.integer(1).unwrap(),
+                process.integer(2).unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(tuple, record_tag, process.integer(3).unwrap()),
+            Ok(true.into())
+        );
+        assert_eq!(
+            native(tuple, record_tag, process.integer(2).unwrap()),
+            Ok(false.into())
+        );
+    });
+}
@@ -83,3 +83,42 @@ fn with_non_empty_proper_list_is_number_of_elements() {
         },
     );
 }
+
+#[test]
+fn with_three_element_list_is_three() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[
+                process.integer(1).unwrap(),
+                process.integer(2).unwrap(),
+                process.integer(3).unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(native(process, list), Ok(process.integer(3).unwrap()));
+    });
+}
+
+#[test]
+fn with_one_element_improper_list_errors_badarg() {
+    with_process(|process| {
+        let list = process
+            .cons(process.integer(1).unwrap(), process.integer(2).unwrap())
+            .unwrap();
+
+        assert_badarg!(
+            native(process, list),
+            format!("list ({}) is improper", list)
+        );
+    });
+}
+
+#[test]
+fn with_long_list_does_not_overflow_stack() {
+    with_process(|process| {
+        let len: usize = 5_000;
+        let list = process.list_from_iter((0..len).map(|_| Term::NIL)).unwrap();
+
+        assert_eq!(native(process, list), Ok(process.integer(len).unwrap()));
+    });
+}
@@ -5,7 +5,7 @@ use proptest::strategy::{Just, Strategy};
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::erlang::list_to_existing_atom_1::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_list_errors_badarg() {
@@ -101,3 +101,14 @@ fn with_list_with_existing_atom_returns_atom() {
         },
     );
 }
+
+#[test]
+fn with_list_of_interned_atom_returns_atom() {
+    with_process(|process| {
+        let string = "list_to_existing_atom_1_interned";
+        let existing_atom = Atom::str_to_term(string);
+        let list = process.charlist_from_str(string).unwrap();
+
+        assert_eq!(native(list), Ok(existing_atom));
+    });
+}
@@ -5,6 +5,7 @@ use proptest::test_runner::{Config, TestRunner};
 
 use crate::erlang::list_to_float_1::native;
 use crate::test::strategy;
+use crate::test::with_process;
 use crate::test::with_process_arc;
 
 #[test]
@@ -40,7 +41,10 @@ fn with_list_with_integer_errors_badarg() {
         |(arc_process, string, list)| {
             prop_assert_badarg!(
                 native(&arc_process, list),
-                format!("list ('{}') does not contain decimal point", string)
+                format!(
+                    "list ('{}') does not contain decimal point or exponent",
+                    string
+                )
             );
 
             Ok(())
@@ -94,3 +98,33 @@ fn with_list_with_greater_than_max_f64_errors_badarg() {
         );
     });
 }
+
+#[test]
+fn with_list_with_decimal_point_returns_float() {
+    with_process(|process| {
+        let list = process.charlist_from_str("3.0").unwrap();
+
+        assert_eq!(native(process, list), Ok(process.float(3.0).unwrap()));
+    });
+}
+
+#[test]
+fn with_list_without_decimal_point_or_exponent_errors_badarg() {
+    with_process(|process| {
+        let list = process.charlist_from_str("3").unwrap();
+
+        assert_badarg!(
+            native(process, list),
+            "list ('3') does not contain decimal point or exponent"
+        );
+    });
+}
+
+#[test]
+fn with_list_with_exponent_without_decimal_point_returns_float() {
+    with_process(|process| {
+        let list = process.charlist_from_str("3e2").unwrap();
+
+        assert_eq!(native(process, list), Ok(process.float(300.0).unwrap()));
+    });
+}
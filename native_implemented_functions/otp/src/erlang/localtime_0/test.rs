@@ -2,7 +2,7 @@ use std::thread;
 use std::time::Duration;
 
 use crate::erlang::localtime_0::native;
-use crate::test::with_process;
+use crate::test::{assert_datetime_in_range, with_process};
 
 #[test]
 fn increases_after_2_seconds() {
@@ -16,3 +16,10 @@ fn increases_after_2_seconds() {
         assert!(first < second);
     });
 }
+
+#[test]
+fn returns_date_and_time_tuples_with_fields_in_range() {
+    with_process(|process| {
+        assert_datetime_in_range(native(process).unwrap());
+    });
+}
@@ -0,0 +1,19 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang::localtime_to_universaltime_2;
+
+#[native_implemented_function(localtime_to_universaltime/1)]
+pub fn native(process: &Process, localtime: Term) -> exception::Result<Term> {
+    localtime_to_universaltime_2::native(process, localtime, Atom::str_to_term("undefined"))
+}
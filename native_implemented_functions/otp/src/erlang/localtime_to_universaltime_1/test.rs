@@ -0,0 +1,48 @@
+use crate::erlang::datetime_tuple::datetime_to_term;
+use crate::erlang::localtime_to_universaltime_1::native;
+use crate::erlang::universaltime_to_localtime_1;
+use crate::test::with_process;
+
+#[test]
+fn without_datetime_errors_badarg() {
+    with_process(|process| {
+        let localtime = process
+            .tuple_from_slice(&[
+                process.integer(2020).unwrap(),
+                process.integer(1).unwrap(),
+                process.integer(15).unwrap(),
+            ])
+            .unwrap();
+
+        assert_badarg!(
+            native(process, localtime),
+            "is not a {{year, month, day}, {hour, minute, second}} tuple"
+        );
+    });
+}
+
+#[test]
+fn with_invalid_date_errors_badarg() {
+    with_process(|process| {
+        let localtime = datetime_to_term(process, [2020, 2, 30, 12, 0, 0]).unwrap();
+
+        assert_badarg!(
+            native(process, localtime),
+            format!("localtime ({}) is not a valid date and time", localtime)
+        );
+    });
+}
+
+#[test]
+fn with_valid_datetime_round_trips_through_universaltime_to_localtime() {
+    with_process(|process| {
+        // January 15th at noon is away from any DST transition
+        let localtime = datetime_to_term(process, [2020, 1, 15, 12, 0, 0]).unwrap();
+        let universaltime = native(process, localtime).unwrap();
+
+        assert_eq!(
+            universaltime_to_localtime_1::native(process, universaltime),
+            Ok(localtime)
+        );
+    });
+}
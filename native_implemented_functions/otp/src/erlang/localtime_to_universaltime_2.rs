@@ -0,0 +1,53 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use lumen_rt_core::time::datetime;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang::datetime_tuple::{datetime_to_term, term_try_into_datetime};
+
+/// `localtime_to_universaltime/2`
+///
+/// Like in OTP, `true` converts `localtime` with the DST offset and `false` with the standard
+/// offset, even when `localtime` is not during DST or is during DST, respectively, while
+/// `undefined` uses the offset in effect at `localtime`.  When clocks were turned back, so
+/// `localtime` occurred twice, `true` picks the occurrence during DST, `false` picks the one
+/// after, and `undefined` picks the first.
+#[native_implemented_function(localtime_to_universaltime/2)]
+pub fn native(process: &Process, localtime: Term, is_dst: Term) -> exception::Result<Term> {
+    let localtime_datetime = term_try_into_datetime("localtime", localtime)?;
+    let is_dst_option = term_try_into_is_dst(is_dst)?;
+    let universaltime_datetime = datetime::local_to_utc(localtime_datetime, is_dst_option)
+        .with_context(|| format!("localtime ({}) is not a valid date and time", localtime))?;
+
+    datetime_to_term(process, universaltime_datetime)
+}
+
+// Private
+
+fn term_try_into_is_dst(is_dst: Term) -> exception::Result<Option<bool>> {
+    match is_dst.decode()? {
+        TypedTerm::Atom(atom) => match atom.name() {
+            "true" => Ok(Some(true)),
+            "false" => Ok(Some(false)),
+            "undefined" => Ok(None),
+            _ => Err(anyhow!("is_dst ({}) is not true, false, or undefined", is_dst).into()),
+        },
+        _ => Err(TypeError)
+            .context(format!(
+                "is_dst ({}) is not true, false, or undefined",
+                is_dst
+            ))
+            .map_err(From::from),
+    }
+}
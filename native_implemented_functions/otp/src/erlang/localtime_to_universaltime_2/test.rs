@@ -0,0 +1,104 @@
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::datetime_tuple::{datetime_to_term, term_try_into_datetime};
+use crate::erlang::localtime_to_universaltime_1;
+use crate::erlang::localtime_to_universaltime_2::native;
+use crate::test::with_process;
+
+#[test]
+fn without_boolean_or_undefined_is_dst_errors_badarg() {
+    with_process(|process| {
+        let localtime = datetime_to_term(process, [2020, 1, 15, 12, 0, 0]).unwrap();
+        let is_dst = Atom::str_to_term("maybe");
+
+        assert_badarg!(
+            native(process, localtime, is_dst),
+            "is_dst (:'maybe') is not true, false, or undefined"
+        );
+        assert_badarg!(
+            native(process, localtime, process.integer(1).unwrap()),
+            "is_dst (1) is not true, false, or undefined"
+        );
+    });
+}
+
+#[test]
+fn with_invalid_date_errors_badarg() {
+    with_process(|process| {
+        let localtime = datetime_to_term(process, [2019, 2, 29, 0, 0, 0]).unwrap();
+
+        assert_badarg!(
+            native(process, localtime, Atom::str_to_term("false")),
+            format!("localtime ({}) is not a valid date and time", localtime)
+        );
+    });
+}
+
+#[test]
+fn with_undefined_is_dst_is_same_as_localtime_to_universaltime_1() {
+    with_process(|process| {
+        for localtime_array in &[JANUARY_NOON, JULY_NOON] {
+            let localtime = datetime_to_term(process, *localtime_array).unwrap();
+
+            assert_eq!(
+                native(process, localtime, Atom::str_to_term("undefined")),
+                localtime_to_universaltime_1::native(process, localtime)
+            );
+        }
+    });
+}
+
+#[test]
+fn with_true_is_dst_converts_with_dst_offset_even_when_not_in_dst() {
+    with_process(|process| {
+        // Which half of the year is in DST depends on the hemisphere, but the bigger offset from
+        // UTC is the DST one.  Without DST, both offsets are the same.
+        let dst_offset = local_minus_utc_seconds(process, JANUARY_NOON, "undefined")
+            .max(local_minus_utc_seconds(process, JULY_NOON, "undefined"));
+
+        assert_eq!(
+            local_minus_utc_seconds(process, JANUARY_NOON, "true"),
+            dst_offset
+        );
+        assert_eq!(
+            local_minus_utc_seconds(process, JULY_NOON, "true"),
+            dst_offset
+        );
+    });
+}
+
+#[test]
+fn with_false_is_dst_converts_with_standard_offset_even_when_in_dst() {
+    with_process(|process| {
+        let standard_offset = local_minus_utc_seconds(process, JANUARY_NOON, "undefined")
+            .min(local_minus_utc_seconds(process, JULY_NOON, "undefined"));
+
+        assert_eq!(
+            local_minus_utc_seconds(process, JANUARY_NOON, "false"),
+            standard_offset
+        );
+        assert_eq!(
+            local_minus_utc_seconds(process, JULY_NOON, "false"),
+            standard_offset
+        );
+    });
+}
+
+// Noon on the 15th, so that converting with any offset stays in the same month
+const JANUARY_NOON: [usize; 6] = [2020, 1, 15, 12, 0, 0];
+const JULY_NOON: [usize; 6] = [2020, 7, 15, 12, 0, 0];
+
+fn local_minus_utc_seconds(process: &Process, localtime_array: [usize; 6], is_dst: &str) -> isize {
+    let localtime = datetime_to_term(process, localtime_array).unwrap();
+    let universaltime = native(process, localtime, Atom::str_to_term(is_dst)).unwrap();
+    let universaltime_array = term_try_into_datetime("universaltime", universaltime).unwrap();
+
+    month_seconds(localtime_array) - month_seconds(universaltime_array)
+}
+
+fn month_seconds(datetime: [usize; 6]) -> isize {
+    let [_, _, day, hour, minute, second] = datetime;
+
+    (((day * 24 + hour) * 60 + minute) * 60 + second) as isize
+}
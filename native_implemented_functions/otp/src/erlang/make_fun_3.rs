@@ -0,0 +1,68 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::sync::Arc;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::code::stack::frame::Placement;
+use liblumen_alloc::erts::process::{code, Process};
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use lumen_rt_core::context::*;
+
+use crate::erlang::apply_3;
+
+/// Returns the external fun `fun module:function/arity`.
+///
+/// `module:function/arity` does not need to be exported yet.  If it is not exported when the fun is
+/// called, the call fails with `undef`, but the fun itself can be made and passed around.
+#[native_implemented_function(make_fun/3)]
+pub fn native(
+    process: &Process,
+    module: Term,
+    function: Term,
+    arity: Term,
+) -> exception::Result<Term> {
+    let module_atom = term_try_into_atom!(module)?;
+    let function_atom = term_try_into_atom!(function)?;
+    let arity_u8 = term_try_into_arity(arity)?;
+
+    let code = lumen_rt_full::code::export::get(&module_atom, &function_atom, arity_u8)
+        .unwrap_or(apply_when_called_code);
+
+    process
+        .export_closure(module_atom, function_atom, arity_u8, Some(code))
+        .map_err(From::from)
+}
+
+// Private
+
+/// Code for a fun whose `module:function/arity` was not exported when the fun was made.  The
+/// lookup is repeated by `apply/3` when the fun is called, so that the call either reaches code
+/// exported since or fails with `undef`.
+fn apply_when_called_code(arc_process: &Arc<Process>) -> code::Result {
+    let module_function_arity = arc_process.current_module_function_arity().unwrap();
+    let arity = module_function_arity.arity as usize;
+
+    let argument_vec: Vec<Term> = (1..=arity)
+        .map(|one_based_index| arc_process.stack_peek(one_based_index).unwrap())
+        .collect();
+    let argument_list = arc_process.list_from_slice(&argument_vec)?;
+    arc_process.stack_popn(arity);
+
+    apply_3::place_frame_with_arguments(
+        arc_process,
+        Placement::Replace,
+        module_function_arity.module.encode().unwrap(),
+        module_function_arity.function.encode().unwrap(),
+        argument_list,
+    )?;
+
+    Process::call_code(arc_process)
+}
@@ -0,0 +1,118 @@
+use std::mem;
+
+use liblumen_alloc::erts::process::code::stack::frame::Placement;
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_full::future::Ready;
+
+use crate::erlang::apply_2;
+use crate::erlang::make_fun_3::native;
+use crate::test::{assert_exits_undef, with_process};
+
+#[test]
+fn without_atom_module_errors_badarg() {
+    with_process(|process| {
+        let module = process.integer(0).unwrap();
+
+        assert_badarg!(
+            native(
+                process,
+                module,
+                Atom::str_to_term("function"),
+                process.integer(0).unwrap()
+            ),
+            format!("module ({}) is not an atom", module)
+        );
+    });
+}
+
+#[test]
+fn without_arity_errors_badarg() {
+    with_process(|process| {
+        let arity = process.integer(256).unwrap();
+
+        assert_badarg!(
+            native(
+                process,
+                Atom::str_to_term("module"),
+                Atom::str_to_term("function"),
+                arity
+            ),
+            format!("arity ({}) is not", arity)
+        );
+    });
+}
+
+#[test]
+fn with_exported_function_can_be_applied() {
+    crate::erlang::add_2::export();
+
+    let Ready {
+        arc_process: child_arc_process,
+        result,
+    } = run_until_ready(
+        Atom::str_to_term("erlang"),
+        Atom::str_to_term("+"),
+        2,
+        &[1, 2],
+    );
+
+    assert_eq!(result, Ok(child_arc_process.integer(3).unwrap()));
+
+    mem::drop(child_arc_process);
+}
+
+#[test]
+fn without_exported_function_makes_function_that_errors_undef_when_applied() {
+    let module = Atom::str_to_term("make_fun_3_test");
+    let function = Atom::str_to_term("unexported");
+
+    with_process(|process| {
+        let fun = native(process, module, function, process.integer(1).unwrap()).unwrap();
+
+        assert!(fun.is_function());
+    });
+
+    let Ready {
+        arc_process: child_arc_process,
+        ..
+    } = run_until_ready(module, function, 1, &[7]);
+
+    let arguments = child_arc_process
+        .list_from_slice(&[child_arc_process.integer(7).unwrap()])
+        .unwrap();
+
+    assert_exits_undef(
+        &child_arc_process,
+        module,
+        function,
+        arguments,
+        ":make_fun_3_test.unexported/1 is not exported",
+    );
+
+    mem::drop(child_arc_process);
+}
+
+fn run_until_ready(module: Term, function: Term, arity: isize, arguments: &[isize]) -> Ready {
+    lumen_rt_full::future::run_until_ready(
+        Default::default(),
+        |child_process| {
+            let fun = native(
+                child_process,
+                module,
+                function,
+                child_process.integer(arity)?,
+            )?;
+            let argument_vec = arguments
+                .iter()
+                .map(|argument| child_process.integer(*argument))
+                .collect::<Result<Vec<Term>, _>>()?;
+            let argument_list = child_process.list_from_slice(&argument_vec)?;
+
+            apply_2::place_frame_with_arguments(child_process, Placement::Push, fun, argument_list)
+                .map_err(|e| e.into())
+        },
+        5_000,
+    )
+    .unwrap()
+}
@@ -1,10 +1,13 @@
+use std::convert::TryInto;
+
 use proptest::prop_assert_eq;
 use proptest::strategy::{Just, Strategy};
 
-use liblumen_alloc::erts::term::prelude::Term;
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
 
 use crate::erlang::map_size_1::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_map_errors_badmap() {
@@ -57,3 +60,80 @@ fn with_map_returns_number_of_entries() {
         },
     );
 }
+
+#[test]
+fn with_empty_map_returns_zero() {
+    with_process(|process| {
+        let map = process.map_from_slice(&[]).unwrap();
+
+        assert_eq!(native(process, map), Ok(process.integer(0).unwrap()));
+    });
+}
+
+#[test]
+fn with_one_entry_returns_one() {
+    with_process(|process| {
+        let map = process
+            .map_from_slice(&[(atom!("key"), atom!("value"))])
+            .unwrap();
+
+        assert_eq!(native(process, map), Ok(process.integer(1).unwrap()));
+    });
+}
+
+#[test]
+fn with_many_entries_returns_number_of_entries() {
+    with_process(|process| {
+        let entry_vec: Vec<(Term, Term)> = (0..100)
+            .map(|i| (process.integer(i).unwrap(), process.integer(-i).unwrap()))
+            .collect();
+        let map = process.map_from_slice(&entry_vec).unwrap();
+
+        assert_eq!(native(process, map), Ok(process.integer(100).unwrap()));
+    });
+}
+
+#[test]
+fn with_duplicate_keys_returns_number_of_unique_keys() {
+    with_process(|process| {
+        let map = process
+            .map_from_slice(&[
+                (atom!("a"), process.integer(1).unwrap()),
+                (atom!("b"), process.integer(2).unwrap()),
+                (atom!("a"), process.integer(3).unwrap()),
+            ])
+            .unwrap();
+
+        assert_eq!(native(process, map), Ok(process.integer(2).unwrap()));
+    });
+}
+
+#[test]
+fn with_map_strategy_returns_number_of_unique_keys() {
+    run!(
+        |arc_process| (Just(arc_process.clone()), strategy::term::map(arc_process)),
+        |(arc_process, map)| {
+            let boxed_map: Boxed<Map> = map.try_into().unwrap();
+            let key_vec = boxed_map.keys();
+            let mut unique_key_vec: Vec<Term> = Vec::with_capacity(key_vec.len());
+
+            for key in key_vec {
+                let typed_key = key.decode().unwrap();
+
+                if !unique_key_vec
+                    .iter()
+                    .any(|unique_key| unique_key.decode().unwrap().exact_eq(&typed_key))
+                {
+                    unique_key_vec.push(key);
+                }
+            }
+
+            prop_assert_eq!(
+                native(&arc_process, map),
+                Ok(arc_process.integer(unique_key_vec.len()).unwrap())
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -83,3 +83,14 @@ where
         assert_eq!(max, expected);
     });
 }
+
+#[test]
+fn with_integer_and_float_that_compare_equal_returns_first() {
+    with_process(|process| {
+        let integer = process.integer(1).unwrap();
+        let float = process.float(1.0).unwrap();
+
+        assert!(native(integer, float).is_smallint());
+        assert!(native(float, integer).is_float());
+    });
+}
@@ -17,6 +17,17 @@ fn with_number_second_returns_first() {
     );
 }
 
+#[test]
+fn with_integer_second_returns_atom() {
+    with_process(|process| {
+        let atom = Atom::str_to_term("atom");
+        let integer = process.integer(1).unwrap();
+
+        assert_eq!(native(atom, integer), atom);
+        assert_eq!(native(integer, atom), atom);
+    });
+}
+
 #[test]
 fn with_lesser_atom_returns_first() {
     max(|_, _| Atom::str_to_term("eirst"), First);
@@ -0,0 +1,51 @@
+//! The categories of `memory/0` and `memory/1` in bytes.
+//!
+//! The sizes are approximate, but consistent with each other, so `total` is `processes` plus
+//! `system`, and `system` is `atom` plus `binary`.  Lumen does not separate used from allocated
+//! memory, so `processes_used` and `atom_used` are the same as `processes` and `atom`.
+
+use std::mem;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::atom::atom_table_size;
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_core::registry;
+
+pub const CATEGORIES: &[&str] = &[
+    "total",
+    "processes",
+    "processes_used",
+    "system",
+    "atom",
+    "atom_used",
+    "binary",
+];
+
+pub fn category_to_bytes(category: &str) -> Option<usize> {
+    match category {
+        "total" => Some(processes() + system()),
+        "processes" | "processes_used" => Some(processes()),
+        "system" => Some(system()),
+        "atom" | "atom_used" => Some(atom_table_size()),
+        "binary" => Some(ProcBin::allocated_bytes()),
+        _ => None,
+    }
+}
+
+// Private
+
+/// The process control blocks and their heaps.  Unlike `process_info(Pid, memory)`, the procbins
+/// on the virtual binary heaps are counted in `binary` instead.
+fn processes() -> usize {
+    registry::processes()
+        .iter()
+        .map(|arc_process| {
+            mem::size_of::<Process>() + arc_process.total_heap_size() * mem::size_of::<Term>()
+        })
+        .sum()
+}
+
+fn system() -> usize {
+    atom_table_size() + ProcBin::allocated_bytes()
+}
@@ -0,0 +1,29 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use super::memory::{category_to_bytes, CATEGORIES};
+
+/// Returns `[{Type, Size}]` for all the memory types in bytes.
+#[native_implemented_function(memory/0)]
+pub fn native(process: &Process) -> exception::Result<Term> {
+    let mut entry_vec = Vec::with_capacity(CATEGORIES.len());
+
+    for category in CATEGORIES {
+        let bytes = category_to_bytes(category).unwrap();
+        let entry =
+            process.tuple_from_slice(&[Atom::str_to_term(category), process.integer(bytes)?])?;
+        entry_vec.push(entry);
+    }
+
+    process.list_from_slice(&entry_vec).map_err(From::from)
+}
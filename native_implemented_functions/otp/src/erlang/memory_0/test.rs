@@ -0,0 +1,41 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::memory_0::native;
+use crate::test::with_process;
+
+#[test]
+fn returns_size_of_each_type() {
+    with_process(|process| {
+        let list = native(process).unwrap();
+        let boxed_cons: Boxed<Cons> = list.try_into().unwrap();
+        let type_vec: Vec<String> = boxed_cons
+            .into_iter()
+            .map(|result| {
+                let entry = result.unwrap();
+                let tuple: Boxed<Tuple> = entry.try_into().unwrap();
+
+                assert_eq!(tuple.len(), 2);
+
+                let type_atom: Atom = tuple[0].try_into().unwrap();
+                let _: usize = tuple[1].try_into().unwrap();
+
+                type_atom.name().to_string()
+            })
+            .collect();
+
+        assert_eq!(
+            type_vec,
+            vec![
+                "total",
+                "processes",
+                "processes_used",
+                "system",
+                "atom",
+                "atom_used",
+                "binary"
+            ]
+        );
+    });
+}
@@ -0,0 +1,58 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use super::memory::category_to_bytes;
+
+/// Returns the size in bytes of the memory `Type` or, for a list of types, `[{Type, Size}]` in the
+/// same order.
+#[native_implemented_function(memory/1)]
+pub fn native(process: &Process, r#type: Term) -> exception::Result<Term> {
+    match r#type.decode()? {
+        TypedTerm::Atom(atom) => {
+            let bytes = atom_to_bytes(r#type, atom)?;
+
+            process.integer(bytes).map_err(From::from)
+        }
+        TypedTerm::List(cons) => {
+            let mut entry_vec = Vec::new();
+
+            for result in cons.into_iter() {
+                let element = result.map_err(|_| type_error(r#type))?;
+                let atom: Atom = element.try_into().map_err(|_| type_error(r#type))?;
+                let bytes = atom_to_bytes(r#type, atom)?;
+
+                entry_vec.push(process.tuple_from_slice(&[element, process.integer(bytes)?])?);
+            }
+
+            process.list_from_slice(&entry_vec).map_err(From::from)
+        }
+        _ => Err(type_error(r#type).into()),
+    }
+}
+
+// Private
+
+fn atom_to_bytes(r#type: Term, atom: Atom) -> exception::Result<usize> {
+    category_to_bytes(atom.name()).ok_or_else(|| type_error(r#type).into())
+}
+
+fn type_error(r#type: Term) -> anyhow::Error {
+    anyhow!(
+        "type ({}) is not total, processes, processes_used, system, atom, atom_used, binary, or a list of them",
+        r#type
+    )
+}
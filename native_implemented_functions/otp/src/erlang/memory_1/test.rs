@@ -0,0 +1,92 @@
+use std::convert::TryInto;
+
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::memory_1::native;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_atom_or_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone())
+                    .prop_filter("Type cannot be a list", |r#type| !r#type.is_list()),
+            )
+        },
+        |(arc_process, r#type)| {
+            prop_assert_badarg!(
+                native(&arc_process, r#type),
+                format!(
+                    "type ({}) is not total, processes, processes_used, system, atom, atom_used, binary, or a list of them",
+                    r#type
+                )
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_unsupported_type_errors_badarg() {
+    with_process(|process| {
+        let r#type = Atom::str_to_term("code");
+
+        assert_badarg!(
+            native(process, r#type),
+            format!(
+                "type ({}) is not total, processes, processes_used, system, atom, atom_used, binary, or a list of them",
+                r#type
+            )
+        );
+    });
+}
+
+#[test]
+fn with_binary_increases_after_allocating_procbins() {
+    with_process(|process| {
+        let before = bytes(process, "binary");
+
+        let len = 1024 * 1024;
+        let binary_vec: Vec<Term> = (0..4)
+            .map(|_| process.binary_from_bytes(&vec![0; len]).unwrap())
+            .collect();
+
+        assert!(binary_vec.iter().all(|binary| binary.is_boxed_procbin()));
+        // other tests concurrently allocate and free procbins
+        assert!(before + 2 * len <= bytes(process, "binary"));
+    });
+}
+
+#[test]
+fn with_list_returns_size_of_each_type_in_order() {
+    with_process(|process| {
+        let type_vec = vec![Atom::str_to_term("binary"), Atom::str_to_term("atom")];
+        let list = process.list_from_slice(&type_vec).unwrap();
+        let boxed_cons: Boxed<Cons> = native(process, list).unwrap().try_into().unwrap();
+        let entry_type_vec: Vec<Term> = boxed_cons
+            .into_iter()
+            .map(|result| {
+                let tuple: Boxed<Tuple> = result.unwrap().try_into().unwrap();
+                let _: usize = tuple[1].try_into().unwrap();
+
+                tuple[0]
+            })
+            .collect();
+
+        assert_eq!(entry_type_vec, type_vec);
+        assert!(0 < bytes(process, "atom"));
+    });
+}
+
+fn bytes(process: &Process, r#type: &str) -> usize {
+    native(process, Atom::str_to_term(r#type))
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
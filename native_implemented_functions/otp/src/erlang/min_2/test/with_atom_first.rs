@@ -17,6 +17,17 @@ fn with_number_second_returns_second() {
     );
 }
 
+#[test]
+fn with_integer_second_returns_integer() {
+    with_process(|process| {
+        let atom = Atom::str_to_term("atom");
+        let integer = process.integer(1).unwrap();
+
+        assert_eq!(native(atom, integer), integer);
+        assert_eq!(native(integer, atom), integer);
+    });
+}
+
 #[test]
 fn with_lesser_atom_returns_second() {
     min(|_, _| Atom::str_to_term("eirst"), Second);
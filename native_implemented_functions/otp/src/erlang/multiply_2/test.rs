@@ -35,3 +35,18 @@ fn without_number_multiplier_errors_badarith() {
         },
     );
 }
+
+#[test]
+fn with_integer_operands_returns_integer() {
+    with_process(|process| {
+        let product = native(
+            process,
+            process.integer(2).unwrap(),
+            process.integer(3).unwrap(),
+        )
+        .unwrap();
+
+        assert!(product.is_smallint());
+        assert_eq!(product, process.integer(6).unwrap());
+    });
+}
@@ -1,5 +1,7 @@
 use super::*;
 
+use num_bigint::BigInt;
+
 #[test]
 fn without_number_multiplicand_errors_badarith() {
     run!(
@@ -140,3 +142,36 @@ where
         f(multiplier, &process)
     })
 }
+
+#[test]
+fn at_small_integer_boundaries_promotes_to_big_integer() {
+    with_process(|process| {
+        let max = process.integer(SmallInteger::MAX_VALUE).unwrap();
+        let min = process.integer(SmallInteger::MIN_VALUE).unwrap();
+        let two = process.integer(2).unwrap();
+
+        let twice_max = native(&process, max, two).unwrap();
+
+        assert!(twice_max.is_boxed_bigint());
+        assert_eq!(
+            twice_max,
+            process.integer(SmallInteger::MAX_VALUE * 2).unwrap()
+        );
+
+        let twice_min = native(&process, min, two).unwrap();
+
+        assert!(twice_min.is_boxed_bigint());
+        assert_eq!(
+            twice_min,
+            process.integer(SmallInteger::MIN_VALUE * 2).unwrap()
+        );
+
+        // Overflows `isize` and not just `SmallInteger`
+        let max_big_int: BigInt = SmallInteger::MAX_VALUE.into();
+
+        assert_eq!(
+            native(&process, max, max),
+            Ok(process.integer(max_big_int.clone() * max_big_int).unwrap())
+        );
+    })
+}
@@ -7,6 +7,7 @@ use lumen_rt_core::distribution::nodes::node;
 
 use native_implemented_function::native_implemented_function;
 
+/// The name of the local node, which is `nonode@nohost` when it is not distributed.
 #[native_implemented_function(node/0)]
 pub fn native() -> Term {
     node::term()
@@ -1,14 +1,59 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::max;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
 use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
-use native_implemented_function::native_implemented_function;
+use lumen_rt_core::time::{system, Unit::Microsecond};
+
+use lumen_rt_full::system::time::ErlangTimestamp;
 
-use crate::erlang::timestamp_0;
+use native_implemented_function::native_implemented_function;
 
-// now/0 is deprecated. We are implementing here using timestamp/0
-// which is not deprecated.
+/// now/0 is deprecated in favor of timestamp/0, but unlike timestamp/0 it guarantees that every
+/// call returns a later timestamp than the previous call, so it runs ahead of the system time when
+/// called more than once per microsecond.
 #[native_implemented_function(now/0)]
 pub fn native(process: &Process) -> exception::Result<Term> {
-    timestamp_0::native(process)
+    let system_microseconds = system::time(Microsecond).to_u64().unwrap();
+    let mut last_microseconds = LAST_MICROSECONDS.load(Ordering::SeqCst);
+
+    let microseconds = loop {
+        let next_microseconds = max(system_microseconds, last_microseconds + 1);
+
+        match LAST_MICROSECONDS.compare_exchange_weak(
+            last_microseconds,
+            next_microseconds,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        ) {
+            Ok(_) => break next_microseconds,
+            Err(current_microseconds) => last_microseconds = current_microseconds,
+        }
+    };
+
+    let erlang_timestamp = ErlangTimestamp::from_microseconds(&BigInt::from(microseconds));
+
+    process
+        .tuple_from_slice(&[
+            process.integer(erlang_timestamp.megaseconds as usize)?,
+            process.integer(erlang_timestamp.seconds as usize)?,
+            process.integer(erlang_timestamp.microseconds as usize)?,
+        ])
+        .map_err(|error| error.into())
 }
+
+// Private
+
+static LAST_MICROSECONDS: AtomicU64 = AtomicU64::new(0);
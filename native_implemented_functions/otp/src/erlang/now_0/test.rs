@@ -0,0 +1,17 @@
+use crate::erlang::now_0::native;
+use crate::test::with_process;
+
+#[test]
+fn is_strictly_increasing() {
+    with_process(|process| {
+        let mut previous = native(process).unwrap();
+
+        for _ in 0..1_000 {
+            let next = native(process).unwrap();
+
+            assert!(previous < next, "{} is not before {}", previous, next);
+
+            previous = next;
+        }
+    });
+}
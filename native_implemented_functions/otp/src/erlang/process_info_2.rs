@@ -22,10 +22,10 @@ pub fn native(process: &Process, pid: Term, item: Term) -> exception::Result<Ter
     let item_atom: Atom = term_try_into_atom!(item)?;
 
     if process.pid() == pid_pid {
-        process_info(process, item_atom)
+        process_info(process, process, item_atom)
     } else {
         match pid_to_process(&pid_pid) {
-            Some(pid_arc_process) => process_info(&pid_arc_process, item_atom),
+            Some(pid_arc_process) => process_info(process, &pid_arc_process, item_atom),
             None => Ok(atom!("undefined")),
         }
     }
@@ -34,7 +34,9 @@ pub fn native(process: &Process, pid: Term, item: Term) -> exception::Result<Ter
 
 // Private
 
-fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
+/// The info is read from `info_process`, but the returned term is allocated on `process`, the
+/// caller, as `info_process` may be another process.
+fn process_info(process: &Process, info_process: &Process, item: Atom) -> InternalResult<Term> {
     match item.name() {
         "backtrace" => unimplemented!(),
         "binary" => unimplemented!(),
@@ -47,11 +49,11 @@ fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
         "garbage_collection" => unimplemented!(),
         "garbage_collection_info" => unimplemented!(),
         "group_leader" => unimplemented!(),
-        "heap_size" => unimplemented!(),
+        "heap_size" => size(process, item, info_process.heap_size()),
         "initial_call" => unimplemented!(),
         "links" => unimplemented!(),
         "last_calls" => unimplemented!(),
-        "memory" => unimplemented!(),
+        "memory" => size(process, item, info_process.memory()),
         "message_queue_len" => unimplemented!(),
         "messages" => unimplemented!(),
         "min_heap_size" => unimplemented!(),
@@ -61,12 +63,12 @@ fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
         "message_queue_data" => unimplemented!(),
         "priority" => unimplemented!(),
         "reductions" => unimplemented!(),
-        "registered_name" => registered_name(process),
+        "registered_name" => registered_name(process, info_process),
         "sequential_trace_token" => unimplemented!(),
         "stack_size" => unimplemented!(),
         "status" => unimplemented!(),
         "suspending" => unimplemented!(),
-        "total_heap_size" => unimplemented!(),
+        "total_heap_size" => size(process, item, info_process.total_heap_size()),
         "trace" => unimplemented!(),
         "trap_exit" => unimplemented!(),
         name => Err(TryAtomFromTermError(name))
@@ -84,8 +86,8 @@ fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
     }
 }
 
-fn registered_name(process: &Process) -> InternalResult<Term> {
-    match *process.registered_name.read() {
+fn registered_name(process: &Process, info_process: &Process) -> InternalResult<Term> {
+    match *info_process.registered_name.read() {
         Some(registered_name) => {
             let tag = atom!("registered_name");
             let value = registered_name.encode()?;
@@ -97,3 +99,14 @@ fn registered_name(process: &Process) -> InternalResult<Term> {
         None => Ok(Term::NIL),
     }
 }
+
+/// `{item, size}` for the heap accounting items.  `heap_size` and `total_heap_size` are in words
+/// and `memory` is in bytes like in OTP.
+fn size(process: &Process, item: Atom, size: usize) -> InternalResult<Term> {
+    let tag = item.encode()?;
+    let value = process.integer(size)?;
+
+    process
+        .tuple_from_slice(&[tag, value])
+        .map_err(|error| error.into())
+}
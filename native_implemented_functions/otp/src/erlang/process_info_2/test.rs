@@ -5,13 +5,14 @@ use std::convert::TryInto;
 use proptest::strategy::{BoxedStrategy, Just, Strategy};
 use proptest::test_runner::{Config, TestRunner};
 
+use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
 use lumen_rt_core::registry;
 
 use crate::erlang::process_info_2::native;
 use crate::test;
-use crate::test::{registered_name, strategy, with_process_arc};
+use crate::test::{registered_name, strategy, with_process, with_process_arc};
 
 #[test]
 fn without_local_pid_errors_badarg() {
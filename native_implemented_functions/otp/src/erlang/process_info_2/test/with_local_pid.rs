@@ -1,4 +1,7 @@
+mod with_heap_size;
+mod with_memory;
 mod with_registered_name;
+mod with_total_heap_size;
 
 use super::*;
 
@@ -29,9 +32,21 @@ fn without_supported_item_errors_badarg() {
 fn unsupported_item_atom() -> BoxedStrategy<Term> {
     strategy::atom()
         .prop_filter("Item cannot be supported", |atom| match atom.name() {
-            "registered_name" => false,
+            "heap_size" | "memory" | "registered_name" | "total_heap_size" => false,
             _ => true,
         })
         .prop_map(|atom| atom.encode().unwrap())
         .boxed()
 }
+
+fn size(process: &Process, item: Term) -> usize {
+    let tuple: Boxed<Tuple> = native(process, process.pid_term(), item)
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    assert_eq!(tuple.len(), 2);
+    assert_eq!(tuple[0], item);
+
+    tuple[1].try_into().unwrap()
+}
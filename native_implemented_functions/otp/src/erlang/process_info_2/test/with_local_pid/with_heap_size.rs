@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn returns_size_of_young_heap_in_words() {
+    with_process(|process| {
+        let item = item();
+        let heap_size = size(process, item);
+
+        assert!(0 < heap_size);
+        assert!(heap_size <= size(process, Atom::str_to_term("total_heap_size")));
+    });
+}
+
+#[test]
+fn with_other_process_returns_its_size_allocated_on_calling_process() {
+    with_process_arc(|parent_arc_process| {
+        let other_arc_process = test::process::child(&parent_arc_process);
+        let item = item();
+
+        let tuple: Boxed<Tuple> = native(&parent_arc_process, other_arc_process.pid_term(), item)
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert!(parent_arc_process.is_owner(tuple.as_ptr()));
+        assert!(!other_arc_process.is_owner(tuple.as_ptr()));
+        assert_eq!(tuple[0], item);
+
+        let heap_size: usize = tuple[1].try_into().unwrap();
+
+        assert_eq!(heap_size, other_arc_process.heap_size());
+    });
+}
+
+fn item() -> Term {
+    Atom::str_to_term("heap_size")
+}
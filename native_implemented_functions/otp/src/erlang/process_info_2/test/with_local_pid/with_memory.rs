@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn is_at_least_total_heap_size_in_bytes() {
+    with_process(|process| {
+        let memory = size(process, item());
+        let total_heap_size = size(process, Atom::str_to_term("total_heap_size"));
+
+        assert!(total_heap_size * std::mem::size_of::<Term>() <= memory);
+    });
+}
+
+#[test]
+fn grows_after_allocating_large_binary() {
+    with_process(|process| {
+        let before = size(process, item());
+
+        let byte_count = 1024 * 1024;
+        process.binary_from_bytes(&vec![0; byte_count]).unwrap();
+
+        assert!(before + byte_count <= size(process, item()));
+    });
+}
+
+fn item() -> Term {
+    Atom::str_to_term("memory")
+}
@@ -28,15 +28,22 @@ fn with_registered_returns_empty_list() {
             registered_process_arc.clone()
         ));
 
+        let result = native(
+            &parent_process_arc,
+            registered_process_arc.pid_term(),
+            item(),
+        );
+
         assert_eq!(
-            native(
-                &parent_process_arc,
-                registered_process_arc.pid_term(),
-                item()
-            ),
+            result,
             Ok(parent_process_arc
                 .tuple_from_slice(&[item(), registered_name])
                 .unwrap())
         );
+
+        let tuple: Boxed<Tuple> = result.unwrap().try_into().unwrap();
+
+        assert!(parent_process_arc.is_owner(tuple.as_ptr()));
+        assert!(!registered_process_arc.is_owner(tuple.as_ptr()));
     });
 }
@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn includes_heap_fragments() {
+    with_process(|process| {
+        let item = item();
+        let before = size(process, item);
+
+        let fragment_word_size = 1024;
+        unsafe {
+            let fragment_ptr = process.alloc_fragment(fragment_word_size).unwrap();
+            // the fragment releases its first term when dropped
+            fragment_ptr.as_ptr().write(Term::NIL);
+        }
+
+        assert!(before + fragment_word_size <= size(process, item));
+    });
+}
+
+fn item() -> Term {
+    Atom::str_to_term("total_heap_size")
+}
@@ -109,3 +109,31 @@ where
 {
     with_process(|process| assert_badarith!(actual(&process)))
 }
+
+#[test]
+fn with_negative_dividend_truncates_toward_zero() {
+    with_process(|process| {
+        assert_eq!(
+            native(
+                process,
+                process.integer(-7).unwrap(),
+                process.integer(2).unwrap()
+            ),
+            Ok(process.integer(-1).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_negative_big_integer_dividend_truncates_toward_zero() {
+    with_process(|process| {
+        let dividend = process.integer(SmallInteger::MIN_VALUE - 1).unwrap();
+
+        assert!(dividend.is_boxed_bigint());
+
+        assert_eq!(
+            native(process, dividend, process.integer(2).unwrap()),
+            Ok(process.integer(-1).unwrap())
+        );
+    });
+}
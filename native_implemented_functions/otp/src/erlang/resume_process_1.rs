@@ -0,0 +1,40 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use lumen_rt_core::registry::pid_to_process;
+
+use lumen_rt_full::scheduler::Scheduled;
+
+/// Undoes one `suspend_process/1` of the process `pid`.  `pid` runs again once every
+/// `suspend_process/1` has been matched by a `resume_process/1`.
+#[native_implemented_function(resume_process/1)]
+pub fn native(pid: Term) -> exception::Result<Term> {
+    let pid_pid = term_try_into_local_pid!(pid)?;
+
+    match pid_to_process(&pid_pid) {
+        Some(arc_process) if !arc_process.is_exiting() => match arc_process.resume() {
+            Some(0) => {
+                if let Some(arc_scheduler) = arc_process.scheduler() {
+                    arc_scheduler.resume(&arc_process);
+                }
+
+                Ok(true.into())
+            }
+            Some(_) => Ok(true.into()),
+            None => Err(anyhow!("pid ({}) is not suspended", pid).into()),
+        },
+        _ => Err(anyhow!("pid ({}) is not alive", pid).into()),
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::*;
+
+use proptest::strategy::Just;
+
+use crate::erlang::resume_process_1::native;
+use crate::erlang::suspend_process_1;
+use crate::test::{self, strategy, with_process_arc};
+
+#[test]
+fn without_pid_errors_badarg() {
+    run!(
+        |arc_process| strategy::term::is_not_pid(arc_process.clone()),
+        |pid| {
+            prop_assert_is_not_local_pid!(native(pid), pid);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_suspended_process_errors_badarg() {
+    with_process_arc(|arc_process| {
+        let other_arc_process = test::process::child(&arc_process);
+        let pid = other_arc_process.pid_term();
+
+        assert_badarg!(native(pid), format!("pid ({}) is not suspended", pid));
+    });
+}
+
+#[test]
+fn with_exiting_process_errors_badarg() {
+    with_process_arc(|arc_process| {
+        let other_arc_process = test::process::child(&arc_process);
+        let pid = other_arc_process.pid_term();
+
+        assert_eq!(
+            suspend_process_1::native(&arc_process, pid),
+            Ok(true.into())
+        );
+
+        other_arc_process.exit_normal(anyhow!("Test").into());
+
+        assert_badarg!(native(pid), format!("pid ({}) is not alive", pid));
+    });
+}
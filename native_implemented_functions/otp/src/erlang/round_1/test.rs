@@ -3,6 +3,7 @@ use std::convert::TryInto;
 use proptest::prop_assert;
 
 use crate::erlang::round_1::native;
+use crate::test::with_process;
 
 #[test]
 fn without_number_errors_badarg() {
@@ -24,3 +25,23 @@ fn with_float_rounds_to_nearest_integer() {
         Ok(())
     });
 }
+
+#[test]
+fn with_two_and_a_half_rounds_away_from_zero() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.float(2.5).unwrap()),
+            Ok(process.integer(3).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_negative_two_and_a_half_rounds_away_from_zero() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.float(-2.5).unwrap()),
+            Ok(process.integer(-3).unwrap())
+        );
+    });
+}
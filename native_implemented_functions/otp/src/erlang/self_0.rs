@@ -6,6 +6,7 @@ use liblumen_alloc::erts::term::prelude::Term;
 
 use native_implemented_function::native_implemented_function;
 
+/// The pid of the calling process.  Local pids are immediates, so nothing is allocated.
 #[native_implemented_function(self/0)]
 pub fn native(process: &Process) -> Term {
     process.pid_term()
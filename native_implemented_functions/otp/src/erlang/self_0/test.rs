@@ -1,5 +1,6 @@
 use crate::erlang::self_0::native;
-use crate::test::with_process;
+use crate::erlang::send_2;
+use crate::test::{self, with_process, with_process_arc};
 
 #[test]
 fn returns_process_pid() {
@@ -7,3 +8,40 @@ fn returns_process_pid() {
         assert_eq!(native(&process), process.pid_term());
     });
 }
+
+#[test]
+fn sent_to_other_process_and_back_is_own_pid() {
+    with_process_arc(|arc_process| {
+        let other_arc_process = test::process::child(&arc_process);
+        let pid = native(&arc_process);
+
+        assert_eq!(
+            send_2::native(&arc_process, other_arc_process.pid_term(), pid),
+            Ok(pid)
+        );
+
+        let received = other_arc_process
+            .mailbox
+            .lock()
+            .borrow_mut()
+            .receive(&other_arc_process)
+            .unwrap()
+            .unwrap();
+
+        // the other process replies to the pid with the pid itself
+        assert_eq!(
+            send_2::native(&other_arc_process, received, received),
+            Ok(received)
+        );
+
+        let received_back = arc_process
+            .mailbox
+            .lock()
+            .borrow_mut()
+            .receive(&arc_process)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(received_back, arc_process.pid_term());
+    });
+}
@@ -2,19 +2,24 @@ mod with_atom_destination;
 mod with_local_pid_destination;
 mod with_tuple_destination;
 
+use std::sync::Arc;
+
 use proptest::strategy::Just;
 use proptest::test_runner::{Config, TestRunner};
 use proptest::{prop_assert, prop_assert_eq};
 
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::{code, Process};
 use liblumen_alloc::erts::term::prelude::*;
 
+use lumen_rt_full::process::spawn::options::Options;
+use lumen_rt_full::scheduler::Scheduler;
+
 use crate::erlang;
 use crate::erlang::send_2::native;
 use crate::test;
 use crate::test::{
-    external_arc_node, has_heap_message, has_process_message, registered_name, strategy,
-    with_process, with_process_arc,
+    external_arc_node, has_heap_message, has_process_message, r#loop, registered_name, strategy,
+    with_deterministic_scheduler, with_process, with_process_arc,
 };
 
 #[test]
@@ -40,3 +45,56 @@ fn without_atom_pid_or_tuple_destination_errors_badarg() {
         },
     );
 }
+
+#[test]
+fn with_deterministic_scheduler_messages_from_yielding_senders_interleave_in_spawn_order() {
+    with_deterministic_scheduler(|scheduler, arc_process| {
+        let destination = arc_process.pid_term();
+
+        for tag in &["first", "second"] {
+            Scheduler::spawn_code(
+                &arc_process,
+                Options::default(),
+                r#loop::module(),
+                r#loop::function(),
+                &[destination, Atom::str_to_term(tag)],
+                send_and_yield_code,
+            )
+            .unwrap();
+        }
+
+        // only the senders are run, not `arc_process` or its init parent
+        assert_eq!(scheduler.run_round(), 2);
+        assert_eq!(scheduler.run_round(), 2);
+
+        let message_vec: Vec<Term> = arc_process
+            .mailbox
+            .lock()
+            .borrow()
+            .iter()
+            .map(|message| *message.data())
+            .collect();
+
+        assert_eq!(
+            message_vec,
+            vec![
+                Atom::str_to_term("first"),
+                Atom::str_to_term("second"),
+                Atom::str_to_term("first"),
+                Atom::str_to_term("second"),
+            ]
+        );
+    });
+}
+
+/// Sends its second argument to its first argument once per run
+fn send_and_yield_code(arc_process: &Arc<Process>) -> code::Result {
+    arc_process.reduce();
+
+    let destination = arc_process.stack_peek(1).unwrap();
+    let message = arc_process.stack_peek(2).unwrap();
+    native(arc_process, destination, message).unwrap();
+    erlang::yield_0::native(arc_process);
+
+    Process::call_code(arc_process)
+}
@@ -9,9 +9,11 @@ use proptest::strategy::Strategy;
 use proptest::test_runner::{Config, TestRunner};
 use proptest::{prop_assert, prop_assert_eq};
 
+use liblumen_alloc::erts::exception::RuntimeException;
+use liblumen_alloc::erts::process::code::Code;
 use liblumen_alloc::erts::process::{Process, Status};
 use liblumen_alloc::erts::term::prelude::*;
-use liblumen_alloc::{atom, exit};
+use liblumen_alloc::{atom, error, exit};
 
 use lumen_rt_core::registry::pid_to_process;
 
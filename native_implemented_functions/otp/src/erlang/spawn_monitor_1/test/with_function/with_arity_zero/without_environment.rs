@@ -2,6 +2,35 @@ use super::*;
 
 #[test]
 fn without_expected_exit_in_child_process_sends_exit_message_to_parent() {
+    exception_in_child_process_sends_down_message_to_parent(
+        |arc_process| {
+            arc_process.exception(exit!(atom!("not_normal"), anyhow!("Test").into()));
+
+            Ok(())
+        },
+        exit!(atom!("not_normal"), anyhow!("Test").into()),
+        atom!("not_normal"),
+    );
+}
+
+/// OTP wraps the reason of an error that exits a process as `{Reason, Stacktrace}`, but the
+/// runtime propagates the bare reason of every exception to both monitors and links, so the `DOWN`
+/// reason is the bare `test_error` until exits carry stacktraces.
+#[test]
+fn with_error_in_child_process_sends_down_message_with_error_reason_to_parent() {
+    exception_in_child_process_sends_down_message_to_parent(
+        |arc_process| {
+            arc_process.exception(error!(atom!("test_error"), anyhow!("Test").into()));
+
+            Ok(())
+        },
+        error!(atom!("test_error"), anyhow!("Test").into()),
+        atom!("test_error"),
+    );
+}
+
+#[test]
+fn with_expected_exit_in_child_process_sends_exit_message_to_parent() {
     TestRunner::new(Config::with_source_file(file!()))
         .run(
             &(
@@ -12,10 +41,7 @@ fn without_expected_exit_in_child_process_sends_exit_message_to_parent() {
                     let arc_process = test::process::init();
                     let arity = 0;
                     let code = |arc_process: &Arc<Process>| {
-                        arc_process.exception(exit!(
-                            Atom::str_to_term("not_normal"),
-                            anyhow!("Test").into()
-                        ));
+                        arc_process.return_from_call(0, Atom::str_to_term("ok"))?;
 
                         Ok(())
                     };
@@ -32,15 +58,9 @@ fn without_expected_exit_in_child_process_sends_exit_message_to_parent() {
 
                 prop_assert!(result.is_ok());
 
-                let returned = result.unwrap();
-
-                let result_boxed_tuple: Result<Boxed<Tuple>, _> = returned.try_into();
+                let result_boxed_tuple: Result<Boxed<Tuple>, _> = result.unwrap().try_into();
 
-                prop_assert!(
-                    result_boxed_tuple.is_ok(),
-                    "Returned ({:?}) is not a tuple",
-                    returned
-                );
+                prop_assert!(result_boxed_tuple.is_ok());
 
                 let boxed_tuple = result_boxed_tuple.unwrap();
 
@@ -59,10 +79,10 @@ fn without_expected_exit_in_child_process_sends_exit_message_to_parent() {
 
                 let scheduler = Scheduler::current();
 
-                prop_assert!(scheduler.run_once());
-                prop_assert!(scheduler.run_once());
+                prop_assert!(scheduler.run_through(&child_arc_process));
+                prop_assert!(scheduler.run_through(&child_arc_process));
 
-                let reason = Atom::str_to_term("not_normal");
+                let reason = Atom::str_to_term("normal");
 
                 match *child_arc_process.status.read() {
                     Status::Exiting(ref exception) => {
@@ -99,22 +119,20 @@ fn without_expected_exit_in_child_process_sends_exit_message_to_parent() {
         .unwrap();
 }
 
-#[test]
-fn with_expected_exit_in_child_process_sends_exit_message_to_parent() {
+fn exception_in_child_process_sends_down_message_to_parent(
+    code: Code,
+    exception: RuntimeException,
+    reason: Term,
+) {
     TestRunner::new(Config::with_source_file(file!()))
         .run(
             &(
                 strategy::module_function_arity::module(),
                 strategy::module_function_arity::function(),
             )
-                .prop_map(|(module, function)| {
+                .prop_map(move |(module, function)| {
                     let arc_process = test::process::init();
                     let arity = 0;
-                    let code = |arc_process: &Arc<Process>| {
-                        arc_process.return_from_call(0, Atom::str_to_term("ok"))?;
-
-                        Ok(())
-                    };
 
                     (
                         arc_process.clone(),
@@ -128,9 +146,15 @@ fn with_expected_exit_in_child_process_sends_exit_message_to_parent() {
 
                 prop_assert!(result.is_ok());
 
-                let result_boxed_tuple: Result<Boxed<Tuple>, _> = result.unwrap().try_into();
+                let returned = result.unwrap();
 
-                prop_assert!(result_boxed_tuple.is_ok());
+                let result_boxed_tuple: Result<Boxed<Tuple>, _> = returned.try_into();
+
+                prop_assert!(
+                    result_boxed_tuple.is_ok(),
+                    "Returned ({:?}) is not a tuple",
+                    returned
+                );
 
                 let boxed_tuple = result_boxed_tuple.unwrap();
 
@@ -149,14 +173,12 @@ fn with_expected_exit_in_child_process_sends_exit_message_to_parent() {
 
                 let scheduler = Scheduler::current();
 
-                prop_assert!(scheduler.run_through(&child_arc_process));
-                prop_assert!(scheduler.run_through(&child_arc_process));
-
-                let reason = Atom::str_to_term("normal");
+                prop_assert!(scheduler.run_once());
+                prop_assert!(scheduler.run_once());
 
                 match *child_arc_process.status.read() {
-                    Status::Exiting(ref exception) => {
-                        prop_assert_eq!(exception, &exit!(reason, anyhow!("Test").into()));
+                    Status::Exiting(ref child_exception) => {
+                        prop_assert_eq!(child_exception, &exception);
                     }
                     ref status => {
                         return Err(proptest::test_runner::TestCaseError::fail(format!(
@@ -168,7 +190,7 @@ fn with_expected_exit_in_child_process_sends_exit_message_to_parent() {
 
                 prop_assert!(!parent_arc_process.is_exiting());
 
-                let tag = Atom::str_to_term("DOWN");
+                let tag = atom!("DOWN");
 
                 prop_assert!(has_message(
                     &parent_arc_process,
@@ -176,7 +198,7 @@ fn with_expected_exit_in_child_process_sends_exit_message_to_parent() {
                         .tuple_from_slice(&[
                             tag,
                             monitor_reference,
-                            Atom::str_to_term("process"),
+                            atom!("process"),
                             child_pid_term,
                             reason
                         ])
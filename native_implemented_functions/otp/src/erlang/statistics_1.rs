@@ -0,0 +1,76 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{total_reductions, Process};
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use lumen_rt_core::time::monotonic;
+use lumen_rt_full::scheduler::Scheduled;
+
+/// Supports the `reductions`, `run_queue`, `runtime`, and `wall_clock` items.
+///
+/// `reductions`, `runtime`, and `wall_clock` return `{Total, SinceLastCall}`, where
+/// `SinceLastCall` is since the last call with the same item by any process.  `runtime` and
+/// `wall_clock` are in milliseconds.  CPU time is not measured separately from elapsed time, so
+/// `runtime` is the same as `wall_clock`.
+#[native_implemented_function(statistics/1)]
+pub fn native(process: &Process, item: Term) -> exception::Result<Term> {
+    let item_atom: Atom = term_try_into_atom!(item)?;
+
+    match item_atom.name() {
+        "reductions" => {
+            // the current run has not been added to the total yet
+            let total = total_reductions() + process.run_reductions() as u64;
+
+            total_and_since_last_call(process, total, &LAST_REDUCTIONS)
+        }
+        "run_queue" => {
+            let run_queues_len = process
+                .scheduler()
+                .map(|scheduler| scheduler.run_queues_len())
+                .unwrap_or(0);
+
+            process.integer(run_queues_len).map_err(From::from)
+        }
+        "runtime" => {
+            total_and_since_last_call(process, monotonic::time_in_milliseconds(), &LAST_RUNTIME)
+        }
+        "wall_clock" => {
+            total_and_since_last_call(process, monotonic::time_in_milliseconds(), &LAST_WALL_CLOCK)
+        }
+        name => Err(TryAtomFromTermError(name))
+            .context("supported items are reductions, run_queue, runtime, and wall_clock")
+            .map_err(From::from),
+    }
+}
+
+// Private
+
+static LAST_REDUCTIONS: AtomicU64 = AtomicU64::new(0);
+static LAST_RUNTIME: AtomicU64 = AtomicU64::new(0);
+static LAST_WALL_CLOCK: AtomicU64 = AtomicU64::new(0);
+
+fn total_and_since_last_call(
+    process: &Process,
+    total: u64,
+    last: &AtomicU64,
+) -> exception::Result<Term> {
+    // Other processes can update `last` concurrently, so a total from just before theirs can be
+    // behind `last`
+    let since_last_call = total.saturating_sub(last.swap(total, Ordering::SeqCst));
+
+    process
+        .tuple_from_slice(&[process.integer(total)?, process.integer(since_last_call)?])
+        .map_err(From::from)
+}
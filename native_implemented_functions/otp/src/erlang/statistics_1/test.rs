@@ -0,0 +1,92 @@
+use std::convert::TryInto;
+
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::statistics_1::native;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_atom_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+            )
+        },
+        |(arc_process, item)| {
+            prop_assert_is_not_atom!(native(&arc_process, item), item);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_supported_item_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            native(process, Atom::str_to_term("unsupported")),
+            "supported items are reductions, run_queue, runtime, and wall_clock"
+        );
+    });
+}
+
+#[test]
+fn with_reductions_since_last_call_includes_work_between_calls() {
+    with_process(|process| {
+        let item = Atom::str_to_term("reductions");
+        let (first_total, _) = total_and_since_last_call(process, item);
+
+        process.reduce_by(100);
+
+        let (second_total, second_since_last_call) = total_and_since_last_call(process, item);
+
+        assert!(first_total + 100 <= second_total);
+        assert!(0 < second_since_last_call);
+    });
+}
+
+#[test]
+fn with_run_queue_returns_non_negative_integer() {
+    with_process(|process| {
+        let run_queue = native(process, Atom::str_to_term("run_queue")).unwrap();
+        let run_queue_usize: Result<usize, _> = run_queue.try_into();
+
+        assert!(run_queue_usize.is_ok());
+    });
+}
+
+#[test]
+fn with_runtime_total_does_not_decrease() {
+    total_does_not_decrease("runtime");
+}
+
+#[test]
+fn with_wall_clock_total_does_not_decrease() {
+    total_does_not_decrease("wall_clock");
+}
+
+fn total_and_since_last_call(process: &Process, item: Term) -> (u64, u64) {
+    let tuple: Boxed<Tuple> = native(process, item).unwrap().try_into().unwrap();
+
+    assert_eq!(tuple.len(), 2);
+
+    (tuple[0].try_into().unwrap(), tuple[1].try_into().unwrap())
+}
+
+fn total_does_not_decrease(item: &str) {
+    with_process(|process| {
+        let item = Atom::str_to_term(item);
+        let (first_total, _) = total_and_since_last_call(process, item);
+
+        std::thread::sleep(std::time::Duration::from_millis(2));
+
+        let (second_total, _) = total_and_since_last_call(process, item);
+
+        assert!(first_total <= second_total);
+    });
+}
@@ -2,7 +2,7 @@ use std::num::FpCategory;
 
 use anyhow::*;
 
-use liblumen_alloc::erts::exception::InternalResult;
+use liblumen_alloc::erts::exception::{InternalException, InternalResult};
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
@@ -17,15 +17,11 @@ pub fn string_to_float(
     match value.parse::<f64>() {
         Ok(inner) => {
             match inner.classify() {
-                FpCategory::Normal | FpCategory::Subnormal =>
-                // unlike Rust, Erlang requires float strings to have a decimal point
-                {
-                    if (inner.fract() == 0.0) && !value.chars().any(|b| b == '.') {
-                        Err(anyhow!(
-                            "{} does not contain decimal point",
-                            context::string(name, quote, value)
-                        )
-                        .into())
+                FpCategory::Normal | FpCategory::Subnormal => {
+                    if (inner.fract() == 0.0) && !has_decimal_point_or_exponent(value) {
+                        Err(does_not_contain_decimal_point_or_exponent(
+                            name, quote, value,
+                        ))
                     } else {
                         process.float(inner).map_err(|error| error.into())
                     }
@@ -36,12 +32,10 @@ pub fn string_to_float(
                     Err(anyhow!("Erlang does not support infinities ({})", value).into())
                 }
                 FpCategory::Zero => {
-                    if !value.chars().any(|b| b == '.') {
-                        Err(anyhow!(
-                            "{} does not contain decimal point",
-                            context::string(name, quote, value)
-                        )
-                        .into())
+                    if !has_decimal_point_or_exponent(value) {
+                        Err(does_not_contain_decimal_point_or_exponent(
+                            name, quote, value,
+                        ))
                     } else {
                         // Erlang does not track the difference without +0 and -0.
                         let zero = inner.abs();
@@ -59,3 +53,22 @@ pub fn string_to_float(
             .map_err(From::from),
     }
 }
+
+// Private
+
+fn does_not_contain_decimal_point_or_exponent(
+    name: &'static str,
+    quote: char,
+    value: &str,
+) -> InternalException {
+    anyhow!(
+        "{} does not contain decimal point or exponent",
+        context::string(name, quote, value)
+    )
+    .into()
+}
+
+// Unlike Rust, Erlang does not allow bare integers to be parsed as floats
+fn has_decimal_point_or_exponent(value: &str) -> bool {
+    value.chars().any(|c| c == '.' || c == 'e' || c == 'E')
+}
@@ -69,3 +69,35 @@ where
         f(minuend, &process)
     })
 }
+
+#[test]
+fn at_small_integer_boundaries_promotes_to_big_integer_and_back() {
+    with_process(|process| {
+        let max = process.integer(SmallInteger::MAX_VALUE).unwrap();
+        let min = process.integer(SmallInteger::MIN_VALUE).unwrap();
+        let one = process.integer(1).unwrap();
+        let negative_one = process.integer(-1).unwrap();
+
+        let below_min = native(&process, min, one).unwrap();
+
+        assert!(below_min.is_boxed_bigint());
+        assert_eq!(
+            below_min,
+            process.integer(SmallInteger::MIN_VALUE - 1).unwrap()
+        );
+        assert_eq!(native(&process, below_min, negative_one), Ok(min));
+        assert!(native(&process, below_min, negative_one)
+            .unwrap()
+            .is_smallint());
+
+        let above_max = native(&process, max, negative_one).unwrap();
+
+        assert!(above_max.is_boxed_bigint());
+        assert_eq!(
+            above_max,
+            process.integer(SmallInteger::MAX_VALUE + 1).unwrap()
+        );
+        assert_eq!(native(&process, above_max, one), Ok(max));
+        assert!(native(&process, above_max, one).unwrap().is_smallint());
+    })
+}
@@ -0,0 +1,38 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use lumen_rt_core::registry::pid_to_process;
+
+/// Suspends the process `pid`, so that it is not run until `resume_process/1` is called on it as
+/// many times as it was suspended.
+///
+/// This is meant for debugging only.  `pid` can't be the calling process and must be alive.
+#[native_implemented_function(suspend_process/1)]
+pub fn native(process: &Process, pid: Term) -> exception::Result<Term> {
+    let pid_pid = term_try_into_local_pid!(pid)?;
+
+    if pid_pid == process.pid() {
+        Err(anyhow!("pid ({}) is the calling process", pid).into())
+    } else {
+        match pid_to_process(&pid_pid) {
+            Some(arc_process) if !arc_process.is_exiting() => {
+                arc_process.suspend();
+
+                Ok(true.into())
+            }
+            _ => Err(anyhow!("pid ({}) is not alive", pid).into()),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use anyhow::*;
+
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::process::Process;
+
+use lumen_rt_full::scheduler::Scheduler;
+
+use crate::erlang::resume_process_1;
+use crate::erlang::suspend_process_1::native;
+use crate::test::{self, strategy, with_process_arc};
+
+#[test]
+fn without_pid_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_pid(arc_process.clone()),
+            )
+        },
+        |(arc_process, pid)| {
+            prop_assert_is_not_local_pid!(native(&arc_process, pid), pid);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_self_errors_badarg() {
+    with_process_arc(|arc_process| {
+        let pid = arc_process.pid_term();
+
+        assert_badarg!(
+            native(&arc_process, pid),
+            format!("pid ({}) is the calling process", pid)
+        );
+    });
+}
+
+#[test]
+fn with_exiting_process_errors_badarg() {
+    with_process_arc(|arc_process| {
+        let other_arc_process = test::process::child(&arc_process);
+        other_arc_process.exit_normal(anyhow!("Test").into());
+        let pid = other_arc_process.pid_term();
+
+        assert_badarg!(
+            native(&arc_process, pid),
+            format!("pid ({}) is not alive", pid)
+        );
+    });
+}
+
+#[test]
+fn with_process_makes_no_progress_until_resumed() {
+    with_process_arc(|arc_process| {
+        let other_arc_process = test::process::child(&arc_process);
+        let scheduler = Scheduler::current();
+
+        assert!(scheduler.run_through(&other_arc_process));
+
+        let pid = other_arc_process.pid_term();
+
+        assert_eq!(native(&arc_process, pid), Ok(true.into()));
+
+        assert_no_progress(&scheduler, &other_arc_process);
+
+        assert_eq!(resume_process_1::native(pid), Ok(true.into()));
+
+        assert!(scheduler.run_through(&other_arc_process));
+    });
+}
+
+#[test]
+fn with_nested_suspends_makes_no_progress_until_every_suspend_is_resumed() {
+    with_process_arc(|arc_process| {
+        let other_arc_process = test::process::child(&arc_process);
+        let scheduler = Scheduler::current();
+        let pid = other_arc_process.pid_term();
+
+        assert_eq!(native(&arc_process, pid), Ok(true.into()));
+        assert_eq!(native(&arc_process, pid), Ok(true.into()));
+
+        assert_eq!(resume_process_1::native(pid), Ok(true.into()));
+
+        assert_no_progress(&scheduler, &other_arc_process);
+
+        assert_eq!(resume_process_1::native(pid), Ok(true.into()));
+
+        assert!(scheduler.run_through(&other_arc_process));
+    });
+}
+
+fn assert_no_progress(scheduler: &Scheduler, arc_process: &Arc<Process>) {
+    let total_reductions = arc_process.total_reductions.load(Ordering::SeqCst);
+
+    for _ in 0..4 {
+        let _ = scheduler.run_once();
+    }
+
+    assert_eq!(
+        arc_process.total_reductions.load(Ordering::SeqCst),
+        total_reductions
+    );
+}
@@ -0,0 +1,59 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::mem;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use lumen_rt_core::registry;
+use lumen_rt_full::scheduler::Scheduler;
+
+/// The OTP release whose behavior the runtime follows, which code uses for feature detection
+pub const OTP_RELEASE: &str = "22";
+
+/// Supports the `otp_release`, `process_count`, `schedulers`, `system_version`, and `wordsize`
+/// items.
+#[native_implemented_function(system_info/1)]
+pub fn native(process: &Process, item: Term) -> exception::Result<Term> {
+    let item_atom: Atom = term_try_into_atom!(item)?;
+
+    let term = match item_atom.name() {
+        "otp_release" => process.charlist_from_str(OTP_RELEASE)?,
+        "process_count" => process.integer(registry::processes().len())?,
+        "schedulers" => process.integer(Scheduler::count())?,
+        "system_version" => process.charlist_from_str(&system_version())?,
+        "wordsize" => process.integer(mem::size_of::<usize>())?,
+        name => {
+            return Err(TryAtomFromTermError(name))
+                .context(
+                    "supported items are otp_release, process_count, schedulers, \
+                     system_version, and wordsize",
+                )
+                .map_err(From::from)
+        }
+    };
+
+    Ok(term)
+}
+
+// Private
+
+fn system_version() -> String {
+    format!(
+        "Lumen/OTP {} [lumen-{}] [{}-bit] [smp:{}]\n",
+        OTP_RELEASE,
+        env!("CARGO_PKG_VERSION"),
+        mem::size_of::<usize>() * 8,
+        Scheduler::count()
+    )
+}
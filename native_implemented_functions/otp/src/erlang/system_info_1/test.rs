@@ -0,0 +1,92 @@
+use std::convert::TryInto;
+
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::erlang::system_info_1::{native, OTP_RELEASE};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_atom_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+            )
+        },
+        |(arc_process, item)| {
+            prop_assert_is_not_atom!(native(&arc_process, item), item);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_supported_item_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            native(process, Atom::str_to_term("unsupported")),
+            "supported items are otp_release, process_count, schedulers, system_version, and \
+             wordsize"
+        );
+    });
+}
+
+#[test]
+fn with_otp_release_returns_charlist() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, Atom::str_to_term("otp_release")),
+            Ok(process.charlist_from_str(OTP_RELEASE).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_process_count_is_at_least_one() {
+    with_process(|process| {
+        let process_count: usize = native(process, Atom::str_to_term("process_count"))
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert!(1 <= process_count);
+    });
+}
+
+#[test]
+fn with_schedulers_is_at_least_one() {
+    with_process(|process| {
+        let schedulers: usize = native(process, Atom::str_to_term("schedulers"))
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert!(1 <= schedulers);
+    });
+}
+
+#[test]
+fn with_system_version_returns_charlist_ending_in_newline() {
+    with_process(|process| {
+        let system_version = native(process, Atom::str_to_term("system_version")).unwrap();
+        let system_version_string = list_to_string(system_version).unwrap();
+
+        assert!(system_version_string.starts_with(&format!("Lumen/OTP {}", OTP_RELEASE)));
+        assert!(system_version_string.ends_with('\n'));
+    });
+}
+
+#[test]
+fn with_wordsize_returns_size_of_usize() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, Atom::str_to_term("wordsize")),
+            Ok(process.integer(std::mem::size_of::<usize>()).unwrap())
+        );
+    });
+}
@@ -1,3 +1,4 @@
+mod io_buf;
 mod options;
 
 use std::collections::VecDeque;
@@ -9,7 +10,7 @@ use num_bigint::{BigInt, Sign};
 
 use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::Process;
-use liblumen_alloc::erts::term::closure::{Creator, Definition};
+use liblumen_alloc::erts::term::closure::{Creator, Definition, Index, OldUnique, Unique};
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::erts::Node;
 
@@ -17,16 +18,44 @@ use lumen_rt_core::distribution::nodes::node::{self, arc_node};
 
 use lumen_rt_full::distribution::external_term_format::{version, Tag};
 
-use options::*;
+use io_buf::{Counter, IoBuf, SliceBuf};
+pub use options::Options;
 
+/// The number of bytes that `term_to_binary` returns for `term`.  The bytes are only counted, so
+/// nothing is allocated for them.
+///
+/// Compression is not implemented yet, so the size is the same with or without `compressed`,
+/// which is an upper bound for what the size will be once compression is implemented.
+pub fn external_size(process: &Process, term: Term, options: &Options) -> usize {
+    count(process, term, options).len
+}
+
+/// The binary is sized by counting the bytes first and then the term is written directly into it,
+/// so large terms aren't encoded into an intermediate buffer that is then copied.
 pub fn term_to_binary(process: &Process, term: Term, options: Options) -> exception::Result<Term> {
-    let byte_vec = term_to_byte_vec(process, &options, term);
+    let counter = count(process, term, &options);
+    let len = counter.len;
 
     process
-        .binary_from_bytes(&byte_vec)
+        .binary_from_fn(len, |bytes| {
+            let mut slice_buf = SliceBuf::new(bytes, &counter.sizes);
+            append_versioned_term(&mut slice_buf, process, &options, term);
+
+            assert_eq!(slice_buf.len, len);
+        })
         .map_err(|alloc| alloc.into())
 }
 
+/// Encodes `term` into a growable buffer, like `term_to_binary` did before writing in place, so
+/// the two can be compared.
+#[cfg(test)]
+pub fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8> {
+    let mut byte_vec = Vec::new();
+    append_versioned_term(&mut byte_vec, process, options, term);
+
+    byte_vec
+}
+
 // Private
 
 // TODO implement creation rotation
@@ -49,8 +78,28 @@ const STRING_EXT_MAX_LEN: usize = std::u16::MAX as usize;
 const SMALL_BIG_EXT_MAX_LEN: usize = std::u8::MAX as usize;
 const SMALL_ATOM_UTF8_EXT_MAX_LEN: usize = std::u8::MAX as usize;
 
-fn append_big_int(byte_vec: &mut Vec<u8>, big_int: &BigInt) {
-    let (sign, mut little_endian_bytes) = big_int.to_bytes_le();
+fn append_atom<W: IoBuf>(buf: &mut W, atom: Atom) {
+    let bytes = atom.name().as_bytes();
+    let len_usize = bytes.len();
+
+    if bytes.iter().all(|byte| byte.is_ascii()) {
+        push_tag(buf, Tag::Atom);
+        append_usize_as_u16(buf, len_usize);
+    } else if len_usize <= SMALL_ATOM_UTF8_EXT_MAX_LEN {
+        push_tag(buf, Tag::SmallAtomUTF8);
+
+        let len_u8 = len_usize as u8;
+        buf.push(len_u8);
+    } else {
+        push_tag(buf, Tag::AtomUTF8);
+        append_usize_as_u16(buf, len_usize);
+    }
+
+    buf.extend_from_slice(bytes);
+}
+
+fn append_big_int<W: IoBuf>(buf: &mut W, big_int: &BigInt) {
+    let (sign, little_endian_bytes) = big_int.to_bytes_le();
 
     let sign_byte: u8 = match sign {
         Sign::Minus => 1,
@@ -60,31 +109,31 @@ fn append_big_int(byte_vec: &mut Vec<u8>, big_int: &BigInt) {
     let len_usize = little_endian_bytes.len();
 
     if len_usize <= SMALL_BIG_EXT_MAX_LEN {
-        push_tag(byte_vec, Tag::SmallBig);
-        byte_vec.push(len_usize as u8);
+        push_tag(buf, Tag::SmallBig);
+        buf.push(len_usize as u8);
     } else {
-        push_tag(byte_vec, Tag::LargeBig);
-        append_usize_as_u32(byte_vec, len_usize);
+        push_tag(buf, Tag::LargeBig);
+        append_usize_as_u32(buf, len_usize);
     }
 
-    byte_vec.push(sign_byte);
-    byte_vec.append(&mut little_endian_bytes);
+    buf.push(sign_byte);
+    buf.extend_from_slice(&little_endian_bytes);
 }
 
-fn append_binary_bytes(byte_vec: &mut Vec<u8>, binary_bytes: &[u8]) {
-    byte_vec.extend_from_slice(binary_bytes)
+fn append_binary_bytes<W: IoBuf>(buf: &mut W, binary_bytes: &[u8]) {
+    buf.extend_from_slice(binary_bytes)
 }
 
-fn append_creator(byte_vec: &mut Vec<u8>, creator: &Creator) {
+fn append_creator<W: IoBuf>(buf: &mut W, creator: &Creator) {
     match creator {
         Creator::Local(pid) => append_pid(
-            byte_vec,
+            buf,
             node::arc_node(),
             pid.number() as u32,
             pid.serial() as u32,
         ),
         Creator::External(external_pid) => append_pid(
-            byte_vec,
+            buf,
             external_pid.arc_node(),
             external_pid.number() as u32,
             external_pid.serial() as u32,
@@ -92,7 +141,46 @@ fn append_creator(byte_vec: &mut Vec<u8>, creator: &Creator) {
     }
 }
 
-fn append_pid(byte_vec: &mut Vec<u8>, arc_node: Arc<Node>, id: u32, serial: u32) {
+/// The part of `NEW_FUN_EXT` after `Size`, which is the size of this part plus the size of `Size`
+/// itself.
+fn append_new_function_sized<W: IoBuf>(
+    buf: &mut W,
+    process: &Process,
+    options: &Options,
+    closure: &Closure,
+    index: Index,
+    old_unique: OldUnique,
+    unique: &Unique,
+) {
+    let default_creator = Creator::Local(Pid::default());
+
+    let module_function_arity = closure.module_function_arity();
+    buf.push(module_function_arity.arity);
+
+    buf.extend_from_slice(unique);
+    buf.extend_from_slice(&index.to_be_bytes());
+
+    let env_len_u32: u32 = closure.env_len().try_into().unwrap();
+    buf.extend_from_slice(&env_len_u32.to_be_bytes());
+
+    append_atom(buf, module_function_arity.module);
+
+    // > [index] encoded using SMALL_INTEGER_EXT or INTEGER_EXT.
+    try_append_isize_as_small_integer_or_integer(buf, index.try_into().unwrap()).unwrap();
+
+    // > An integer encoded using SMALL_INTEGER_EXT or INTEGER_EXT
+    // But this means OldUniq can't be the same a Uniq with a different
+    // encoding,
+    try_append_isize_as_small_integer_or_integer(buf, old_unique.try_into().unwrap()).unwrap();
+
+    append_creator(buf, &default_creator);
+
+    for term in closure.env_slice() {
+        append_versioned_term(buf, process, options, *term);
+    }
+}
+
+fn append_pid<W: IoBuf>(buf: &mut W, arc_node: Arc<Node>, id: u32, serial: u32) {
     let creation = arc_node.creation();
 
     let tag = if creation <= (std::u8::MAX as u32) {
@@ -101,98 +189,52 @@ fn append_pid(byte_vec: &mut Vec<u8>, arc_node: Arc<Node>, id: u32, serial: u32)
         Tag::NewPID
     };
 
-    push_tag(byte_vec, tag);
+    push_tag(buf, tag);
 
-    byte_vec.extend_from_slice(&atom_to_byte_vec(arc_node.name()));
-    byte_vec.extend_from_slice(&id.to_be_bytes());
-    byte_vec.extend_from_slice(&serial.to_be_bytes());
+    append_atom(buf, arc_node.name());
+    buf.extend_from_slice(&id.to_be_bytes());
+    buf.extend_from_slice(&serial.to_be_bytes());
 
     if creation <= (std::u8::MAX as u32) {
-        byte_vec.push(creation as u8);
+        buf.push(creation as u8);
     } else {
-        byte_vec.extend_from_slice(&creation.to_be_bytes());
+        buf.extend_from_slice(&creation.to_be_bytes());
     };
 }
 
-fn append_usize_as_u16(byte_vec: &mut Vec<u8>, len_usize: usize) {
+fn append_usize_as_u16<W: IoBuf>(buf: &mut W, len_usize: usize) {
     assert!(len_usize <= (std::u16::MAX as usize));
     let len_u16 = len_usize as u16;
-    byte_vec.extend_from_slice(&len_u16.to_be_bytes());
+    buf.extend_from_slice(&len_u16.to_be_bytes());
 }
 
-fn append_usize_as_u32(byte_vec: &mut Vec<u8>, len_usize: usize) {
+fn append_usize_as_u32<W: IoBuf>(buf: &mut W, len_usize: usize) {
     assert!(len_usize <= (std::u32::MAX as usize));
     let len_u32 = len_usize as u32;
-    byte_vec.extend_from_slice(&len_u32.to_be_bytes());
-}
-
-fn atom_to_byte_vec(atom: Atom) -> Vec<u8> {
-    let bytes = atom.name().as_bytes();
-    let len_usize = bytes.len();
-    let mut byte_vec: Vec<u8> = Vec::new();
-
-    if bytes.iter().all(|byte| byte.is_ascii()) {
-        push_tag(&mut byte_vec, Tag::Atom);
-        append_usize_as_u16(&mut byte_vec, len_usize);
-    } else if len_usize <= SMALL_ATOM_UTF8_EXT_MAX_LEN {
-        push_tag(&mut byte_vec, Tag::SmallAtomUTF8);
-
-        let len_u8 = len_usize as u8;
-        byte_vec.push(len_u8);
-    } else {
-        push_tag(&mut byte_vec, Tag::AtomUTF8);
-        append_usize_as_u16(&mut byte_vec, len_usize);
-    }
-
-    byte_vec.extend_from_slice(bytes);
-
-    byte_vec
-}
-
-// Tail is the final tail  of the list; it is NIL_EXT for a proper list, but can be any type if the
-// list is improper (for example, [a|b]).
-// -- http://erlang.org/doc/apps/erts/erl_ext_dist.html#list_ext
-fn cons_to_element_vec_tail(cons: &Cons) -> (Vec<Term>, Term) {
-    let mut element_vec: Vec<Term> = Vec::new();
-    let mut tail = Term::NIL;
-
-    for result in cons.into_iter() {
-        match result {
-            Ok(element) => element_vec.push(element),
-            Err(ImproperList {
-                tail: improper_list_tail,
-            }) => tail = improper_list_tail,
-        }
-    }
-
-    (element_vec, tail)
-}
-
-fn push_tag(byte_vec: &mut Vec<u8>, tag: Tag) {
-    byte_vec.push(tag.into());
+    buf.extend_from_slice(&len_u32.to_be_bytes());
 }
 
-fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8> {
+fn append_versioned_term<W: IoBuf>(buf: &mut W, process: &Process, options: &Options, term: Term) {
     let mut stack = VecDeque::new();
     stack.push_front(term);
 
-    let mut byte_vec: Vec<u8> = vec![version::NUMBER];
+    buf.push(version::NUMBER);
 
     while let Some(front_term) = stack.pop_front() {
         match front_term.decode().unwrap() {
             TypedTerm::Atom(atom) => {
-                byte_vec.extend_from_slice(&atom_to_byte_vec(atom));
+                append_atom(buf, atom);
             }
             TypedTerm::List(cons) => {
-                match try_cons_to_string_ext_byte_vec(&cons) {
-                    Ok(mut string_ext_byte_vec) => byte_vec.append(&mut string_ext_byte_vec),
+                match try_append_cons_as_string_ext(buf, &cons) {
+                    Ok(()) => (),
                     Err(_) => {
-                        push_tag(&mut byte_vec, Tag::List);
+                        push_tag(buf, Tag::List);
 
                         let (element_vec, tail) = cons_to_element_vec_tail(&cons);
 
                         let len_usize = element_vec.len();
-                        append_usize_as_u32(&mut byte_vec, len_usize);
+                        append_usize_as_u32(buf, len_usize);
 
                         stack.push_front(tail);
 
@@ -203,23 +245,15 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                 };
             }
             TypedTerm::Nil => {
-                push_tag(&mut byte_vec, Tag::Nil);
+                push_tag(buf, Tag::Nil);
             }
             TypedTerm::Pid(pid) => {
-                append_pid(
-                    &mut byte_vec,
-                    arc_node(),
-                    pid.number() as u32,
-                    pid.serial() as u32,
-                );
+                append_pid(buf, arc_node(), pid.number() as u32, pid.serial() as u32);
             }
             TypedTerm::SmallInteger(small_integer) => {
                 let small_integer_isize: isize = small_integer.into();
 
-                match try_append_isize_as_small_integer_or_integer(
-                    &mut byte_vec,
-                    small_integer_isize,
-                ) {
+                match try_append_isize_as_small_integer_or_integer(buf, small_integer_isize) {
                     Ok(()) => (),
                     Err(_) => {
                         let small_integer_i64 = small_integer_isize as i64;
@@ -227,32 +261,29 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                         // jumping to 8 to hold i64.
                         let small_integer_big_int: BigInt = small_integer_i64.into();
 
-                        append_big_int(&mut byte_vec, &small_integer_big_int);
+                        append_big_int(buf, &small_integer_big_int);
                     }
                 }
             }
             TypedTerm::BigInteger(big_integer) => {
                 let big_int: &BigInt = big_integer.as_ref().into();
 
-                append_big_int(&mut byte_vec, big_int);
+                append_big_int(buf, big_int);
             }
             TypedTerm::Float(float) => {
                 let float_f64: f64 = float.into();
 
-                push_tag(&mut byte_vec, Tag::NewFloat);
-                byte_vec.extend_from_slice(&float_f64.to_be_bytes());
+                push_tag(buf, Tag::NewFloat);
+                buf.extend_from_slice(&float_f64.to_be_bytes());
             }
             TypedTerm::Closure(closure) => {
                 match closure.definition() {
                     Definition::Export { function } => {
-                        push_tag(&mut byte_vec, Tag::Export);
-                        byte_vec.append(&mut atom_to_byte_vec(closure.module()));
-                        byte_vec.append(&mut atom_to_byte_vec(*function));
-                        try_append_isize_as_small_integer_or_integer(
-                            &mut byte_vec,
-                            closure.arity() as isize,
-                        )
-                        .unwrap();
+                        push_tag(buf, Tag::Export);
+                        append_atom(buf, closure.module());
+                        append_atom(buf, *function);
+                        try_append_isize_as_small_integer_or_integer(buf, closure.arity() as isize)
+                            .unwrap();
                     }
                     Definition::Anonymous {
                         index,
@@ -260,84 +291,53 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                         unique,
                         //creator,
                     } => {
-                        let default_creator = Creator::Local(Pid::default());
-                        let mut sized_byte_vec: Vec<u8> = Vec::new();
-
-                        let module_function_arity = closure.module_function_arity();
-                        sized_byte_vec.push(module_function_arity.arity);
-
-                        sized_byte_vec.extend_from_slice(unique);
-                        sized_byte_vec.extend_from_slice(&index.to_be_bytes());
-
-                        let env_len_u32: u32 = closure.env_len().try_into().unwrap();
-                        sized_byte_vec.extend_from_slice(&env_len_u32.to_be_bytes());
-
-                        sized_byte_vec.append(&mut atom_to_byte_vec(module_function_arity.module));
-
-                        // > [index] encoded using SMALL_INTEGER_EXT or INTEGER_EXT.
-                        try_append_isize_as_small_integer_or_integer(
-                            &mut sized_byte_vec,
-                            (*index).try_into().unwrap(),
-                        )
-                        .unwrap();
-
-                        // > An integer encoded using SMALL_INTEGER_EXT or INTEGER_EXT
-                        // But this means OldUniq can't be the same a Uniq with a different
-                        // encoding,
-                        try_append_isize_as_small_integer_or_integer(
-                            &mut sized_byte_vec,
-                            (*old_unique).try_into().unwrap(),
-                        )
-                        .unwrap();
-
-                        append_creator(&mut sized_byte_vec, &default_creator);
-
-                        for term in closure.env_slice() {
-                            sized_byte_vec.append(&mut term_to_byte_vec(process, options, *term));
-                        }
-
-                        const SIZE_BYTE_LEN: usize = mem::size_of::<u32>();
-                        let size = (SIZE_BYTE_LEN + sized_byte_vec.len()) as u32;
-
-                        push_tag(&mut byte_vec, Tag::NewFunction);
-                        byte_vec.extend_from_slice(&size.to_be_bytes());
-                        byte_vec.append(&mut sized_byte_vec);
+                        push_tag(buf, Tag::NewFunction);
+                        buf.append_sized(|buf| {
+                            append_new_function_sized(
+                                buf,
+                                process,
+                                options,
+                                &closure,
+                                *index,
+                                *old_unique,
+                                unique,
+                            )
+                        });
                     }
                 }
             }
             TypedTerm::ExternalPid(external_pid) => {
                 append_pid(
-                    &mut byte_vec,
+                    buf,
                     external_pid.arc_node(),
                     external_pid.number() as u32,
                     external_pid.serial() as u32,
                 );
             }
             TypedTerm::Map(map) => {
-                push_tag(&mut byte_vec, Tag::Map);
+                push_tag(buf, Tag::Map);
 
                 let len_usize = map.len();
-                append_usize_as_u32(&mut byte_vec, len_usize);
+                append_usize_as_u32(buf, len_usize);
 
-                for (key, value) in map.iter() {
-                    stack.push_front(*value);
-                    stack.push_front(*key);
+                // Reversed because `push_front` means the last entry pushed is encoded first
+                for (key, value) in map.iter_sorted().rev() {
+                    stack.push_front(value);
+                    stack.push_front(key);
                 }
             }
             TypedTerm::HeapBinary(heap_bin) => {
-                push_tag(&mut byte_vec, Tag::Binary);
+                push_tag(buf, Tag::Binary);
 
                 let len_usize = heap_bin.full_byte_len();
-                append_usize_as_u32(&mut byte_vec, len_usize);
+                append_usize_as_u32(buf, len_usize);
 
-                byte_vec.extend_from_slice(heap_bin.as_bytes());
+                buf.extend_from_slice(heap_bin.as_bytes());
             }
             TypedTerm::MatchContext(match_context) => {
                 if match_context.is_binary() {
                     if match_context.is_aligned() {
-                        append_binary_bytes(&mut byte_vec, unsafe {
-                            match_context.as_bytes_unchecked()
-                        });
+                        append_binary_bytes(buf, unsafe { match_context.as_bytes_unchecked() });
                     } else {
                         unimplemented!()
                     }
@@ -346,58 +346,58 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                 }
             }
             TypedTerm::ProcBin(proc_bin) => {
-                push_tag(&mut byte_vec, Tag::Binary);
+                push_tag(buf, Tag::Binary);
 
                 let len_usize = proc_bin.full_byte_len();
-                append_usize_as_u32(&mut byte_vec, len_usize);
+                append_usize_as_u32(buf, len_usize);
 
-                byte_vec.extend_from_slice(proc_bin.as_bytes());
+                buf.extend_from_slice(proc_bin.as_bytes());
             }
             TypedTerm::Reference(reference) => {
                 let scheduler_id_u32: u32 = reference.scheduler_id().into();
                 let number: u64 = reference.number().into();
 
-                push_tag(&mut byte_vec, Tag::NewerReference);
+                push_tag(buf, Tag::NewerReference);
 
                 let u32_byte_len = mem::size_of::<u32>();
                 let len_usize = (mem::size_of::<u32>() + mem::size_of::<u64>()) / u32_byte_len;
                 // > Len - A 16-bit big endian unsigned integer not larger than 3.
                 assert!(len_usize <= NEWER_REFERENCE_EXT_MAX_U32_LEN);
-                append_usize_as_u16(&mut byte_vec, len_usize);
+                append_usize_as_u16(buf, len_usize);
 
-                byte_vec.extend_from_slice(&atom_to_byte_vec(node::atom()));
+                append_atom(buf, node::atom());
 
                 let creation_u32 = CREATION as u32;
-                byte_vec.extend_from_slice(&creation_u32.to_be_bytes());
+                buf.extend_from_slice(&creation_u32.to_be_bytes());
 
-                byte_vec.extend_from_slice(&scheduler_id_u32.to_be_bytes());
-                byte_vec.extend_from_slice(&number.to_be_bytes());
+                buf.extend_from_slice(&scheduler_id_u32.to_be_bytes());
+                buf.extend_from_slice(&number.to_be_bytes());
             }
             TypedTerm::SubBinary(subbinary) => {
                 if subbinary.is_binary() {
-                    push_tag(&mut byte_vec, Tag::Binary);
+                    push_tag(buf, Tag::Binary);
 
                     let len_usize = subbinary.full_byte_len();
-                    append_usize_as_u32(&mut byte_vec, len_usize);
+                    append_usize_as_u32(buf, len_usize);
 
                     if subbinary.is_aligned() {
-                        byte_vec.extend_from_slice(unsafe { subbinary.as_bytes_unchecked() });
+                        buf.extend_from_slice(unsafe { subbinary.as_bytes_unchecked() });
                     } else {
-                        byte_vec.extend(subbinary.full_byte_iter());
+                        buf.extend(subbinary.full_byte_iter());
                     }
                 } else {
-                    push_tag(&mut byte_vec, Tag::BitBinary);
+                    push_tag(buf, Tag::BitBinary);
 
                     let len_usize = subbinary.total_byte_len();
-                    append_usize_as_u32(&mut byte_vec, len_usize);
+                    append_usize_as_u32(buf, len_usize);
 
                     let bits_u8 = subbinary.partial_byte_bit_len();
-                    byte_vec.push(bits_u8);
+                    buf.push(bits_u8);
 
                     if subbinary.is_aligned() {
-                        byte_vec.extend_from_slice(unsafe { subbinary.as_bytes_unchecked() });
+                        buf.extend_from_slice(unsafe { subbinary.as_bytes_unchecked() });
                     } else {
-                        byte_vec.extend(subbinary.full_byte_iter());
+                        buf.extend(subbinary.full_byte_iter());
                     }
 
                     let mut last_byte: u8 = 0;
@@ -406,18 +406,18 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
                         last_byte |= bit << (7 - index);
                     }
 
-                    byte_vec.push(last_byte);
+                    buf.push(last_byte);
                 }
             }
             TypedTerm::Tuple(tuple) => {
                 let len_usize = tuple.len();
 
                 if len_usize <= SMALL_TUPLE_EXT_MAX_LEN {
-                    push_tag(&mut byte_vec, Tag::SmallTuple);
-                    byte_vec.push(len_usize as u8);
+                    push_tag(buf, Tag::SmallTuple);
+                    buf.push(len_usize as u8);
                 } else {
-                    push_tag(&mut byte_vec, Tag::LargeTuple);
-                    append_usize_as_u32(&mut byte_vec, len_usize);
+                    push_tag(buf, Tag::LargeTuple);
+                    append_usize_as_u32(buf, len_usize);
                 }
 
                 for element in tuple.iter().rev() {
@@ -427,26 +427,54 @@ fn term_to_byte_vec(process: &Process, options: &Options, term: Term) -> Vec<u8>
             _ => unimplemented!("term_to_binary({:?})", front_term),
         };
     }
+}
 
-    byte_vec
+// Tail is the final tail  of the list; it is NIL_EXT for a proper list, but can be any type if the
+// list is improper (for example, [a|b]).
+// -- http://erlang.org/doc/apps/erts/erl_ext_dist.html#list_ext
+fn cons_to_element_vec_tail(cons: &Cons) -> (Vec<Term>, Term) {
+    let mut element_vec: Vec<Term> = Vec::new();
+    let mut tail = Term::NIL;
+
+    for result in cons.into_iter() {
+        match result {
+            Ok(element) => element_vec.push(element),
+            Err(ImproperList {
+                tail: improper_list_tail,
+            }) => tail = improper_list_tail,
+        }
+    }
+
+    (element_vec, tail)
 }
 
-fn try_append_isize_as_small_integer_or_integer(
-    mut byte_vec: &mut Vec<u8>,
+fn count(process: &Process, term: Term, options: &Options) -> Counter {
+    let mut counter = Counter::default();
+    append_versioned_term(&mut counter, process, options, term);
+
+    counter
+}
+
+fn push_tag<W: IoBuf>(buf: &mut W, tag: Tag) {
+    buf.push(tag.into());
+}
+
+fn try_append_isize_as_small_integer_or_integer<W: IoBuf>(
+    buf: &mut W,
     integer: isize,
 ) -> Result<(), TypeError> {
     if SMALL_INTEGER_EXT_MIN <= integer && integer <= SMALL_INTEGER_EXT_MAX {
         let integer_u8: u8 = integer as u8;
 
-        push_tag(&mut byte_vec, Tag::SmallInteger);
-        byte_vec.extend_from_slice(&integer_u8.to_be_bytes());
+        push_tag(buf, Tag::SmallInteger);
+        buf.extend_from_slice(&integer_u8.to_be_bytes());
 
         Ok(())
     } else if INTEGER_EXT_MIN <= integer && integer <= INTEGER_EXT_MAX {
         let small_integer_i32: i32 = integer as i32;
 
-        push_tag(&mut byte_vec, Tag::Integer);
-        byte_vec.extend_from_slice(&small_integer_i32.to_be_bytes());
+        push_tag(buf, Tag::Integer);
+        buf.extend_from_slice(&small_integer_i32.to_be_bytes());
 
         Ok(())
     } else {
@@ -454,8 +482,10 @@ fn try_append_isize_as_small_integer_or_integer(
     }
 }
 
-fn try_cons_to_string_ext_byte_vec(cons: &Cons) -> Result<Vec<u8>, TypeError> {
-    let mut character_byte_vec: Vec<u8> = Vec::new();
+/// Nothing is written if `cons` can't be a `STRING_EXT`, so that it can be written as a `LIST_EXT`
+/// instead.
+fn try_append_cons_as_string_ext<W: IoBuf>(buf: &mut W, cons: &Cons) -> Result<(), TypeError> {
+    let mut len_usize = 0;
 
     // STRING_EXT is used (https://github.com/erlang/otp/blob/e6a69b021bc2aee6aca42bd72583a96d06f4ba9d/erts/emulator/beam/external.c#L2893)
     // only after checking `is_external_string` (https://github.com/erlang/otp/blob/e6a69b021bc2aee6aca42bd72583a96d06f4ba9d/erts/emulator/beam/external.c#L2892).
@@ -465,8 +495,8 @@ fn try_cons_to_string_ext_byte_vec(cons: &Cons) -> Result<Vec<u8>, TypeError> {
         if index < STRING_EXT_MAX_LEN {
             match result {
                 Ok(element) => {
-                    let character_byte: u8 = element.try_into().map_err(|_| TypeError)?;
-                    character_byte_vec.push(character_byte);
+                    let _: u8 = element.try_into().map_err(|_| TypeError)?;
+                    len_usize += 1;
                 }
                 Err(_) => return Err(TypeError),
             }
@@ -475,12 +505,14 @@ fn try_cons_to_string_ext_byte_vec(cons: &Cons) -> Result<Vec<u8>, TypeError> {
         }
     }
 
-    let mut byte_vec = vec![Tag::String.into()];
+    push_tag(buf, Tag::String);
+    append_usize_as_u16(buf, len_usize);
 
-    let len_usize = character_byte_vec.len();
-    append_usize_as_u16(&mut byte_vec, len_usize);
-
-    byte_vec.extend_from_slice(&character_byte_vec);
+    // checked above, so the elements are all proper bytes
+    for result in cons.into_iter() {
+        let character_byte: u8 = result.unwrap().try_into().unwrap();
+        buf.push(character_byte);
+    }
 
-    Ok(byte_vec)
+    Ok(())
 }
@@ -0,0 +1,125 @@
+use std::mem;
+
+/// Where the external term format is written, so that the same encoder can count the bytes
+/// (`Counter`), append to a growable buffer (`Vec<u8>`), or write in place into a binary that was
+/// sized with the count (`SliceBuf`).
+pub trait IoBuf {
+    fn push(&mut self, byte: u8);
+
+    fn extend_from_slice(&mut self, bytes: &[u8]);
+
+    /// Writes a 32-bit big endian `Size`, like that of `NEW_FUN_EXT`, and then the part that
+    /// `append` writes.  `Size` includes its own bytes.
+    ///
+    /// How the size is found depends on the buffer, but no buffer writes the part more than once,
+    /// so that nesting doesn't multiply the work.
+    fn append_sized<F: FnOnce(&mut Self)>(&mut self, append: F);
+
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for byte in iter {
+            self.push(byte);
+        }
+    }
+}
+
+/// Only counts the bytes written.  The only thing allocated is `sizes`, which is empty unless
+/// there are sized parts.
+#[derive(Default)]
+pub struct Counter {
+    pub len: usize,
+    /// The `Size` of each sized part in the order they start, so that a `SliceBuf` can write them
+    /// without counting the parts again
+    pub sizes: Vec<u32>,
+}
+
+impl IoBuf for Counter {
+    fn push(&mut self, _byte: u8) {
+        self.len += 1;
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.len += bytes.len();
+    }
+
+    fn append_sized<F: FnOnce(&mut Self)>(&mut self, append: F) {
+        // reserved before `append`, so that nested parts come after the part they are nested in
+        let index = self.sizes.len();
+        self.sizes.push(0);
+
+        let start = self.len;
+        self.len += SIZE_BYTE_LEN;
+        append(self);
+
+        self.sizes[index] = (self.len - start) as u32;
+    }
+}
+
+/// Writes into pre-sized `bytes`, such as the data of a binary; panics if more bytes are written
+/// than fit.  The `Size`s of sized parts are taken in order from `sizes`, as counted by a
+/// `Counter`.
+pub struct SliceBuf<'a> {
+    bytes: &'a mut [u8],
+    pub len: usize,
+    sizes: &'a [u32],
+    sized_count: usize,
+}
+
+impl<'a> SliceBuf<'a> {
+    pub fn new(bytes: &'a mut [u8], sizes: &'a [u32]) -> Self {
+        Self {
+            bytes,
+            len: 0,
+            sizes,
+            sized_count: 0,
+        }
+    }
+}
+
+impl<'a> IoBuf for SliceBuf<'a> {
+    fn push(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        let end = self.len + bytes.len();
+        self.bytes[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+    }
+
+    fn append_sized<F: FnOnce(&mut Self)>(&mut self, append: F) {
+        let size = self.sizes[self.sized_count];
+        self.sized_count += 1;
+
+        self.extend_from_slice(&size.to_be_bytes());
+        append(self);
+    }
+}
+
+impl IoBuf for Vec<u8> {
+    fn push(&mut self, byte: u8) {
+        Vec::push(self, byte)
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        Vec::extend_from_slice(self, bytes)
+    }
+
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        Extend::extend(self, iter)
+    }
+
+    /// `Size` is written as a placeholder and then overwritten once the part is written
+    fn append_sized<F: FnOnce(&mut Self)>(&mut self, append: F) {
+        let start = self.len();
+        Vec::extend_from_slice(self, &[0; SIZE_BYTE_LEN]);
+        append(self);
+
+        let size = (self.len() - start) as u32;
+        self[start..start + SIZE_BYTE_LEN].copy_from_slice(&size.to_be_bytes());
+    }
+}
+
+// Private
+
+const SIZE_BYTE_LEN: usize = mem::size_of::<u32>();
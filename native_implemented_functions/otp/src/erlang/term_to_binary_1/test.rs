@@ -1,3 +1,5 @@
+use libtest::Bencher;
+
 use proptest::strategy::Just;
 use proptest::{prop_assert, prop_assert_eq};
 
@@ -6,7 +8,10 @@ use liblumen_alloc::erts::scheduler;
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::erlang::binary_to_term_1;
+use crate::erlang::external_size_1;
+use crate::erlang::term_to_binary::term_to_byte_vec;
 use crate::erlang::term_to_binary_1::native;
+use crate::test::alloc::with_peak_allocated_bytes;
 use crate::test::strategy;
 use crate::test::with_process;
 
@@ -647,6 +652,104 @@ fn non_empty_map_returns_map_ext() {
     });
 }
 
+#[test]
+fn with_large_map_writes_same_bytes_in_place_as_through_byte_vec() {
+    with_process(|process| {
+        let map = large_map(process);
+        let byte_vec = term_to_byte_vec(process, &Default::default(), map);
+
+        assert_eq!(
+            external_size_1::native(process, map),
+            Ok(process.integer(byte_vec.len()).unwrap())
+        );
+        assert_eq!(
+            native(process, map),
+            Ok(process.binary_from_bytes(&byte_vec).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_large_map_in_place_peak_allocation_excludes_byte_vec() {
+    with_process(|process| {
+        let map = large_map(process);
+
+        let (byte_vec, through_byte_vec_peak) = with_peak_allocated_bytes(|| {
+            let byte_vec = term_to_byte_vec(process, &Default::default(), map);
+            process.binary_from_bytes(&byte_vec).unwrap();
+
+            byte_vec
+        });
+        let (_, in_place_peak) = with_peak_allocated_bytes(|| native(process, map).unwrap());
+
+        // the binary's data isn't counted, so only the `Vec` and the encoder's own stack are
+        assert!(byte_vec.len() <= through_byte_vec_peak);
+        assert!(
+            in_place_peak < byte_vec.len() / 2,
+            "in place peak allocation ({}) should be less than half the encoding ({})",
+            in_place_peak,
+            byte_vec.len()
+        );
+    });
+}
+
+#[test]
+fn with_deeply_nested_closures_writes_same_bytes_in_place_as_through_byte_vec() {
+    with_process(|process| {
+        // counting each closure's env again for its `Size` would take 2^depth encodings
+        let closure = (0..32).fold(Term::NIL, |env, index| {
+            process
+                .anonymous_closure_with_env_from_slice(
+                    Atom::try_from_str("module").unwrap(),
+                    index,
+                    0,
+                    [0; 16],
+                    0,
+                    None,
+                    process.pid().into(),
+                    &[env],
+                )
+                .unwrap()
+        });
+        let byte_vec = term_to_byte_vec(process, &Default::default(), closure);
+
+        assert_eq!(
+            external_size_1::native(process, closure),
+            Ok(process.integer(byte_vec.len()).unwrap())
+        );
+        assert_eq!(
+            native(process, closure),
+            Ok(process.binary_from_bytes(&byte_vec).unwrap())
+        );
+    });
+}
+
+// Times copying through a `Vec`.  `with_large_map_in_place_peak_allocation_excludes_byte_vec`
+// measures the allocation.
+#[bench]
+fn bench_large_map_through_byte_vec(bencher: &mut Bencher) {
+    with_process(|process| {
+        let map = large_map(process);
+
+        bencher.iter(|| {
+            let byte_vec = term_to_byte_vec(process, &Default::default(), map);
+
+            process.binary_from_bytes(&byte_vec).unwrap()
+        })
+    });
+}
+
+// Times writing in place, which encodes the map twice: once to count the bytes and once to write
+// them into the binary
+#[bench]
+fn bench_large_map_in_place(bencher: &mut Bencher) {
+    with_process(|process| {
+        let map = large_map(process);
+
+        bencher.iter(|| native(process, map).unwrap())
+    });
+}
+
 // SMALL_ATOM_UTF8_EXT (119)
 #[test]
 fn with_small_utf8_atom_returns_small_atom_utf8_ext() {
@@ -672,6 +775,16 @@ const NIL_EXT: u8 = 106;
 const STRING_EXT: u8 = 107;
 const BINARY_EXT: u8 = 109;
 
+/// A map whose encoding is about 1 MiB
+fn large_map(process: &Process) -> Term {
+    let value = process.binary_from_bytes(&[0xAB; 100]).unwrap();
+    let entry_vec: Vec<(Term, Term)> = (0..10_000)
+        .map(|key| (process.integer(key).unwrap(), value))
+        .collect();
+
+    process.map_from_slice(&entry_vec).unwrap()
+}
+
 fn non_empty_atom_term() -> Term {
     Atom::str_to_term("atom")
 }
@@ -0,0 +1,131 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_full::binary_to_string::binary_to_string;
+
+use native_implemented_function::native_implemented_function;
+
+/// `term_to_json/1`
+///
+/// Not part of OTP: serializes `term` to a UTF-8 JSON binary for inspecting runtime state from
+/// external tools.  Only terms with an obvious JSON equivalent are supported:
+///
+/// * maps with binary or atom keys are objects, with the keys in sorted order
+/// * proper lists are arrays
+/// * UTF-8 binaries are strings
+/// * `true`, `false`, and `null` are literals
+/// * integers and floats are numbers
+///
+/// Any other term, such as a tuple, pid, reference, or fun, is `badarg`.
+#[native_implemented_function(term_to_json/1)]
+pub fn native(process: &Process, term: Term) -> exception::Result<Term> {
+    let mut json = String::new();
+    write_json(&mut json, term)?;
+
+    process.binary_from_str(&json).map_err(From::from)
+}
+
+// Private
+
+fn is_not_json(term: Term) -> exception::Result<()> {
+    Err(TypeError)
+        .context(format!("term ({}) cannot be converted to JSON", term))
+        .map_err(From::from)
+}
+
+fn write_json(json: &mut String, term: Term) -> exception::Result<()> {
+    match term.decode()? {
+        TypedTerm::Atom(atom) => match atom.name() {
+            name @ "true" | name @ "false" | name @ "null" => json.push_str(name),
+            _ => return is_not_json(term),
+        },
+        TypedTerm::SmallInteger(small_integer) => json.push_str(&small_integer.to_string()),
+        TypedTerm::BigInteger(big_integer) => json.push_str(&big_integer.to_string()),
+        TypedTerm::Float(float) => {
+            let f: f64 = float.into();
+
+            json.push_str(&format!("{:?}", f));
+        }
+        TypedTerm::Nil => json.push_str("[]"),
+        TypedTerm::List(cons) => {
+            json.push('[');
+
+            for (index, result) in cons.into_iter().enumerate() {
+                match result {
+                    Ok(element) => {
+                        if 0 < index {
+                            json.push(',');
+                        }
+
+                        write_json(json, element)?;
+                    }
+                    Err(_) => return is_not_json(term),
+                }
+            }
+
+            json.push(']');
+        }
+        TypedTerm::Map(map) => {
+            json.push('{');
+
+            for (index, (key, value)) in map.iter_sorted().enumerate() {
+                if 0 < index {
+                    json.push(',');
+                }
+
+                let key_string = match key.decode()? {
+                    TypedTerm::Atom(atom) => atom.name().to_owned(),
+                    _ if key.is_binary() => binary_to_string(key)?,
+                    _ => return is_not_json(key),
+                };
+
+                write_string(json, &key_string);
+                json.push(':');
+                write_json(json, value)?;
+            }
+
+            json.push('}');
+        }
+        TypedTerm::HeapBinary(_)
+        | TypedTerm::ProcBin(_)
+        | TypedTerm::BinaryLiteral(_)
+        | TypedTerm::SubBinary(_)
+            if term.is_binary() =>
+        {
+            write_string(json, &binary_to_string(term)?)
+        }
+        _ => return is_not_json(term),
+    }
+
+    Ok(())
+}
+
+fn write_string(json: &mut String, s: &str) {
+    json.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            '\u{8}' => json.push_str("\\b"),
+            '\u{c}' => json.push_str("\\f"),
+            _ if c < ' ' => json.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => json.push(c),
+        }
+    }
+
+    json.push('"');
+}
@@ -0,0 +1,79 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::term_to_json_1::native;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn with_nested_map_and_list_returns_json_binary() {
+    with_process(|process| {
+        let inner_map = process
+            .map_from_slice(&[(process.binary_from_str("c").unwrap(), atom!("null"))])
+            .unwrap();
+        let list = process
+            .list_from_slice(&[
+                process.integer(1).unwrap(),
+                process.float(2.5).unwrap(),
+                atom!("true"),
+                process.binary_from_str("say \"hi\"\n").unwrap(),
+            ])
+            .unwrap();
+        let map = process
+            .map_from_slice(&[
+                (process.binary_from_str("b").unwrap(), inner_map),
+                (process.binary_from_str("a").unwrap(), list),
+                (atom!("empty"), Term::NIL),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(process, map),
+            Ok(process
+                .binary_from_str(r#"{"empty":[],"a":[1,2.5,true,"say \"hi\"\n"],"b":{"c":null}}"#)
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_atom_other_than_literal_errors_badarg() {
+    with_process(|process| {
+        let term = atom!("undefined");
+
+        assert_badarg!(
+            native(process, term),
+            format!("term ({}) cannot be converted to JSON", term)
+        );
+    });
+}
+
+#[test]
+fn with_tuple_in_list_errors_badarg() {
+    with_process(|process| {
+        let tuple = process.tuple_from_slice(&[atom!("true")]).unwrap();
+        let list = process.list_from_slice(&[tuple]).unwrap();
+
+        assert_badarg!(
+            native(process, list),
+            format!("term ({}) cannot be converted to JSON", tuple)
+        );
+    });
+}
+
+#[test]
+fn with_pid_errors_badarg() {
+    run!(
+        |arc_process| (Just(arc_process.clone()), strategy::term::pid::local()),
+        |(arc_process, pid)| {
+            prop_assert_badarg!(
+                native(&arc_process, pid),
+                format!("term ({}) cannot be converted to JSON", pid)
+            );
+
+            Ok(())
+        },
+    );
+}
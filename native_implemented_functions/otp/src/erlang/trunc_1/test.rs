@@ -3,6 +3,7 @@ use std::convert::TryInto;
 use proptest::prop_assert_eq;
 
 use crate::erlang::trunc_1::native;
+use crate::test::with_process;
 
 #[test]
 fn without_number_errors_badarg() {
@@ -25,3 +26,13 @@ fn with_float_truncates_to_integer() {
         Ok(())
     });
 }
+
+#[test]
+fn with_negative_two_and_a_half_truncates_toward_zero() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.float(-2.5).unwrap()),
+            Ok(process.integer(-2).unwrap())
+        );
+    });
+}
@@ -2,7 +2,7 @@ use proptest::prop_assert_eq;
 use proptest::strategy::{Just, Strategy};
 
 use crate::erlang::tuple_size_1::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process};
 
 #[test]
 fn without_tuple_errors_badarg() {
@@ -47,3 +47,41 @@ fn with_tuple_returns_arity() {
         },
     );
 }
+
+#[test]
+fn with_empty_tuple_returns_zero() {
+    with_process(|process| {
+        let tuple = process.tuple_from_slice(&[]).unwrap();
+
+        assert_eq!(native(process, tuple), Ok(process.integer(0).unwrap()));
+    });
+}
+
+#[test]
+fn with_three_tuple_returns_three() {
+    with_process(|process| {
+        let tuple = process
+            .tuple_from_slice(&[
+                process.integer(1).unwrap(),
+                process.integer(2).unwrap(),
+                process.integer(3).unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(native(process, tuple), Ok(process.integer(3).unwrap()));
+    });
+}
+
+#[test]
+fn with_list_errors_badarg() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[process.integer(1).unwrap()])
+            .unwrap();
+
+        assert_badarg!(
+            native(process, list),
+            format!("tuple ({}) is not a tuple", list)
+        );
+    });
+}
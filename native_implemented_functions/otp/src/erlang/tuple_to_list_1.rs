@@ -5,20 +5,132 @@
 #[cfg(all(not(target_arch = "wasm32"), test))]
 mod test;
 
+use std::convert::TryInto;
+use std::sync::Arc;
+
 use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::alloc::TermAlloc;
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::code::stack::frame::{Frame, Placement};
+use liblumen_alloc::erts::process::{code, Process, MAX_REDUCTIONS_PER_RUN};
 use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::{Arity, ModuleFunctionArity};
+
+const ARITY: Arity = 1;
 
-use native_implemented_function::native_implemented_function;
+pub fn export() {
+    lumen_rt_full::code::export::insert(super::module(), function(), ARITY, code);
+}
 
-#[native_implemented_function(tuple_to_list/1)]
+/// One reduction is charged per element.  `native` converts all of `tuple` before returning, but
+/// `code` yields whenever the process has used up its reductions and resumes the conversion where
+/// it left off on the next run, so converting a large tuple can't starve other processes.
 pub fn native(process: &Process, tuple: Term) -> exception::Result<Term> {
-    let tuple = term_try_into_tuple!(tuple)?;
+    let boxed_tuple = term_try_into_tuple!(tuple)?;
+    let acc = prepend_range(process, boxed_tuple, 0, boxed_tuple.len(), Term::NIL)?;
+    process.reduce_by(boxed_tuple.len());
+
+    Ok(acc)
+}
+
+pub fn place_frame_with_arguments(
+    process: &Process,
+    placement: Placement,
+    tuple: Term,
+) -> code::Result {
+    process.stack_push(tuple)?;
+    process.place_frame(frame(), placement);
+
+    Ok(())
+}
+
+// Private
+
+/// Both `code` and `resume_code` keep one term on the stack: the tuple and then the
+/// `{Tuple, Remaining, Acc}` state
+const STACK_USED: usize = 1;
+
+fn code(arc_process: &Arc<Process>) -> code::Result {
+    arc_process.reduce();
+
+    let tuple = arc_process.stack_peek(1).unwrap();
+
+    match term_try_into_tuple!(tuple) {
+        Ok(boxed_tuple) => run(arc_process, tuple, boxed_tuple.len(), Term::NIL),
+        Err(exception) => code::result_from_exception(arc_process, STACK_USED, exception),
+    }
+}
+
+fn frame() -> Frame {
+    Frame::new(module_function_arity(), code)
+}
+
+fn function() -> Atom {
+    Atom::try_from_str("tuple_to_list").unwrap()
+}
+
+fn module_function_arity() -> Arc<ModuleFunctionArity> {
+    Arc::new(ModuleFunctionArity {
+        module: super::module(),
+        function: function(),
+        arity: ARITY,
+    })
+}
+
+fn resume_code(arc_process: &Arc<Process>) -> code::Result {
+    arc_process.reduce();
+
+    let state = arc_process.stack_peek(1).unwrap();
+    let state_tuple: Boxed<Tuple> = state.try_into().unwrap();
+    let tuple = state_tuple[0];
+    let remaining: usize = state_tuple[1].try_into().unwrap();
+    let acc = state_tuple[2];
+
+    run(arc_process, tuple, remaining, acc)
+}
+
+/// Conses as many of the `remaining` elements as the process has reductions left for.  When
+/// elements are still remaining, the state replaces the term on the stack and `resume_code`
+/// replaces the frame, so the next run resumes from there.
+///
+/// The stack is only changed once allocating has succeeded, so when it fails, the scheduler can
+/// garbage collect and rerun the frame from the last saved state.
+fn run(arc_process: &Arc<Process>, tuple: Term, remaining: usize, acc: Term) -> code::Result {
+    let boxed_tuple: Boxed<Tuple> = tuple.try_into().unwrap();
+    let budget = MAX_REDUCTIONS_PER_RUN.saturating_sub(arc_process.run_reductions()) as usize;
+    let len = remaining.min(budget.max(1));
+    let remaining = remaining - len;
+
+    let acc = match prepend_range(arc_process, boxed_tuple, remaining, len, acc) {
+        Ok(acc) => acc,
+        Err(exception) => return code::result_from_exception(arc_process, STACK_USED, exception),
+    };
+    arc_process.reduce_by(len);
+
+    if remaining == 0 {
+        arc_process.return_from_call(STACK_USED, acc)?;
+
+        Process::call_code(arc_process)
+    } else {
+        let state = arc_process.tuple_from_slice(&[tuple, arc_process.integer(remaining)?, acc])?;
+        arc_process.stack_popn(STACK_USED);
+        arc_process.stack_push(state)?;
+        arc_process.replace_frame(Frame::new(module_function_arity(), resume_code));
+
+        Ok(())
+    }
+}
+
+/// Conses the `len` elements of `tuple` starting at `start` in front of `acc`
+fn prepend_range(
+    process: &Process,
+    tuple: Boxed<Tuple>,
+    start: usize,
+    len: usize,
+    mut acc: Term,
+) -> exception::Result<Term> {
     let mut heap = process.acquire_heap();
-    let mut acc = Term::NIL;
 
-    for element in tuple.iter().rev() {
+    for element in tuple.elements()[start..start + len].iter().rev() {
         acc = heap.cons(*element, acc)?.into();
     }
 
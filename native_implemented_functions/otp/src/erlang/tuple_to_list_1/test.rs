@@ -1,8 +1,13 @@
+use std::convert::TryInto;
+
 use proptest::prop_assert_eq;
 use proptest::strategy::Just;
 
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::tuple_to_list_1;
 use crate::erlang::tuple_to_list_1::native;
-use crate::test::strategy;
+use crate::test::{strategy, with_process, with_ticks_while_applying};
 
 #[test]
 fn without_tuple_errors_badarg() {
@@ -40,3 +45,71 @@ fn with_tuple_returns_list() {
         },
     );
 }
+
+#[test]
+fn with_large_tuple_returns_list_with_elements_in_order() {
+    with_process(|process| {
+        let element_vec: Vec<Term> = (0..2_000).map(|i| process.integer(i).unwrap()).collect();
+        let tuple = process.tuple_from_slice(&element_vec).unwrap();
+
+        let list = native(process, tuple).unwrap();
+        let boxed_cons: Boxed<Cons> = list.try_into().unwrap();
+        let list_element_vec: Vec<Term> = boxed_cons.into_iter().map(|r| r.unwrap()).collect();
+
+        assert_eq!(list_element_vec, element_vec);
+    });
+}
+
+#[test]
+fn charges_reductions_that_grow_with_tuple_arity() {
+    let small_reductions = tuple_to_list_reductions(10);
+    let large_reductions = tuple_to_list_reductions(1_000);
+
+    assert!(small_reductions < large_reductions);
+    assert!(1_000 <= large_reductions);
+}
+
+#[test]
+fn with_large_tuple_yields_to_other_processes_until_converted() {
+    tuple_to_list_1::export();
+
+    let arity = 3_000;
+
+    with_ticks_while_applying(
+        Atom::try_from_str("erlang").unwrap(),
+        Atom::try_from_str("tuple_to_list").unwrap(),
+        move |process| {
+            let element_vec: Vec<Term> = (0..arity).map(|i| process.integer(i).unwrap()).collect();
+
+            vec![process.tuple_from_slice(&element_vec).unwrap()]
+        },
+        move |process, ticks, list| {
+            // one reduction per element is more than one run's reductions
+            assert!(
+                1 < ticks,
+                "ticks ({}) should be sent while converting",
+                ticks
+            );
+
+            let element_vec: Vec<Term> = (0..arity).map(|i| process.integer(i).unwrap()).collect();
+
+            assert_eq!(list, process.list_from_slice(&element_vec).unwrap());
+        },
+    );
+}
+
+fn tuple_to_list_reductions(arity: isize) -> u16 {
+    let mut reductions = 0;
+
+    with_process(|process| {
+        let element_vec: Vec<Term> = (0..arity).map(|i| process.integer(i).unwrap()).collect();
+        let tuple = process.tuple_from_slice(&element_vec).unwrap();
+        let before = process.run_reductions();
+
+        native(process, tuple).unwrap();
+
+        reductions = process.run_reductions() - before;
+    });
+
+    reductions
+}
@@ -0,0 +1,30 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use lumen_rt_core::time::datetime;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang::datetime_tuple::{datetime_to_term, term_try_into_datetime};
+
+#[native_implemented_function(universaltime_to_localtime/1)]
+pub fn native(process: &Process, universaltime: Term) -> exception::Result<Term> {
+    let universaltime_datetime = term_try_into_datetime("universaltime", universaltime)?;
+    let localtime_datetime = datetime::utc_to_local(universaltime_datetime).with_context(|| {
+        format!(
+            "universaltime ({}) is not a valid date and time",
+            universaltime
+        )
+    })?;
+
+    datetime_to_term(process, localtime_datetime)
+}
@@ -0,0 +1,30 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::datetime_tuple::datetime_to_term;
+use crate::erlang::universaltime_to_localtime_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_invalid_time_errors_badarg() {
+    with_process(|process| {
+        let universaltime = datetime_to_term(process, [2020, 1, 15, 24, 0, 0]).unwrap();
+
+        assert_badarg!(
+            native(process, universaltime),
+            format!(
+                "universaltime ({}) is not a valid date and time",
+                universaltime
+            )
+        );
+    });
+}
+
+#[test]
+fn with_valid_datetime_returns_datetime() {
+    with_process(|process| {
+        let universaltime = datetime_to_term(process, [2000, 2, 29, 23, 59, 59]).unwrap();
+        let localtime = native(process, universaltime).unwrap();
+
+        assert!(localtime.is_boxed_tuple());
+    });
+}
@@ -0,0 +1,18 @@
+#[cfg(test)]
+mod test;
+
+use liblumen_alloc::erts::process::{Process, MAX_REDUCTIONS_PER_RUN};
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+/// Uses up the rest of the calling process's reductions for this run, so that it returns to the
+/// scheduler as soon as `yield` returns.  The process stays runnable and is pushed to the back of
+/// its run queue, so any other runnable processes run before it continues.
+#[native_implemented_function(yield/0)]
+pub fn native(process: &Process) -> Term {
+    let remaining = MAX_REDUCTIONS_PER_RUN.saturating_sub(process.run_reductions());
+    process.reduce_by(remaining as usize);
+
+    true.into()
+}
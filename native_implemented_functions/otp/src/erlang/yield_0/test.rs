@@ -0,0 +1,105 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use liblumen_alloc::erts::process::{code, Process};
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_full::process::spawn::options::Options;
+use lumen_rt_full::scheduler::{Scheduler, Spawned};
+
+use crate::erlang::yield_0::native;
+use crate::test::{r#loop, with_process, with_process_arc};
+
+#[test]
+fn returns_true_and_uses_up_reductions() {
+    with_process(|process| {
+        assert!(!process.is_reduced());
+
+        assert_eq!(native(process), true.into());
+
+        assert!(process.is_reduced());
+    });
+}
+
+#[test]
+fn lets_other_runnable_process_run_before_yielder_continues() {
+    with_process_arc(|arc_process| {
+        let yielder_arc_process = spawn(&arc_process, yielder_code);
+        let other_arc_process = spawn(&arc_process, other_code);
+        let scheduler = Scheduler::current();
+
+        // both runs of the yielder are logged
+        for _ in 0..8 {
+            if run_pid_count(yielder_arc_process.pid()) == 2 {
+                break;
+            }
+
+            let _ = scheduler.run_once();
+        }
+
+        let run_pid_vec = RUN_PID_VEC.with(|run_pid_vec| run_pid_vec.borrow().clone());
+        let first_yielder_index = run_pid_vec
+            .iter()
+            .position(|pid| *pid == yielder_arc_process.pid())
+            .unwrap();
+
+        assert_eq!(
+            run_pid_vec.get(first_yielder_index + 1),
+            Some(&other_arc_process.pid())
+        );
+        assert_eq!(run_pid_count(yielder_arc_process.pid()), 2);
+    });
+}
+
+thread_local! {
+    static RUN_PID_VEC: RefCell<Vec<Pid>> = RefCell::new(Vec::new());
+}
+
+fn log_run(process: &Process) {
+    RUN_PID_VEC.with(|run_pid_vec| run_pid_vec.borrow_mut().push(process.pid()));
+}
+
+fn run_pid_count(pid: Pid) -> usize {
+    RUN_PID_VEC.with(|run_pid_vec| {
+        run_pid_vec
+            .borrow()
+            .iter()
+            .filter(|run_pid| **run_pid == pid)
+            .count()
+    })
+}
+
+/// Yields every time it is called, so that without `yield` it would log runs until reduced
+fn yielder_code(arc_process: &Arc<Process>) -> code::Result {
+    arc_process.reduce();
+    log_run(arc_process);
+    native(arc_process);
+
+    Process::call_code(arc_process)
+}
+
+/// Only logs the first call of each run, as it loops until reduced
+fn other_code(arc_process: &Arc<Process>) -> code::Result {
+    if arc_process.run_reductions() == 0 {
+        log_run(arc_process);
+    }
+
+    r#loop::code(arc_process)
+}
+
+fn spawn(parent_process: &Process, code: code::Code) -> Arc<Process> {
+    let mut options: Options = Default::default();
+    options.min_heap_size = Some(16_000);
+
+    let Spawned { arc_process, .. } = Scheduler::spawn_code(
+        parent_process,
+        options,
+        r#loop::module(),
+        r#loop::function(),
+        &[],
+        code,
+    )
+    .unwrap();
+
+    arc_process
+}
@@ -0,0 +1,278 @@
+//! Mirrors [gb_trees](http://erlang.org/doc/man/gb_trees.html) module
+//!
+//! Trees are `{Size, Node}` like in OTP, where `Node` is `nil` or `{Key, Value, Smaller, Bigger}`,
+//! so they can be passed to and from Erlang code that uses `gb_trees`.  Keys are ordered and
+//! compared with term order, so `1` and `1.0` are the same key.
+
+pub mod empty_0;
+pub mod enter_3;
+pub mod insert_3;
+pub mod lookup_2;
+pub mod to_list_1;
+
+use std::cmp::{max, Ordering};
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::error;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("gb_trees").unwrap()
+}
+
+// Private
+
+/// Rebuilds `node`, which has `size` keys, as a perfectly balanced node
+fn balance(process: &Process, node: Term, size: u64) -> exception::Result<Term> {
+    let mut pairs = Vec::with_capacity(size as usize);
+    in_order(node, &mut pairs)?;
+
+    balance_list(process, &mut pairs.into_iter(), size)
+}
+
+fn balance_list(
+    process: &Process,
+    pairs: &mut impl Iterator<Item = (Term, Term)>,
+    size: u64,
+) -> exception::Result<Term> {
+    if size == 0 {
+        Ok(nil())
+    } else {
+        let bigger_size = (size - 1) / 2;
+        let smaller_size = size - 1 - bigger_size;
+        let smaller = balance_list(process, pairs, smaller_size)?;
+        let (key, value) = pairs.next().unwrap();
+        let bigger = balance_list(process, pairs, bigger_size)?;
+
+        node_from_parts(process, key, value, smaller, bigger)
+    }
+}
+
+/// The `(Height, Size)` of `node`, where, like in OTP, `Height` is 2 to the power of the depth, so
+/// that it can be compared to the squared size.  Also like in OTP, a leaf has the same `Height` as
+/// `nil`.
+fn count(node: Term) -> exception::Result<(u64, u64)> {
+    match node_try_from(node)? {
+        Some(tuple) if tuple[2] == nil() && tuple[3] == nil() => Ok((1, 1)),
+        Some(tuple) => {
+            let (smaller_height, smaller_size) = count(tuple[2])?;
+            let (bigger_height, bigger_size) = count(tuple[3])?;
+
+            Ok((
+                max(smaller_height, bigger_height).saturating_mul(2),
+                smaller_size + bigger_size + 1,
+            ))
+        }
+        None => Ok((1, 0)),
+    }
+}
+
+/// Appends the `(Key, Value)` pairs of `node` to `pairs` in key order
+fn in_order(node: Term, pairs: &mut Vec<(Term, Term)>) -> exception::Result<()> {
+    if let Some(tuple) = node_try_from(node)? {
+        in_order(tuple[2], pairs)?;
+        pairs.push((tuple[0], tuple[1]));
+        in_order(tuple[3], pairs)?;
+    }
+
+    Ok(())
+}
+
+/// Inserts `key` that is not already in `tree`, rebalancing the path to it if it got too deep
+fn insert(process: &Process, key: Term, value: Term, tree: Term) -> exception::Result<Term> {
+    let (size, node) = tree_try_into_size_node("tree", tree)?;
+    let inserted_size = size + 1;
+    let inserted_size_u64 = inserted_size as u64;
+    let inserted_node = match insert_node(
+        process,
+        key,
+        value,
+        node,
+        inserted_size_u64.saturating_mul(inserted_size_u64),
+    )? {
+        Inserted::Balanced(node) => node,
+        // the root is already rebalanced when the tree is too deep, but balancing is still correct
+        Inserted::Unbalanced { node, .. } => balance(process, node, inserted_size_u64)?,
+    };
+
+    tree_from_size_node(process, inserted_size, inserted_node)
+}
+
+/// Once `weight` is halved to `0` on the way down, the new leaf is deeper than a balanced tree
+/// would allow, so on the way back up the height and size are counted until the subtree where
+/// the height is acceptable again or the subtree that needs to be rebalanced is found.
+fn insert_node(
+    process: &Process,
+    key: Term,
+    value: Term,
+    node: Term,
+    weight: u64,
+) -> exception::Result<Inserted> {
+    match node_try_from(node)? {
+        Some(tuple) => {
+            let node_key = tuple[0];
+            let node_value = tuple[1];
+            let smaller = tuple[2];
+            let bigger = tuple[3];
+
+            let ordering = key.cmp(&node_key);
+            let (inserted, other) = match ordering {
+                Ordering::Less => (
+                    insert_node(process, key, value, smaller, weight / 2)?,
+                    bigger,
+                ),
+                Ordering::Greater => (
+                    insert_node(process, key, value, bigger, weight / 2)?,
+                    smaller,
+                ),
+                Ordering::Equal => {
+                    let reason = process.tuple_from_slice(&[atom!("key_exists"), key])?;
+
+                    return Err(
+                        error!(reason, anyhow!("key ({}) is already in tree", key).into()).into(),
+                    );
+                }
+            };
+            let rebuild = |child| {
+                if ordering == Ordering::Less {
+                    node_from_parts(process, node_key, node_value, child, bigger)
+                } else {
+                    node_from_parts(process, node_key, node_value, smaller, child)
+                }
+            };
+
+            match inserted {
+                Inserted::Balanced(child) => Ok(Inserted::Balanced(rebuild(child)?)),
+                Inserted::Unbalanced {
+                    node: child,
+                    height: child_height,
+                    size: child_size,
+                } => {
+                    let rebuilt = rebuild(child)?;
+                    let (other_height, other_size) = count(other)?;
+                    let height = max(child_height, other_height).saturating_mul(2);
+                    let size = child_size + other_size + 1;
+
+                    if size.saturating_mul(size) < height {
+                        Ok(Inserted::Balanced(balance(process, rebuilt, size)?))
+                    } else {
+                        Ok(Inserted::Unbalanced {
+                            node: rebuilt,
+                            height,
+                            size,
+                        })
+                    }
+                }
+            }
+        }
+        None => {
+            let leaf = node_from_parts(process, key, value, nil(), nil())?;
+
+            if weight == 0 {
+                Ok(Inserted::Unbalanced {
+                    node: leaf,
+                    height: 1,
+                    size: 1,
+                })
+            } else {
+                Ok(Inserted::Balanced(leaf))
+            }
+        }
+    }
+}
+
+/// The value of `key` in `node`
+fn lookup(key: Term, mut node: Term) -> exception::Result<Option<Term>> {
+    while let Some(tuple) = node_try_from(node)? {
+        node = match key.cmp(&tuple[0]) {
+            Ordering::Less => tuple[2],
+            Ordering::Greater => tuple[3],
+            Ordering::Equal => return Ok(Some(tuple[1])),
+        };
+    }
+
+    Ok(None)
+}
+
+fn nil() -> Term {
+    atom!("nil")
+}
+
+fn node_from_parts(
+    process: &Process,
+    key: Term,
+    value: Term,
+    smaller: Term,
+    bigger: Term,
+) -> exception::Result<Term> {
+    process
+        .tuple_from_slice(&[key, value, smaller, bigger])
+        .map_err(From::from)
+}
+
+fn node_try_from(node: Term) -> exception::Result<Option<Boxed<Tuple>>> {
+    match node.decode()? {
+        TypedTerm::Atom(_) if node == nil() => Ok(None),
+        TypedTerm::Tuple(tuple) if tuple.len() == 4 => Ok(Some(tuple)),
+        _ => Err(TypeError)
+            .context(format!(
+                "node ({}) is not nil or {{Key, Value, Smaller, Bigger}}",
+                node
+            ))
+            .map_err(From::from),
+    }
+}
+
+fn tree_from_size_node(process: &Process, size: usize, node: Term) -> exception::Result<Term> {
+    process
+        .tuple_from_slice(&[process.integer(size)?, node])
+        .map_err(From::from)
+}
+
+/// The `Size` and `Node` of `tree`
+fn tree_try_into_size_node(name: &str, tree: Term) -> exception::Result<(usize, Term)> {
+    let option = match tree.decode()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+            let size: Option<usize> = tuple[0].try_into().ok();
+
+            size.map(|size| (size, tuple[1]))
+        }
+        _ => None,
+    };
+
+    option
+        .with_context(|| format!("{} ({}) is not a gb_tree", name, tree))
+        .map_err(From::from)
+}
+
+/// Replaces the value of `key` that is already in `node`
+fn update(process: &Process, key: Term, value: Term, node: Term) -> exception::Result<Term> {
+    match node_try_from(node)? {
+        Some(tuple) => match key.cmp(&tuple[0]) {
+            Ordering::Less => {
+                let smaller = update(process, key, value, tuple[2])?;
+
+                node_from_parts(process, tuple[0], tuple[1], smaller, tuple[3])
+            }
+            Ordering::Greater => {
+                let bigger = update(process, key, value, tuple[3])?;
+
+                node_from_parts(process, tuple[0], tuple[1], tuple[2], bigger)
+            }
+            Ordering::Equal => node_from_parts(process, key, value, tuple[2], tuple[3]),
+        },
+        None => Err(anyhow!("key ({}) is not in tree", key).into()),
+    }
+}
+
+enum Inserted {
+    /// No rebalancing is needed above this node
+    Balanced(Term),
+    /// The height and size are being counted to find where to rebalance
+    Unbalanced { node: Term, height: u64, size: u64 },
+}
@@ -0,0 +1,20 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::gb_trees::{nil, tree_from_size_node};
+
+/// A new tree with no keys
+#[native_implemented_function(empty/0)]
+pub fn native(process: &Process) -> exception::Result<Term> {
+    tree_from_size_node(process, 0, nil())
+}
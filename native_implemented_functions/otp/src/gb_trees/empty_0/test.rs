@@ -0,0 +1,16 @@
+use liblumen_alloc::atom;
+
+use crate::gb_trees::empty_0::native;
+use crate::test::with_process;
+
+#[test]
+fn returns_tree_with_size_0_and_nil_node() {
+    with_process(|process| {
+        assert_eq!(
+            native(process),
+            Ok(process
+                .tuple_from_slice(&[process.integer(0).unwrap(), atom!("nil")])
+                .unwrap())
+        );
+    });
+}
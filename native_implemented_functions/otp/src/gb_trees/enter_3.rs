@@ -0,0 +1,29 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::gb_trees::{insert, lookup, tree_from_size_node, tree_try_into_size_node, update};
+
+/// Inserts `key` with `value` into `tree` or, if `key` is already in `tree`, replaces its value
+#[native_implemented_function(enter/3)]
+pub fn native(process: &Process, key: Term, value: Term, tree: Term) -> exception::Result<Term> {
+    let (size, node) = tree_try_into_size_node("tree", tree)?;
+
+    match lookup(key, node)? {
+        Some(_) => {
+            let updated_node = update(process, key, value, node)?;
+
+            tree_from_size_node(process, size, updated_node)
+        }
+        None => insert(process, key, value, tree),
+    }
+}
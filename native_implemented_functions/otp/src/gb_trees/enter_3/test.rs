@@ -0,0 +1,45 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::enter_3::native;
+use crate::gb_trees::{empty_0, lookup_2, to_list_1};
+use crate::test::with_process;
+
+#[test]
+fn without_key_in_tree_inserts_key() {
+    with_process(|process| {
+        let tree = empty_0::native(process).unwrap();
+        let tree = native(process, atom!("key"), atom!("value"), tree).unwrap();
+
+        assert_eq!(
+            to_list_1::native(process, tree),
+            Ok(process
+                .list_from_slice(&[process
+                    .tuple_from_slice(&[atom!("key"), atom!("value")])
+                    .unwrap()])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_key_in_tree_replaces_value_and_keeps_size() {
+    with_process(|process| {
+        let tree = empty_0::native(process).unwrap();
+        let tree = native(process, atom!("a"), atom!("old"), tree).unwrap();
+        let tree = native(process, atom!("b"), atom!("b"), tree).unwrap();
+        let tree = native(process, atom!("a"), atom!("new"), tree).unwrap();
+
+        assert_eq!(
+            lookup_2::native(process, atom!("a"), tree),
+            Ok(process
+                .tuple_from_slice(&[atom!("value"), atom!("new")])
+                .unwrap())
+        );
+        let tree_tuple: Boxed<Tuple> = tree.try_into().unwrap();
+
+        assert_eq!(tree_tuple[0], process.integer(2).unwrap());
+    });
+}
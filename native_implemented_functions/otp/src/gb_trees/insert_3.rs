@@ -0,0 +1,21 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::gb_trees::insert;
+
+/// Inserts `key` with `value` into `tree`.  Errors with `{key_exists, Key}` if `key` is already
+/// in `tree`.
+#[native_implemented_function(insert/3)]
+pub fn native(process: &Process, key: Term, value: Term, tree: Term) -> exception::Result<Term> {
+    insert(process, key, value, tree)
+}
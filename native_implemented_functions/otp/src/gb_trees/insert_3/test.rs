@@ -0,0 +1,153 @@
+use std::convert::TryInto;
+
+use proptest::prop_assert;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::insert_3::native;
+use crate::gb_trees::{count, empty_0, lookup, nil, to_list_1};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn with_random_keys_to_list_is_sorted_by_key() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term(arc_process.clone()), 0..=32),
+            )
+        },
+        |(arc_process, keys)| {
+            let mut tree = empty_0::native(&arc_process).unwrap();
+
+            for (index, key) in keys.iter().enumerate() {
+                let tree_tuple: Boxed<Tuple> = tree.try_into().unwrap();
+
+                if lookup(*key, tree_tuple[1]).unwrap().is_none() {
+                    let value = arc_process.integer(index).unwrap();
+                    tree = native(&arc_process, *key, value, tree).unwrap();
+                }
+            }
+
+            let pairs = to_list_1::native(&arc_process, tree).unwrap();
+            let mut sorted_keys = Vec::new();
+
+            if let TypedTerm::List(cons) = pairs.decode().unwrap() {
+                for result in cons.into_iter() {
+                    let pair: Boxed<Tuple> = result.unwrap().try_into().unwrap();
+                    sorted_keys.push(pair[0]);
+                }
+            }
+
+            for window in sorted_keys.windows(2) {
+                prop_assert!(window[0] < window[1]);
+            }
+
+            for key in keys {
+                prop_assert!(sorted_keys.iter().any(|sorted_key| *sorted_key == key));
+            }
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_ascending_keys_stays_balanced() {
+    with_process(|process| {
+        let mut tree = empty_0::native(process).unwrap();
+
+        // each insert copies the path to the new node, so this stays within the test heap
+        for i in 1..=100 {
+            let key = process.integer(i).unwrap();
+            tree = native(process, key, key, tree).unwrap();
+        }
+
+        let tree_tuple: Boxed<Tuple> = tree.try_into().unwrap();
+
+        assert_eq!(tree_tuple[0], process.integer(100).unwrap());
+        // 2 * log2(100) is the height OTP allows
+        assert!(depth(tree_tuple[1]) <= 14);
+    });
+}
+
+#[test]
+fn with_ascending_keys_has_same_shape_as_otp() {
+    with_process(|process| {
+        let node = |key: isize, smaller: Term, bigger: Term| {
+            let key = process.integer(key).unwrap();
+
+            process
+                .tuple_from_slice(&[key, key, smaller, bigger])
+                .unwrap()
+        };
+        let leaf = |key: isize| node(key, nil(), nil());
+        let mut tree = empty_0::native(process).unwrap();
+
+        for i in 1..=6 {
+            let key = process.integer(i).unwrap();
+            tree = native(process, key, key, tree).unwrap();
+        }
+
+        // `gb_trees:insert(6, 6, T)` for `T` from inserting `1` to `5` in OTP
+        let chain = node(
+            1,
+            nil(),
+            node(
+                2,
+                nil(),
+                node(3, nil(), node(4, nil(), node(5, nil(), leaf(6)))),
+            ),
+        );
+        let tree_tuple: Boxed<Tuple> = tree.try_into().unwrap();
+
+        assert_eq!(tree_tuple[1], chain);
+
+        let key = process.integer(7).unwrap();
+        tree = native(process, key, key, tree).unwrap();
+
+        // The 7th key is too deep, so OTP rebalances the whole tree
+        let balanced = node(4, node(2, leaf(1), leaf(3)), node(6, leaf(5), leaf(7)));
+        let tree_tuple: Boxed<Tuple> = tree.try_into().unwrap();
+
+        assert_eq!(tree_tuple[1], balanced);
+        assert_eq!(count(balanced), Ok((4, 7)));
+        assert_eq!(count(leaf(1)), Ok((1, 1)));
+    });
+}
+
+#[test]
+fn with_key_in_tree_errors_key_exists() {
+    with_process(|process| {
+        let tree = empty_0::native(process).unwrap();
+        let tree = native(process, atom!("key"), atom!("value"), tree).unwrap();
+
+        assert_error!(
+            native(process, atom!("key"), atom!("other"), tree),
+            process
+                .tuple_from_slice(&[atom!("key_exists"), atom!("key")])
+                .unwrap()
+        );
+    });
+}
+
+#[test]
+fn without_tree_errors_badarg() {
+    with_process(|process| {
+        let tree = atom!("tree");
+
+        assert_badarg!(
+            native(process, atom!("key"), atom!("value"), tree),
+            format!("tree ({}) is not a gb_tree", tree)
+        );
+    });
+}
+
+fn depth(node: Term) -> usize {
+    match node.decode().unwrap() {
+        TypedTerm::Tuple(tuple) => 1 + std::cmp::max(depth(tuple[2]), depth(tuple[3])),
+        _ => 0,
+    }
+}
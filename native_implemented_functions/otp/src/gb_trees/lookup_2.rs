@@ -0,0 +1,28 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::gb_trees::{lookup, tree_try_into_size_node};
+
+/// `{value, Value}` if `key` is in `tree`; otherwise, `none`
+#[native_implemented_function(lookup/2)]
+pub fn native(process: &Process, key: Term, tree: Term) -> exception::Result<Term> {
+    let (_, node) = tree_try_into_size_node("tree", tree)?;
+
+    match lookup(key, node)? {
+        Some(value) => process
+            .tuple_from_slice(&[atom!("value"), value])
+            .map_err(From::from),
+        None => Ok(atom!("none")),
+    }
+}
@@ -0,0 +1,47 @@
+use liblumen_alloc::atom;
+
+use crate::gb_trees::lookup_2::native;
+use crate::gb_trees::{empty_0, insert_3};
+use crate::test::with_process;
+
+#[test]
+fn with_key_in_tree_returns_value_tuple() {
+    with_process(|process| {
+        let tree = empty_0::native(process).unwrap();
+        let tree = insert_3::native(process, atom!("a"), atom!("value"), tree).unwrap();
+        let tree = insert_3::native(process, atom!("b"), atom!("other"), tree).unwrap();
+
+        assert_eq!(
+            native(process, atom!("a"), tree),
+            Ok(process
+                .tuple_from_slice(&[atom!("value"), atom!("value")])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_equal_key_of_different_type_returns_value_tuple() {
+    with_process(|process| {
+        let tree = empty_0::native(process).unwrap();
+        let tree =
+            insert_3::native(process, process.integer(1).unwrap(), atom!("value"), tree).unwrap();
+
+        assert_eq!(
+            native(process, process.float(1.0).unwrap(), tree),
+            Ok(process
+                .tuple_from_slice(&[atom!("value"), atom!("value")])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_key_in_tree_returns_none() {
+    with_process(|process| {
+        let tree = empty_0::native(process).unwrap();
+        let tree = insert_3::native(process, atom!("a"), atom!("value"), tree).unwrap();
+
+        assert_eq!(native(process, atom!("b"), tree), Ok(atom!("none")));
+    });
+}
@@ -0,0 +1,30 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::gb_trees::{in_order, tree_try_into_size_node};
+
+/// The `{Key, Value}` pairs of `tree` in key order
+#[native_implemented_function(to_list/1)]
+pub fn native(process: &Process, tree: Term) -> exception::Result<Term> {
+    let (size, node) = tree_try_into_size_node("tree", tree)?;
+    let mut pairs = Vec::with_capacity(size);
+    in_order(node, &mut pairs)?;
+
+    let mut pair_vec = Vec::with_capacity(pairs.len());
+
+    for (key, value) in pairs {
+        pair_vec.push(process.tuple_from_slice(&[key, value])?);
+    }
+
+    process.list_from_slice(&pair_vec).map_err(From::from)
+}
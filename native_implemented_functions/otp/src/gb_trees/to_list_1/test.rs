@@ -0,0 +1,55 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::to_list_1::native;
+use crate::gb_trees::{empty_0, insert_3};
+use crate::test::with_process;
+
+#[test]
+fn with_empty_tree_returns_empty_list() {
+    with_process(|process| {
+        let tree = empty_0::native(process).unwrap();
+
+        assert_eq!(native(process, tree), Ok(Term::NIL));
+    });
+}
+
+#[test]
+fn with_tree_returns_pairs_in_key_order() {
+    with_process(|process| {
+        let mut tree = empty_0::native(process).unwrap();
+
+        for key in &[atom!("c"), atom!("a"), atom!("b")] {
+            tree = insert_3::native(process, *key, *key, tree).unwrap();
+        }
+
+        assert_eq!(
+            native(process, tree),
+            Ok(process
+                .list_from_slice(&[
+                    process.tuple_from_slice(&[atom!("a"), atom!("a")]).unwrap(),
+                    process.tuple_from_slice(&[atom!("b"), atom!("b")]).unwrap(),
+                    process.tuple_from_slice(&[atom!("c"), atom!("c")]).unwrap(),
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_invalid_node_errors_badarg() {
+    with_process(|process| {
+        let node = atom!("node");
+        let tree = process
+            .tuple_from_slice(&[process.integer(1).unwrap(), node])
+            .unwrap();
+
+        assert_badarg!(
+            native(process, tree),
+            format!(
+                "node ({}) is not nil or {{Key, Value, Smaller, Bigger}}",
+                node
+            )
+        );
+    });
+}
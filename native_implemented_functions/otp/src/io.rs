@@ -0,0 +1,11 @@
+//! Mirrors [io](http://erlang.org/doc/man/io.html) module
+
+pub mod format;
+pub mod format_1;
+pub mod format_2;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("io").unwrap()
+}
@@ -0,0 +1,635 @@
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_full::binary_to_string::binary_to_string;
+
+use crate::erlang::list_to_string::list_to_string;
+
+/// Replaces the control sequences in `format` with the corresponding element of `arguments`.
+///
+/// Supported control sequences are
+///
+/// * `~p` - pretty-print the term, so printable charlists and binaries are shown as strings
+/// * `~w` - write the term with standard syntax
+/// * `~P` - like `~p`, but the next argument is the depth to write to, and lines wrap at 80
+///   columns
+/// * `~W` - like `~w`, but the next argument is the depth to write to
+/// * `~s` - a charlist, binary, or atom as a string
+/// * `~b` - an integer in base 10
+/// * `~c` - an integer as the character it encodes
+/// * `~n` - a newline
+/// * `~~` - a literal `~`
+pub fn format(format: Term, arguments: Term) -> exception::Result<String> {
+    let format_string = format_to_string(format)?;
+    let argument_vec = arguments_to_vec(arguments)?;
+    let mut argument_iter = argument_vec.into_iter();
+    let mut formatted = String::new();
+    let mut char_iter = format_string.chars();
+
+    while let Some(c) = char_iter.next() {
+        if c != '~' {
+            formatted.push(c);
+            continue;
+        }
+
+        let control = char_iter.next().with_context(|| {
+            format!(
+                "format ({}) ends with an incomplete control sequence",
+                format
+            )
+        })?;
+
+        match control {
+            '~' => formatted.push('~'),
+            'n' => formatted.push('\n'),
+            'b' | 'c' | 'p' | 'P' | 's' | 'w' | 'W' => {
+                let argument = argument_iter.next().with_context(|| {
+                    format!(
+                        "format ({}) has more control sequences than arguments ({})",
+                        format, arguments
+                    )
+                })?;
+
+                match control {
+                    'b' => write_integer(&mut formatted, argument)?,
+                    'c' => {
+                        let c: char = argument.try_into().with_context(|| {
+                            format!("~c argument ({}) is not a unicode scalar value", argument)
+                        })?;
+
+                        formatted.push(c);
+                    }
+                    'p' => write_term(&mut formatted, argument, true)?,
+                    's' => {
+                        if argument.is_atom() || argument.is_list() || argument.is_binary() {
+                            formatted.push_str(&chardata_to_string(argument)?)
+                        } else {
+                            return Err(TypeError)
+                                .context(format!(
+                                    "~s argument ({}) is not a charlist, binary, or atom",
+                                    argument
+                                ))
+                                .map_err(From::from);
+                        }
+                    }
+                    'w' => write_term(&mut formatted, argument, false)?,
+                    'P' | 'W' => {
+                        let depth = argument_iter.next().with_context(|| {
+                            format!(
+                                "format ({}) ~{} has no depth argument after the term in arguments ({})",
+                                format, control, arguments
+                            )
+                        })?;
+                        let pretty = control == 'P';
+                        let limits = Limits {
+                            depth: depth_try_from(depth)?,
+                            width: if pretty { Some(PRETTY_WIDTH) } else { None },
+                        };
+
+                        write_term_limited(&mut formatted, argument, pretty, limits)?;
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            _ => {
+                return Err(anyhow!(
+                    "format ({}) control sequence (~{}) is not supported",
+                    format,
+                    control
+                )
+                .into())
+            }
+        }
+    }
+
+    if argument_iter.next().is_some() {
+        Err(anyhow!(
+            "format ({}) has fewer control sequences than arguments ({})",
+            format,
+            arguments
+        )
+        .into())
+    } else {
+        Ok(formatted)
+    }
+}
+
+/// Writes `term` using Erlang syntax, so the output could be read back in as `term`.  When
+/// `pretty`, lists and binaries that are printable are shown as strings like `~p`.
+pub fn write_term(s: &mut String, term: Term, pretty: bool) -> exception::Result<()> {
+    write_term_limited(s, term, pretty, Default::default())
+}
+
+/// Like `write_term`, but elides the parts of `term` deeper than `limits.depth` with `...` and
+/// wraps lists, tuples, and maps that do not fit in `limits.width` with one element per line,
+/// like `~P` and `~W`.  Maps are written in key order, so the output is stable.
+pub fn write_term_limited(
+    s: &mut String,
+    term: Term,
+    pretty: bool,
+    limits: Limits,
+) -> exception::Result<()> {
+    let column = match s.rfind('\n') {
+        Some(index) => s[index + 1..].chars().count(),
+        None => s.chars().count(),
+    };
+    let layout = layout(term, pretty, limits.depth)?;
+
+    write_layout(s, &layout, column, limits.width);
+
+    Ok(())
+}
+
+/// Limits on how much of a term `write_term_limited` writes on each line
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Limits {
+    /// The depth of nested elements after which the rest of a term is replaced with `...`.
+    /// `None` writes all elements.
+    pub depth: Option<usize>,
+    /// The line width that lists, tuples, and maps wrap at.  `None` writes terms on one line.
+    pub width: Option<usize>,
+}
+
+// Private
+
+const RESERVED_WORDS: &[&str] = &[
+    "after", "and", "andalso", "band", "begin", "bnot", "bor", "bsl", "bsr", "bxor", "case",
+    "catch", "cond", "div", "end", "fun", "if", "let", "not", "of", "or", "orelse", "receive",
+    "rem", "try", "when", "xor",
+];
+
+/// The line width of `~P`, which is OTP's default line length
+const PRETTY_WIDTH: usize = 80;
+
+/// A term split into its nested containers, so that it can be written on one line or wrapped
+enum Layout {
+    Leaf(String),
+    /// `Key => Value` in a map
+    Association(Box<Layout>, Box<Layout>),
+    /// `items` are each preceded by their separator from the previous item, which is empty for
+    /// the first one
+    Container {
+        open: &'static str,
+        items: Vec<(&'static str, Layout)>,
+        close: &'static str,
+    },
+}
+
+fn arguments_to_vec(arguments: Term) -> exception::Result<Vec<Term>> {
+    match arguments.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<std::result::Result<Vec<Term>, _>>()
+            .map_err(|_| anyhow!("arguments ({}) is not a proper list", arguments).into()),
+        _ => Err(TypeError)
+            .context(format!("arguments ({}) is not a list", arguments))
+            .map_err(From::from),
+    }
+}
+
+fn chardata_to_string(chardata: Term) -> exception::Result<String> {
+    match chardata.decode()? {
+        TypedTerm::Atom(atom) => Ok(atom.name().to_owned()),
+        TypedTerm::Nil | TypedTerm::List(_) => list_to_string(chardata),
+        _ => binary_to_string(chardata),
+    }
+}
+
+fn format_to_string(format: Term) -> exception::Result<String> {
+    if format.is_atom() || format.is_list() || format.is_binary() {
+        chardata_to_string(format)
+    } else {
+        Err(TypeError)
+            .context(format!(
+                "format ({}) is not a charlist, binary, or atom",
+                format
+            ))
+            .map_err(From::from)
+    }
+}
+
+fn depth_try_from(depth: Term) -> exception::Result<Option<usize>> {
+    let depth_isize: isize = depth
+        .try_into()
+        .with_context(|| format!("depth ({}) is not an integer", depth))?;
+
+    // Negative depths are unlimited like in OTP
+    if depth_isize < 0 {
+        Ok(None)
+    } else {
+        Ok(Some(depth_isize as usize))
+    }
+}
+
+fn is_printable_char(c: u32) -> bool {
+    match c {
+        // `\b`, `\t`, `\n`, `\v`, `\f`, `\r`
+        8..=13 => true,
+        // `\e`
+        27 => true,
+        32..=126 | 160..=255 => true,
+        _ => false,
+    }
+}
+
+fn is_printable_charlist(element_vec: &[Term]) -> bool {
+    !element_vec.is_empty()
+        && element_vec.iter().all(|element| match element.decode() {
+            Ok(TypedTerm::SmallInteger(small_integer)) => {
+                let i: isize = small_integer.into();
+
+                0 <= i && is_printable_char(i as u32)
+            }
+            _ => false,
+        })
+}
+
+/// Lays out `term` to `depth` following `io_lib:write/2`: each element of a list, tuple, or map
+/// is one level deeper than the one before it, so long containers are cut off like deep ones.
+fn layout(term: Term, pretty: bool, depth: Option<usize>) -> exception::Result<Layout> {
+    if depth == Some(0) {
+        return Ok(leaf("..."));
+    }
+
+    let layout = match term.decode()? {
+        TypedTerm::Atom(atom) => {
+            let mut s = String::new();
+            write_atom(&mut s, atom.name());
+
+            Layout::Leaf(s)
+        }
+        TypedTerm::SmallInteger(small_integer) => Layout::Leaf(small_integer.to_string()),
+        TypedTerm::BigInteger(big_integer) => Layout::Leaf(big_integer.to_string()),
+        TypedTerm::Float(float) => {
+            let mut s = String::new();
+            write_float(&mut s, float.into());
+
+            Layout::Leaf(s)
+        }
+        TypedTerm::Nil => leaf("[]"),
+        TypedTerm::List(cons) => {
+            let mut element_vec = Vec::new();
+            let mut tail = Term::NIL;
+
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => element_vec.push(element),
+                    Err(ImproperList {
+                        tail: improper_tail,
+                    }) => tail = improper_tail,
+                }
+            }
+
+            if pretty && tail.is_nil() && is_printable_charlist(&element_vec) {
+                let mut s = String::new();
+                write_quoted(
+                    &mut s,
+                    element_vec.iter().map(|element| {
+                        let c: char = (*element).try_into().unwrap();
+
+                        c
+                    }),
+                    '"',
+                );
+
+                Layout::Leaf(s)
+            } else if depth == Some(1) {
+                leaf("[...]")
+            } else {
+                let mut items = vec![("", layout(element_vec[0], pretty, decrement(depth))?)];
+                let mut tail_depth = decrement(depth);
+                let mut cut_off = false;
+
+                for element in &element_vec[1..] {
+                    if tail_depth == Some(1) {
+                        cut_off = true;
+                        break;
+                    }
+
+                    items.push((",", layout(*element, pretty, decrement(tail_depth))?));
+                    tail_depth = decrement(tail_depth);
+                }
+
+                if cut_off || (!tail.is_nil() && tail_depth == Some(1)) {
+                    items.push(("|", leaf("...")));
+                } else if !tail.is_nil() {
+                    items.push(("|", layout(tail, pretty, decrement(tail_depth))?));
+                }
+
+                Layout::Container {
+                    open: "[",
+                    items,
+                    close: "]",
+                }
+            }
+        }
+        TypedTerm::Tuple(tuple) => {
+            if tuple.len() == 0 {
+                leaf("{}")
+            } else if depth == Some(1) {
+                leaf("{...}")
+            } else {
+                let mut items = vec![("", layout(tuple[0], pretty, decrement(depth))?)];
+                let mut tail_depth = decrement(depth);
+
+                for element in tuple.iter().skip(1) {
+                    if tail_depth == Some(1) {
+                        items.push((",", leaf("...")));
+                        break;
+                    }
+
+                    items.push((",", layout(*element, pretty, decrement(tail_depth))?));
+                    tail_depth = decrement(tail_depth);
+                }
+
+                Layout::Container {
+                    open: "{",
+                    items,
+                    close: "}",
+                }
+            }
+        }
+        TypedTerm::Map(map) => {
+            if map.len() == 0 {
+                leaf("#{}")
+            } else if depth == Some(1) {
+                leaf("#{...}")
+            } else {
+                // Unlike lists and tuples, keys and values are all at the same depth
+                let association_depth = decrement(depth);
+                let mut items = Vec::new();
+                let mut tail_depth = association_depth;
+
+                for (index, (key, value)) in map.iter_sorted().enumerate() {
+                    let separator = if index == 0 { "" } else { "," };
+
+                    if 0 < index {
+                        if tail_depth == Some(1) {
+                            items.push((separator, leaf("...")));
+                            break;
+                        }
+
+                        tail_depth = decrement(tail_depth);
+                    }
+
+                    let association = Layout::Association(
+                        Box::new(layout(key, pretty, association_depth)?),
+                        Box::new(layout(value, pretty, association_depth)?),
+                    );
+                    items.push((separator, association));
+                }
+
+                Layout::Container {
+                    open: "#{",
+                    items,
+                    close: "}",
+                }
+            }
+        }
+        TypedTerm::HeapBinary(heap_binary) => bytes_layout(heap_binary.as_bytes(), pretty, depth),
+        TypedTerm::ProcBin(process_binary) => {
+            bytes_layout(process_binary.as_bytes(), pretty, depth)
+        }
+        TypedTerm::BinaryLiteral(binary_literal) => {
+            bytes_layout(binary_literal.as_bytes(), pretty, depth)
+        }
+        TypedTerm::SubBinary(subbinary) => {
+            let byte_vec: Vec<u8> = subbinary.full_byte_iter().collect();
+
+            if subbinary.is_binary() {
+                bytes_layout(&byte_vec, pretty, depth)
+            } else {
+                let partial_byte_bit_len = subbinary.partial_byte_bit_len();
+                let partial_byte = subbinary
+                    .partial_byte_bit_iter()
+                    .fold(0_u8, |acc, bit| (acc << 1) | bit);
+                let mut s = String::new();
+
+                s.push_str("<<");
+
+                match depth {
+                    // The partial byte counts as an element like the full bytes
+                    Some(depth) if byte_vec.len() + 1 >= depth => {
+                        for byte in &byte_vec[..depth - 1] {
+                            s.push_str(&byte.to_string());
+                            s.push(',');
+                        }
+
+                        s.push_str("...>>");
+                    }
+                    _ => {
+                        for byte in byte_vec {
+                            s.push_str(&byte.to_string());
+                            s.push(',');
+                        }
+
+                        s.push_str(&format!("{}:{}>>", partial_byte, partial_byte_bit_len));
+                    }
+                }
+
+                Layout::Leaf(s)
+            }
+        }
+        TypedTerm::Pid(pid) => Layout::Leaf(format!("<0.{}.{}>", pid.number(), pid.serial())),
+        _ => Layout::Leaf(term.to_string()),
+    };
+
+    Ok(layout)
+}
+
+fn bytes_layout(bytes: &[u8], pretty: bool, depth: Option<usize>) -> Layout {
+    let mut s = String::new();
+    write_bytes(&mut s, bytes, pretty, depth);
+
+    Layout::Leaf(s)
+}
+
+fn decrement(depth: Option<usize>) -> Option<usize> {
+    depth.map(|depth| depth - 1)
+}
+
+fn leaf(s: &str) -> Layout {
+    Layout::Leaf(s.to_string())
+}
+
+fn write_atom(s: &mut String, name: &str) {
+    let mut char_iter = name.chars();
+
+    let unquoted = match char_iter.next() {
+        Some(first) => {
+            first.is_ascii_lowercase()
+                && char_iter.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '@')
+                && !RESERVED_WORDS.contains(&name)
+        }
+        None => false,
+    };
+
+    if unquoted {
+        s.push_str(name);
+    } else {
+        write_quoted(s, name.chars(), '\'');
+    }
+}
+
+/// Writes the bytes of a binary.  When `depth` is reached, the remaining bytes are elided with
+/// `...`, but printable binaries are always written in full.
+fn write_bytes(s: &mut String, bytes: &[u8], pretty: bool, depth: Option<usize>) {
+    if pretty && !bytes.is_empty() && bytes.iter().all(|byte| is_printable_char(*byte as u32)) {
+        s.push_str("<<");
+        write_quoted(s, bytes.iter().map(|byte| *byte as char), '"');
+        s.push_str(">>");
+    } else {
+        let (written, cut_off) = match depth {
+            Some(depth) if depth <= bytes.len() => (&bytes[..depth - 1], true),
+            _ => (bytes, false),
+        };
+
+        s.push_str("<<");
+
+        for (index, byte) in written.iter().enumerate() {
+            if 0 < index {
+                s.push(',');
+            }
+
+            s.push_str(&byte.to_string());
+        }
+
+        if cut_off {
+            if !written.is_empty() {
+                s.push(',');
+            }
+
+            s.push_str("...");
+        }
+
+        s.push_str(">>");
+    }
+}
+
+fn write_float(s: &mut String, f: f64) {
+    // Debug always includes a decimal point, except in scientific notation, where Erlang still
+    // requires one in the mantissa.
+    let debug = format!("{:?}", f);
+
+    match debug.find('e') {
+        Some(index) if !debug[..index].contains('.') => {
+            s.push_str(&debug[..index]);
+            s.push_str(".0");
+            s.push_str(&debug[index..]);
+        }
+        _ => s.push_str(&debug),
+    }
+}
+
+fn write_integer(s: &mut String, integer: Term) -> exception::Result<()> {
+    match integer.decode()? {
+        TypedTerm::SmallInteger(small_integer) => s.push_str(&small_integer.to_string()),
+        TypedTerm::BigInteger(big_integer) => s.push_str(&big_integer.to_string()),
+        _ => {
+            return Err(TypeError)
+                .context(format!("~b argument ({}) is not an integer", integer))
+                .map_err(From::from)
+        }
+    }
+
+    Ok(())
+}
+
+fn write_flat(s: &mut String, layout: &Layout) {
+    match layout {
+        Layout::Leaf(leaf) => s.push_str(leaf),
+        Layout::Association(key, value) => {
+            write_flat(s, key);
+            s.push_str(" => ");
+            write_flat(s, value);
+        }
+        Layout::Container { open, items, close } => {
+            s.push_str(open);
+
+            for (separator, item) in items {
+                s.push_str(separator);
+                write_flat(s, item);
+            }
+
+            s.push_str(close);
+        }
+    }
+}
+
+/// Writes `layout` on one line if it fits in `width` from `column`, otherwise containers write
+/// each item on its own line, indented to just after the opening bracket.
+fn write_layout(s: &mut String, layout: &Layout, column: usize, width: Option<usize>) {
+    let mut flat = String::new();
+    write_flat(&mut flat, layout);
+
+    let fits = match width {
+        Some(width) => column + flat.chars().count() <= width,
+        None => true,
+    };
+
+    if fits {
+        s.push_str(&flat);
+    } else {
+        match layout {
+            Layout::Leaf(_) => s.push_str(&flat),
+            Layout::Association(key, value) => {
+                let mut key_string = String::new();
+                write_flat(&mut key_string, key);
+                key_string.push_str(" => ");
+
+                let value_column = column + key_string.chars().count();
+                s.push_str(&key_string);
+                write_layout(s, value, value_column, width);
+            }
+            Layout::Container { open, items, close } => {
+                let item_column = column + open.chars().count();
+                s.push_str(open);
+
+                for (index, (separator, item)) in items.iter().enumerate() {
+                    if 0 < index {
+                        s.push_str(separator);
+                        s.push('\n');
+                        s.extend(std::iter::repeat(' ').take(item_column));
+                    }
+
+                    write_layout(s, item, item_column, width);
+                }
+
+                s.push_str(close);
+            }
+        }
+    }
+}
+
+fn write_quoted<I>(s: &mut String, char_iter: I, quote: char)
+where
+    I: Iterator<Item = char>,
+{
+    s.push(quote);
+
+    for c in char_iter {
+        match c {
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            '\u{8}' => s.push_str("\\b"),
+            '\u{b}' => s.push_str("\\v"),
+            '\u{c}' => s.push_str("\\f"),
+            '\u{1b}' => s.push_str("\\e"),
+            _ if c == quote => {
+                s.push('\\');
+                s.push(c);
+            }
+            _ if c.is_control() => s.push_str(&format!("\\{:03o}", c as u32)),
+            _ => s.push(c),
+        }
+    }
+
+    s.push(quote);
+}
@@ -0,0 +1,19 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::io::format_2;
+
+/// Same as `io:format(format, [])`.
+#[native_implemented_function(format/1)]
+pub fn native(format: Term) -> exception::Result<Term> {
+    format_2::native(format, Term::NIL)
+}
@@ -0,0 +1,30 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io::format::format;
+use crate::io::format_1::native;
+use crate::test::with_process;
+
+#[test]
+fn without_control_sequences_returns_ok() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("hello~n").unwrap();
+
+        assert_eq!(
+            format(format_charlist, Term::NIL),
+            Ok("hello\n".to_string())
+        );
+        assert_eq!(native(format_charlist), Ok(Atom::str_to_term("ok")));
+    });
+}
+
+#[test]
+fn with_control_sequence_consuming_argument_errors_badarg() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~p").unwrap();
+
+        assert_badarg!(
+            native(format_charlist),
+            "has more control sequences than arguments"
+        );
+    });
+}
@@ -0,0 +1,27 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_full::system;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::io::format;
+
+/// Writes `format` with its control sequences replaced by the formatted `arguments` to standard
+/// output.
+#[native_implemented_function(format/2)]
+pub fn native(format: Term, arguments: Term) -> exception::Result<Term> {
+    let formatted = format::format(format, arguments)?;
+
+    // NOT A DEBUGGING LOG
+    system::io::print(&formatted);
+
+    Ok(Atom::str_to_term("ok"))
+}
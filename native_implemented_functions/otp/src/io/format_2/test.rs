@@ -0,0 +1,236 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io::format::format;
+use crate::io::format_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_p_and_n_formats_term_followed_by_newline() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~p~n").unwrap();
+        let arguments = process
+            .list_from_slice(&[Atom::str_to_term("foo")])
+            .unwrap();
+
+        assert_eq!(format(format_charlist, arguments), Ok("foo\n".to_string()));
+        assert_eq!(
+            native(format_charlist, arguments),
+            Ok(Atom::str_to_term("ok"))
+        );
+    });
+}
+
+#[test]
+fn with_p_formats_printable_charlist_as_string_and_w_as_list() {
+    with_process(|process| {
+        let format_binary = process.binary_from_str("~p ~w").unwrap();
+        let string = process.charlist_from_str("hi").unwrap();
+        let arguments = process.list_from_slice(&[string, string]).unwrap();
+
+        assert_eq!(
+            format(format_binary, arguments),
+            Ok("\"hi\" [104,105]".to_string())
+        );
+    });
+}
+
+#[test]
+fn with_w_formats_nested_terms_with_erlang_syntax() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~w").unwrap();
+        let tuple = process
+            .tuple_from_slice(&[
+                Atom::str_to_term("Quoted"),
+                process.float(2.5).unwrap(),
+                process
+                    .improper_list_from_slice(
+                        &[process.integer(1).unwrap()],
+                        process.integer(2).unwrap(),
+                    )
+                    .unwrap(),
+            ])
+            .unwrap();
+        let arguments = process.list_from_slice(&[tuple]).unwrap();
+
+        assert_eq!(
+            format(format_charlist, arguments),
+            Ok("{'Quoted',2.5,[1|2]}".to_string())
+        );
+    });
+}
+
+#[test]
+fn with_capital_w_truncates_deep_list_at_depth() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~W ~W").unwrap();
+        let mut deep = process.list_from_slice(&[Atom::str_to_term("a")]).unwrap();
+
+        for _ in 0..3 {
+            deep = process.list_from_slice(&[deep]).unwrap();
+        }
+
+        let long = process
+            .list_from_slice(
+                &(1..=5)
+                    .map(|i| process.integer(i).unwrap())
+                    .collect::<Vec<Term>>(),
+            )
+            .unwrap();
+        let depth = process.integer(3).unwrap();
+        let arguments = process
+            .list_from_slice(&[deep, depth, long, depth])
+            .unwrap();
+
+        assert_eq!(
+            format(format_charlist, arguments),
+            Ok("[[[...]]] [1,2|...]".to_string())
+        );
+    });
+}
+
+#[test]
+fn with_capital_w_truncates_tuples_and_maps_in_key_order() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~W ~W").unwrap();
+        let tuple = process
+            .tuple_from_slice(&[
+                Atom::str_to_term("a"),
+                Atom::str_to_term("b"),
+                Atom::str_to_term("c"),
+                Atom::str_to_term("d"),
+            ])
+            .unwrap();
+        let map = process
+            .map_from_slice(&[
+                (Atom::str_to_term("c"), process.integer(3).unwrap()),
+                (Atom::str_to_term("a"), process.integer(1).unwrap()),
+                (Atom::str_to_term("b"), process.integer(2).unwrap()),
+            ])
+            .unwrap();
+        let depth = process.integer(3).unwrap();
+        let arguments = process
+            .list_from_slice(&[tuple, depth, map, depth])
+            .unwrap();
+
+        assert_eq!(
+            format(format_charlist, arguments),
+            Ok("{a,b,...} #{a => 1,b => 2,...}".to_string())
+        );
+    });
+}
+
+#[test]
+fn with_capital_w_and_negative_depth_writes_whole_term() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~W").unwrap();
+        let list = process
+            .list_from_slice(
+                &(1..=5)
+                    .map(|i| process.integer(i).unwrap())
+                    .collect::<Vec<Term>>(),
+            )
+            .unwrap();
+        let arguments = process
+            .list_from_slice(&[list, process.integer(-1).unwrap()])
+            .unwrap();
+
+        assert_eq!(
+            format(format_charlist, arguments),
+            Ok("[1,2,3,4,5]".to_string())
+        );
+    });
+}
+
+#[test]
+fn with_capital_p_wraps_list_wider_than_line() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~P").unwrap();
+        let element = Atom::str_to_term("abcdefghij");
+        let list = process.list_from_slice(&[element; 20]).unwrap();
+        let arguments = process
+            .list_from_slice(&[list, process.integer(-1).unwrap()])
+            .unwrap();
+
+        assert_eq!(
+            format(format_charlist, arguments),
+            Ok(format!("[{}]", vec!["abcdefghij"; 20].join(",\n ")))
+        );
+    });
+}
+
+#[test]
+fn with_capital_p_without_depth_errors_badarg() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~P").unwrap();
+        let arguments = process
+            .list_from_slice(&[Atom::str_to_term("foo")])
+            .unwrap();
+
+        assert_badarg!(
+            native(format_charlist, arguments),
+            "has no depth argument after the term"
+        );
+    });
+}
+
+#[test]
+fn with_s_b_c_and_tilde_formats_arguments() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~s ~b ~c ~~").unwrap();
+        let arguments = process
+            .list_from_slice(&[
+                process.binary_from_str("text").unwrap(),
+                process.integer(-42).unwrap(),
+                process.integer('z' as isize).unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            format(format_charlist, arguments),
+            Ok("text -42 z ~".to_string())
+        );
+    });
+}
+
+#[test]
+fn with_more_control_sequences_than_arguments_errors_badarg() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~p ~p").unwrap();
+        let arguments = process
+            .list_from_slice(&[Atom::str_to_term("foo")])
+            .unwrap();
+
+        assert_badarg!(
+            native(format_charlist, arguments),
+            "has more control sequences than arguments"
+        );
+    });
+}
+
+#[test]
+fn with_fewer_control_sequences_than_arguments_errors_badarg() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~p").unwrap();
+        let arguments = process
+            .list_from_slice(&[Atom::str_to_term("foo"), Atom::str_to_term("bar")])
+            .unwrap();
+
+        assert_badarg!(
+            native(format_charlist, arguments),
+            "has fewer control sequences than arguments"
+        );
+    });
+}
+
+#[test]
+fn without_list_arguments_errors_badarg() {
+    with_process(|process| {
+        let format_charlist = process.charlist_from_str("~p").unwrap();
+        let arguments = Atom::str_to_term("foo");
+
+        assert_badarg!(
+            native(format_charlist, arguments),
+            format!("arguments ({}) is not a list", arguments)
+        );
+    });
+}
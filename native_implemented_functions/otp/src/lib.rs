@@ -2,15 +2,38 @@
 #![feature(backtrace)]
 // for `liblumen_otp/src/erlang/subtract_list_2`.
 #![feature(vec_remove_item)]
+// for benchmarks in tests
+#![feature(test)]
+// for counting allocations per thread in tests
+#![feature(thread_local)]
+
+// `test` is the name of the test helpers module
+#[cfg(test)]
+extern crate test as libtest;
 
 #[macro_use]
 mod macros;
 
+pub mod array;
+pub mod base64;
 pub mod binary;
+pub mod calendar;
+pub mod dict;
 pub mod erlang;
+pub mod gb_trees;
+pub mod io;
 pub mod lists;
 pub mod maps;
+pub mod math;
+pub mod ordsets;
+pub mod os;
+pub mod proplists;
+pub mod queue;
+pub mod re;
+pub mod sets;
+pub mod string;
 pub mod timer;
+pub mod unicode;
 
 #[cfg(test)]
 mod test;
@@ -5,6 +5,7 @@ pub mod keymember_3;
 pub mod member_2;
 pub mod reverse_1;
 pub mod reverse_2;
+pub mod sort_1;
 
 use liblumen_alloc::erts::term::prelude::Atom;
 
@@ -0,0 +1,435 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception::{self, AllocResult};
+use liblumen_alloc::erts::process::code::stack::frame::{Frame, Placement};
+use liblumen_alloc::erts::process::{code, Process};
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::{Arity, ModuleFunctionArity};
+
+const ARITY: Arity = 1;
+
+pub fn export() {
+    lumen_rt_full::code::export::insert(super::module(), function(), ARITY, code);
+}
+
+/// Sorts `list` in term order.  The sort is stable, so elements that compare equal, like `1` and
+/// `1.0`, stay in their original order.
+///
+/// One reduction is charged per comparison and per element copied while merging.  `native` sorts
+/// all of `list` before returning, but `code` yields whenever the process has used up its
+/// reductions and resumes the sort where it left off on the next run, so sorting a long list
+/// can't starve other processes.
+pub fn native(process: &Process, list: Term) -> exception::Result<Term> {
+    let mut sorting = Sorting::new(list)?;
+
+    loop {
+        match sorting {
+            Sorting::Sorted(sorted) => return Ok(sorted),
+            _ => sorting = sorting.step(process)?,
+        }
+    }
+}
+
+pub fn place_frame_with_arguments(
+    process: &Process,
+    placement: Placement,
+    list: Term,
+) -> code::Result {
+    process.stack_push(list)?;
+    process.place_frame(frame(), placement);
+
+    Ok(())
+}
+
+// Private
+
+/// Both `code` and `resume_code` keep one term on the stack: the list and then the `Sorting` state
+const STACK_USED: usize = 1;
+
+/// The number of elements sorted at once into each of the runs that are then merged.
+const RUN_LEN: usize = 16;
+
+fn code(arc_process: &Arc<Process>) -> code::Result {
+    arc_process.reduce();
+
+    let list = arc_process.stack_peek(1).unwrap();
+
+    match Sorting::new(list) {
+        Ok(sorting) => run(arc_process, sorting),
+        Err(exception) => code::result_from_exception(arc_process, STACK_USED, exception),
+    }
+}
+
+fn frame() -> Frame {
+    Frame::new(module_function_arity(), code)
+}
+
+fn function() -> Atom {
+    Atom::try_from_str("sort").unwrap()
+}
+
+fn module_function_arity() -> Arc<ModuleFunctionArity> {
+    Arc::new(ModuleFunctionArity {
+        module: super::module(),
+        function: function(),
+        arity: ARITY,
+    })
+}
+
+fn resume_code(arc_process: &Arc<Process>) -> code::Result {
+    arc_process.reduce();
+
+    let state = arc_process.stack_peek(1).unwrap();
+
+    run(arc_process, Sorting::from_state(state))
+}
+
+/// Steps `sorting` until it is sorted or the process is reduced.  When the process is reduced,
+/// the state replaces the term on the stack and `resume_code` replaces the frame, so the next run
+/// resumes from there.
+///
+/// The stack is only changed once a step has succeeded, so when allocating fails, the scheduler
+/// can garbage collect and rerun the frame from the last saved state.
+fn run(arc_process: &Arc<Process>, mut sorting: Sorting) -> code::Result {
+    loop {
+        sorting = match sorting.step(arc_process) {
+            Ok(Sorting::Sorted(sorted)) => {
+                arc_process.return_from_call(STACK_USED, sorted)?;
+
+                break Process::call_code(arc_process);
+            }
+            Ok(stepped) => stepped,
+            Err(exception) => {
+                break code::result_from_exception(arc_process, STACK_USED, exception)
+            }
+        };
+
+        if arc_process.is_reduced() {
+            let state = sorting.to_state(arc_process)?;
+            arc_process.stack_popn(STACK_USED);
+            arc_process.stack_push(state)?;
+            arc_process.replace_frame(Frame::new(module_function_arity(), resume_code));
+
+            break Ok(());
+        }
+    }
+}
+
+/// How far a bottom-up merge sort has gotten.  Every step does a bounded amount of work, so that
+/// `run` can stop between any two steps.
+enum Sorting {
+    /// Taking `RUN_LEN` elements at a time from `unsorted`, sorting them, and pushing them as a
+    /// run onto `runs`.  `list` is kept for the error when it turns out to be improper.
+    Splitting {
+        list: Term,
+        unsorted: Term,
+        runs: Term,
+    },
+    /// Taking the next pair of `runs` to merge.  Merged runs are pushed onto `merged`, so every
+    /// pass reverses the order of the runs, and `backwards` says whether later runs come first.
+    Pairing {
+        runs: Term,
+        merged: Term,
+        backwards: bool,
+    },
+    /// Pushing the smaller head of `earlier` and `later` onto `reversed`.  `earlier` wins ties,
+    /// which keeps the sort stable.
+    Merging {
+        runs: Term,
+        merged: Term,
+        backwards: bool,
+        earlier: Term,
+        later: Term,
+        reversed: Term,
+    },
+    /// Moving the elements of `reversed` back in front of `tail`, the rest of the run that was
+    /// not used up while merging.
+    Unwinding {
+        runs: Term,
+        merged: Term,
+        backwards: bool,
+        reversed: Term,
+        tail: Term,
+    },
+    Sorted(Term),
+}
+
+impl Sorting {
+    fn new(list: Term) -> exception::Result<Self> {
+        match list.decode()? {
+            TypedTerm::Nil => Ok(Sorting::Sorted(list)),
+            TypedTerm::List(_) => Ok(Sorting::Splitting {
+                list,
+                unsorted: list,
+                runs: Term::NIL,
+            }),
+            _ => Err(TypeError)
+                .context(format!("list ({}) is not a proper list", list))
+                .map_err(From::from),
+        }
+    }
+
+    fn from_state(state: Term) -> Self {
+        let tuple: Boxed<Tuple> = state.try_into().unwrap();
+        let backwards = |index: usize| -> bool { tuple[index].try_into().unwrap() };
+
+        match tuple[0].decode().unwrap() {
+            TypedTerm::Atom(tag) => match tag.name() {
+                "splitting" => Sorting::Splitting {
+                    list: tuple[1],
+                    unsorted: tuple[2],
+                    runs: tuple[3],
+                },
+                "pairing" => Sorting::Pairing {
+                    runs: tuple[1],
+                    merged: tuple[2],
+                    backwards: backwards(3),
+                },
+                "merging" => Sorting::Merging {
+                    runs: tuple[1],
+                    merged: tuple[2],
+                    backwards: backwards(3),
+                    earlier: tuple[4],
+                    later: tuple[5],
+                    reversed: tuple[6],
+                },
+                "unwinding" => Sorting::Unwinding {
+                    runs: tuple[1],
+                    merged: tuple[2],
+                    backwards: backwards(3),
+                    reversed: tuple[4],
+                    tail: tuple[5],
+                },
+                name => unreachable!("sorting state ({}) is unknown", name),
+            },
+            _ => unreachable!("sorting state ({}) is not tagged", state),
+        }
+    }
+
+    fn to_state(&self, process: &Process) -> AllocResult<Term> {
+        let state = match *self {
+            Sorting::Splitting {
+                list,
+                unsorted,
+                runs,
+            } => process.tuple_from_slice(&[atom!("splitting"), list, unsorted, runs])?,
+            Sorting::Pairing {
+                runs,
+                merged,
+                backwards,
+            } => process.tuple_from_slice(&[atom!("pairing"), runs, merged, backwards.into()])?,
+            Sorting::Merging {
+                runs,
+                merged,
+                backwards,
+                earlier,
+                later,
+                reversed,
+            } => process.tuple_from_slice(&[
+                atom!("merging"),
+                runs,
+                merged,
+                backwards.into(),
+                earlier,
+                later,
+                reversed,
+            ])?,
+            Sorting::Unwinding {
+                runs,
+                merged,
+                backwards,
+                reversed,
+                tail,
+            } => process.tuple_from_slice(&[
+                atom!("unwinding"),
+                runs,
+                merged,
+                backwards.into(),
+                reversed,
+                tail,
+            ])?,
+            Sorting::Sorted(sorted) => sorted,
+        };
+
+        Ok(state)
+    }
+
+    fn step(self, process: &Process) -> exception::Result<Self> {
+        match self {
+            Sorting::Splitting {
+                list,
+                unsorted,
+                runs,
+            } => {
+                let mut run_vec = Vec::with_capacity(RUN_LEN);
+                let mut rest = unsorted;
+
+                while run_vec.len() < RUN_LEN {
+                    match rest.decode()? {
+                        TypedTerm::Nil => break,
+                        TypedTerm::List(cons) => {
+                            run_vec.push(cons.head);
+                            rest = cons.tail;
+                        }
+                        _ => {
+                            return Err(ImproperListError)
+                                .context(format!("list ({}) is not a proper list", list))
+                                .map_err(From::from)
+                        }
+                    }
+                }
+
+                if run_vec.is_empty() {
+                    // the latest run was pushed last, so it is first
+                    Ok(Sorting::Pairing {
+                        runs,
+                        merged: Term::NIL,
+                        backwards: true,
+                    })
+                } else {
+                    let mut comparisons = 0;
+                    run_vec.sort_by(|left, right| {
+                        comparisons += 1;
+
+                        left.cmp(right)
+                    });
+                    process.reduce_by(comparisons);
+
+                    let run = process.list_from_slice(&run_vec)?;
+
+                    Ok(Sorting::Splitting {
+                        list,
+                        unsorted: rest,
+                        runs: process.cons(run, runs)?,
+                    })
+                }
+            }
+            Sorting::Pairing {
+                runs,
+                merged,
+                backwards,
+            } => match runs.decode()? {
+                TypedTerm::List(first_cons) => match first_cons.tail.decode()? {
+                    TypedTerm::List(second_cons) => {
+                        let (earlier, later) = if backwards {
+                            (second_cons.head, first_cons.head)
+                        } else {
+                            (first_cons.head, second_cons.head)
+                        };
+
+                        Ok(Sorting::Merging {
+                            runs: second_cons.tail,
+                            merged,
+                            backwards,
+                            earlier,
+                            later,
+                            reversed: Term::NIL,
+                        })
+                    }
+                    // an odd run out is kept as is
+                    _ => Ok(Sorting::Pairing {
+                        runs: Term::NIL,
+                        merged: process.cons(first_cons.head, merged)?,
+                        backwards,
+                    }),
+                },
+                _ => {
+                    let merged_cons: Boxed<Cons> = merged.try_into().unwrap();
+
+                    if merged_cons.tail.is_nil() {
+                        Ok(Sorting::Sorted(merged_cons.head))
+                    } else {
+                        Ok(Sorting::Pairing {
+                            runs: merged,
+                            merged: Term::NIL,
+                            backwards: !backwards,
+                        })
+                    }
+                }
+            },
+            Sorting::Merging {
+                runs,
+                merged,
+                backwards,
+                earlier,
+                later,
+                reversed,
+            } => match (earlier.decode()?, later.decode()?) {
+                (TypedTerm::List(earlier_cons), TypedTerm::List(later_cons)) => {
+                    process.reduce();
+
+                    if later_cons.head.cmp(&earlier_cons.head) == Ordering::Less {
+                        Ok(Sorting::Merging {
+                            runs,
+                            merged,
+                            backwards,
+                            earlier,
+                            later: later_cons.tail,
+                            reversed: process.cons(later_cons.head, reversed)?,
+                        })
+                    } else {
+                        Ok(Sorting::Merging {
+                            runs,
+                            merged,
+                            backwards,
+                            earlier: earlier_cons.tail,
+                            later,
+                            reversed: process.cons(earlier_cons.head, reversed)?,
+                        })
+                    }
+                }
+                (TypedTerm::List(_), _) => Ok(Sorting::Unwinding {
+                    runs,
+                    merged,
+                    backwards,
+                    reversed,
+                    tail: earlier,
+                }),
+                _ => Ok(Sorting::Unwinding {
+                    runs,
+                    merged,
+                    backwards,
+                    reversed,
+                    tail: later,
+                }),
+            },
+            Sorting::Unwinding {
+                runs,
+                merged,
+                backwards,
+                reversed,
+                tail,
+            } => match reversed.decode()? {
+                TypedTerm::List(reversed_cons) => {
+                    process.reduce();
+
+                    Ok(Sorting::Unwinding {
+                        runs,
+                        merged,
+                        backwards,
+                        reversed: reversed_cons.tail,
+                        tail: process.cons(reversed_cons.head, tail)?,
+                    })
+                }
+                _ => Ok(Sorting::Pairing {
+                    runs,
+                    merged: process.cons(tail, merged)?,
+                    backwards,
+                }),
+            },
+            Sorting::Sorted(_) => Ok(self),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::convert::TryInto;
+
+use proptest::test_runner::{Config, TestRunner};
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::sort_1;
+use crate::lists::sort_1::native;
+use crate::test::strategy;
+use crate::test::{with_process, with_process_arc, with_ticks_while_applying};
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    with_process_arc(|arc_process| {
+        TestRunner::new(Config::with_source_file(file!()))
+            .run(
+                &strategy::term::is_not_proper_list(arc_process.clone()),
+                |list| {
+                    prop_assert_badarg!(
+                        native(&arc_process, list),
+                        format!("list ({}) is not a proper list", list)
+                    );
+
+                    Ok(())
+                },
+            )
+            .unwrap();
+    });
+}
+
+#[test]
+fn with_proper_list_returns_elements_in_term_order() {
+    with_process(|process| {
+        let tuple = process.tuple_from_slice(&[]).unwrap();
+        let list = process
+            .list_from_slice(&[
+                tuple,
+                atom!("b"),
+                process.integer(2).unwrap(),
+                atom!("a"),
+                process.float(1.5).unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(process, list),
+            Ok(process
+                .list_from_slice(&[
+                    process.float(1.5).unwrap(),
+                    process.integer(2).unwrap(),
+                    atom!("a"),
+                    atom!("b"),
+                    tuple,
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_equal_integer_and_float_keeps_original_order() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[process.float(1.0).unwrap(), process.integer(1).unwrap()])
+            .unwrap();
+
+        let sorted = native(process, list).unwrap();
+        let boxed_cons: Boxed<Cons> = sorted.try_into().unwrap();
+        let element_vec: Vec<Term> = boxed_cons.into_iter().map(|r| r.unwrap()).collect();
+
+        assert!(element_vec[0].is_float());
+        assert!(element_vec[1].is_smallint());
+    });
+}
+
+#[test]
+fn charges_reductions_that_grow_with_list_length() {
+    let short_reductions = sort_reductions(10);
+    let long_reductions = sort_reductions(100);
+
+    assert!(0 < short_reductions);
+    assert!(short_reductions < long_reductions);
+}
+
+#[test]
+fn with_long_list_yields_to_other_processes_until_sorted() {
+    sort_1::export();
+
+    let len = 1024;
+
+    with_ticks_while_applying(
+        Atom::try_from_str("lists").unwrap(),
+        Atom::try_from_str("sort").unwrap(),
+        move |process| vec![descending_list(process, len)],
+        move |process, ticks, sorted| {
+            // the comparisons alone take more than one run's reductions
+            assert!(1 < ticks, "ticks ({}) should be sent while sorting", ticks);
+
+            let element_vec: Vec<Term> = (0..len).map(|i| process.integer(i).unwrap()).collect();
+
+            assert_eq!(sorted, process.list_from_slice(&element_vec).unwrap());
+        },
+    );
+}
+
+#[test]
+fn with_short_list_returns_in_one_run() {
+    sort_1::export();
+
+    with_ticks_while_applying(
+        Atom::try_from_str("lists").unwrap(),
+        Atom::try_from_str("sort").unwrap(),
+        |process| vec![descending_list(process, 10)],
+        |_, ticks, _| assert_eq!(ticks, 0),
+    );
+}
+
+fn sort_reductions(len: isize) -> u16 {
+    let mut reductions = 0;
+
+    with_process(|process| {
+        let list = descending_list(process, len);
+        let before = process.run_reductions();
+
+        native(process, list).unwrap();
+
+        reductions = process.run_reductions() - before;
+    });
+
+    reductions
+}
+
+fn descending_list(process: &Process, len: isize) -> Term {
+    let element_vec: Vec<Term> = (0..len)
+        .rev()
+        .map(|i| process.integer(i).unwrap())
+        .collect();
+
+    process.list_from_slice(&element_vec).unwrap()
+}
@@ -88,7 +88,9 @@ macro_rules! bitshift_infix_operator {
                     let shift_usize = shift_isize as usize;
 
                     if shift_usize <= MAX_SHIFT {
-                        let shifted = integer_isize $positive shift_usize;
+                        // Widen so that `bsl` of large small integers doesn't overflow `isize`.
+                        // For `bsr`, this is a right shift, which can only shrink the integer.
+                        let shifted = (integer_isize as i128) $positive shift_usize;
                         let shifted_term = $process.integer(shifted)?;
 
                         Some(shifted_term)
@@ -103,7 +105,9 @@ macro_rules! bitshift_infix_operator {
                     let shift_usize = (-shift_isize) as usize;
 
                     if shift_usize <= MAX_SHIFT {
-                        let shifted = integer_isize $negative shift_usize;
+                        // A negative shift for `bsr` is a left shift, so widen so that it doesn't
+                        // overflow `isize`.  For `bsl`, this is a right shift by `-shift`.
+                        let shifted = (integer_isize as i128) $negative shift_usize;
                         let shifted_term = $process.integer(shifted)?;
 
                         Some(shifted_term)
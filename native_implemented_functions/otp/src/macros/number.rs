@@ -69,18 +69,15 @@ macro_rules! number_infix_operator {
         match operands {
             Bad => Err(badarith(anyhow!("{} ({}) and {} ({}) aren't both numbers", stringify!($left), $left, stringify!($right), $right).into()).into()),
             ISizes(left_isize, right_isize) => {
-                match left_isize.$checked(right_isize) {
-                    Some(sum_isize) => Ok($process.integer(sum_isize)?),
-                    None => {
-                        let left_big_int: BigInt = left_isize.into();
-                        let right_big_int: BigInt = right_isize.into();
-
-                        let sum_big_int = left_big_int $infix right_big_int;
-                        let sum_term = $process.integer(sum_big_int)?;
+                let output = lumen_rt_full::number::isizes_to_integer(
+                    left_isize,
+                    right_isize,
+                    isize::$checked,
+                    |left_big_int, right_big_int| left_big_int $infix right_big_int
+                );
+                let output_term = $process.integer(output)?;
 
-                        Ok(sum_term)
-                    }
-                }
+                Ok(output_term)
             }
             Floats(left, right) => {
                 let output = left $infix right;
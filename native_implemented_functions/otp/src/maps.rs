@@ -5,9 +5,12 @@ pub mod get_3;
 pub mod is_key_2;
 pub mod keys_1;
 pub mod merge_2;
+pub mod new_0;
 pub mod put_3;
 pub mod remove_2;
+pub mod size_1;
 pub mod take_2;
+pub mod to_list_1;
 pub mod update_3;
 pub mod values_1;
 
@@ -51,6 +51,24 @@ fn without_tuple_list_errors_badarg() {
     );
 }
 
+#[test]
+fn with_non_two_element_tuple_list_errors_badarg() {
+    with_process_arc(|arc_process| {
+        let element = arc_process
+            .tuple_from_slice(&[atom!("key"), atom!("value"), atom!("extra")])
+            .unwrap();
+        let list = arc_process.list_from_slice(&[element]).unwrap();
+
+        assert_badarg!(
+            native(&arc_process, list),
+            format!(
+                "element ({}) of list ({}) is not a 2-arity tuple",
+                element, list
+            )
+        );
+    });
+}
+
 #[test]
 fn with_two_element_tuple_list_returns_value() {
     run!(
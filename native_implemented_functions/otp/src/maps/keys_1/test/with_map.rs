@@ -1,5 +1,7 @@
 use super::*;
 
+use std::convert::TryInto;
+
 #[test]
 fn returns_empty_list_of_keys() {
     with_process_arc(|arc_process| {
@@ -31,3 +33,43 @@ fn returns_list_of_keys() {
             .unwrap();
     });
 }
+
+#[test]
+fn with_values_1_zip_reproduces_entries() {
+    with_process_arc(|arc_process| {
+        let entry_vec: Vec<(Term, Term)> = (0..10_usize)
+            .rev()
+            .map(|i| {
+                (
+                    arc_process.integer(i).unwrap(),
+                    arc_process.integer(i * 10).unwrap(),
+                )
+            })
+            .collect();
+        let map = arc_process.map_from_slice(&entry_vec).unwrap();
+
+        let keys = native(&arc_process, map).unwrap();
+        let values = crate::maps::values_1::native(&arc_process, map).unwrap();
+
+        let keys_boxed_cons: Boxed<Cons> = keys.try_into().unwrap();
+        let key_vec: Vec<Term> = keys_boxed_cons
+            .into_iter()
+            .map(|result| result.unwrap())
+            .collect();
+        let values_boxed_cons: Boxed<Cons> = values.try_into().unwrap();
+        let value_vec: Vec<Term> = values_boxed_cons
+            .into_iter()
+            .map(|result| result.unwrap())
+            .collect();
+
+        let mut zipped_vec: Vec<(Term, Term)> = key_vec.into_iter().zip(value_vec).collect();
+
+        assert!(zipped_vec
+            .windows(2)
+            .all(|window| window[0].0 < window[1].0));
+
+        zipped_vec.reverse();
+
+        assert_eq!(zipped_vec, entry_vec);
+    });
+}
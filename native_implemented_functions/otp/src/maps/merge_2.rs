@@ -18,15 +18,15 @@ pub fn native(process: &Process, map1: Term, map2: Term) -> exception::Result<Te
     let boxed_map1 = term_try_into_map_or_badmap!(process, map1)?;
     let boxed_map2 = term_try_into_map_or_badmap!(process, map2)?;
 
-    let mut merged: HashMap<Term, Term> =
+    let mut merged: HashMap<MapKey, Term> =
         HashMap::with_capacity(boxed_map1.len() + boxed_map2.len());
 
     for (key, value) in boxed_map1.iter() {
-        merged.insert(*key, *value);
+        merged.insert(MapKey(*key), *value);
     }
 
     for (key, value) in boxed_map2.iter() {
-        merged.insert(*key, *value);
+        merged.insert(MapKey(*key), *value);
     }
 
     process.map_from_hash_map(merged).map_err(From::from)
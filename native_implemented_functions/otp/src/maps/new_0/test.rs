@@ -0,0 +1,18 @@
+use liblumen_alloc::atom;
+
+use crate::maps::new_0::native;
+use crate::test::with_process;
+
+#[test]
+fn returns_empty_map() {
+    with_process(|process| {
+        let map = native(process).unwrap();
+
+        assert!(map.is_boxed_map());
+        assert_eq!(process.map_from_slice(&[]), Ok(map));
+        assert_eq!(
+            crate::maps::is_key_2::native(process, atom!("key"), map),
+            Ok(false.into())
+        );
+    });
+}
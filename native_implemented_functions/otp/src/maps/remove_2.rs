@@ -11,7 +11,7 @@ use liblumen_alloc::erts::term::prelude::*;
 
 use native_implemented_function::native_implemented_function;
 
-#[native_implemented_function(+/2)]
+#[native_implemented_function(remove/2)]
 pub fn native(process: &Process, key: Term, map: Term) -> exception::Result<Term> {
     let boxed_map = term_try_into_map_or_badmap!(process, map)?;
 
@@ -0,0 +1,52 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+
+use crate::maps::size_1::native;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_map_errors_badmap() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_map(arc_process.clone()),
+            )
+        },
+        |(arc_process, map)| {
+            prop_assert_badmap!(
+                native(&arc_process, map),
+                &arc_process,
+                map,
+                format!("map ({}) is not a map", map)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_map_returns_number_of_entries() {
+    with_process(|process| {
+        let map = process
+            .map_from_slice(&[
+                (atom!("a"), process.integer(1).unwrap()),
+                (atom!("b"), process.integer(2).unwrap()),
+                (atom!("c"), process.integer(3).unwrap()),
+            ])
+            .unwrap();
+
+        assert_eq!(native(process, map), Ok(process.integer(3).unwrap()));
+        assert_eq!(
+            crate::maps::is_key_2::native(process, atom!("b"), map),
+            Ok(true.into())
+        );
+        assert_eq!(
+            crate::maps::is_key_2::native(process, atom!("d"), map),
+            Ok(false.into())
+        );
+    });
+}
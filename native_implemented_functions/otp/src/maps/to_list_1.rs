@@ -0,0 +1,27 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+/// Entries are in the same sorted key order as `keys/1` and `values/1`.
+#[native_implemented_function(to_list/1)]
+pub fn native(process: &Process, map: Term) -> exception::Result<Term> {
+    let boxed_map = term_try_into_map_or_badmap!(process, map)?;
+    let mut entry_vec: Vec<Term> = Vec::with_capacity(boxed_map.len());
+
+    for (key, value) in boxed_map.iter_sorted() {
+        entry_vec.push(process.tuple_from_slice(&[key, value])?);
+    }
+
+    let list = process.list_from_slice(&entry_vec)?;
+
+    Ok(list)
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+
+#[test]
+fn without_entries_returns_empty_list() {
+    with_process_arc(|arc_process| {
+        let empty_map = arc_process.map_from_slice(&[]).unwrap();
+
+        assert_eq!(native(&arc_process, empty_map), Ok(Term::NIL));
+    });
+}
+
+#[test]
+fn with_entry_returns_list_of_key_value_tuple() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+            )
+        },
+        |(arc_process, key)| {
+            let value = atom!("value");
+            let map = arc_process.map_from_slice(&[(key, value)]).unwrap();
+            let tuple = arc_process.tuple_from_slice(&[key, value]).unwrap();
+            let list = arc_process.list_from_slice(&[tuple]).unwrap();
+
+            prop_assert_eq!(native(&arc_process, map), Ok(list));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_from_list_1_round_trips() {
+    with_process_arc(|arc_process| {
+        let map = arc_process
+            .map_from_slice(&[
+                (atom!("a"), arc_process.integer(1).unwrap()),
+                (arc_process.integer(2).unwrap(), atom!("b")),
+                (
+                    arc_process.tuple_from_slice(&[atom!("c")]).unwrap(),
+                    Term::NIL,
+                ),
+            ])
+            .unwrap();
+
+        let list = native(&arc_process, map).unwrap();
+
+        assert_eq!(
+            crate::maps::from_list_1::native(&arc_process, list),
+            Ok(map)
+        );
+    });
+}
@@ -0,0 +1,43 @@
+//! Mirrors [math](http://erlang.org/doc/man/math.html) module
+
+pub mod cos_1;
+pub mod exp_1;
+pub mod log_1;
+pub mod pow_2;
+pub mod sin_1;
+pub mod sqrt_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception::{self, *};
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_core::context::term_is_not_number;
+
+fn module() -> Atom {
+    Atom::try_from_str("math").unwrap()
+}
+
+/// Like C-BEAM, integer arguments are promoted to floats and non-numbers are `badarg`.
+fn number_to_f64(name: &'static str, number: Term) -> exception::Result<f64> {
+    number
+        .try_into()
+        .with_context(|| term_is_not_number(name, number))
+        .map_err(From::from)
+}
+
+/// Results outside the domain or range of the function are `NaN` or infinite, neither of which can
+/// be represented as an Erlang `float`, so they are `badarith` as in C-BEAM.
+fn f64_to_float<S>(process: &Process, f: f64, source: S) -> exception::Result<Term>
+where
+    S: FnOnce() -> String,
+{
+    if f.is_finite() {
+        process.float(f).map_err(From::from)
+    } else {
+        Err(badarith(anyhow!("{} is not a finite float", source()).into()).into())
+    }
+}
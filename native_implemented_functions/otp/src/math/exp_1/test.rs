@@ -0,0 +1,22 @@
+use crate::math::exp_1::native;
+use crate::test::with_process;
+
+#[test]
+fn without_number_errors_badarg() {
+    crate::test::without_number_errors_badarg(file!(), native);
+}
+
+#[test]
+fn with_zero_returns_one() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.integer(0).unwrap()),
+            Ok(process.float(1.0).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_overflow_errors_badarith() {
+    with_process(|process| assert_badarith!(native(process, process.float(1000.0).unwrap())));
+}
@@ -0,0 +1,27 @@
+use crate::math::log_1::native;
+use crate::test::with_process;
+
+#[test]
+fn without_number_errors_badarg() {
+    crate::test::without_number_errors_badarg(file!(), native);
+}
+
+#[test]
+fn with_one_returns_zero() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.float(1.0).unwrap()),
+            Ok(process.float(0.0).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_zero_errors_badarith() {
+    with_process(|process| assert_badarith!(native(process, process.float(0.0).unwrap())));
+}
+
+#[test]
+fn with_negative_errors_badarith() {
+    with_process(|process| assert_badarith!(native(process, process.integer(-1).unwrap())));
+}
@@ -0,0 +1,24 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::math::{f64_to_float, number_to_f64};
+
+#[native_implemented_function(pow/2)]
+pub fn native(process: &Process, x: Term, y: Term) -> exception::Result<Term> {
+    let x_f64 = number_to_f64("x", x)?;
+    let y_f64 = number_to_f64("y", y)?;
+
+    f64_to_float(process, x_f64.powf(y_f64), || {
+        format!("x ({}) raised to y ({})", x, y)
+    })
+}
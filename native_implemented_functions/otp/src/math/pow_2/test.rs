@@ -0,0 +1,65 @@
+use proptest::strategy::Just;
+
+use crate::math::pow_2::native;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_number_base_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_number(arc_process.clone()),
+                strategy::term::is_number(arc_process.clone()),
+            )
+        },
+        |(arc_process, x, y)| {
+            prop_assert_is_not_number!(native(&arc_process, x, y), x);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_number_exponent_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_number(arc_process.clone()),
+                strategy::term::is_not_number(arc_process.clone()),
+            )
+        },
+        |(arc_process, x, y)| {
+            prop_assert_is_not_number!(native(&arc_process, x, y), y);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_integers_returns_float() {
+    with_process(|process| {
+        assert_eq!(
+            native(
+                process,
+                process.integer(2).unwrap(),
+                process.integer(10).unwrap()
+            ),
+            Ok(process.float(1024.0).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_negative_base_and_fractional_exponent_errors_badarith() {
+    with_process(|process| {
+        assert_badarith!(native(
+            process,
+            process.float(-8.0).unwrap(),
+            process.float(0.5).unwrap()
+        ))
+    });
+}
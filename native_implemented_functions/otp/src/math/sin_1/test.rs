@@ -0,0 +1,17 @@
+use crate::math::sin_1::native;
+use crate::test::with_process;
+
+#[test]
+fn without_number_errors_badarg() {
+    crate::test::without_number_errors_badarg(file!(), native);
+}
+
+#[test]
+fn with_zero_returns_zero() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.float(0.0).unwrap()),
+            Ok(process.float(0.0).unwrap())
+        );
+    });
+}
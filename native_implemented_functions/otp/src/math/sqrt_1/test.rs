@@ -0,0 +1,32 @@
+use crate::math::sqrt_1::native;
+use crate::test::with_process;
+
+#[test]
+fn without_number_errors_badarg() {
+    crate::test::without_number_errors_badarg(file!(), native);
+}
+
+#[test]
+fn with_four_returns_two() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.float(4.0).unwrap()),
+            Ok(process.float(2.0).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_integer_promotes_to_float() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.integer(9).unwrap()),
+            Ok(process.float(3.0).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_negative_errors_badarith() {
+    with_process(|process| assert_badarith!(native(process, process.float(-1.0).unwrap())));
+}
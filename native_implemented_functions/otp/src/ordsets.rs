@@ -0,0 +1,114 @@
+//! Mirrors [ordsets](http://erlang.org/doc/man/ordsets.html) module
+//!
+//! Ordsets are proper lists sorted in term order without duplicates.  Like in OTP, elements are
+//! duplicates when they compare equal, so unlike in `sets`, `1` and `1.0` are the same element.
+
+pub mod add_element_2;
+pub mod from_list_1;
+pub mod intersection_2;
+pub mod is_element_2;
+pub mod new_0;
+pub mod subtract_2;
+pub mod union_2;
+
+use std::cmp::Ordering;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("ordsets").unwrap()
+}
+
+// Private
+
+/// Which elements `merge` keeps
+struct Keep {
+    /// Elements only in `left`
+    left: bool,
+    /// Elements in both `left` and `right`
+    both: bool,
+    /// Elements only in `right`
+    right: bool,
+}
+
+/// Merges the ordsets `left` and `right` into an ordset of the elements that `keep` says to keep.
+/// Elements in both come from `left`.
+fn merge(
+    process: &Process,
+    (left_name, left): (&str, Term),
+    (right_name, right): (&str, Term),
+    keep: Keep,
+) -> exception::Result<Term> {
+    let left_vec = proper_list_to_vec(left_name, left)?;
+    let right_vec = proper_list_to_vec(right_name, right)?;
+    let mut merged_vec = Vec::with_capacity(left_vec.len() + right_vec.len());
+    let mut left_iter = left_vec.into_iter().peekable();
+    let mut right_iter = right_vec.into_iter().peekable();
+
+    loop {
+        match (left_iter.peek(), right_iter.peek()) {
+            (Some(left_element), Some(right_element)) => match left_element.cmp(right_element) {
+                Ordering::Less => {
+                    let element = left_iter.next().unwrap();
+
+                    if keep.left {
+                        merged_vec.push(element);
+                    }
+                }
+                Ordering::Equal => {
+                    let element = left_iter.next().unwrap();
+                    right_iter.next();
+
+                    if keep.both {
+                        merged_vec.push(element);
+                    }
+                }
+                Ordering::Greater => {
+                    let element = right_iter.next().unwrap();
+
+                    if keep.right {
+                        merged_vec.push(element);
+                    }
+                }
+            },
+            (Some(_), None) => {
+                if keep.left {
+                    merged_vec.extend(left_iter);
+                }
+
+                break;
+            }
+            (None, Some(_)) => {
+                if keep.right {
+                    merged_vec.extend(right_iter);
+                }
+
+                break;
+            }
+            (None, None) => break,
+        }
+    }
+
+    process.list_from_slice(&merged_vec).map_err(From::from)
+}
+
+fn proper_list_to_vec(name: &str, list: Term) -> exception::Result<Vec<Term>> {
+    match list.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<std::result::Result<Vec<Term>, _>>()
+            .map_err(|_| {
+                anyhow!(ImproperListError)
+                    .context(format!("{} ({}) is not a proper list", name, list))
+                    .into()
+            }),
+        _ => Err(TypeError)
+            .context(format!("{} ({}) is not a proper list", name, list))
+            .map_err(From::from),
+    }
+}
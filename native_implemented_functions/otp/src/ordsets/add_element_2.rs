@@ -0,0 +1,30 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::ordsets::{merge, Keep};
+
+#[native_implemented_function(add_element/2)]
+pub fn native(process: &Process, element: Term, ordset1: Term) -> exception::Result<Term> {
+    let element_ordset = process.list_from_slice(&[element])?;
+
+    merge(
+        process,
+        ("ordset1", ordset1),
+        ("element", element_ordset),
+        Keep {
+            left: true,
+            both: true,
+            right: true,
+        },
+    )
+}
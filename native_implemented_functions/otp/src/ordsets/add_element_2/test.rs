@@ -0,0 +1,20 @@
+use liblumen_alloc::atom;
+
+use crate::ordsets::add_element_2::native;
+use crate::test::with_process;
+
+#[test]
+fn adds_element_in_order_once() {
+    with_process(|process| {
+        let ordset1 = process.list_from_slice(&[atom!("a"), atom!("c")]).unwrap();
+        let ordset2 = native(process, atom!("b"), ordset1).unwrap();
+
+        assert_eq!(
+            ordset2,
+            process
+                .list_from_slice(&[atom!("a"), atom!("b"), atom!("c")])
+                .unwrap()
+        );
+        assert_eq!(native(process, atom!("b"), ordset2), Ok(ordset2));
+    });
+}
@@ -0,0 +1,27 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::ordsets::proper_list_to_vec;
+
+/// Sorts `list` and removes duplicates, keeping the first of the elements that compare equal.
+#[native_implemented_function(from_list/1)]
+pub fn native(process: &Process, list: Term) -> exception::Result<Term> {
+    let mut element_vec = proper_list_to_vec("list", list)?;
+    // Stable, so the first of equal elements is first after sorting
+    element_vec.sort_by(|left, right| left.cmp(right));
+    element_vec.dedup_by(|right, left| left.cmp(&right) == Ordering::Equal);
+
+    process.list_from_slice(&element_vec).map_err(From::from)
+}
@@ -0,0 +1,48 @@
+use liblumen_alloc::atom;
+
+use crate::ordsets::from_list_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_list_returns_sorted_list_without_duplicates() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[atom!("c"), atom!("a"), atom!("b"), atom!("a"), atom!("c")])
+            .unwrap();
+
+        assert_eq!(
+            native(process, list),
+            Ok(process
+                .list_from_slice(&[atom!("a"), atom!("b"), atom!("c")])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_equal_elements_keeps_first() {
+    with_process(|process| {
+        let float = process.float(1.0).unwrap();
+        let integer = process.integer(1).unwrap();
+        let list = process.list_from_slice(&[float, integer]).unwrap();
+
+        assert_eq!(
+            native(process, list),
+            Ok(process.list_from_slice(&[float]).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_improper_list_errors_badarg() {
+    with_process(|process| {
+        let list = process
+            .improper_list_from_slice(&[atom!("a")], atom!("b"))
+            .unwrap();
+
+        assert_badarg!(
+            native(process, list),
+            format!("list ({}) is not a proper list", list)
+        );
+    });
+}
@@ -0,0 +1,29 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::ordsets::{merge, Keep};
+
+/// Elements in both `ordset1` and `ordset2`.
+#[native_implemented_function(intersection/2)]
+pub fn native(process: &Process, ordset1: Term, ordset2: Term) -> exception::Result<Term> {
+    merge(
+        process,
+        ("ordset1", ordset1),
+        ("ordset2", ordset2),
+        Keep {
+            left: false,
+            both: true,
+            right: false,
+        },
+    )
+}
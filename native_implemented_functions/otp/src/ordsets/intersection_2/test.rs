@@ -0,0 +1,27 @@
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ordsets::intersection_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_overlapping_ordsets_returns_sorted_ordset() {
+    with_process(|process| {
+        let ordset1 = integers(process, &[1, 3, 5, 7]);
+        let ordset2 = integers(process, &[2, 3, 4, 7, 8]);
+
+        assert_eq!(
+            native(process, ordset1, ordset2),
+            Ok(integers(process, &[3, 7]))
+        );
+    });
+}
+
+fn integers(process: &Process, integers: &[isize]) -> Term {
+    let integer_vec: Vec<Term> = integers
+        .iter()
+        .map(|integer| process.integer(*integer).unwrap())
+        .collect();
+
+    process.list_from_slice(&integer_vec).unwrap()
+}
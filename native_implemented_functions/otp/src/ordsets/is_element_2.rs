@@ -0,0 +1,44 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+/// Stops at the first element that is not less than `element`, since `ordset` is sorted.
+#[native_implemented_function(is_element/2)]
+pub fn native(element: Term, ordset: Term) -> exception::Result<Term> {
+    match ordset.decode()? {
+        TypedTerm::Nil => Ok(false.into()),
+        TypedTerm::List(cons) => {
+            for result in cons.into_iter() {
+                match result {
+                    Ok(ordset_element) => match ordset_element.cmp(&element) {
+                        Ordering::Less => continue,
+                        Ordering::Equal => return Ok(true.into()),
+                        Ordering::Greater => return Ok(false.into()),
+                    },
+                    Err(_) => {
+                        return Err(ImproperListError)
+                            .context(format!("ordset ({}) is not a proper list", ordset))
+                            .map_err(From::from)
+                    }
+                }
+            }
+
+            Ok(false.into())
+        }
+        _ => Err(TypeError)
+            .context(format!("ordset ({}) is not a proper list", ordset))
+            .map_err(From::from),
+    }
+}
@@ -0,0 +1,15 @@
+use liblumen_alloc::atom;
+
+use crate::ordsets::is_element_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_element_returns_true_and_without_returns_false() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[atom!("a"), atom!("c")]).unwrap();
+
+        assert_eq!(native(atom!("a"), ordset), Ok(true.into()));
+        assert_eq!(native(atom!("b"), ordset), Ok(false.into()));
+        assert_eq!(native(atom!("d"), ordset), Ok(false.into()));
+    });
+}
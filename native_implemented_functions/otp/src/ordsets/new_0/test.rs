@@ -0,0 +1,8 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ordsets::new_0::native;
+
+#[test]
+fn returns_empty_list() {
+    assert_eq!(native(), Term::NIL);
+}
@@ -0,0 +1,13 @@
+//! Mirrors [os](http://erlang.org/doc/man/os.html) module
+
+mod env;
+pub mod getenv_1;
+pub mod getenv_2;
+pub mod putenv_2;
+pub mod timestamp_0;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("os").unwrap()
+}
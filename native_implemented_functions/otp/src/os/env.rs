@@ -0,0 +1,49 @@
+use std::env;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+
+/// Returns the value of the environment variable `name`, which is `None` if it is not set or
+/// `name` could never be set.
+pub fn get(name: &str) -> Option<String> {
+    if is_valid_name(name) {
+        env::var_os(name).map(|value| value.to_string_lossy().into_owned())
+    } else {
+        None
+    }
+}
+
+/// Whether `name` can be set, as `std::env::set_var` panics on empty names and names containing
+/// `=` or NUL.
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains('=') && !name.contains('\0')
+}
+
+/// Sets the environment variable `name` to `value`, which must not contain NUL, and `name` must
+/// be valid.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set(name: &str, value: &str) -> exception::Result<()> {
+    env::set_var(name, value);
+
+    Ok(())
+}
+
+/// wasm32 has no environment to set `name` in, and `std::env::set_var` panics there.
+#[cfg(target_arch = "wasm32")]
+pub fn set(name: &str, _value: &str) -> exception::Result<()> {
+    Err(anyhow!(
+        "name ({}) cannot be set as there is no environment on wasm32",
+        name
+    )
+    .into())
+}
+
+pub fn term_try_into_string(name: &str, term: Term) -> exception::Result<String> {
+    list_to_string(term)
+        .map_err(|_| anyhow!("{} ({}) is not a string", name, term))
+        .map_err(From::from)
+}
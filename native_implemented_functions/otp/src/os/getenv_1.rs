@@ -0,0 +1,20 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::os::getenv_2;
+
+/// Returns the value of the environment variable `name` as a string or `false` if it is not set.
+#[native_implemented_function(getenv/1)]
+pub fn native(process: &Process, name: Term) -> exception::Result<Term> {
+    getenv_2::native(process, name, false.into())
+}
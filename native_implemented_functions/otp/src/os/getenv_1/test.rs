@@ -0,0 +1,36 @@
+use crate::os::getenv_1::native;
+use crate::test::with_process;
+
+#[test]
+fn without_string_errors_badarg() {
+    with_process(|process| {
+        let name = process.integer(1).unwrap();
+
+        assert_badarg!(native(process, name), "name (1) is not a string");
+    });
+}
+
+#[test]
+fn without_set_variable_returns_false() {
+    with_process(|process| {
+        let name = process
+            .charlist_from_str("LUMEN_OTP_OS_GETENV_1_UNSET")
+            .unwrap();
+
+        assert_eq!(native(process, name), Ok(false.into()));
+    });
+}
+
+#[test]
+fn with_invalid_name_returns_false() {
+    with_process(|process| {
+        assert_eq!(
+            native(process, process.charlist_from_str("").unwrap()),
+            Ok(false.into())
+        );
+        assert_eq!(
+            native(process, process.charlist_from_str("A=B").unwrap()),
+            Ok(false.into())
+        );
+    });
+}
@@ -0,0 +1,26 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::os::env;
+
+/// Returns the value of the environment variable `name` as a string or `default` if it is not
+/// set.
+#[native_implemented_function(getenv/2)]
+pub fn native(process: &Process, name: Term, default: Term) -> exception::Result<Term> {
+    let name_string = env::term_try_into_string("name", name)?;
+
+    match env::get(&name_string) {
+        Some(value) => process.charlist_from_str(&value).map_err(From::from),
+        None => Ok(default),
+    }
+}
@@ -0,0 +1,33 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::os::getenv_2::native;
+use crate::os::putenv_2;
+use crate::test::with_process;
+
+#[test]
+fn without_set_variable_returns_default() {
+    with_process(|process| {
+        let name = process
+            .charlist_from_str("LUMEN_OTP_OS_GETENV_2_UNSET")
+            .unwrap();
+        let default = Atom::str_to_term("default");
+
+        assert_eq!(native(process, name, default), Ok(default));
+    });
+}
+
+#[test]
+fn with_set_variable_returns_value_as_charlist() {
+    with_process(|process| {
+        let name = process
+            .charlist_from_str("LUMEN_OTP_OS_GETENV_2_SET")
+            .unwrap();
+        let value = process.charlist_from_str("value").unwrap();
+
+        assert_eq!(putenv_2::native(name, value), Ok(true.into()));
+        assert_eq!(
+            native(process, name, Atom::str_to_term("default")),
+            Ok(value)
+        );
+    });
+}
@@ -0,0 +1,36 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::os::env;
+
+/// Sets the environment variable `name` to `value` and returns `true`.  On wasm32, there is no
+/// environment, so it errors instead.
+#[native_implemented_function(putenv/2)]
+pub fn native(name: Term, value: Term) -> exception::Result<Term> {
+    let name_string = env::term_try_into_string("name", name)?;
+
+    if !env::is_valid_name(&name_string) {
+        return Err(anyhow!("name ({}) is empty or contains `=` or NUL", name).into());
+    }
+
+    let value_string = env::term_try_into_string("value", value)?;
+
+    if value_string.contains('\0') {
+        return Err(anyhow!("value ({}) contains NUL", value).into());
+    }
+
+    env::set(&name_string, &value_string)?;
+
+    Ok(true.into())
+}
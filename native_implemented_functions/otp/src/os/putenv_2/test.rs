@@ -0,0 +1,49 @@
+use crate::os::getenv_1;
+use crate::os::putenv_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_invalid_name_errors_badarg() {
+    with_process(|process| {
+        let value = process.charlist_from_str("value").unwrap();
+
+        assert_badarg!(
+            native(process.charlist_from_str("").unwrap(), value),
+            "is empty or contains `=` or NUL"
+        );
+        assert_badarg!(
+            native(process.charlist_from_str("A=B").unwrap(), value),
+            "is empty or contains `=` or NUL"
+        );
+    });
+}
+
+#[test]
+fn without_string_value_errors_badarg() {
+    with_process(|process| {
+        let name = process
+            .charlist_from_str("LUMEN_OTP_OS_PUTENV_2_INVALID_VALUE")
+            .unwrap();
+        let value = process.integer(1).unwrap();
+
+        assert_badarg!(native(name, value), "value (1) is not a string");
+    });
+}
+
+#[test]
+fn round_trips_through_getenv() {
+    with_process(|process| {
+        let name = process
+            .charlist_from_str("LUMEN_OTP_OS_PUTENV_2_ROUND_TRIP")
+            .unwrap();
+        let first_value = process.charlist_from_str("first").unwrap();
+
+        assert_eq!(native(name, first_value), Ok(true.into()));
+        assert_eq!(getenv_1::native(process, name), Ok(first_value));
+
+        let second_value = process.charlist_from_str("sëcond").unwrap();
+
+        assert_eq!(native(name, second_value), Ok(true.into()));
+        assert_eq!(getenv_1::native(process, name), Ok(second_value));
+    });
+}
@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang;
+
+/// `os:timestamp/0` is the same as `erlang:timestamp/0`, as neither is corrected for time warps.
+#[native_implemented_function(timestamp/0)]
+pub fn native(process: &Process) -> exception::Result<Term> {
+    erlang::timestamp_0::native(process)
+}
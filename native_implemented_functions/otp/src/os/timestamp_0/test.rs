@@ -0,0 +1,29 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::system_time_1;
+use crate::os::timestamp_0::native;
+use crate::test::with_process;
+
+#[test]
+fn megaseconds_and_seconds_are_seconds_since_epoch() {
+    with_process(|process| {
+        let before_term = system_time_1::native(process, Atom::str_to_term("second")).unwrap();
+        let timestamp = native(process).unwrap();
+        let after_term = system_time_1::native(process, Atom::str_to_term("second")).unwrap();
+
+        let timestamp_tuple: Boxed<Tuple> = timestamp.try_into().unwrap();
+        let megaseconds: u64 = timestamp_tuple.get_element(0).unwrap().try_into().unwrap();
+        let seconds: u64 = timestamp_tuple.get_element(1).unwrap().try_into().unwrap();
+        let microseconds: u64 = timestamp_tuple.get_element(2).unwrap().try_into().unwrap();
+
+        let before: u64 = before_term.try_into().unwrap();
+        let after: u64 = after_term.try_into().unwrap();
+        let timestamp_seconds = megaseconds * 1_000_000 + seconds;
+
+        assert!(seconds < 1_000_000);
+        assert!(microseconds < 1_000_000);
+        assert!(before <= timestamp_seconds && timestamp_seconds <= after);
+    });
+}
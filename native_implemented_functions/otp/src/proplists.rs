@@ -0,0 +1,58 @@
+//! Mirrors [proplists](http://erlang.org/doc/man/proplists.html) module
+//!
+//! Properties are either `{Key, Value}` tuples or atoms, where an atom `Key` is shorthand for
+//! `{Key, true}`.
+
+pub mod get_value_2;
+pub mod get_value_3;
+pub mod lookup_2;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("proplists").unwrap()
+}
+
+// Private
+
+/// The first element of `list` that is the atom `key` or a tuple whose first element is exactly
+/// equal (`=:=`) to `key`.  Like OTP, other elements are skipped.
+fn lookup(key: Term, list: Term) -> exception::Result<Option<Term>> {
+    match list.decode()? {
+        TypedTerm::Nil => Ok(None),
+        TypedTerm::List(cons) => {
+            let typed_key = key.decode()?;
+
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => {
+                        let found = match element.decode()? {
+                            TypedTerm::Atom(_) => element == key,
+                            TypedTerm::Tuple(tuple) if 1 <= tuple.len() => {
+                                tuple[0].decode()?.exact_eq(&typed_key)
+                            }
+                            _ => false,
+                        };
+
+                        if found {
+                            return Ok(Some(element));
+                        }
+                    }
+                    Err(_) => {
+                        return Err(ImproperListError)
+                            .context(format!("list ({}) is not a proper list", list))
+                            .map_err(From::from)
+                    }
+                }
+            }
+
+            Ok(None)
+        }
+        _ => Err(TypeError)
+            .context(format!("list ({}) is not a proper list", list))
+            .map_err(From::from),
+    }
+}
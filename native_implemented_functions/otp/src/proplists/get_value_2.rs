@@ -0,0 +1,20 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::proplists::get_value_3;
+
+/// Like `get_value/3` with a `default` of `undefined`.
+#[native_implemented_function(get_value/2)]
+pub fn native(key: Term, list: Term) -> exception::Result<Term> {
+    get_value_3::native(key, list, atom!("undefined"))
+}
@@ -0,0 +1,60 @@
+use liblumen_alloc::atom;
+
+use crate::proplists::get_value_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_shorthand_atom_returns_true() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[
+                process
+                    .tuple_from_slice(&[atom!("other"), atom!("value")])
+                    .unwrap(),
+                atom!("key"),
+            ])
+            .unwrap();
+
+        assert_eq!(native(atom!("key"), list), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_first_tuple_for_key_returns_its_value() {
+    with_process(|process| {
+        let first = process.integer(1).unwrap();
+        let list = process
+            .list_from_slice(&[
+                process.tuple_from_slice(&[atom!("key"), first]).unwrap(),
+                process
+                    .tuple_from_slice(&[atom!("key"), process.integer(2).unwrap()])
+                    .unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(native(atom!("key"), list), Ok(first));
+    });
+}
+
+#[test]
+fn without_key_returns_undefined() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("other")]).unwrap();
+
+        assert_eq!(native(atom!("key"), list), Ok(atom!("undefined")));
+    });
+}
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    with_process(|process| {
+        let list = process
+            .improper_list_from_slice(&[atom!("other")], atom!("tail"))
+            .unwrap();
+
+        assert_badarg!(
+            native(atom!("key"), list),
+            format!("list ({}) is not a proper list", list)
+        );
+    });
+}
@@ -0,0 +1,29 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::proplists::lookup;
+
+/// The value of the first property for `key` in `list`, or `default` if there is none or the
+/// first one is a tuple that is not `{Key, Value}`.
+#[native_implemented_function(get_value/3)]
+pub fn native(key: Term, list: Term, default: Term) -> exception::Result<Term> {
+    let value = match lookup(key, list)? {
+        Some(property) => match property.decode()? {
+            TypedTerm::Atom(_) => true.into(),
+            TypedTerm::Tuple(tuple) if tuple.len() == 2 => tuple[1],
+            _ => default,
+        },
+        None => default,
+    };
+
+    Ok(value)
+}
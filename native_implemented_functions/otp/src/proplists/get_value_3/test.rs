@@ -0,0 +1,49 @@
+use liblumen_alloc::atom;
+
+use crate::proplists::get_value_3::native;
+use crate::test::with_process;
+
+#[test]
+fn without_key_returns_default() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[process
+                .tuple_from_slice(&[atom!("other"), atom!("value")])
+                .unwrap()])
+            .unwrap();
+        let default = process.integer(0).unwrap();
+
+        assert_eq!(native(atom!("key"), list, default), Ok(default));
+    });
+}
+
+#[test]
+fn with_tuple_that_is_not_key_value_returns_default() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[process
+                .tuple_from_slice(&[atom!("key"), atom!("one"), atom!("two")])
+                .unwrap()])
+            .unwrap();
+        let default = process.integer(0).unwrap();
+
+        assert_eq!(native(atom!("key"), list, default), Ok(default));
+    });
+}
+
+#[test]
+fn with_key_that_is_only_equal_returns_default() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[process
+                .tuple_from_slice(&[process.float(1.0).unwrap(), atom!("value")])
+                .unwrap()])
+            .unwrap();
+        let default = process.integer(0).unwrap();
+
+        assert_eq!(
+            native(process.integer(1).unwrap(), list, default),
+            Ok(default)
+        );
+    });
+}
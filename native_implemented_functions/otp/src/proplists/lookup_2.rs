@@ -0,0 +1,27 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::proplists::lookup;
+
+/// The first property for `key` in `list` as a tuple, or `none` if there is none.
+#[native_implemented_function(lookup/2)]
+pub fn native(process: &Process, key: Term, list: Term) -> exception::Result<Term> {
+    match lookup(key, list)? {
+        Some(property) if property.is_atom() => process
+            .tuple_from_slice(&[property, true.into()])
+            .map_err(From::from),
+        Some(property) => Ok(property),
+        None => Ok(atom!("none")),
+    }
+}
@@ -0,0 +1,39 @@
+use liblumen_alloc::atom;
+
+use crate::proplists::lookup_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_shorthand_atom_returns_key_true_tuple() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("key")]).unwrap();
+
+        assert_eq!(
+            native(process, atom!("key"), list),
+            Ok(process
+                .tuple_from_slice(&[atom!("key"), true.into()])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_tuple_returns_tuple() {
+    with_process(|process| {
+        let tuple = process
+            .tuple_from_slice(&[atom!("key"), atom!("one"), atom!("two")])
+            .unwrap();
+        let list = process.list_from_slice(&[tuple]).unwrap();
+
+        assert_eq!(native(process, atom!("key"), list), Ok(tuple));
+    });
+}
+
+#[test]
+fn without_key_returns_none() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("other")]).unwrap();
+
+        assert_eq!(native(process, atom!("key"), list), Ok(atom!("none")));
+    });
+}
@@ -0,0 +1,64 @@
+//! Mirrors [queue](http://erlang.org/doc/man/queue.html) module
+//!
+//! Queues are `{Rear, Front}` like in OTP, so they can be passed to and from Erlang code that uses
+//! `queue`.  Items are added to the head of `Rear` and removed from the head of `Front`.  `Rear` is
+//! only reversed into `Front` when `Front` is empty, so each item is reversed at most once and
+//! `in/2` and `out/1` are amortized O(1).
+
+pub mod in_2;
+pub mod new_0;
+pub mod out_1;
+pub mod to_list_1;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("queue").unwrap()
+}
+
+// Private
+
+fn from_rear_front(process: &Process, rear: Term, front: Term) -> exception::Result<Term> {
+    process.tuple_from_slice(&[rear, front]).map_err(From::from)
+}
+
+fn not_a_queue<T>(queue: Term) -> exception::Result<T> {
+    Err(TypeError)
+        .context(format!("queue ({}) is not a queue", queue))
+        .map_err(From::from)
+}
+
+/// Only checks that `Rear` and `Front` are lists, so that operations stay O(1).  They are checked
+/// to be proper lists when they are walked.
+fn rear_front(queue: Term) -> exception::Result<(Term, Term)> {
+    match queue.decode()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 && tuple[0].is_list() && tuple[1].is_list() => {
+            Ok((tuple[0], tuple[1]))
+        }
+        _ => not_a_queue(queue),
+    }
+}
+
+/// Reverses the elements of `list` onto `tail`
+fn reverse(process: &Process, queue: Term, list: Term, tail: Term) -> exception::Result<Term> {
+    let mut reversed = tail;
+
+    match list.decode()? {
+        TypedTerm::Nil => (),
+        TypedTerm::List(cons) => {
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => reversed = process.cons(element, reversed)?,
+                    Err(_) => return not_a_queue(queue),
+                }
+            }
+        }
+        _ => return not_a_queue(queue),
+    }
+
+    Ok(reversed)
+}
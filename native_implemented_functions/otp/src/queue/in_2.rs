@@ -0,0 +1,23 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::queue::{from_rear_front, rear_front};
+
+/// Adds `item` to the rear of `q1`.
+#[native_implemented_function(in/2)]
+pub fn native(process: &Process, item: Term, q1: Term) -> exception::Result<Term> {
+    let (rear, front) = rear_front(q1)?;
+    let rear_with_item = process.cons(item, rear)?;
+
+    from_rear_front(process, rear_with_item, front)
+}
@@ -0,0 +1,47 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::in_2::native;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_queue_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_not_tuple(arc_process),
+            )
+        },
+        |(arc_process, item, q1)| {
+            prop_assert_badarg!(
+                native(&arc_process, item, q1),
+                format!("queue ({}) is not a queue", q1)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_queue_adds_item_to_head_of_rear() {
+    with_process(|process| {
+        let front = process
+            .list_from_slice(&[process.integer(1).unwrap()])
+            .unwrap();
+        let q1 = process.tuple_from_slice(&[Term::NIL, front]).unwrap();
+        let item = process.integer(2).unwrap();
+
+        assert_eq!(
+            native(process, item, q1),
+            Ok(process
+                .tuple_from_slice(&[process.list_from_slice(&[item]).unwrap(), front])
+                .unwrap())
+        );
+    });
+}
@@ -0,0 +1,20 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::new_0::native;
+use crate::test::with_process;
+
+#[test]
+fn returns_empty_queue() {
+    with_process(|process| {
+        let queue = native(process).unwrap();
+
+        assert_eq!(
+            queue,
+            process.tuple_from_slice(&[Term::NIL, Term::NIL]).unwrap()
+        );
+        assert_eq!(
+            crate::queue::to_list_1::native(process, queue),
+            Ok(Term::NIL)
+        );
+    });
+}
@@ -0,0 +1,43 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::queue::{from_rear_front, not_a_queue, rear_front, reverse};
+
+/// Removes the item at the front of `q1`, returning `{{value, Item}, Q2}`, or `{empty, Q1}` when
+/// `q1` is empty.
+#[native_implemented_function(out/1)]
+pub fn native(process: &Process, q1: Term) -> exception::Result<Term> {
+    let (rear, front) = rear_front(q1)?;
+
+    let (rear, front) = if front.is_nil() {
+        if rear.is_nil() {
+            return process
+                .tuple_from_slice(&[atom!("empty"), q1])
+                .map_err(From::from);
+        }
+
+        (Term::NIL, reverse(process, q1, rear, Term::NIL)?)
+    } else {
+        (rear, front)
+    };
+
+    let cons: Boxed<Cons> = match front.decode()? {
+        TypedTerm::List(cons) => cons,
+        _ => return not_a_queue(q1),
+    };
+    let value = process.tuple_from_slice(&[atom!("value"), cons.head])?;
+    let q2 = from_rear_front(process, rear, cons.tail)?;
+
+    process.tuple_from_slice(&[value, q2]).map_err(From::from)
+}
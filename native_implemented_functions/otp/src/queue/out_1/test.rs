@@ -0,0 +1,72 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::out_1::native;
+use crate::queue::{in_2, new_0};
+use crate::test::with_process;
+
+#[test]
+fn with_empty_queue_returns_empty_and_queue() {
+    with_process(|process| {
+        let q1 = new_0::native(process).unwrap();
+
+        assert_eq!(
+            native(process, q1),
+            Ok(process.tuple_from_slice(&[atom!("empty"), q1]).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_items_in_and_out_returns_them_in_first_in_first_out_order() {
+    with_process(|process| {
+        let mut queue = new_0::native(process).unwrap();
+
+        for i in 1..=3 {
+            queue = in_2::native(process, process.integer(i).unwrap(), queue).unwrap();
+        }
+
+        // Reverses the rear of [3, 2, 1] into the front
+        queue = assert_out(process, queue, 1);
+        queue = in_2::native(process, process.integer(4).unwrap(), queue).unwrap();
+        queue = assert_out(process, queue, 2);
+        queue = assert_out(process, queue, 3);
+        // Reverses the rear of [4] into the front
+        queue = assert_out(process, queue, 4);
+
+        assert_eq!(
+            native(process, queue),
+            Ok(process.tuple_from_slice(&[atom!("empty"), queue]).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_improper_rear_errors_badarg() {
+    with_process(|process| {
+        let rear = process
+            .improper_list_from_slice(&[process.integer(1).unwrap()], process.integer(2).unwrap())
+            .unwrap();
+        let q1 = process.tuple_from_slice(&[rear, Term::NIL]).unwrap();
+
+        assert_badarg!(
+            native(process, q1),
+            format!("queue ({}) is not a queue", q1)
+        );
+    });
+}
+
+fn assert_out(process: &Process, queue: Term, expected: isize) -> Term {
+    let result = native(process, queue).unwrap();
+    let result_tuple: Boxed<Tuple> = result.try_into().unwrap();
+    let value = process
+        .tuple_from_slice(&[atom!("value"), process.integer(expected).unwrap()])
+        .unwrap();
+
+    assert_eq!(result_tuple[0], value);
+
+    result_tuple[1]
+}
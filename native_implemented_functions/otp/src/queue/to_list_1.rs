@@ -0,0 +1,24 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::queue::{rear_front, reverse};
+
+/// The items in `q` from front to rear.
+#[native_implemented_function(to_list/1)]
+pub fn native(process: &Process, q: Term) -> exception::Result<Term> {
+    let (rear, front) = rear_front(q)?;
+    let reversed_rear = reverse(process, q, rear, Term::NIL)?;
+    let reversed_front = reverse(process, q, front, Term::NIL)?;
+
+    reverse(process, q, reversed_front, reversed_rear)
+}
@@ -0,0 +1,32 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::to_list_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_front_and_rear_returns_items_from_front_to_rear() {
+    with_process(|process| {
+        let integers: Vec<Term> = (1..=4).map(|i| process.integer(i).unwrap()).collect();
+        let rear = process
+            .list_from_slice(&[integers[3], integers[2]])
+            .unwrap();
+        let front = process
+            .list_from_slice(&[integers[0], integers[1]])
+            .unwrap();
+        let q = process.tuple_from_slice(&[rear, front]).unwrap();
+
+        assert_eq!(
+            native(process, q),
+            Ok(process.list_from_slice(&integers).unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_queue_errors_badarg() {
+    with_process(|process| {
+        let q = process.list_from_slice(&[Term::NIL, Term::NIL]).unwrap();
+
+        assert_badarg!(native(process, q), format!("queue ({}) is not a queue", q));
+    });
+}
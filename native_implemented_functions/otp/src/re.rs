@@ -0,0 +1,148 @@
+//! Mirrors [re](http://erlang.org/doc/man/re.html) module
+//!
+//! Patterns are matched with a Thompson NFA instead of PCRE, so matching takes linear time, but
+//! backreferences and lookaround are not supported.  Like in OTP, without the `unicode` option,
+//! patterns and subjects are Latin-1, so each byte of a binary is a character, and capture indices
+//! are byte offsets.
+
+pub mod compile_1;
+pub mod compile_2;
+mod pattern;
+pub mod run_2;
+pub mod run_3;
+
+use std::convert::TryInto;
+use std::str;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+
+use pattern::{CompileError, CompileOptions, Pattern};
+
+fn module() -> Atom {
+    Atom::try_from_str("re").unwrap()
+}
+
+// Private
+
+/// A subject or pattern as characters, with the byte offset of each character, and of the end, in
+/// `bytes`, which capture indices and binaries are taken from.
+struct Characters {
+    chars: Vec<char>,
+    byte_offsets: Vec<usize>,
+    bytes: Vec<u8>,
+}
+
+fn characters_try_from(
+    process: &Process,
+    name: &str,
+    term: Term,
+    unicode: bool,
+) -> exception::Result<Characters> {
+    let (chars, bytes) = if term.is_binary() {
+        let bytes = process
+            .bytes_from_binary(term)
+            .with_context(|| format!("{} ({}) is not a binary or charlist", name, term))?
+            .to_vec();
+        let chars = if unicode {
+            str::from_utf8(&bytes)
+                .with_context(|| format!("{} ({}) is not UTF-8", name, term))?
+                .chars()
+                .collect()
+        } else {
+            bytes.iter().map(|byte| *byte as char).collect()
+        };
+
+        (chars, bytes)
+    } else if term.is_list() {
+        let s = list_to_string(term)?;
+        let chars: Vec<char> = s.chars().collect();
+        let bytes = if unicode {
+            s.into_bytes()
+        } else if chars.iter().all(|c| (*c as u32) <= 0xFF) {
+            chars.iter().map(|c| *c as u8).collect()
+        } else {
+            return Err(anyhow!(
+                "{} ({}) has characters that are not Latin-1 without the unicode option",
+                name,
+                term
+            )
+            .into());
+        };
+
+        (chars, bytes)
+    } else {
+        return Err(TypeError)
+            .context(format!("{} ({}) is not a binary or charlist", name, term))
+            .map_err(From::from);
+    };
+
+    let mut byte_offsets = Vec::with_capacity(chars.len() + 1);
+    let mut byte_offset = 0;
+
+    for c in &chars {
+        byte_offsets.push(byte_offset);
+        byte_offset += if unicode { c.len_utf8() } else { 1 };
+    }
+
+    byte_offsets.push(byte_offset);
+
+    Ok(Characters {
+        chars,
+        byte_offsets,
+        bytes,
+    })
+}
+
+fn compile(
+    process: &Process,
+    regexp: Term,
+    options: CompileOptions,
+) -> exception::Result<Result<Pattern, CompileError>> {
+    let source = characters_try_from(process, "regexp", regexp, options.unicode)?;
+
+    Ok(Pattern::compile(&source.chars, options))
+}
+
+/// Sets the compile option named `name` in `options`, returning `false` if it is not a compile
+/// option
+fn compile_option_set(options: &mut CompileOptions, name: &str) -> bool {
+    match name {
+        "anchored" => options.anchored = true,
+        "caseless" => options.caseless = true,
+        "dotall" => options.dotall = true,
+        "multiline" => options.multiline = true,
+        "unicode" => options.unicode = true,
+        _ => return false,
+    }
+
+    true
+}
+
+/// `{re_pattern, Resource}`, so that the compiled `pattern` is opaque
+fn compiled_to_term(process: &Process, pattern: Pattern) -> exception::Result<Term> {
+    let resource = process.resource(Box::new(pattern))?;
+
+    process
+        .tuple_from_slice(&[atom!("re_pattern"), resource])
+        .map_err(From::from)
+}
+
+/// The resource in `regexp` if it is the `{re_pattern, Resource}` returned by `compile`.  The
+/// `Pattern` is downcast from the resource where it is used, so that it is borrowed for as long as
+/// the resource instead of cloned for every run.
+fn compiled_resource_try_from(regexp: Term) -> Option<Boxed<Resource>> {
+    let tuple: Boxed<Tuple> = regexp.try_into().ok()?;
+
+    if tuple.len() == 2 && tuple[0] == atom!("re_pattern") {
+        tuple[1].try_into().ok()
+    } else {
+        None
+    }
+}
@@ -0,0 +1,72 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::{compile_1::native, run_2};
+use crate::test::with_process;
+
+#[test]
+fn with_valid_regexp_returns_ok_compiled_regexp_that_can_be_run() {
+    with_process(|process| {
+        let regexp = process.charlist_from_str("b+").unwrap();
+        let ok_compiled: Boxed<Tuple> = native(process, regexp).unwrap().try_into().unwrap();
+
+        assert_eq!(ok_compiled[0], atom!("ok"));
+
+        let subject = process.binary_from_str("abbc").unwrap();
+
+        assert_eq!(
+            run_2::native(process, subject, ok_compiled[1]),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("match"),
+                    process
+                        .list_from_slice(&[process
+                            .tuple_from_slice(&[
+                                process.integer(1).unwrap(),
+                                process.integer(2).unwrap()
+                            ])
+                            .unwrap()])
+                        .unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_missing_close_parenthesis_returns_error_at_end() {
+    with_process(|process| {
+        let regexp = process.charlist_from_str("(ab").unwrap();
+
+        assert_eq!(native(process, regexp), Ok(error(process, "missing )", 3)));
+    });
+}
+
+#[test]
+fn with_leading_quantifier_returns_error_at_quantifier() {
+    with_process(|process| {
+        let regexp = process.charlist_from_str("*a").unwrap();
+
+        assert_eq!(
+            native(process, regexp),
+            Ok(error(process, "nothing to repeat", 0))
+        );
+    });
+}
+
+fn error(process: &Process, message: &str, position: usize) -> Term {
+    process
+        .tuple_from_slice(&[
+            atom!("error"),
+            process
+                .tuple_from_slice(&[
+                    process.charlist_from_str(message).unwrap(),
+                    process.integer(position).unwrap(),
+                ])
+                .unwrap(),
+        ])
+        .unwrap()
+}
@@ -0,0 +1,79 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::re::pattern::CompileOptions;
+use crate::re::{compile, compile_option_set, compiled_to_term};
+
+/// `{ok, MP}` with the compiled `regexp`, or `{error, {ErrString, Position}}` if `regexp` is
+/// invalid.  `options` can be `anchored`, `caseless`, `dotall`, `multiline`, and `unicode`.
+#[native_implemented_function(compile/2)]
+pub fn native(process: &Process, regexp: Term, options: Term) -> exception::Result<Term> {
+    let compile_options = compile_options_try_from(options)?;
+
+    match compile(process, regexp, compile_options)? {
+        Ok(pattern) => {
+            let compiled = compiled_to_term(process, pattern)?;
+
+            process
+                .tuple_from_slice(&[atom!("ok"), compiled])
+                .map_err(From::from)
+        }
+        Err(error) => {
+            let reason = process.tuple_from_slice(&[
+                process.charlist_from_str(error.message)?,
+                process.integer(error.position)?,
+            ])?;
+
+            process
+                .tuple_from_slice(&[atom!("error"), reason])
+                .map_err(From::from)
+        }
+    }
+}
+
+fn compile_options_try_from(options: Term) -> exception::Result<CompileOptions> {
+    let mut compile_options: CompileOptions = Default::default();
+
+    match options.decode()? {
+        TypedTerm::Nil => (),
+        TypedTerm::List(cons) => {
+            for result in cons.into_iter() {
+                let option = result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("options ({}) is not a proper list", options))?;
+                let option_atom: Option<Atom> = option.try_into().ok();
+                let is_set = option_atom.map_or(false, |atom| {
+                    compile_option_set(&mut compile_options, atom.name())
+                });
+
+                if !is_set {
+                    return Err(
+                        anyhow!("option ({}) is not a supported compile option", option).into(),
+                    );
+                }
+            }
+        }
+        _ => {
+            return Err(TypeError)
+                .context(format!("options ({}) is not a proper list", options))
+                .map_err(From::from)
+        }
+    }
+
+    Ok(compile_options)
+}
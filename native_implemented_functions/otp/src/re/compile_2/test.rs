@@ -0,0 +1,114 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::{compile_2::native, run_2};
+use crate::test::with_process;
+
+#[test]
+fn with_caseless_compiled_regexp_ignores_case() {
+    with_process(|process| {
+        let regexp = process.charlist_from_str("abc").unwrap();
+        let options = process.list_from_slice(&[atom!("caseless")]).unwrap();
+        let ok_compiled: Boxed<Tuple> = native(process, regexp, options)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let subject = process.binary_from_str("xABC").unwrap();
+
+        assert_eq!(
+            run_2::native(process, subject, ok_compiled[1]),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("match"),
+                    process
+                        .list_from_slice(&[process
+                            .tuple_from_slice(&[
+                                process.integer(1).unwrap(),
+                                process.integer(3).unwrap()
+                            ])
+                            .unwrap()])
+                        .unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_backreference_returns_error() {
+    with_process(|process| {
+        let regexp = process.charlist_from_str("(a)\\1").unwrap();
+
+        assert_eq!(
+            native(process, regexp, Term::NIL),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("error"),
+                    process
+                        .tuple_from_slice(&[
+                            process
+                                .charlist_from_str("backreferences are not supported")
+                                .unwrap(),
+                            process.integer(4).unwrap()
+                        ])
+                        .unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_counted_repetition_out_of_order_returns_error() {
+    with_process(|process| {
+        let regexp = process.charlist_from_str("a{2,1}").unwrap();
+
+        assert_eq!(
+            native(process, regexp, Term::NIL),
+            Ok(error(process, "numbers out of order in {} quantifier", 6))
+        );
+    });
+}
+
+#[test]
+fn with_counted_repetition_too_large_returns_error() {
+    with_process(|process| {
+        let regexp = process.charlist_from_str("(a{1000}){2000}").unwrap();
+
+        assert_eq!(
+            native(process, regexp, Term::NIL),
+            Ok(error(process, "regular expression is too large", 15))
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process(|process| {
+        let regexp = process.charlist_from_str("a").unwrap();
+        let option = atom!("extended");
+        let options = process.list_from_slice(&[option]).unwrap();
+
+        assert_badarg!(
+            native(process, regexp, options),
+            format!("option ({}) is not a supported compile option", option)
+        );
+    });
+}
+
+fn error(process: &Process, message: &str, position: isize) -> Term {
+    process
+        .tuple_from_slice(&[
+            atom!("error"),
+            process
+                .tuple_from_slice(&[
+                    process.charlist_from_str(message).unwrap(),
+                    process.integer(position).unwrap(),
+                ])
+                .unwrap(),
+        ])
+        .unwrap()
+}
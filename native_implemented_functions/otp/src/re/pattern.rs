@@ -0,0 +1,943 @@
+//! A Thompson NFA simulated like a Pike VM, so matching takes time linear in the length of the
+//! subject.  Matches are leftmost-first like PCRE: alternatives are preferred in order and greedy
+//! repetitions are preferred over lazy ones.
+//!
+//! Supports literals, `.`, `*`, `+`, `?`, `{n}`, `{n,}`, `{n,m}` and their lazy forms, `|`,
+//! character classes, the `\d`, `\w`, and `\s` shorthands, the `^`, `$`, `\A`, `\z`, `\Z`, `\b`, and `\B` assertions, and
+//! capturing and non-capturing groups.  Backreferences and lookaround need backtracking, so they
+//! are compile errors.  Counted repetitions are compiled into copies of what they repeat, so the
+//! number of instructions is limited instead of the time to match.
+
+use std::mem;
+
+#[derive(Clone, Copy, Default)]
+pub struct CompileOptions {
+    pub anchored: bool,
+    pub caseless: bool,
+    pub dotall: bool,
+    pub multiline: bool,
+    pub unicode: bool,
+}
+
+/// An error compiling a pattern, like the `{ErrString, Position}` of `re:compile/2`
+#[derive(Debug, PartialEq)]
+pub struct CompileError {
+    pub message: &'static str,
+    /// Index of the character in the pattern where the error was found
+    pub position: usize,
+}
+
+#[derive(Debug)]
+pub struct Pattern {
+    instructions: Vec<Instruction>,
+    group_count: usize,
+    anchored: bool,
+    caseless: bool,
+    unicode: bool,
+}
+
+impl Pattern {
+    pub fn compile(source: &[char], options: CompileOptions) -> Result<Self, CompileError> {
+        let mut parser = Parser {
+            chars: source,
+            position: 0,
+            group_count: 0,
+            options,
+        };
+        let node = parser.parse_alternation()?;
+
+        // alternation only stops early at a `)` without a `(`
+        if parser.position < source.len() {
+            return Err(parser.error("unmatched parentheses"));
+        }
+
+        if MAX_INSTRUCTIONS < node.instruction_len() {
+            return Err(parser.error("regular expression is too large"));
+        }
+
+        let mut compiler = Compiler::default();
+        compiler.push(Instruction::Save(0));
+        compiler.compile(&node);
+        compiler.push(Instruction::Save(1));
+        compiler.push(Instruction::Match);
+
+        Ok(Self {
+            instructions: compiler.instructions,
+            group_count: parser.group_count,
+            anchored: options.anchored,
+            caseless: options.caseless,
+            unicode: options.unicode,
+        })
+    }
+
+    /// The number of capturing groups, not counting the whole match as group 0
+    pub fn group_count(&self) -> usize {
+        self.group_count
+    }
+
+    pub fn is_unicode(&self) -> bool {
+        self.unicode
+    }
+
+    /// The start and end character indices of the whole match and then of each group, or `None`
+    /// for groups that did not participate, for the leftmost match starting at or after `start`,
+    /// or only at `start` if `anchored` here or when compiled.
+    pub fn find_at(
+        &self,
+        subject: &[char],
+        start: usize,
+        anchored: bool,
+    ) -> Option<Vec<Option<usize>>> {
+        let anchored = anchored || self.anchored;
+        let slot_count = 2 * (self.group_count + 1);
+        let mut current: Vec<Thread> = Vec::new();
+        let mut next: Vec<Thread> = Vec::new();
+        // the position each instruction was last added at, so that it is added at most once per
+        // position, which keeps the first, highest priority thread and stops empty loops
+        let mut added_at: Vec<Option<usize>> = vec![None; self.instructions.len()];
+        let mut matched = None;
+
+        for position in start..=subject.len() {
+            // a thread starting later has a lower priority, so it goes after `current`
+            if matched.is_none() && (position == start || !anchored) {
+                let mut adder = ThreadAdder {
+                    pattern: self,
+                    subject,
+                    position,
+                    added_at: &mut added_at,
+                };
+                adder.add(&mut current, 0, vec![None; slot_count]);
+            }
+
+            if current.is_empty() {
+                break;
+            }
+
+            for thread in current.drain(..) {
+                let character = subject.get(position).copied();
+                let consumes = match &self.instructions[thread.pc] {
+                    Instruction::Any { newline } => {
+                        character.map_or(false, |c| *newline || c != '\n')
+                    }
+                    Instruction::Char(expected) => {
+                        character.map_or(false, |c| chars_eq(c, *expected, self.caseless))
+                    }
+                    Instruction::Class(class) => {
+                        character.map_or(false, |c| class.matches(c, self.caseless))
+                    }
+                    Instruction::Match => {
+                        // lower priority threads are cut
+                        matched = Some(thread.slots);
+                        break;
+                    }
+                    // `ThreadAdder` follows these instead of adding them
+                    Instruction::Assert(_)
+                    | Instruction::Jump(_)
+                    | Instruction::Save(_)
+                    | Instruction::Split(..) => unreachable!(),
+                };
+
+                if consumes {
+                    let mut adder = ThreadAdder {
+                        pattern: self,
+                        subject,
+                        position: position + 1,
+                        added_at: &mut added_at,
+                    };
+                    adder.add(&mut next, thread.pc + 1, thread.slots);
+                }
+            }
+
+            mem::swap(&mut current, &mut next);
+        }
+
+        matched
+    }
+}
+
+// Private
+
+/// The most a counted repetition can repeat, like PCRE
+const MAX_REPEAT: usize = 65535;
+
+/// Counted repetitions are copied, so patterns like `(a{1000}){2000}` have to be limited
+const MAX_INSTRUCTIONS: usize = 1 << 20;
+
+#[derive(Clone, Copy, Debug)]
+enum Assertion {
+    /// `$` and `\Z`, which also match before a newline at the end
+    End,
+    /// `$` with `multiline`
+    LineEnd,
+    /// `^` with `multiline`
+    LineStart,
+    /// `\B`
+    NotWordBoundary,
+    /// `^` and `\A`
+    Start,
+    /// `\z`
+    SubjectEnd,
+    /// `\b`
+    WordBoundary,
+}
+
+impl Assertion {
+    fn holds(self, subject: &[char], position: usize) -> bool {
+        let len = subject.len();
+
+        match self {
+            Self::End => position == len || (position + 1 == len && subject[position] == '\n'),
+            Self::LineEnd => position == len || subject[position] == '\n',
+            Self::LineStart => position == 0 || subject[position - 1] == '\n',
+            Self::NotWordBoundary => !is_word_boundary(subject, position),
+            Self::Start => position == 0,
+            Self::SubjectEnd => position == len,
+            Self::WordBoundary => is_word_boundary(subject, position),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Class {
+    negated: bool,
+    items: Vec<ClassItem>,
+}
+
+impl Class {
+    fn matches(&self, c: char, caseless: bool) -> bool {
+        let item_matches = |c: char| self.items.iter().any(|item| item.matches(c));
+        let found = item_matches(c)
+            || (caseless && c.to_lowercase().chain(c.to_uppercase()).any(item_matches));
+
+        found != self.negated
+    }
+}
+
+#[derive(Clone, Debug)]
+enum ClassItem {
+    Range(char, char),
+    Shorthand(Shorthand),
+}
+
+impl ClassItem {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Self::Range(low, high) => *low <= c && c <= *high,
+            Self::Shorthand(shorthand) => shorthand.matches(c),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Compiler {
+    instructions: Vec<Instruction>,
+}
+
+impl Compiler {
+    fn compile(&mut self, node: &Node) {
+        match node {
+            Node::Alternation(alternatives) => {
+                let last_index = alternatives.len() - 1;
+                let mut jumps = Vec::with_capacity(last_index);
+
+                for (index, alternative) in alternatives.iter().enumerate() {
+                    if index < last_index {
+                        let split = self.push(Instruction::Split(0, 0));
+                        self.compile(alternative);
+                        jumps.push(self.push(Instruction::Jump(0)));
+                        self.instructions[split] =
+                            Instruction::Split(split + 1, self.instructions.len());
+                    } else {
+                        self.compile(alternative);
+                    }
+                }
+
+                let end = self.instructions.len();
+
+                for jump in jumps {
+                    self.instructions[jump] = Instruction::Jump(end);
+                }
+            }
+            Node::Any { newline } => {
+                self.push(Instruction::Any { newline: *newline });
+            }
+            Node::Assertion(assertion) => {
+                self.push(Instruction::Assert(*assertion));
+            }
+            Node::Char(c) => {
+                self.push(Instruction::Char(*c));
+            }
+            Node::Class(class) => {
+                self.push(Instruction::Class(class.clone()));
+            }
+            Node::Concatenation(nodes) => {
+                for node in nodes {
+                    self.compile(node);
+                }
+            }
+            Node::Group { node, capture } => match capture {
+                Some(group) => {
+                    self.push(Instruction::Save(2 * group));
+                    self.compile(node);
+                    self.push(Instruction::Save(2 * group + 1));
+                }
+                None => self.compile(node),
+            },
+            Node::Repetition {
+                node,
+                repetition,
+                greedy,
+            } => match repetition {
+                Repetition::OneOrMore => {
+                    let start = self.instructions.len();
+                    self.compile(node);
+                    let split = self.instructions.len();
+                    self.push(split_instruction(start, split + 1, *greedy));
+                }
+                Repetition::ZeroOrMore => {
+                    let split = self.push(Instruction::Split(0, 0));
+                    self.compile(node);
+                    self.push(Instruction::Jump(split));
+                    self.instructions[split] =
+                        split_instruction(split + 1, self.instructions.len(), *greedy);
+                }
+                Repetition::ZeroOrOne => {
+                    let split = self.push(Instruction::Split(0, 0));
+                    self.compile(node);
+                    self.instructions[split] =
+                        split_instruction(split + 1, self.instructions.len(), *greedy);
+                }
+                Repetition::Counted { min, max } => {
+                    for _ in 0..*min {
+                        self.compile(node);
+                    }
+
+                    match max {
+                        Some(max) => {
+                            // nested like `xx(?:x(?:x)?)?` for `x{2,4}`, so skipping any optional
+                            // copy skips the rest
+                            let mut splits = Vec::with_capacity(max - min);
+
+                            for _ in *min..*max {
+                                splits.push(self.push(Instruction::Split(0, 0)));
+                                self.compile(node);
+                            }
+
+                            let end = self.instructions.len();
+
+                            for split in splits {
+                                self.instructions[split] =
+                                    split_instruction(split + 1, end, *greedy);
+                            }
+                        }
+                        None => {
+                            let split = self.push(Instruction::Split(0, 0));
+                            self.compile(node);
+                            self.push(Instruction::Jump(split));
+                            self.instructions[split] =
+                                split_instruction(split + 1, self.instructions.len(), *greedy);
+                        }
+                    }
+                }
+            },
+        }
+    }
+
+    /// Returns the index of `instruction`, so that it can be patched
+    fn push(&mut self, instruction: Instruction) -> usize {
+        self.instructions.push(instruction);
+
+        self.instructions.len() - 1
+    }
+}
+
+enum Escaped {
+    Char(char),
+    Shorthand(Shorthand),
+}
+
+#[derive(Clone, Debug)]
+enum Instruction {
+    Any {
+        newline: bool,
+    },
+    Assert(Assertion),
+    Char(char),
+    Class(Class),
+    Jump(usize),
+    Match,
+    /// Saves the position into the slot
+    Save(usize),
+    /// Follows both, but the first has priority
+    Split(usize, usize),
+}
+
+enum Node {
+    Alternation(Vec<Node>),
+    Any {
+        newline: bool,
+    },
+    Assertion(Assertion),
+    Char(char),
+    Class(Class),
+    Concatenation(Vec<Node>),
+    Group {
+        node: Box<Node>,
+        capture: Option<usize>,
+    },
+    Repetition {
+        node: Box<Node>,
+        repetition: Repetition,
+        greedy: bool,
+    },
+}
+
+impl Node {
+    /// The number of instructions `Compiler::compile` pushes for this node
+    fn instruction_len(&self) -> usize {
+        match self {
+            Node::Alternation(alternatives) => alternatives
+                .iter()
+                .map(Node::instruction_len)
+                .fold(2 * (alternatives.len() - 1), usize::saturating_add),
+            Node::Any { .. } | Node::Assertion(_) | Node::Char(_) | Node::Class(_) => 1,
+            Node::Concatenation(nodes) => nodes
+                .iter()
+                .map(Node::instruction_len)
+                .fold(0, usize::saturating_add),
+            Node::Group { node, capture } => {
+                let len = node.instruction_len();
+
+                if capture.is_some() {
+                    len.saturating_add(2)
+                } else {
+                    len
+                }
+            }
+            Node::Repetition {
+                node, repetition, ..
+            } => {
+                let len = node.instruction_len();
+
+                match repetition {
+                    Repetition::OneOrMore | Repetition::ZeroOrOne => len.saturating_add(1),
+                    Repetition::ZeroOrMore => len.saturating_add(2),
+                    Repetition::Counted { min, max } => {
+                        let optional_len = match max {
+                            Some(max) => (max - min).saturating_mul(len.saturating_add(1)),
+                            None => len.saturating_add(2),
+                        };
+
+                        min.saturating_mul(len).saturating_add(optional_len)
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    chars: &'a [char],
+    position: usize,
+    group_count: usize,
+    options: CompileOptions,
+}
+
+impl<'a> Parser<'a> {
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, message: &'static str) -> CompileError {
+        CompileError {
+            message,
+            position: self.position,
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let option = self.peek();
+
+        if option.is_some() {
+            self.position += 1;
+        }
+
+        option
+    }
+
+    fn parse_alternation(&mut self) -> Result<Node, CompileError> {
+        let mut alternatives = vec![self.parse_concatenation()?];
+
+        while self.eat('|') {
+            alternatives.push(self.parse_concatenation()?);
+        }
+
+        if alternatives.len() == 1 {
+            Ok(alternatives.pop().unwrap())
+        } else {
+            Ok(Node::Alternation(alternatives))
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, CompileError> {
+        match self.next().unwrap() {
+            '(' => self.parse_group(),
+            '[' => self.parse_class().map(Node::Class),
+            '.' => Ok(Node::Any {
+                newline: self.options.dotall,
+            }),
+            '^' if self.options.multiline => Ok(Node::Assertion(Assertion::LineStart)),
+            '^' => Ok(Node::Assertion(Assertion::Start)),
+            '$' if self.options.multiline => Ok(Node::Assertion(Assertion::LineEnd)),
+            '$' => Ok(Node::Assertion(Assertion::End)),
+            '*' | '+' | '?' => {
+                self.position -= 1;
+
+                Err(self.error("nothing to repeat"))
+            }
+            '{' => {
+                let start = self.position - 1;
+                self.position = start;
+
+                match self.parse_counted()? {
+                    Some(_) => {
+                        self.position = start;
+
+                        Err(self.error("nothing to repeat"))
+                    }
+                    None => {
+                        self.position += 1;
+
+                        Ok(Node::Char('{'))
+                    }
+                }
+            }
+            '\\' => self.parse_escape(),
+            c => Ok(Node::Char(c)),
+        }
+    }
+
+    fn parse_class(&mut self) -> Result<Class, CompileError> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+        // `]` first is literal
+        let mut first = true;
+
+        loop {
+            let low = match self.next_in_class()? {
+                ']' if !first => break,
+                '\\' => match self.parse_escaped_in_class()? {
+                    Escaped::Char(c) => c,
+                    Escaped::Shorthand(shorthand) => {
+                        items.push(ClassItem::Shorthand(shorthand));
+                        first = false;
+
+                        continue;
+                    }
+                },
+                c => c,
+            };
+            first = false;
+
+            let is_range =
+                self.peek() == Some('-') && self.chars.get(self.position + 1) != Some(&']');
+
+            if is_range {
+                self.position += 1;
+
+                let high = match self.next_in_class()? {
+                    '\\' => match self.parse_escaped_in_class()? {
+                        Escaped::Char(c) => c,
+                        Escaped::Shorthand(_) => {
+                            return Err(self.error("invalid range in character class"))
+                        }
+                    },
+                    c => c,
+                };
+
+                if high < low {
+                    return Err(self.error("range out of order in character class"));
+                }
+
+                items.push(ClassItem::Range(low, high));
+            } else {
+                items.push(ClassItem::Range(low, low));
+            }
+        }
+
+        Ok(Class { negated, items })
+    }
+
+    /// Parses `{n}`, `{n,}`, or `{n,m}` at `position`, leaving `position` after it.  Like PCRE,
+    /// when `{` does not start one of these, it is a literal, so `None` is returned and `position`
+    /// is left at the `{`.
+    fn parse_counted(&mut self) -> Result<Option<Repetition>, CompileError> {
+        let start = self.position;
+        self.position += 1;
+
+        let counts = self.parse_count().and_then(|min| {
+            let max = if self.eat(',') {
+                if self.peek() == Some('}') {
+                    None
+                } else {
+                    Some(self.parse_count()?)
+                }
+            } else {
+                Some(min)
+            };
+
+            if self.eat('}') {
+                Some((min, max))
+            } else {
+                None
+            }
+        });
+
+        let (min, max) = match counts {
+            Some(counts) => counts,
+            None => {
+                self.position = start;
+
+                return Ok(None);
+            }
+        };
+
+        if MAX_REPEAT < min || max.map_or(false, |max| MAX_REPEAT < max) {
+            return Err(self.error("number too big in {} quantifier"));
+        }
+
+        if max.map_or(false, |max| max < min) {
+            return Err(self.error("numbers out of order in {} quantifier"));
+        }
+
+        Ok(Some(Repetition::Counted { min, max }))
+    }
+
+    /// The decimal digits at `position`, saturating instead of overflowing, or `None` if there are
+    /// none
+    fn parse_count(&mut self) -> Option<usize> {
+        let start = self.position;
+        let mut count: usize = 0;
+
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            count = count.saturating_mul(10).saturating_add(digit as usize);
+            self.position += 1;
+        }
+
+        if self.position == start {
+            None
+        } else {
+            Some(count)
+        }
+    }
+
+    fn parse_concatenation(&mut self) -> Result<Node, CompileError> {
+        let mut nodes = Vec::new();
+
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+
+            let atom = self.parse_atom()?;
+            nodes.push(self.parse_repetition(atom)?);
+        }
+
+        Ok(Node::Concatenation(nodes))
+    }
+
+    fn parse_escape(&mut self) -> Result<Node, CompileError> {
+        let c = match self.next() {
+            Some(c) => c,
+            None => return Err(self.error("\\ at end of pattern")),
+        };
+
+        match c {
+            'A' => Ok(Node::Assertion(Assertion::Start)),
+            'b' => Ok(Node::Assertion(Assertion::WordBoundary)),
+            'B' => Ok(Node::Assertion(Assertion::NotWordBoundary)),
+            'z' => Ok(Node::Assertion(Assertion::SubjectEnd)),
+            'Z' => Ok(Node::Assertion(Assertion::End)),
+            '1'..='9' => {
+                self.position -= 1;
+
+                Err(self.error("backreferences are not supported"))
+            }
+            _ => match self.parse_escaped(c)? {
+                Escaped::Char(c) => Ok(Node::Char(c)),
+                Escaped::Shorthand(shorthand) => Ok(Node::Class(Class {
+                    negated: false,
+                    items: vec![ClassItem::Shorthand(shorthand)],
+                })),
+            },
+        }
+    }
+
+    /// Parses the escape after `\` that means the same in and outside of classes
+    fn parse_escaped(&mut self, c: char) -> Result<Escaped, CompileError> {
+        let shorthand = |kind, negated| Ok(Escaped::Shorthand(Shorthand { kind, negated }));
+
+        match c {
+            'd' => shorthand(ShorthandKind::Digit, false),
+            'D' => shorthand(ShorthandKind::Digit, true),
+            's' => shorthand(ShorthandKind::Space, false),
+            'S' => shorthand(ShorthandKind::Space, true),
+            'w' => shorthand(ShorthandKind::Word, false),
+            'W' => shorthand(ShorthandKind::Word, true),
+            '0' => Ok(Escaped::Char('\0')),
+            'e' => Ok(Escaped::Char('\x1b')),
+            'f' => Ok(Escaped::Char('\x0c')),
+            'n' => Ok(Escaped::Char('\n')),
+            'r' => Ok(Escaped::Char('\r')),
+            't' => Ok(Escaped::Char('\t')),
+            'x' => self.parse_hex().map(Escaped::Char),
+            c if c.is_ascii_alphanumeric() => {
+                self.position -= 1;
+
+                Err(self.error("unrecognized character follows \\"))
+            }
+            c => Ok(Escaped::Char(c)),
+        }
+    }
+
+    fn parse_escaped_in_class(&mut self) -> Result<Escaped, CompileError> {
+        match self.next_in_class()? {
+            // backspace instead of a word boundary
+            'b' => Ok(Escaped::Char('\x08')),
+            c => self.parse_escaped(c),
+        }
+    }
+
+    fn parse_group(&mut self) -> Result<Node, CompileError> {
+        let capture = if self.eat('?') {
+            match self.peek() {
+                Some(':') => {
+                    self.position += 1;
+
+                    None
+                }
+                Some('=') | Some('!') | Some('<') => {
+                    return Err(self.error("lookaround assertions are not supported"))
+                }
+                _ => return Err(self.error("unrecognized character after (? or (?-")),
+            }
+        } else {
+            self.group_count += 1;
+
+            Some(self.group_count)
+        };
+
+        let node = self.parse_alternation()?;
+
+        if self.eat(')') {
+            Ok(Node::Group {
+                node: Box::new(node),
+                capture,
+            })
+        } else {
+            Err(self.error("missing )"))
+        }
+    }
+
+    /// `\xHH` or `\x{HHH...}`
+    fn parse_hex(&mut self) -> Result<char, CompileError> {
+        let digits_start = self.position;
+        let braced = self.eat('{');
+        let max_len = if braced { usize::max_value() } else { 2 };
+        let start = self.position;
+
+        while self.position - start < max_len
+            && self.peek().map_or(false, |c| c.is_ascii_hexdigit())
+        {
+            self.position += 1;
+        }
+
+        let digits: String = self.chars[start..self.position].iter().collect();
+
+        if braced && !self.eat('}') {
+            return Err(self.error("missing } after \\x{"));
+        }
+
+        let code_point = if digits.is_empty() {
+            Some(0)
+        } else {
+            u32::from_str_radix(&digits, 16).ok()
+        };
+
+        code_point
+            .filter(|code_point| self.options.unicode || *code_point <= 0xFF)
+            .and_then(std::char::from_u32)
+            .ok_or(CompileError {
+                message: "character code point value in \\x{} is too large",
+                position: digits_start,
+            })
+    }
+
+    fn parse_repetition(&mut self, atom: Node) -> Result<Node, CompileError> {
+        let start = self.position;
+        let repetition = match self.peek() {
+            Some('*') => Repetition::ZeroOrMore,
+            Some('+') => Repetition::OneOrMore,
+            Some('?') => Repetition::ZeroOrOne,
+            Some('{') => match self.parse_counted()? {
+                Some(repetition) => repetition,
+                None => return Ok(atom),
+            },
+            _ => return Ok(atom),
+        };
+
+        if let Node::Assertion(_) = atom {
+            self.position = start;
+
+            return Err(self.error("nothing to repeat"));
+        }
+
+        // `parse_counted` is already after the `}`
+        if self.position == start {
+            self.position += 1;
+        }
+
+        let greedy = !self.eat('?');
+
+        // possessive quantifiers and repeated quantifiers
+        if let Some('*') | Some('+') | Some('?') = self.peek() {
+            return Err(self.error("nothing to repeat"));
+        }
+
+        Ok(Node::Repetition {
+            node: Box::new(atom),
+            repetition,
+            greedy,
+        })
+    }
+
+    fn next_in_class(&mut self) -> Result<char, CompileError> {
+        self.next()
+            .ok_or_else(|| self.error("missing terminating ] for character class"))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+}
+
+enum Repetition {
+    /// `{n}`, `{n,}`, and `{n,m}`, where `max` is `None` for `{n,}`
+    Counted {
+        min: usize,
+        max: Option<usize>,
+    },
+    OneOrMore,
+    ZeroOrMore,
+    ZeroOrOne,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Shorthand {
+    kind: ShorthandKind,
+    negated: bool,
+}
+
+impl Shorthand {
+    /// Like PCRE without `ucp`, the shorthands only match ASCII
+    fn matches(self, c: char) -> bool {
+        let kind_matches = match self.kind {
+            ShorthandKind::Digit => c.is_ascii_digit(),
+            ShorthandKind::Space => match c {
+                ' ' | '\t' | '\n' | '\x0b' | '\x0c' | '\r' => true,
+                _ => false,
+            },
+            ShorthandKind::Word => is_word(c),
+        };
+
+        kind_matches != self.negated
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum ShorthandKind {
+    Digit,
+    Space,
+    Word,
+}
+
+struct Thread {
+    pc: usize,
+    slots: Vec<Option<usize>>,
+}
+
+/// Adds a thread for an instruction, following the instructions that don't consume a character
+struct ThreadAdder<'a> {
+    pattern: &'a Pattern,
+    subject: &'a [char],
+    position: usize,
+    added_at: &'a mut [Option<usize>],
+}
+
+impl<'a> ThreadAdder<'a> {
+    /// Follows the instructions with an explicit stack instead of recursion, so that long chains
+    /// of them, like those copied for `(?:a?){65535}`, can't overflow the stack.
+    fn add(&mut self, threads: &mut Vec<Thread>, pc: usize, slots: Vec<Option<usize>>) {
+        let pattern = self.pattern;
+        let mut stack = vec![(pc, slots)];
+
+        while let Some((pc, mut slots)) = stack.pop() {
+            if self.added_at[pc] == Some(self.position) {
+                continue;
+            }
+
+            self.added_at[pc] = Some(self.position);
+
+            match &pattern.instructions[pc] {
+                Instruction::Assert(assertion) => {
+                    if assertion.holds(self.subject, self.position) {
+                        stack.push((pc + 1, slots));
+                    }
+                }
+                Instruction::Jump(to) => stack.push((*to, slots)),
+                Instruction::Save(slot) => {
+                    slots[*slot] = Some(self.position);
+                    stack.push((pc + 1, slots));
+                }
+                Instruction::Split(preferred, other) => {
+                    // popped after everything `preferred` leads to, like the second of two calls
+                    stack.push((*other, slots.clone()));
+                    stack.push((*preferred, slots));
+                }
+                Instruction::Any { .. }
+                | Instruction::Char(_)
+                | Instruction::Class(_)
+                | Instruction::Match => threads.push(Thread { pc, slots }),
+            }
+        }
+    }
+}
+
+fn chars_eq(c: char, expected: char, caseless: bool) -> bool {
+    c == expected || (caseless && c.to_lowercase().eq(expected.to_lowercase()))
+}
+
+fn is_word(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_word_boundary(subject: &[char], position: usize) -> bool {
+    let before = position > 0 && is_word(subject[position - 1]);
+    let after = subject.get(position).map_or(false, |c| is_word(*c));
+
+    before != after
+}
+
+fn split_instruction(preferred: usize, other: usize, greedy: bool) -> Instruction {
+    if greedy {
+        Instruction::Split(preferred, other)
+    } else {
+        Instruction::Split(other, preferred)
+    }
+}
@@ -0,0 +1,188 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::run_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_groups_returns_index_of_match_and_each_group() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abc123").unwrap();
+        let regexp = process.charlist_from_str("([a-z]+)([0-9]+)").unwrap();
+
+        assert_eq!(
+            native(process, subject, regexp),
+            Ok(matched(process, &[(0, 6), (0, 3), (3, 3)]))
+        );
+    });
+}
+
+#[test]
+fn without_match_returns_nomatch() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abc").unwrap();
+        let regexp = process.charlist_from_str("[0-9]").unwrap();
+
+        assert_eq!(native(process, subject, regexp), Ok(atom!("nomatch")));
+    });
+}
+
+#[test]
+fn with_alternation_prefers_left_alternative() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abcd").unwrap();
+        let regexp = process.charlist_from_str("ab|abcd").unwrap();
+
+        assert_eq!(
+            native(process, subject, regexp),
+            Ok(matched(process, &[(0, 2)]))
+        );
+    });
+}
+
+#[test]
+fn with_lazy_quantifier_matches_shortest() {
+    with_process(|process| {
+        let subject = process.binary_from_str("<a><b>").unwrap();
+        let greedy = process.charlist_from_str("<.*>").unwrap();
+        let lazy = process.charlist_from_str("<.*?>").unwrap();
+
+        assert_eq!(
+            native(process, subject, greedy),
+            Ok(matched(process, &[(0, 6)]))
+        );
+        assert_eq!(
+            native(process, subject, lazy),
+            Ok(matched(process, &[(0, 3)]))
+        );
+    });
+}
+
+#[test]
+fn with_counted_repetition_matches_count() {
+    with_process(|process| {
+        let subject = process.binary_from_str("aaa").unwrap();
+        let regexp = |source: &str| process.charlist_from_str(source).unwrap();
+
+        assert_eq!(
+            native(process, subject, regexp("a{2}")),
+            Ok(matched(process, &[(0, 2)]))
+        );
+        assert_eq!(
+            native(process, subject, regexp("a{2,}")),
+            Ok(matched(process, &[(0, 3)]))
+        );
+        assert_eq!(
+            native(process, subject, regexp("a{1,2}")),
+            Ok(matched(process, &[(0, 2)]))
+        );
+        assert_eq!(
+            native(process, subject, regexp("a{1,2}?")),
+            Ok(matched(process, &[(0, 1)]))
+        );
+        assert_eq!(
+            native(process, subject, regexp("^a{4}")),
+            Ok(atom!("nomatch"))
+        );
+    });
+}
+
+#[test]
+fn with_deep_counted_repetition_matches() {
+    with_process(|process| {
+        let subject = process.binary_from_str("aaa").unwrap();
+        let regexp = process.charlist_from_str("(?:a?){65535}").unwrap();
+
+        assert_eq!(
+            native(process, subject, regexp),
+            Ok(matched(process, &[(0, 3)]))
+        );
+    });
+}
+
+#[test]
+fn with_brace_not_starting_counted_repetition_matches_literal() {
+    with_process(|process| {
+        let subject = process.binary_from_str("a{,2}").unwrap();
+        let regexp = process.charlist_from_str("a{,2}").unwrap();
+
+        assert_eq!(
+            native(process, subject, regexp),
+            Ok(matched(process, &[(0, 5)]))
+        );
+    });
+}
+
+#[test]
+fn with_anchors_and_classes_matches_whole_subject() {
+    with_process(|process| {
+        let regexp = process.charlist_from_str("^\\w+\\s[^a-z]+$").unwrap();
+
+        assert_eq!(
+            native(process, process.binary_from_str("abc 123").unwrap(), regexp),
+            Ok(matched(process, &[(0, 7)]))
+        );
+        assert_eq!(
+            native(process, process.binary_from_str("abc 12x").unwrap(), regexp),
+            Ok(atom!("nomatch"))
+        );
+    });
+}
+
+#[test]
+fn with_unset_group_returns_negative_one_index() {
+    with_process(|process| {
+        let subject = process.binary_from_str("b").unwrap();
+        let regexp = process.charlist_from_str("(a)?b").unwrap();
+
+        assert_eq!(
+            native(process, subject, regexp),
+            Ok(matched(process, &[(0, 1), (-1, 0)]))
+        );
+    });
+}
+
+#[test]
+fn with_charlist_subject_matches() {
+    with_process(|process| {
+        let subject = process.charlist_from_str("xyz").unwrap();
+        let regexp = process.binary_from_str("y").unwrap();
+
+        assert_eq!(
+            native(process, subject, regexp),
+            Ok(matched(process, &[(1, 1)]))
+        );
+    });
+}
+
+#[test]
+fn without_binary_or_charlist_subject_errors_badarg() {
+    with_process(|process| {
+        let subject = atom!("abc");
+        let regexp = process.charlist_from_str("a").unwrap();
+
+        assert_badarg!(
+            native(process, subject, regexp),
+            format!("subject ({}) is not a binary or charlist", subject)
+        );
+    });
+}
+
+fn matched(process: &Process, indices: &[(isize, isize)]) -> Term {
+    let index_vec: Vec<Term> = indices
+        .iter()
+        .map(|(start, length)| {
+            process
+                .tuple_from_slice(&[
+                    process.integer(*start).unwrap(),
+                    process.integer(*length).unwrap(),
+                ])
+                .unwrap()
+        })
+        .collect();
+
+    process
+        .tuple_from_slice(&[atom!("match"), process.list_from_slice(&index_vec).unwrap()])
+        .unwrap()
+}
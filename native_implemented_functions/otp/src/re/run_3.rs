@@ -0,0 +1,376 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::re::pattern::{CompileOptions, Pattern};
+use crate::re::{
+    characters_try_from, compile, compile_option_set, compiled_resource_try_from, Characters,
+};
+
+/// Matches `subject` against `regexp`, which is either compiled by `compile` or a pattern that is
+/// compiled with the compile options in `options`.  Returns `nomatch`, `match` with
+/// `{capture, none}`, or `{match, Captured}`, where `Captured` is a list of captures for each
+/// match with `global`.
+///
+/// `options` can also be `anchored`, `global`, `{capture, ValueSpec}`,
+/// `{capture, ValueSpec, Type}`, and `{offset, Offset}`, where `ValueSpec` is `all`,
+/// `all_but_first`, `first`, `none`, or a list of group numbers, and `Type` is `index`, `list`, or
+/// `binary`.  Captures default to `{capture, all, index}`.
+#[native_implemented_function(run/3)]
+pub fn native(
+    process: &Process,
+    subject: Term,
+    regexp: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let compiled_resource = compiled_resource_try_from(regexp);
+    let compiled = compiled_resource
+        .as_ref()
+        .and_then(|resource| resource.downcast_ref::<Pattern>());
+    let run_options = RunOptions::try_from(options, regexp, compiled.is_none())?;
+    let uncompiled;
+    let pattern = match compiled {
+        Some(pattern) => pattern,
+        None => {
+            uncompiled = compile(process, regexp, run_options.compile)?.map_err(|error| {
+                anyhow!(
+                    "regexp ({}) is invalid: {} at position {}",
+                    regexp,
+                    error.message,
+                    error.position
+                )
+            })?;
+
+            &uncompiled
+        }
+    };
+
+    let characters = characters_try_from(process, "subject", subject, pattern.is_unicode())?;
+    let start = characters
+        .byte_offsets
+        .iter()
+        .position(|byte_offset| *byte_offset == run_options.offset)
+        .with_context(|| {
+            format!(
+                "offset ({}) is not at a character in subject ({})",
+                run_options.offset, subject
+            )
+        })?;
+
+    let slots_vec = find(pattern, &characters, start, &run_options);
+
+    if slots_vec.is_empty() {
+        Ok(atom!("nomatch"))
+    } else if let Values::None = run_options.capture.values {
+        Ok(atom!("match"))
+    } else {
+        let mut captured_vec = Vec::with_capacity(slots_vec.len());
+
+        for slots in &slots_vec {
+            captured_vec.push(captured(
+                process,
+                pattern,
+                &characters,
+                slots,
+                &run_options.capture,
+            )?);
+        }
+
+        let captured = if run_options.global {
+            process.list_from_slice(&captured_vec)?
+        } else {
+            captured_vec[0]
+        };
+
+        process
+            .tuple_from_slice(&[atom!("match"), captured])
+            .map_err(From::from)
+    }
+}
+
+struct Capture {
+    values: Values,
+    kind: Kind,
+}
+
+impl Default for Capture {
+    fn default() -> Self {
+        Self {
+            values: Values::All,
+            kind: Kind::Index,
+        }
+    }
+}
+
+impl Capture {
+    fn try_from(option: Term, tuple: &Tuple) -> exception::Result<Self> {
+        let values = Values::try_from(option, tuple[1])?;
+        let kind = if tuple.len() == 3 {
+            Kind::try_from(option, tuple[2])?
+        } else {
+            Kind::Index
+        };
+
+        Ok(Self { values, kind })
+    }
+}
+
+enum Kind {
+    Binary,
+    Index,
+    List,
+}
+
+impl Kind {
+    fn try_from(option: Term, kind: Term) -> exception::Result<Self> {
+        let kind_atom: Atom = kind
+            .try_into()
+            .with_context(|| invalid_run_option(option))?;
+
+        match kind_atom.name() {
+            "binary" => Ok(Self::Binary),
+            "index" => Ok(Self::Index),
+            "list" => Ok(Self::List),
+            _ => Err(anyhow!(invalid_run_option(option)).into()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct RunOptions {
+    anchored: bool,
+    capture: Capture,
+    compile: CompileOptions,
+    global: bool,
+    /// Byte offset in the subject
+    offset: usize,
+}
+
+impl RunOptions {
+    fn try_from(options: Term, regexp: Term, allow_compile: bool) -> exception::Result<Self> {
+        let mut run_options: Self = Default::default();
+
+        match options.decode()? {
+            TypedTerm::Nil => (),
+            TypedTerm::List(cons) => {
+                for result in cons.into_iter() {
+                    let option = result
+                        .map_err(|_| ImproperListError)
+                        .with_context(|| format!("options ({}) is not a proper list", options))?;
+
+                    run_options.put(option, regexp, allow_compile)?;
+                }
+            }
+            _ => {
+                return Err(TypeError)
+                    .context(format!("options ({}) is not a proper list", options))
+                    .map_err(From::from)
+            }
+        }
+
+        Ok(run_options)
+    }
+
+    fn put(&mut self, option: Term, regexp: Term, allow_compile: bool) -> exception::Result<()> {
+        match option.decode()? {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "anchored" => self.anchored = true,
+                "global" => self.global = true,
+                name => {
+                    let mut compile = self.compile;
+
+                    if !compile_option_set(&mut compile, name) {
+                        return Err(anyhow!(invalid_run_option(option)).into());
+                    } else if !allow_compile {
+                        return Err(anyhow!(
+                            "option ({}) is a compile option, so it cannot be used with compiled regexp ({})",
+                            option,
+                            regexp
+                        )
+                        .into());
+                    }
+
+                    self.compile = compile;
+                }
+            },
+            TypedTerm::Tuple(tuple) if 2 <= tuple.len() => {
+                let name: Atom = tuple[0]
+                    .try_into()
+                    .with_context(|| invalid_run_option(option))?;
+
+                match (name.name(), tuple.len()) {
+                    ("capture", 2) | ("capture", 3) => {
+                        self.capture = Capture::try_from(option, &tuple)?;
+                    }
+                    ("offset", 2) => {
+                        self.offset = tuple[1]
+                            .try_into()
+                            .with_context(|| invalid_run_option(option))?;
+                    }
+                    _ => return Err(anyhow!(invalid_run_option(option)).into()),
+                }
+            }
+            _ => return Err(anyhow!(invalid_run_option(option)).into()),
+        }
+
+        Ok(())
+    }
+}
+
+enum Values {
+    All,
+    AllButFirst,
+    First,
+    Groups(Vec<usize>),
+    None,
+}
+
+impl Values {
+    fn try_from(option: Term, values: Term) -> exception::Result<Self> {
+        match values.decode()? {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "all" => Ok(Self::All),
+                "all_but_first" => Ok(Self::AllButFirst),
+                "first" => Ok(Self::First),
+                "none" => Ok(Self::None),
+                _ => Err(anyhow!(invalid_run_option(option)).into()),
+            },
+            TypedTerm::Nil => Ok(Self::Groups(Vec::new())),
+            TypedTerm::List(cons) => {
+                let mut groups = Vec::new();
+
+                for result in cons.into_iter() {
+                    let group: usize = result
+                        .ok()
+                        .and_then(|group| group.try_into().ok())
+                        .with_context(|| invalid_run_option(option))?;
+                    groups.push(group);
+                }
+
+                Ok(Self::Groups(groups))
+            }
+            _ => Err(anyhow!(invalid_run_option(option)).into()),
+        }
+    }
+
+    fn groups(&self, group_count: usize) -> Vec<usize> {
+        match self {
+            Self::All => (0..=group_count).collect(),
+            Self::AllButFirst => (1..=group_count).collect(),
+            Self::First => vec![0],
+            Self::Groups(groups) => groups.clone(),
+            Self::None => Vec::new(),
+        }
+    }
+}
+
+/// The captures of one match.  Groups that did not participate are `{-1, 0}`, `[]`, or `<<>>`,
+/// like in OTP.
+fn captured(
+    process: &Process,
+    pattern: &Pattern,
+    characters: &Characters,
+    slots: &[Option<usize>],
+    capture: &Capture,
+) -> exception::Result<Term> {
+    let groups = capture.values.groups(pattern.group_count());
+    let mut capture_vec = Vec::with_capacity(groups.len());
+
+    for group in groups {
+        let range = match (slots.get(2 * group), slots.get(2 * group + 1)) {
+            (Some(Some(start)), Some(Some(end))) => Some(*start..*end),
+            _ => None,
+        };
+
+        let capture_term = match capture.kind {
+            Kind::Binary => {
+                let bytes: &[u8] = match range {
+                    Some(range) => {
+                        &characters.bytes[characters.byte_offsets[range.start]
+                            ..characters.byte_offsets[range.end]]
+                    }
+                    None => &[],
+                };
+
+                process.binary_from_bytes(bytes)?
+            }
+            Kind::Index => match range {
+                Some(range) => {
+                    let byte_start = characters.byte_offsets[range.start];
+                    let byte_len = characters.byte_offsets[range.end] - byte_start;
+
+                    process.tuple_from_slice(&[
+                        process.integer(byte_start)?,
+                        process.integer(byte_len)?,
+                    ])?
+                }
+                None => process.tuple_from_slice(&[process.integer(-1)?, process.integer(0)?])?,
+            },
+            Kind::List => match range {
+                Some(range) => {
+                    let s: String = characters.chars[range].iter().collect();
+
+                    process.charlist_from_str(&s)?
+                }
+                None => Term::NIL,
+            },
+        };
+
+        capture_vec.push(capture_term);
+    }
+
+    process.list_from_slice(&capture_vec).map_err(From::from)
+}
+
+/// The slots of the first match or, with `global`, all matches.  After an empty match, the next
+/// match is searched for from the next character, so that matching always advances.
+fn find(
+    pattern: &Pattern,
+    characters: &Characters,
+    start: usize,
+    run_options: &RunOptions,
+) -> Vec<Vec<Option<usize>>> {
+    let mut slots_vec = Vec::new();
+    let mut position = start;
+
+    while position <= characters.chars.len() {
+        match pattern.find_at(&characters.chars, position, run_options.anchored) {
+            Some(slots) => {
+                let match_start = slots[0].unwrap();
+                let match_end = slots[1].unwrap();
+                slots_vec.push(slots);
+
+                if !run_options.global {
+                    break;
+                }
+
+                position = if match_start == match_end {
+                    match_end + 1
+                } else {
+                    match_end
+                };
+            }
+            None => break,
+        }
+    }
+
+    slots_vec
+}
+
+fn invalid_run_option(option: Term) -> String {
+    format!("option ({}) is not a supported run option", option)
+}
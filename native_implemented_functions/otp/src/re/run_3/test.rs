@@ -0,0 +1,268 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::{compile_1, run_3::native};
+use crate::test::with_process;
+
+#[test]
+fn with_capture_all_but_first_binary_returns_group_binaries() {
+    with_process(|process| {
+        let subject = process.binary_from_str("key=value").unwrap();
+        let regexp = process.charlist_from_str("(\\w+)=(\\w+)").unwrap();
+        let options = options(
+            process,
+            &[process
+                .tuple_from_slice(&[atom!("capture"), atom!("all_but_first"), atom!("binary")])
+                .unwrap()],
+        );
+
+        assert_eq!(
+            native(process, subject, regexp, options),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("match"),
+                    process
+                        .list_from_slice(&[
+                            process.binary_from_str("key").unwrap(),
+                            process.binary_from_str("value").unwrap()
+                        ])
+                        .unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_capture_list_of_groups_returns_charlists() {
+    with_process(|process| {
+        let subject = process.binary_from_str("ab").unwrap();
+        let regexp = process.charlist_from_str("(a)(x)?(b)").unwrap();
+        let groups = process
+            .list_from_slice(&[process.integer(3).unwrap(), process.integer(2).unwrap()])
+            .unwrap();
+        let options = options(
+            process,
+            &[process
+                .tuple_from_slice(&[atom!("capture"), groups, atom!("list")])
+                .unwrap()],
+        );
+
+        assert_eq!(
+            native(process, subject, regexp, options),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("match"),
+                    process
+                        .list_from_slice(&[process.charlist_from_str("b").unwrap(), Term::NIL])
+                        .unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_capture_none_returns_match() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abc").unwrap();
+        let regexp = process.charlist_from_str("b").unwrap();
+        let options = options(
+            process,
+            &[process
+                .tuple_from_slice(&[atom!("capture"), atom!("none")])
+                .unwrap()],
+        );
+
+        assert_eq!(
+            native(process, subject, regexp, options),
+            Ok(atom!("match"))
+        );
+    });
+}
+
+#[test]
+fn with_global_returns_captures_of_each_match() {
+    with_process(|process| {
+        let subject = process.binary_from_str("a1b22").unwrap();
+        let regexp = process.charlist_from_str("[0-9]+").unwrap();
+        let options = options(process, &[atom!("global")]);
+
+        assert_eq!(
+            native(process, subject, regexp, options),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("match"),
+                    process
+                        .list_from_slice(&[
+                            indices(process, &[(1, 1)]),
+                            indices(process, &[(3, 2)])
+                        ])
+                        .unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_global_and_empty_matches_advances() {
+    with_process(|process| {
+        let subject = process.binary_from_str("ab").unwrap();
+        let regexp = process.charlist_from_str("x*").unwrap();
+        let options = options(process, &[atom!("global")]);
+
+        assert_eq!(
+            native(process, subject, regexp, options),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("match"),
+                    process
+                        .list_from_slice(&[
+                            indices(process, &[(0, 0)]),
+                            indices(process, &[(1, 0)]),
+                            indices(process, &[(2, 0)])
+                        ])
+                        .unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_caseless_ignores_case() {
+    with_process(|process| {
+        let subject = process.binary_from_str("HeLLo").unwrap();
+        let regexp = process.charlist_from_str("hello").unwrap();
+        let options = options(process, &[atom!("caseless")]);
+
+        assert_eq!(
+            native(process, subject, regexp, options),
+            Ok(matched(process, &[(0, 5)]))
+        );
+    });
+}
+
+#[test]
+fn with_offset_starts_matching_at_offset() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abab").unwrap();
+        let regexp = process.charlist_from_str("ab").unwrap();
+        let options = options(
+            process,
+            &[process
+                .tuple_from_slice(&[atom!("offset"), process.integer(1).unwrap()])
+                .unwrap()],
+        );
+
+        assert_eq!(
+            native(process, subject, regexp, options),
+            Ok(matched(process, &[(2, 2)]))
+        );
+    });
+}
+
+#[test]
+fn with_anchored_only_matches_at_offset() {
+    with_process(|process| {
+        let subject = process.binary_from_str("xab").unwrap();
+        let regexp = process.charlist_from_str("ab").unwrap();
+        let options = options(process, &[atom!("anchored")]);
+
+        assert_eq!(
+            native(process, subject, regexp, options),
+            Ok(atom!("nomatch"))
+        );
+    });
+}
+
+#[test]
+fn with_unicode_returns_byte_indices() {
+    with_process(|process| {
+        let subject = process.binary_from_str("héllo").unwrap();
+        let regexp = process.charlist_from_str("l+").unwrap();
+        let options = options(process, &[atom!("unicode")]);
+
+        assert_eq!(
+            native(process, subject, regexp, options),
+            Ok(matched(process, &[(3, 2)]))
+        );
+    });
+}
+
+#[test]
+fn with_compiled_regexp_and_compile_option_errors_badarg() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abc").unwrap();
+        let source = process.charlist_from_str("b").unwrap();
+        let compiled: Boxed<Tuple> = compile_1::native(process, source)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let regexp = compiled[1];
+        let options = options(process, &[atom!("caseless")]);
+
+        assert_badarg!(
+            native(process, subject, regexp, options),
+            "is a compile option, so it cannot be used with compiled regexp"
+        );
+    });
+}
+
+#[test]
+fn with_invalid_regexp_errors_badarg() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abc").unwrap();
+        let regexp = process.charlist_from_str("(a").unwrap();
+
+        assert_badarg!(
+            native(process, subject, regexp, Term::NIL),
+            format!("regexp ({}) is invalid: missing ) at position 2", regexp)
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process(|process| {
+        let subject = process.binary_from_str("abc").unwrap();
+        let regexp = process.charlist_from_str("a").unwrap();
+        let option = atom!("notempty");
+        let options = options(process, &[option]);
+
+        assert_badarg!(
+            native(process, subject, regexp, options),
+            format!("option ({}) is not a supported run option", option)
+        );
+    });
+}
+
+fn indices(process: &Process, indices: &[(isize, isize)]) -> Term {
+    let index_vec: Vec<Term> = indices
+        .iter()
+        .map(|(start, length)| {
+            process
+                .tuple_from_slice(&[
+                    process.integer(*start).unwrap(),
+                    process.integer(*length).unwrap(),
+                ])
+                .unwrap()
+        })
+        .collect();
+
+    process.list_from_slice(&index_vec).unwrap()
+}
+
+fn matched(process: &Process, indices_slice: &[(isize, isize)]) -> Term {
+    process
+        .tuple_from_slice(&[atom!("match"), indices(process, indices_slice)])
+        .unwrap()
+}
+
+fn options(process: &Process, options: &[Term]) -> Term {
+    process.list_from_slice(options).unwrap()
+}
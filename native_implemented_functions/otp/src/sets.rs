@@ -0,0 +1,33 @@
+//! Mirrors [sets](http://erlang.org/doc/man/sets.html) module
+//!
+//! Sets are maps from each element to `true`.  Like in OTP, elements are compared with exact
+//! equality (`=:=`), as map keys are, so `1` and `1.0` are different elements.
+
+pub mod add_element_2;
+pub mod del_element_2;
+pub mod is_element_2;
+pub mod new_0;
+pub mod size_1;
+pub mod to_list_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("sets").unwrap()
+}
+
+// Private
+
+/// The value of every element in the map of a set
+const ELEMENT_VALUE: bool = true;
+
+fn set_try_into_map(name: &str, set: Term) -> exception::Result<Boxed<Map>> {
+    set.try_into()
+        .with_context(|| format!("{} ({}) is not a set", name, set))
+        .map_err(From::from)
+}
@@ -0,0 +1,60 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::atom;
+
+use crate::sets::add_element_2::native;
+use crate::sets::{new_0, size_1};
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_not_map(arc_process),
+            )
+        },
+        |(arc_process, element, set1)| {
+            prop_assert_badarg!(
+                native(&arc_process, element, set1),
+                format!("set1 ({}) is not a set", set1)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_duplicate_element_does_not_grow_set() {
+    with_process(|process| {
+        let set = new_0::native(process).unwrap();
+        let set = native(process, atom!("a"), set).unwrap();
+        let set = native(process, atom!("b"), set).unwrap();
+        let set_with_duplicate = native(process, atom!("a"), set).unwrap();
+
+        assert_eq!(set_with_duplicate, set);
+        assert_eq!(
+            size_1::native(process, set_with_duplicate),
+            Ok(process.integer(2).unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_equal_but_not_exactly_equal_element_adds_element() {
+    with_process(|process| {
+        let set = new_0::native(process).unwrap();
+        let set = native(process, process.integer(1).unwrap(), set).unwrap();
+        let set = native(process, process.float(1.0).unwrap(), set).unwrap();
+
+        assert_eq!(
+            size_1::native(process, set),
+            Ok(process.integer(2).unwrap())
+        );
+    });
+}
@@ -0,0 +1,24 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::sets::set_try_into_map;
+
+#[native_implemented_function(del_element/2)]
+pub fn native(process: &Process, element: Term, set1: Term) -> exception::Result<Term> {
+    let boxed_map = set_try_into_map("set1", set1)?;
+
+    match boxed_map.remove(element) {
+        Some(hash_map) => Ok(process.map_from_hash_map(hash_map)?),
+        None => Ok(set1),
+    }
+}
@@ -0,0 +1,38 @@
+use liblumen_alloc::atom;
+
+use crate::sets::del_element_2::native;
+use crate::sets::{add_element_2, is_element_2, new_0};
+use crate::test::with_process;
+
+#[test]
+fn with_element_removes_it() {
+    with_process(|process| {
+        let set = new_0::native(process).unwrap();
+        let set = add_element_2::native(process, atom!("a"), set).unwrap();
+        let set = add_element_2::native(process, atom!("b"), set).unwrap();
+        let set = native(process, atom!("a"), set).unwrap();
+
+        assert_eq!(is_element_2::native(atom!("a"), set), Ok(false.into()));
+        assert_eq!(is_element_2::native(atom!("b"), set), Ok(true.into()));
+    });
+}
+
+#[test]
+fn without_element_returns_set() {
+    with_process(|process| {
+        let set1 = new_0::native(process).unwrap();
+        let set1 = add_element_2::native(process, atom!("a"), set1).unwrap();
+
+        assert_eq!(native(process, atom!("b"), set1), Ok(set1));
+    });
+}
+
+#[test]
+fn with_equal_but_not_exactly_equal_element_returns_set() {
+    with_process(|process| {
+        let set1 = new_0::native(process).unwrap();
+        let set1 = add_element_2::native(process, process.integer(1).unwrap(), set1).unwrap();
+
+        assert_eq!(native(process, process.float(1.0).unwrap(), set1), Ok(set1));
+    });
+}
@@ -0,0 +1,20 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::sets::set_try_into_map;
+
+#[native_implemented_function(is_element/2)]
+pub fn native(element: Term, set: Term) -> exception::Result<Term> {
+    let boxed_map = set_try_into_map("set", set)?;
+
+    Ok(boxed_map.is_key(element).into())
+}
@@ -0,0 +1,27 @@
+use liblumen_alloc::atom;
+
+use crate::sets::is_element_2::native;
+use crate::sets::{add_element_2, new_0};
+use crate::test::with_process;
+
+#[test]
+fn with_element_returns_true_and_without_returns_false() {
+    with_process(|process| {
+        let set = new_0::native(process).unwrap();
+        let set = add_element_2::native(process, atom!("a"), set).unwrap();
+
+        assert_eq!(native(atom!("a"), set), Ok(true.into()));
+        assert_eq!(native(atom!("b"), set), Ok(false.into()));
+    });
+}
+
+#[test]
+fn with_equal_but_not_exactly_equal_element_returns_false() {
+    with_process(|process| {
+        let set = new_0::native(process).unwrap();
+        let set = add_element_2::native(process, process.integer(1).unwrap(), set).unwrap();
+
+        assert_eq!(native(process.float(1.0).unwrap(), set), Ok(false.into()));
+        assert_eq!(native(process.integer(1).unwrap(), set), Ok(true.into()));
+    });
+}
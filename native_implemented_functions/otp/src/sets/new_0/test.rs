@@ -0,0 +1,14 @@
+use crate::sets::new_0::native;
+use crate::test::with_process;
+
+#[test]
+fn returns_empty_set() {
+    with_process(|process| {
+        let set = native(process).unwrap();
+
+        assert_eq!(
+            crate::sets::size_1::native(process, set),
+            Ok(process.integer(0).unwrap())
+        );
+    });
+}
@@ -0,0 +1,25 @@
+use liblumen_alloc::atom;
+
+use crate::sets::size_1::native;
+use crate::sets::{add_element_2, new_0};
+use crate::test::with_process;
+
+#[test]
+fn with_set_returns_number_of_elements() {
+    with_process(|process| {
+        let set = new_0::native(process).unwrap();
+        let set = add_element_2::native(process, atom!("a"), set).unwrap();
+        let set = add_element_2::native(process, atom!("b"), set).unwrap();
+
+        assert_eq!(native(process, set), Ok(process.integer(2).unwrap()));
+    });
+}
+
+#[test]
+fn without_set_errors_badarg() {
+    with_process(|process| {
+        let set = atom!("set");
+
+        assert_badarg!(native(process, set), format!("set ({}) is not a set", set));
+    });
+}
@@ -0,0 +1,26 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::to_list_1::native;
+use crate::sets::{add_element_2, new_0};
+use crate::test::with_process;
+
+#[test]
+fn contains_each_element_once() {
+    with_process(|process| {
+        let mut set = new_0::native(process).unwrap();
+
+        for name in &["a", "b", "a", "c", "b"] {
+            set = add_element_2::native(process, atom!(name), set).unwrap();
+        }
+
+        let list = native(process, set).unwrap();
+        let cons: Boxed<Cons> = list.try_into().unwrap();
+        let mut element_vec: Vec<Term> = cons.into_iter().map(|result| result.unwrap()).collect();
+        element_vec.sort();
+
+        assert_eq!(element_vec, vec![atom!("a"), atom!("b"), atom!("c")]);
+    });
+}
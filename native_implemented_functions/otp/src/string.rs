@@ -0,0 +1,117 @@
+//! Mirrors [string](http://erlang.org/doc/man/string.html) module
+//!
+//! Strings are either UTF-8 binaries or charlists, and strings are returned in the same container
+//! as the `string` argument.  The `pad` functions count extended grapheme clusters, like OTP, but
+//! the `lexemes`, `trim`, and case functions still approximate graphemes as code points.
+
+pub mod lexemes_2;
+pub mod lowercase_1;
+pub mod pad_2;
+pub mod pad_3;
+pub mod pad_4;
+pub mod split_2;
+pub mod split_3;
+pub mod trim_1;
+pub mod trim_2;
+pub mod trim_3;
+pub mod uppercase_1;
+
+use std::str;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_full::binary_to_string::binary_to_string;
+
+use crate::erlang::list_to_string::list_to_string;
+
+fn module() -> Atom {
+    Atom::try_from_str("string").unwrap()
+}
+
+// Private
+
+/// Maps each byte of `string` with `map` when `string` is a binary flagged as Latin-1 that only
+/// contains ASCII, which is how binaries built from ASCII `str`s are flagged, so that case
+/// conversion can skip decoding and re-encoding UTF-8.
+///
+/// Returns `None` when the Unicode path is needed.
+fn ascii_binary_map(
+    process: &Process,
+    string: Term,
+    map: fn(&u8) -> u8,
+) -> Option<exception::Result<Term>> {
+    let mapped = match string.decode().ok()? {
+        TypedTerm::HeapBinary(heap_binary) if heap_binary.is_latin1() => {
+            ascii_bytes_map(heap_binary.as_bytes(), map)
+        }
+        TypedTerm::ProcBin(process_binary) if process_binary.is_latin1() => {
+            ascii_bytes_map(process_binary.as_bytes(), map)
+        }
+        TypedTerm::BinaryLiteral(binary_literal) if binary_literal.is_latin1() => {
+            ascii_bytes_map(binary_literal.as_bytes(), map)
+        }
+        _ => None,
+    }?;
+
+    // ASCII is always valid UTF-8
+    let s = unsafe { str::from_utf8_unchecked(&mapped) };
+
+    Some(process.binary_from_str(s).map_err(From::from))
+}
+
+fn ascii_bytes_map(bytes: &[u8], map: fn(&u8) -> u8) -> Option<Vec<u8>> {
+    if bytes.is_ascii() {
+        Some(bytes.iter().map(map).collect())
+    } else {
+        None
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Container {
+    Binary,
+    Charlist,
+}
+
+fn string_to_rust(name: &'static str, string: Term) -> exception::Result<(String, Container)> {
+    if string.is_binary() {
+        binary_to_string(string).map(|s| (s, Container::Binary))
+    } else if string.is_list() {
+        list_to_string(string).map(|s| (s, Container::Charlist))
+    } else {
+        Err(TypeError)
+            .context(format!(
+                "{} ({}) is not a UTF-8 binary or charlist",
+                name, string
+            ))
+            .map_err(From::from)
+    }
+}
+
+fn rust_to_string(process: &Process, s: &str, container: Container) -> exception::Result<Term> {
+    match container {
+        Container::Binary => process.binary_from_str(s),
+        Container::Charlist => process.charlist_from_str(s),
+    }
+    .map_err(From::from)
+}
+
+fn rust_to_string_list<'a, I>(
+    process: &Process,
+    strs: I,
+    container: Container,
+) -> exception::Result<Term>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let string_vec = strs
+        .into_iter()
+        .map(|s| rust_to_string(process, s, container))
+        .collect::<exception::Result<Vec<Term>>>()?;
+
+    process.list_from_slice(&string_vec).map_err(From::from)
+}
@@ -0,0 +1,98 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::string::{rust_to_string_list, string_to_rust};
+
+/// Splits `string` on every occurrence of any separator in `separator_list`, dropping empty
+/// lexemes.  Separators are code points or strings, such as `"\r\n"`.
+#[native_implemented_function(lexemes/2)]
+pub fn native(process: &Process, string: Term, separator_list: Term) -> exception::Result<Term> {
+    let (s, container) = string_to_rust("string", string)?;
+    let separators = separators_to_vec(separator_list)?;
+
+    let lexemes = split_all(&s, &separators)
+        .into_iter()
+        .filter(|lexeme| !lexeme.is_empty());
+
+    rust_to_string_list(process, lexemes, container)
+}
+
+// Private
+
+fn separators_to_vec(separator_list: Term) -> exception::Result<Vec<String>> {
+    let mut separators = Vec::new();
+
+    match separator_list.decode()? {
+        TypedTerm::Nil => (),
+        TypedTerm::List(cons) => {
+            for result in cons.into_iter() {
+                let separator = result.map_err(|_| {
+                    anyhow!("separator_list ({}) is not a proper list", separator_list)
+                })?;
+
+                let separator_string = if separator.is_smallint() {
+                    let c: char = separator.try_into().with_context(|| {
+                        format!(
+                            "separator_list ({}) element ({}) is not a code point",
+                            separator_list, separator
+                        )
+                    })?;
+
+                    c.to_string()
+                } else {
+                    string_to_rust("separator", separator)?.0
+                };
+
+                if !separator_string.is_empty() {
+                    separators.push(separator_string);
+                }
+            }
+        }
+        _ => {
+            return Err(TypeError)
+                .context(format!("separator_list ({}) is not a list", separator_list))
+                .map_err(From::from)
+        }
+    }
+
+    Ok(separators)
+}
+
+/// Splits at every position where one of the `separators` starts, keeping empty lexemes.
+fn split_all<'s>(s: &'s str, separators: &[String]) -> Vec<&'s str> {
+    let mut lexemes = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < s.len() {
+        match separators
+            .iter()
+            .find(|separator| s[index..].starts_with(separator.as_str()))
+        {
+            Some(separator) => {
+                lexemes.push(&s[start..index]);
+                index += separator.len();
+                start = index;
+            }
+            None => index += s[index..].chars().next().unwrap().len_utf8(),
+        }
+    }
+
+    lexemes.push(&s[start..]);
+
+    lexemes
+}
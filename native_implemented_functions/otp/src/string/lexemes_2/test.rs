@@ -0,0 +1,55 @@
+use crate::string::lexemes_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_binary_drops_empty_lexemes() {
+    with_process(|process| {
+        let string = process.binary_from_str("a,,b").unwrap();
+        let separator_list = process.charlist_from_str(",").unwrap();
+
+        assert_eq!(
+            native(process, string, separator_list),
+            Ok(process
+                .list_from_slice(&[
+                    process.binary_from_str("a").unwrap(),
+                    process.binary_from_str("b").unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_charlist_splits_on_any_separator_and_returns_charlists() {
+    with_process(|process| {
+        let string = process.charlist_from_str(" héllo\r\nwörld ").unwrap();
+        let separator_list = process
+            .list_from_slice(&[
+                process.integer(' ' as u32).unwrap(),
+                process.charlist_from_str("\r\n").unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(process, string, separator_list),
+            Ok(process
+                .list_from_slice(&[
+                    process.charlist_from_str("héllo").unwrap(),
+                    process.charlist_from_str("wörld").unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_string_errors_badarg() {
+    with_process(|process| {
+        let string = process.integer(1).unwrap();
+
+        assert_badarg!(
+            native(process, string, process.charlist_from_str(",").unwrap()),
+            format!("string ({}) is not a UTF-8 binary or charlist", string)
+        );
+    });
+}
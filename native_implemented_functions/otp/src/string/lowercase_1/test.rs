@@ -0,0 +1,41 @@
+use crate::string::lowercase_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_binary_lowercases_multi_byte_characters() {
+    with_process(|process| {
+        let string = process.binary_from_str("ÀÉÎ ΣΩ").unwrap();
+
+        assert_eq!(
+            native(process, string),
+            Ok(process.binary_from_str("àéî σω").unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_string_errors_badarg() {
+    with_process(|process| {
+        let string = process.integer(1).unwrap();
+
+        assert_badarg!(
+            native(process, string),
+            format!("string ({}) is not a UTF-8 binary or charlist", string)
+        );
+    });
+}
+
+#[test]
+fn with_ascii_binary_matches_unicode_path() {
+    with_process(|process| {
+        let ascii: String = (0_u8..128).map(|byte| byte as char).collect();
+        let latin1 = process.binary_from_str(&ascii).unwrap();
+        let raw = process.binary_from_bytes(ascii.as_bytes()).unwrap();
+
+        assert_eq!(native(process, latin1), native(process, raw));
+        assert_eq!(
+            native(process, latin1),
+            Ok(process.binary_from_str(&ascii.to_lowercase()).unwrap())
+        );
+    });
+}
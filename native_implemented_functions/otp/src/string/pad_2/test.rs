@@ -0,0 +1,15 @@
+use crate::string::pad_2::native;
+use crate::test::with_process;
+
+#[test]
+fn pads_trailing_with_spaces() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let length = process.integer(5).unwrap();
+
+        assert_eq!(
+            native(process, string, length),
+            Ok(process.binary_from_str("abc  ").unwrap())
+        );
+    });
+}
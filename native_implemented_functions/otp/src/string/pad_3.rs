@@ -0,0 +1,31 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::string::pad_4;
+
+/// `pad(String, Length, Direction, $\s)`
+#[native_implemented_function(pad/3)]
+pub fn native(
+    process: &Process,
+    string: Term,
+    length: Term,
+    direction: Term,
+) -> exception::Result<Term> {
+    pad_4::native(
+        process,
+        string,
+        length,
+        direction,
+        process.integer(' ' as u32)?,
+    )
+}
@@ -0,0 +1,17 @@
+use liblumen_alloc::atom;
+
+use crate::string::pad_3::native;
+use crate::test::with_process;
+
+#[test]
+fn pads_with_spaces() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let length = process.integer(5).unwrap();
+
+        assert_eq!(
+            native(process, string, length, atom!("both")),
+            Ok(process.binary_from_str(" abc ").unwrap())
+        );
+    });
+}
@@ -0,0 +1,80 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::string::{rust_to_string, string_to_rust};
+
+/// Pads `string` with `character`, a code point or a charlist of one grapheme, until `string` is
+/// `length` graphemes long.  `direction` is `leading`, `trailing`, or `both`.  With `both`, the
+/// extra grapheme of odd padding is trailing, like in OTP.
+///
+/// A `string` that is already at least `length` graphemes long is returned unpadded.
+#[native_implemented_function(pad/4)]
+pub fn native(
+    process: &Process,
+    string: Term,
+    length: Term,
+    direction: Term,
+    character: Term,
+) -> exception::Result<Term> {
+    let (s, container) = string_to_rust("string", string)?;
+    let length_isize = term_try_into_isize!(length)?;
+    let direction_atom = term_try_into_atom!(direction)?;
+    let fill = character_to_string(character)?;
+
+    let padding = (length_isize - s.graphemes(true).count() as isize).max(0) as usize;
+    let (leading, trailing) = match direction_atom.name() {
+        "leading" => (padding, 0),
+        "trailing" => (0, padding),
+        "both" => (padding / 2, padding - padding / 2),
+        _ => {
+            return Err(anyhow!(
+                "direction ({}) is not leading, trailing, or both",
+                direction
+            )
+            .into())
+        }
+    };
+
+    let mut padded = String::with_capacity(s.len() + padding * fill.len());
+    padded.push_str(&fill.repeat(leading));
+    padded.push_str(&s);
+    padded.push_str(&fill.repeat(trailing));
+
+    rust_to_string(process, &padded, container)
+}
+
+/// `character` has to be exactly one grapheme, so that the padding is `length` graphemes long.
+fn character_to_string(character: Term) -> exception::Result<String> {
+    let string = if character.is_list() {
+        list_to_string(character)?
+    } else {
+        let character_char: char = character.try_into().with_context(|| {
+            format!("character ({}) is not a code point or charlist", character)
+        })?;
+
+        character_char.to_string()
+    };
+
+    if string.graphemes(true).count() == 1 {
+        Ok(string)
+    } else {
+        Err(anyhow!("character ({}) is not one grapheme", character).into())
+    }
+}
@@ -0,0 +1,144 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::string::pad_4::native;
+use crate::test::with_process;
+
+#[test]
+fn with_both_pads_evenly() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let length = process.integer(5).unwrap();
+        let character = process.integer('*' as u32).unwrap();
+
+        assert_eq!(
+            native(process, string, length, atom!("both"), character),
+            Ok(process.binary_from_str("*abc*").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_both_and_odd_padding_pads_more_trailing() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let length = process.integer(6).unwrap();
+        let character = process.integer('*' as u32).unwrap();
+
+        assert_eq!(
+            native(process, string, length, atom!("both"), character),
+            Ok(process.binary_from_str("*abc**").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_multi_byte_graphemes_counts_graphemes_not_bytes() {
+    with_process(|process| {
+        let string = process.binary_from_str("héé").unwrap();
+        let length = process.integer(5).unwrap();
+        let character = process.integer('ø' as u32).unwrap();
+
+        assert_eq!(
+            native(process, string, length, atom!("leading"), character),
+            Ok(process.binary_from_str("øøhéé").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_combining_characters_counts_graphemes_not_code_points() {
+    with_process(|process| {
+        // `e` and a combining acute accent are two code points but one grapheme
+        let string = process.binary_from_str("e\u{301}").unwrap();
+        let length = process.integer(3).unwrap();
+        let character = process.integer('*' as u32).unwrap();
+
+        assert_eq!(
+            native(process, string, length, atom!("trailing"), character),
+            Ok(process.binary_from_str("e\u{301}**").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_charlist_returns_charlist() {
+    with_process(|process| {
+        let string = process.charlist_from_str("abc").unwrap();
+        let length = process.integer(4).unwrap();
+        let character = process.charlist_from_str("-").unwrap();
+
+        assert_eq!(
+            native(process, string, length, atom!("trailing"), character),
+            Ok(process.charlist_from_str("abc-").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_string_at_least_length_returns_string_unpadded() {
+    with_process(|process| {
+        let string = process.binary_from_str("abcdef").unwrap();
+        let length = process.integer(5).unwrap();
+        let character = process.integer(' ' as u32).unwrap();
+
+        assert_eq!(
+            native(process, string, length, atom!("both"), character),
+            Ok(process.binary_from_str("abcdef").unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_direction_errors_badarg() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let length = process.integer(5).unwrap();
+        let direction = atom!("middle");
+        let character = process.integer(' ' as u32).unwrap();
+
+        assert_badarg!(
+            native(process, string, length, direction, character),
+            format!(
+                "direction ({}) is not leading, trailing, or both",
+                direction
+            )
+        );
+    });
+}
+
+#[test]
+fn without_one_grapheme_character_errors_badarg() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let length = process.integer(5).unwrap();
+        let direction = atom!("trailing");
+
+        for character in &[
+            Term::NIL,
+            process.charlist_from_str("ab").unwrap(),
+            // `e` and a combining acute accent are one grapheme, so this is two
+            process.charlist_from_str("e\u{301}e").unwrap(),
+        ] {
+            assert_badarg!(
+                native(process, string, length, direction, *character),
+                format!("character ({}) is not one grapheme", character)
+            );
+        }
+    });
+}
+
+#[test]
+fn with_charlist_of_one_grapheme_of_many_code_points_pads_with_grapheme() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let length = process.integer(5).unwrap();
+        let direction = atom!("trailing");
+        let character = process.charlist_from_str("e\u{301}").unwrap();
+
+        assert_eq!(
+            native(process, string, length, direction, character),
+            Ok(process.binary_from_str("abce\u{301}e\u{301}").unwrap())
+        );
+    });
+}
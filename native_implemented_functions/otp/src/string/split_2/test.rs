@@ -0,0 +1,20 @@
+use crate::string::split_2::native;
+use crate::test::with_process;
+
+#[test]
+fn splits_at_first_occurrence() {
+    with_process(|process| {
+        let string = process.binary_from_str("a,,b").unwrap();
+        let search_pattern = process.binary_from_str(",").unwrap();
+
+        assert_eq!(
+            native(process, string, search_pattern),
+            Ok(process
+                .list_from_slice(&[
+                    process.binary_from_str("a").unwrap(),
+                    process.binary_from_str(",b").unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
@@ -0,0 +1,66 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::string::{rust_to_string_list, string_to_rust};
+
+/// Splits `string` on `search_pattern`.  `direction` is `leading` to split at the first occurrence,
+/// `trailing` to split at the last occurrence, or `all` to split at every occurrence.  Unlike
+/// `lexemes/2`, empty parts are kept.
+#[native_implemented_function(split/3)]
+pub fn native(
+    process: &Process,
+    string: Term,
+    search_pattern: Term,
+    direction: Term,
+) -> exception::Result<Term> {
+    let (s, container) = string_to_rust("string", string)?;
+    let (pattern, _) = string_to_rust("search_pattern", search_pattern)?;
+    let direction_atom = term_try_into_atom!(direction)?;
+    // decoded before checking the pattern, so that a bad direction is an error even when it's
+    // unused
+    let direction = match direction_atom.name() {
+        "leading" => Direction::Leading,
+        "trailing" => Direction::Trailing,
+        "all" => Direction::All,
+        _ => {
+            return Err(
+                anyhow!("direction ({}) is not leading, trailing, or all", direction).into(),
+            )
+        }
+    };
+
+    let parts: Vec<&str> = if pattern.is_empty() {
+        vec![&s]
+    } else {
+        match direction {
+            Direction::Leading => s.splitn(2, pattern.as_str()).collect(),
+            Direction::Trailing => match s.rfind(pattern.as_str()) {
+                Some(index) => vec![&s[..index], &s[index + pattern.len()..]],
+                None => vec![&s],
+            },
+            Direction::All => s.split(pattern.as_str()).collect(),
+        }
+    };
+
+    rust_to_string_list(process, parts, container)
+}
+
+// Private
+
+enum Direction {
+    Leading,
+    Trailing,
+    All,
+}
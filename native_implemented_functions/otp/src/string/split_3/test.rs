@@ -0,0 +1,84 @@
+use liblumen_alloc::atom;
+
+use crate::string::split_3::native;
+use crate::test::with_process;
+
+#[test]
+fn with_all_keeps_empty_parts() {
+    with_process(|process| {
+        let string = process.binary_from_str("a,,b").unwrap();
+        let search_pattern = process.charlist_from_str(",").unwrap();
+
+        assert_eq!(
+            native(process, string, search_pattern, atom!("all")),
+            Ok(process
+                .list_from_slice(&[
+                    process.binary_from_str("a").unwrap(),
+                    process.binary_from_str("").unwrap(),
+                    process.binary_from_str("b").unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_trailing_splits_at_last_occurrence() {
+    with_process(|process| {
+        let string = process.charlist_from_str("a::b::c").unwrap();
+        let search_pattern = process.charlist_from_str("::").unwrap();
+
+        assert_eq!(
+            native(process, string, search_pattern, atom!("trailing")),
+            Ok(process
+                .list_from_slice(&[
+                    process.charlist_from_str("a::b").unwrap(),
+                    process.charlist_from_str("c").unwrap()
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_search_pattern_in_string_returns_string() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let search_pattern = process.binary_from_str(",").unwrap();
+
+        for direction in &[atom!("leading"), atom!("trailing"), atom!("all")] {
+            assert_eq!(
+                native(process, string, search_pattern, *direction),
+                Ok(process.list_from_slice(&[string]).unwrap())
+            );
+        }
+    });
+}
+
+#[test]
+fn with_unknown_direction_errors_badarg() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let search_pattern = process.binary_from_str("b").unwrap();
+        let direction = atom!("middle");
+
+        assert_badarg!(
+            native(process, string, search_pattern, direction),
+            format!("direction ({}) is not leading, trailing, or all", direction)
+        );
+    });
+}
+
+#[test]
+fn with_empty_search_pattern_and_unknown_direction_errors_badarg() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc").unwrap();
+        let search_pattern = process.binary_from_str("").unwrap();
+        let direction = atom!("bogus");
+
+        assert_badarg!(
+            native(process, string, search_pattern, direction),
+            format!("direction ({}) is not leading, trailing, or all", direction)
+        );
+    });
+}
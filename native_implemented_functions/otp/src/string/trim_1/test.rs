@@ -0,0 +1,16 @@
+use crate::string::trim_1::native;
+use crate::test::with_process;
+
+#[test]
+fn trims_leading_and_trailing_whitespace() {
+    with_process(|process| {
+        let string = process
+            .binary_from_str(" \t\r\nhello world\n\u{3000}")
+            .unwrap();
+
+        assert_eq!(
+            native(process, string),
+            Ok(process.binary_from_str("hello world").unwrap())
+        );
+    });
+}
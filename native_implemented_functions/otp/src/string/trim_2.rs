@@ -0,0 +1,21 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::string::trim_3::trim;
+
+/// Strips Unicode whitespace from `string` in `direction`, which is `leading`, `trailing`, or
+/// `both`.
+#[native_implemented_function(trim/2)]
+pub fn native(process: &Process, string: Term, direction: Term) -> exception::Result<Term> {
+    trim(process, string, direction, char::is_whitespace)
+}
@@ -0,0 +1,44 @@
+use liblumen_alloc::atom;
+
+use crate::string::trim_2::native;
+use crate::test::with_process;
+
+#[test]
+fn with_leading_only_trims_leading_whitespace() {
+    with_process(|process| {
+        let string = process.charlist_from_str("  hello  ").unwrap();
+
+        assert_eq!(
+            native(process, string, atom!("leading")),
+            Ok(process.charlist_from_str("hello  ").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_trailing_only_trims_trailing_whitespace() {
+    with_process(|process| {
+        let string = process.charlist_from_str("  hello  ").unwrap();
+
+        assert_eq!(
+            native(process, string, atom!("trailing")),
+            Ok(process.charlist_from_str("  hello").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_unknown_direction_errors_badarg() {
+    with_process(|process| {
+        let string = process.charlist_from_str(" hello ").unwrap();
+        let direction = atom!("middle");
+
+        assert_badarg!(
+            native(process, string, direction),
+            format!(
+                "direction ({}) is not leading, trailing, or both",
+                direction
+            )
+        );
+    });
+}
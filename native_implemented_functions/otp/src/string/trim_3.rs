@@ -0,0 +1,60 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::string::{rust_to_string, string_to_rust};
+
+/// Strips the code points in `characters` from `string`.  `direction` is `leading`, `trailing`, or
+/// `both`.
+#[native_implemented_function(trim/3)]
+pub fn native(
+    process: &Process,
+    string: Term,
+    direction: Term,
+    characters: Term,
+) -> exception::Result<Term> {
+    let character_vec: Vec<char> = list_to_string(characters)?.chars().collect();
+
+    trim(process, string, direction, |c| character_vec.contains(&c))
+}
+
+/// Strips the characters matching `is_trimmed` from `string` in `direction`.
+pub fn trim<F>(
+    process: &Process,
+    string: Term,
+    direction: Term,
+    is_trimmed: F,
+) -> exception::Result<Term>
+where
+    F: Fn(char) -> bool,
+{
+    let (s, container) = string_to_rust("string", string)?;
+    let direction_atom = term_try_into_atom!(direction)?;
+
+    let trimmed = match direction_atom.name() {
+        "leading" => s.trim_start_matches(|c| is_trimmed(c)),
+        "trailing" => s.trim_end_matches(|c| is_trimmed(c)),
+        "both" => s.trim_matches(|c| is_trimmed(c)),
+        _ => {
+            return Err(anyhow!(
+                "direction ({}) is not leading, trailing, or both",
+                direction
+            )
+            .into())
+        }
+    };
+
+    rust_to_string(process, trimmed, container)
+}
@@ -0,0 +1,17 @@
+use liblumen_alloc::atom;
+
+use crate::string::trim_3::native;
+use crate::test::with_process;
+
+#[test]
+fn trims_only_characters() {
+    with_process(|process| {
+        let string = process.binary_from_str("-.- hello -.-").unwrap();
+        let characters = process.charlist_from_str("-.").unwrap();
+
+        assert_eq!(
+            native(process, string, atom!("both"), characters),
+            Ok(process.binary_from_str(" hello ").unwrap())
+        );
+    });
+}
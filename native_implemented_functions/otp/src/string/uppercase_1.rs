@@ -0,0 +1,28 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::string::{ascii_binary_map, rust_to_string, string_to_rust};
+
+/// Converts `string` to uppercase with the Unicode case mappings, so non-ASCII characters are
+/// converted too, and a character can map to more than one character.  ASCII binaries are
+/// converted byte-wise.
+#[native_implemented_function(uppercase/1)]
+pub fn native(process: &Process, string: Term) -> exception::Result<Term> {
+    if let Some(result) = ascii_binary_map(process, string, u8::to_ascii_uppercase) {
+        return result;
+    }
+
+    let (s, container) = string_to_rust("string", string)?;
+
+    rust_to_string(process, &s.to_uppercase(), container)
+}
@@ -0,0 +1,76 @@
+use libtest::Bencher;
+
+use crate::string::uppercase_1::native;
+use crate::test::with_process;
+
+#[test]
+fn with_binary_uppercases_multi_byte_characters() {
+    with_process(|process| {
+        let string = process.binary_from_str("héllo wörld").unwrap();
+
+        assert_eq!(
+            native(process, string),
+            Ok(process.binary_from_str("HÉLLO WÖRLD").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_charlist_returns_charlist() {
+    with_process(|process| {
+        // 'ß' uppercases to 2 characters
+        let string = process.charlist_from_str("straße").unwrap();
+
+        assert_eq!(
+            native(process, string),
+            Ok(process.charlist_from_str("STRASSE").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_ascii_binary_matches_unicode_path() {
+    with_process(|process| {
+        let ascii: String = (0_u8..128).map(|byte| byte as char).collect();
+        // `binary_from_str` flags ASCII as Latin-1, so it takes the byte-wise path, while
+        // `binary_from_bytes` flags it as raw, so it takes the Unicode path.
+        let latin1 = process.binary_from_str(&ascii).unwrap();
+        let raw = process.binary_from_bytes(ascii.as_bytes()).unwrap();
+
+        assert_eq!(native(process, latin1), native(process, raw));
+        assert_eq!(
+            native(process, latin1),
+            Ok(process.binary_from_str(&ascii.to_uppercase()).unwrap())
+        );
+    });
+}
+
+#[bench]
+fn bench_ascii(bencher: &mut Bencher) {
+    with_process(|process| {
+        let ascii = process.binary_from_str(&"hello world ".repeat(16)).unwrap();
+
+        bencher.iter(|| native(process, ascii).unwrap())
+    });
+}
+
+#[bench]
+fn bench_ascii_with_unicode_path(bencher: &mut Bencher) {
+    with_process(|process| {
+        // raw, not Latin-1, so it takes the Unicode path
+        let ascii = process
+            .binary_from_bytes("hello world ".repeat(16).as_bytes())
+            .unwrap();
+
+        bencher.iter(|| native(process, ascii).unwrap())
+    });
+}
+
+#[bench]
+fn bench_multi_byte(bencher: &mut Bencher) {
+    with_process(|process| {
+        let multi_byte = process.binary_from_str(&"héllo wörld ".repeat(16)).unwrap();
+
+        bencher.iter(|| native(process, multi_byte).unwrap())
+    });
+}
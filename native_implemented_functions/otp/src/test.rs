@@ -1,3 +1,4 @@
+pub mod alloc;
 pub mod r#loop;
 pub mod process;
 pub mod process_dictionary;
@@ -15,20 +16,47 @@ pub mod strategy;
 pub use self::proptest::*;
 
 use std::convert::TryInto;
+use std::panic;
 use std::sync::Arc;
+use std::thread;
 
 use liblumen_alloc::atom;
 use liblumen_alloc::erts::exception;
-use liblumen_alloc::erts::process::{Process, Status};
+use liblumen_alloc::erts::process::code::stack::frame::{Frame, Placement};
+use liblumen_alloc::erts::process::{code, Process, Status};
 use liblumen_alloc::erts::term::prelude::*;
 
 use lumen_rt_core::time::{monotonic, Milliseconds};
 
 use lumen_rt_full::process::SchedulerDependentAlloc;
+use lumen_rt_full::scheduler::Scheduler;
 use lumen_rt_full::timer;
 
 use crate::erlang;
 
+/// Asserts that `datetime` is a `{{Year, Month, Day}, {Hour, Minute, Second}}` tuple, like from
+/// `localtime/0` or `universaltime/0`, with every field in range.
+pub fn assert_datetime_in_range(datetime: Term) {
+    let datetime_tuple: Boxed<Tuple> = datetime.try_into().unwrap();
+
+    assert_eq!(datetime_tuple.len(), 2);
+
+    let date_vec = datetime_field_vec(datetime_tuple[0]);
+
+    assert_eq!(date_vec.len(), 3);
+    assert!(1970 <= date_vec[0]);
+    assert!(1 <= date_vec[1] && date_vec[1] <= 12);
+    assert!(1 <= date_vec[2] && date_vec[2] <= 31);
+
+    let time_vec = datetime_field_vec(datetime_tuple[1]);
+
+    assert_eq!(time_vec.len(), 3);
+    assert!(time_vec[0] <= 23);
+    assert!(time_vec[1] <= 59);
+    // 60 is allowed for leap seconds
+    assert!(time_vec[2] <= 60);
+}
+
 pub fn assert_exits<F: Fn(Option<Term>)>(
     process: &Process,
     expected_reason: Term,
@@ -111,6 +139,105 @@ pub fn with_big_int(f: fn(&Process, Term) -> ()) {
     })
 }
 
+/// Runs `f` with a process on a scheduler that has no other processes than those made for `f`, so
+/// that tests of message ordering between processes are reproducible.
+///
+/// * `f` runs in a new thread, so the thread-local `Scheduler` has not queued processes left by
+///   earlier tests in the same thread.
+/// * Monotonic time is frozen, so timers only fire when the test advances time with
+///   `freeze_at_timeout`.
+/// * The process passed to `f` and its init parent are taken out of the run queue, so only the
+///   processes `f` spawns are run, but the process still receives their messages.
+///
+/// Step the processes through with `Scheduler::run_round`, which runs each runnable process once
+/// in the order they were queued.
+pub fn with_deterministic_scheduler<F>(f: F)
+where
+    F: FnOnce(Arc<Scheduler>, Arc<Process>) -> () + Send + 'static,
+{
+    let result = thread::spawn(move || {
+        monotonic::freeze_time_in_milliseconds();
+
+        let scheduler = Scheduler::current();
+        let init_arc_process = process::init();
+        let arc_process = process::child(&init_arc_process);
+        scheduler.unschedule(&init_arc_process);
+        scheduler.unschedule(&arc_process);
+
+        f(scheduler, arc_process)
+    })
+    .join();
+
+    if let Err(payload) = result {
+        panic::resume_unwind(payload)
+    }
+}
+
+/// Applies `module:function(arguments)` in one process while another process sends `tick` to the
+/// parent on every run, both on a `with_deterministic_scheduler` scheduler.  `f` gets the number
+/// of ticks before the return value arrived, which is how many more runs the call took than one,
+/// and the return value.
+///
+/// The call runs with the default, smallest heap, so a call that allocates more than that fails to
+/// allocate, is garbage collected and resumes, which also counts as a run.
+///
+/// `module:function/arity` must be exported.
+pub fn with_ticks_while_applying<A, F>(module: Atom, function: Atom, arguments: A, f: F)
+where
+    A: FnOnce(&Process) -> Vec<Term> + Send + 'static,
+    F: FnOnce(&Process, usize, Term) -> () + Send + 'static,
+{
+    with_deterministic_scheduler(move |scheduler, arc_process| {
+        let argument_vec = arguments(&arc_process);
+        let argument_list = arc_process.list_from_slice(&argument_vec).unwrap();
+        let destination = arc_process.pid_term();
+
+        Scheduler::spawn_code(
+            &arc_process,
+            Default::default(),
+            module,
+            function,
+            &[
+                destination,
+                module.encode().unwrap(),
+                function.encode().unwrap(),
+                argument_list,
+            ],
+            apply_and_send_code,
+        )
+        .unwrap();
+        Scheduler::spawn_code(
+            &arc_process,
+            Default::default(),
+            r#loop::module(),
+            r#loop::function(),
+            &[destination],
+            send_tick_and_yield_code,
+        )
+        .unwrap();
+
+        let tick = Atom::str_to_term("tick");
+
+        for _ in 0..1_000 {
+            scheduler.run_round();
+
+            let message_vec: Vec<Term> = arc_process
+                .mailbox
+                .lock()
+                .borrow()
+                .iter()
+                .map(|message| *message.data())
+                .collect();
+
+            if let Some(index) = message_vec.iter().position(|message| *message != tick) {
+                return f(&arc_process, index, message_vec[index]);
+            }
+        }
+
+        panic!("{}:{} did not return", module, function);
+    })
+}
+
 pub fn with_process<F>(f: F)
 where
     F: FnOnce(&Process) -> (),
@@ -195,3 +322,62 @@ pub fn without_timer_returns_false(native: fn(&Process, Term) -> exception::Resu
         assert_eq!(native(process, timer_reference), Ok(false.into()));
     });
 }
+
+// Private
+
+/// Replaces its frame with one to send the return value of `apply(module, function, arguments)`
+/// to `destination`
+fn apply_and_send_code(arc_process: &Arc<Process>) -> code::Result {
+    arc_process.reduce();
+
+    let destination = arc_process.stack_peek(1).unwrap();
+    let module = arc_process.stack_peek(2).unwrap();
+    let function = arc_process.stack_peek(3).unwrap();
+    let argument_list = arc_process.stack_peek(4).unwrap();
+    arc_process.stack_popn(4);
+
+    arc_process.stack_push(destination)?;
+    arc_process.replace_frame(Frame::new(
+        arc_process.current_module_function_arity().unwrap(),
+        send_return_value_code,
+    ));
+    erlang::apply_3::place_frame_with_arguments(
+        arc_process,
+        Placement::Push,
+        module,
+        function,
+        argument_list,
+    )?;
+
+    Process::call_code(arc_process)
+}
+
+fn datetime_field_vec(date_or_time: Term) -> Vec<usize> {
+    let tuple: Boxed<Tuple> = date_or_time.try_into().unwrap();
+
+    tuple
+        .iter()
+        .map(|field| (*field).try_into().unwrap())
+        .collect()
+}
+
+fn send_return_value_code(arc_process: &Arc<Process>) -> code::Result {
+    arc_process.reduce();
+
+    let return_value = arc_process.stack_peek(1).unwrap();
+    let destination = arc_process.stack_peek(2).unwrap();
+    erlang::send_2::native(arc_process, destination, return_value).unwrap();
+    arc_process.return_from_call(2, return_value)?;
+
+    Process::call_code(arc_process)
+}
+
+fn send_tick_and_yield_code(arc_process: &Arc<Process>) -> code::Result {
+    arc_process.reduce();
+
+    let destination = arc_process.stack_peek(1).unwrap();
+    erlang::send_2::native(arc_process, destination, Atom::str_to_term("tick")).unwrap();
+    erlang::yield_0::native(arc_process);
+
+    Process::call_code(arc_process)
+}
@@ -0,0 +1,91 @@
+//! Counts the bytes that each thread allocates with the global allocator, so that a test can
+//! measure its peak allocation while other tests allocate on other threads.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+#[global_allocator]
+static COUNTING_ALLOC: CountingAlloc = CountingAlloc;
+
+/// Runs `f` and returns its result with the most bytes that were allocated by this thread at any
+/// one time while `f` ran, not counting what was allocated before.
+///
+/// Only the global allocator is counted, so the data of a `ProcBin` and process heaps, which are
+/// allocated with `liblumen_core::sys::alloc`, are not.
+pub fn with_peak_allocated_bytes<F, T>(f: F) -> (T, usize)
+where
+    F: FnOnce() -> T,
+{
+    let before = ALLOCATED_BYTES.get();
+    let outer_peak = PEAK_ALLOCATED_BYTES.replace(before);
+
+    let t = f();
+
+    let peak = PEAK_ALLOCATED_BYTES.get();
+    PEAK_ALLOCATED_BYTES.set(outer_peak.max(peak));
+
+    (t, (peak - before) as usize)
+}
+
+// Private
+
+// Signed, because memory can be freed on another thread than the one that allocated it
+#[thread_local]
+static ALLOCATED_BYTES: Cell<isize> = Cell::new(0);
+#[thread_local]
+static PEAK_ALLOCATED_BYTES: Cell<isize> = Cell::new(0);
+
+struct CountingAlloc;
+
+impl CountingAlloc {
+    fn add(size: usize) {
+        let allocated = ALLOCATED_BYTES.get() + size as isize;
+        ALLOCATED_BYTES.set(allocated);
+
+        if PEAK_ALLOCATED_BYTES.get() < allocated {
+            PEAK_ALLOCATED_BYTES.set(allocated);
+        }
+    }
+
+    fn sub(size: usize) {
+        ALLOCATED_BYTES.set(ALLOCATED_BYTES.get() - size as isize);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::sub(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        if !new_ptr.is_null() {
+            Self::sub(layout.size());
+            Self::add(new_size);
+        }
+
+        new_ptr
+    }
+}
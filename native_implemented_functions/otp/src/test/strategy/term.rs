@@ -13,13 +13,14 @@ use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::erts::Process;
 use liblumen_alloc::{atom, fixnum_from};
 
-use super::size_range;
+use super::{size_range, DEPTH};
 
 pub mod atom;
 pub mod binary;
 pub mod function;
 pub mod index;
 pub mod integer;
+pub mod iodata;
 pub mod is_binary;
 pub mod is_bitstring;
 pub mod list;
@@ -168,6 +169,10 @@ pub fn is_integer(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     .boxed()
 }
 
+pub fn iodata(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    iodata::with_depth(DEPTH, arc_process)
+}
+
 pub fn is_iolist(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     list::io::root(arc_process)
 }
@@ -8,7 +8,6 @@ use liblumen_alloc::erts::term::prelude::Term;
 use liblumen_alloc::erts::Process;
 
 use crate::test::strategy::size_range;
-use crate::test::strategy::term::binary::sub::{bit_count, bit_offset, byte_count, byte_offset};
 
 pub mod heap;
 pub mod sub;
@@ -34,11 +33,5 @@ pub fn is_utf8(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
 }
 
 pub fn sub(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
-    sub::with_size_range(
-        byte_offset(),
-        bit_offset(),
-        byte_count(),
-        bit_count(),
-        arc_process,
-    )
+    sub::biased(arc_process)
 }
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use proptest::prop_oneof;
 use proptest::strategy::{BoxedStrategy, Just, Strategy};
 
 use liblumen_alloc::erts::term::prelude::Term;
@@ -18,6 +19,43 @@ pub fn bit_offset() -> BoxedStrategy<u8> {
     bit()
 }
 
+/// Subbinaries spread evenly over the four combinations of a zero or non-zero bit offset and a
+/// whole or partial trailing byte, as uniformly random bit offsets and counts rarely produce the
+/// aligned cases, while the unaligned cases are the ones most likely to find bugs.
+pub fn biased(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    prop_oneof![
+        with_size_range(
+            byte_offset(),
+            zero_bit(),
+            byte_count(),
+            zero_bit(),
+            arc_process.clone()
+        ),
+        with_size_range(
+            byte_offset(),
+            non_zero_bit(),
+            byte_count(),
+            zero_bit(),
+            arc_process.clone()
+        ),
+        with_size_range(
+            byte_offset(),
+            zero_bit(),
+            byte_count(),
+            non_zero_bit(),
+            arc_process.clone()
+        ),
+        with_size_range(
+            byte_offset(),
+            non_zero_bit(),
+            byte_count(),
+            non_zero_bit(),
+            arc_process
+        )
+    ]
+    .boxed()
+}
+
 pub fn byte_count() -> BoxedStrategy<usize> {
     size_range::strategy()
 }
@@ -137,6 +175,14 @@ fn bit() -> BoxedStrategy<u8> {
     (0_u8..=7_u8).boxed()
 }
 
+fn non_zero_bit() -> BoxedStrategy<u8> {
+    (1_u8..=7_u8).boxed()
+}
+
+fn zero_bit() -> BoxedStrategy<u8> {
+    (0_u8..=0_u8).boxed()
+}
+
 fn write_bytes(original_byte_vec: &mut [u8], byte_offset: usize, bit_offset: u8, bytes: &[u8]) {
     for (i, byte) in bytes.iter().enumerate() {
         let first_original_byte_index = byte_offset + i;
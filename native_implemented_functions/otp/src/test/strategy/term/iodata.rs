@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use proptest::prop_oneof;
+use proptest::strategy::{BoxedStrategy, Just, Strategy};
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::strategy::term::{is_binary, is_byte};
+use crate::test::strategy::{byte_vec, MAX_LEN};
+
+/// A binary or an iolist whose nested lists are at most `depth` deep.
+///
+/// Unlike `is_iolist_or_binary`, the binaries include `ProcBin`s in addition to heap binaries and
+/// subbinaries, so that the reference-counted path of the iodata BIFs is also covered.
+pub fn with_depth(depth: u32, arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    prop_oneof![
+        binary(arc_process.clone()),
+        iolist_with_depth(depth, arc_process)
+    ]
+    .boxed()
+}
+
+/// A maybe improper list of bytes, binaries, and nested iolists at most `depth` deep, with a
+/// binary or empty list tail.
+pub fn iolist_with_depth(depth: u32, arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    (
+        Just(arc_process.clone()),
+        proptest::collection::vec(element(depth, arc_process.clone()), 0..=MAX_LEN),
+        tail(arc_process),
+    )
+        .prop_map(|(arc_process, elements, tail)| {
+            arc_process
+                .improper_list_from_slice(&elements, tail)
+                .unwrap()
+        })
+        .boxed()
+}
+
+// Private
+
+fn binary(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    prop_oneof![is_binary(arc_process.clone()), process_binary(arc_process)].boxed()
+}
+
+fn element(depth: u32, arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    prop_oneof![is_byte(arc_process.clone()), binary(arc_process.clone())]
+        .prop_recursive(
+            depth,
+            (MAX_LEN * (depth as usize + 1)) as u32,
+            MAX_LEN as u32,
+            move |element_strategy| {
+                (
+                    Just(arc_process.clone()),
+                    proptest::collection::vec(element_strategy, 0..=MAX_LEN),
+                    tail(arc_process.clone()),
+                )
+                    .prop_map(|(arc_process, elements, tail)| {
+                        arc_process
+                            .improper_list_from_slice(&elements, tail)
+                            .unwrap()
+                    })
+                    .boxed()
+            },
+        )
+        .boxed()
+}
+
+fn process_binary(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    let min_len = heap_bin_limit() + 1;
+
+    byte_vec::with_size_range((min_len..=(min_len + MAX_LEN)).into())
+        .prop_map(move |byte_vec| arc_process.binary_from_bytes(&byte_vec).unwrap())
+        .boxed()
+}
+
+fn tail(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
+    prop_oneof![binary(arc_process), Just(Term::NIL)].boxed()
+}
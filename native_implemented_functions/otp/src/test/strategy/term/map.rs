@@ -3,19 +3,35 @@ use std::sync::Arc;
 use proptest::collection::SizeRange;
 use proptest::strategy::{BoxedStrategy, Strategy};
 
-use liblumen_alloc::erts::term::prelude::Term;
+use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::erts::Process;
 
+/// Maps with `size_range` entries, where the keys and values are both from `key_or_value`.
+///
+/// Duplicate keys are dropped by comparing with exact equality (`=:=`), like map keys are, instead
+/// of relying on `Term`'s `Hash` for every type of term, so the map size is always the number of
+/// unique keys.
 pub fn intermediate(
     key_or_value: BoxedStrategy<Term>,
     size_range: SizeRange,
     arc_process: Arc<Process>,
 ) -> BoxedStrategy<Term> {
-    proptest::collection::hash_map(key_or_value.clone(), key_or_value, size_range)
-        .prop_map(move |mut hash_map| {
-            let entry_vec: Vec<(Term, Term)> = hash_map.drain().collect();
+    proptest::collection::vec((key_or_value.clone(), key_or_value), size_range)
+        .prop_map(move |entry_vec| {
+            let mut unique_entry_vec: Vec<(Term, Term)> = Vec::with_capacity(entry_vec.len());
 
-            arc_process.map_from_slice(&entry_vec).unwrap()
+            for (key, value) in entry_vec {
+                let typed_key = key.decode().unwrap();
+
+                if !unique_entry_vec
+                    .iter()
+                    .any(|(unique_key, _)| unique_key.decode().unwrap().exact_eq(&typed_key))
+                {
+                    unique_entry_vec.push((key, value));
+                }
+            }
+
+            arc_process.map_from_slice(&unique_entry_vec).unwrap()
         })
         .boxed()
 }
@@ -0,0 +1,303 @@
+//! Mirrors [unicode](http://erlang.org/doc/man/unicode.html) module
+
+pub mod characters_to_binary_1;
+pub mod characters_to_binary_2;
+pub mod characters_to_list_1;
+pub mod characters_to_list_2;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::try_from_str("unicode").unwrap()
+}
+
+// Private
+
+/// The encoding of the binaries in chardata.  Integers in chardata are always code points.
+#[derive(Clone, Copy)]
+enum Encoding {
+    Latin1,
+    Utf8,
+    Utf16(Endianness),
+    Utf32(Endianness),
+}
+
+#[derive(Clone, Copy)]
+enum Endianness {
+    Big,
+    Little,
+}
+
+/// Where decoding the bytes of a binary stopped
+enum Decoded {
+    Complete,
+    /// The byte offset of the first byte that is not valid in the encoding
+    Invalid(usize),
+    /// The byte offset of a sequence that is cut off by the end of the binary
+    Incomplete(usize),
+}
+
+/// Converts `data` to code points and then to a term with `chars_to_term`.
+///
+/// Like OTP, invalid code points or bytes do not raise, but return
+/// `{error, Converted, RestData}`, and a binary that ends with a partial sequence at the end of
+/// `data` returns `{incomplete, Converted, RestBinary}`.  `RestData` is the binary when the error
+/// is in `data` itself and otherwise a list of the unconverted chardata.
+fn characters_to(
+    process: &Process,
+    data: Term,
+    in_encoding: Term,
+    chars_to_term: fn(&Process, &str) -> exception::Result<Term>,
+) -> exception::Result<Term> {
+    let encoding = encoding_try_from(in_encoding)?;
+    let mut converted = String::new();
+    let mut stack = vec![data];
+
+    while let Some(top) = stack.pop() {
+        match top.decode()? {
+            TypedTerm::Nil => (),
+            TypedTerm::SmallInteger(_) => match code_point_try_from(top, encoding) {
+                Some(c) => converted.push(c),
+                None => {
+                    return error_tuple(
+                        process,
+                        "error",
+                        chars_to_term(process, &converted)?,
+                        top,
+                        stack,
+                    )
+                }
+            },
+            TypedTerm::List(cons) => {
+                if cons.tail.is_smallint() {
+                    return Err(TypeError)
+                        .context(chardata_context(data, top))
+                        .map_err(From::from);
+                }
+
+                stack.push(cons.tail);
+                stack.push(cons.head);
+            }
+            _ if top.is_binary() => {
+                let bytes = process
+                    .bytes_from_binary(top)
+                    .with_context(|| chardata_context(data, top))?
+                    .to_vec();
+
+                match decode(&bytes, encoding, &mut converted) {
+                    Decoded::Complete => (),
+                    Decoded::Invalid(offset) => {
+                        let rest = process.binary_from_bytes(&bytes[offset..])?;
+
+                        return error_tuple(
+                            process,
+                            "error",
+                            chars_to_term(process, &converted)?,
+                            rest,
+                            stack,
+                        );
+                    }
+                    Decoded::Incomplete(offset) => {
+                        let rest = process.binary_from_bytes(&bytes[offset..])?;
+                        let tag = if stack.iter().all(|term| term.is_nil()) {
+                            "incomplete"
+                        } else {
+                            "error"
+                        };
+
+                        return error_tuple(
+                            process,
+                            tag,
+                            chars_to_term(process, &converted)?,
+                            rest,
+                            stack,
+                        );
+                    }
+                }
+            }
+            _ => {
+                return Err(TypeError)
+                    .context(chardata_context(data, top))
+                    .map_err(From::from)
+            }
+        }
+    }
+
+    chars_to_term(process, &converted)
+}
+
+fn chardata_context(data: Term, element: Term) -> String {
+    format!(
+        "data ({}) element ({}) is not a code point, binary, or chardata",
+        data, element
+    )
+}
+
+fn code_point_try_from(integer: Term, encoding: Encoding) -> Option<char> {
+    let c: char = integer.try_into().ok()?;
+
+    match encoding {
+        Encoding::Latin1 if 255 < (c as u32) => None,
+        _ => Some(c),
+    }
+}
+
+fn decode(bytes: &[u8], encoding: Encoding, converted: &mut String) -> Decoded {
+    match encoding {
+        Encoding::Latin1 => {
+            converted.extend(bytes.iter().map(|byte| *byte as char));
+
+            Decoded::Complete
+        }
+        Encoding::Utf8 => match std::str::from_utf8(bytes) {
+            Ok(s) => {
+                converted.push_str(s);
+
+                Decoded::Complete
+            }
+            Err(utf8_error) => {
+                let valid_up_to = utf8_error.valid_up_to();
+                converted.push_str(std::str::from_utf8(&bytes[..valid_up_to]).unwrap());
+
+                match utf8_error.error_len() {
+                    Some(_) => Decoded::Invalid(valid_up_to),
+                    None => Decoded::Incomplete(valid_up_to),
+                }
+            }
+        },
+        Encoding::Utf16(endianness) => {
+            let mut offset = 0;
+
+            while offset < bytes.len() {
+                let unit = match unit_u16(bytes, offset, endianness) {
+                    Some(unit) => unit,
+                    None => return Decoded::Incomplete(offset),
+                };
+
+                let (code_point, len) = match unit {
+                    0xD800..=0xDBFF => match unit_u16(bytes, offset + 2, endianness) {
+                        Some(low @ 0xDC00..=0xDFFF) => (
+                            0x1_0000 + (((unit as u32) - 0xD800) << 10) + ((low as u32) - 0xDC00),
+                            4,
+                        ),
+                        Some(_) => return Decoded::Invalid(offset),
+                        None => return Decoded::Incomplete(offset),
+                    },
+                    0xDC00..=0xDFFF => return Decoded::Invalid(offset),
+                    _ => (unit as u32, 2),
+                };
+
+                converted.push(std::char::from_u32(code_point).unwrap());
+                offset += len;
+            }
+
+            Decoded::Complete
+        }
+        Encoding::Utf32(endianness) => {
+            let mut offset = 0;
+
+            while offset < bytes.len() {
+                if bytes.len() < offset + 4 {
+                    return Decoded::Incomplete(offset);
+                }
+
+                let mut unit_bytes = [0; 4];
+                unit_bytes.copy_from_slice(&bytes[offset..offset + 4]);
+                let unit = match endianness {
+                    Endianness::Big => u32::from_be_bytes(unit_bytes),
+                    Endianness::Little => u32::from_le_bytes(unit_bytes),
+                };
+
+                match std::char::from_u32(unit) {
+                    Some(c) => converted.push(c),
+                    None => return Decoded::Invalid(offset),
+                }
+
+                offset += 4;
+            }
+
+            Decoded::Complete
+        }
+    }
+}
+
+fn encoding_try_from(encoding: Term) -> exception::Result<Encoding> {
+    let option = match encoding.decode()? {
+        TypedTerm::Atom(atom) => match atom.name() {
+            "latin1" => Some(Encoding::Latin1),
+            "unicode" | "utf8" => Some(Encoding::Utf8),
+            "utf16" => Some(Encoding::Utf16(Endianness::Big)),
+            "utf32" => Some(Encoding::Utf32(Endianness::Big)),
+            _ => None,
+        },
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+            let endianness = match tuple[1].decode()? {
+                TypedTerm::Atom(atom) => match atom.name() {
+                    "big" => Some(Endianness::Big),
+                    "little" => Some(Endianness::Little),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            match (tuple[0].decode()?, endianness) {
+                (TypedTerm::Atom(atom), Some(endianness)) => match atom.name() {
+                    "utf16" => Some(Encoding::Utf16(endianness)),
+                    "utf32" => Some(Encoding::Utf32(endianness)),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+
+    option.with_context(|| {
+        format!(
+            "in_encoding ({}) is not latin1, unicode, utf8, utf16, utf32, {{utf16, big | little}}, or {{utf32, big | little}}",
+            encoding
+        )
+    }).map_err(From::from)
+}
+
+/// `{tag, converted, rest_data}` where `rest_data` is `first` followed by the unconverted
+/// `stack`, which has the next term on its top.
+fn error_tuple(
+    process: &Process,
+    tag: &str,
+    converted: Term,
+    first: Term,
+    stack: Vec<Term>,
+) -> exception::Result<Term> {
+    let rest_data = if stack.is_empty() && first.is_binary() {
+        first
+    } else {
+        let mut rest_vec = vec![first];
+        rest_vec.extend(stack.into_iter().rev().filter(|term| !term.is_nil()));
+
+        process.list_from_slice(&rest_vec)?
+    };
+
+    process
+        .tuple_from_slice(&[Atom::str_to_term(tag), converted, rest_data])
+        .map_err(From::from)
+}
+
+fn unit_u16(bytes: &[u8], offset: usize, endianness: Endianness) -> Option<u16> {
+    if offset + 2 <= bytes.len() {
+        let unit_bytes = [bytes[offset], bytes[offset + 1]];
+
+        Some(match endianness {
+            Endianness::Big => u16::from_be_bytes(unit_bytes),
+            Endianness::Little => u16::from_le_bytes(unit_bytes),
+        })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,21 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::unicode::characters_to_binary_2;
+
+/// `characters_to_binary(Data, unicode)`
+#[native_implemented_function(characters_to_binary/1)]
+pub fn native(process: &Process, data: Term) -> exception::Result<Term> {
+    characters_to_binary_2::native(process, data, atom!("unicode"))
+}
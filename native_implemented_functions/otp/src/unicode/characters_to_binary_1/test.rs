@@ -0,0 +1,108 @@
+use liblumen_alloc::atom;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_binary_1::native;
+
+#[test]
+fn with_utf8_chardata_returns_utf8_binary() {
+    with_process(|process| {
+        let data = process
+            .list_from_slice(&[
+                process.binary_from_str("héllo").unwrap(),
+                process.integer(' ' as u32).unwrap(),
+                process.charlist_from_str("wörld").unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(process, data),
+            Ok(process.binary_from_str("héllo wörld").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_invalid_byte_returns_error_with_converted_and_rest() {
+    with_process(|process| {
+        let data = process
+            .binary_from_bytes(&[b'a', b'b', 0xFF, b'c'])
+            .unwrap();
+
+        assert_eq!(
+            native(process, data),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("error"),
+                    process.binary_from_str("ab").unwrap(),
+                    process.binary_from_bytes(&[0xFF, b'c']).unwrap(),
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_partial_sequence_at_end_returns_incomplete() {
+    with_process(|process| {
+        // The first 2 bytes of the 3 byte encoding of '€'
+        let data = process.binary_from_bytes(&[b'a', 0xE2, 0x82]).unwrap();
+
+        assert_eq!(
+            native(process, data),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("incomplete"),
+                    process.binary_from_str("a").unwrap(),
+                    process.binary_from_bytes(&[0xE2, 0x82]).unwrap(),
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_invalid_code_point_in_list_returns_error_with_rest_list() {
+    with_process(|process| {
+        let invalid = process.integer(0x11_0000).unwrap();
+        let data = process
+            .list_from_slice(&[
+                process.integer('a' as u32).unwrap(),
+                invalid,
+                process.integer('b' as u32).unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(process, data),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("error"),
+                    process.binary_from_str("a").unwrap(),
+                    process
+                        .list_from_slice(&[
+                            invalid,
+                            process
+                                .list_from_slice(&[process.integer('b' as u32).unwrap()])
+                                .unwrap()
+                        ])
+                        .unwrap(),
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_chardata_errors_badarg() {
+    with_process(|process| {
+        let data = atom!("data");
+
+        assert_badarg!(
+            native(process, data),
+            format!(
+                "data ({}) element ({}) is not a code point, binary, or chardata",
+                data, data
+            )
+        );
+    });
+}
@@ -0,0 +1,87 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_binary_2::native;
+
+#[test]
+fn with_latin1_returns_utf8_binary() {
+    with_process(|process| {
+        let data = process.binary_from_bytes(&[b'h', 0xE9]).unwrap();
+
+        assert_eq!(
+            native(process, data, atom!("latin1")),
+            Ok(process.binary_from_str("hé").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_utf16_big_and_little_returns_utf8_binary() {
+    with_process(|process| {
+        // 'h' followed by the surrogate pair for U+1F600
+        let big = process
+            .binary_from_bytes(&[0x00, b'h', 0xD8, 0x3D, 0xDE, 0x00])
+            .unwrap();
+        let little = process
+            .binary_from_bytes(&[b'h', 0x00, 0x3D, 0xD8, 0x00, 0xDE])
+            .unwrap();
+        let expected = process.binary_from_str("h\u{1F600}").unwrap();
+
+        assert_eq!(
+            native(
+                process,
+                big,
+                process
+                    .tuple_from_slice(&[atom!("utf16"), atom!("big")])
+                    .unwrap()
+            ),
+            Ok(expected)
+        );
+        assert_eq!(
+            native(
+                process,
+                little,
+                process
+                    .tuple_from_slice(&[atom!("utf16"), atom!("little")])
+                    .unwrap()
+            ),
+            Ok(expected)
+        );
+    });
+}
+
+#[test]
+fn with_utf16_lone_low_surrogate_returns_error() {
+    with_process(|process| {
+        let data = process
+            .binary_from_bytes(&[0x00, b'h', 0xDE, 0x00])
+            .unwrap();
+
+        assert_eq!(
+            native(process, data, atom!("utf16")),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("error"),
+                    process.binary_from_str("h").unwrap(),
+                    process.binary_from_bytes(&[0xDE, 0x00]).unwrap(),
+                ])
+                .unwrap())
+        );
+    });
+}
+
+#[test]
+fn without_encoding_errors_badarg() {
+    with_process(|process| {
+        let in_encoding = atom!("ascii");
+
+        assert_badarg!(
+            native(process, Term::NIL, in_encoding),
+            format!(
+                "in_encoding ({}) is not latin1, unicode, utf8, utf16, utf32, {{utf16, big | little}}, or {{utf32, big | little}}",
+                in_encoding
+            )
+        );
+    });
+}
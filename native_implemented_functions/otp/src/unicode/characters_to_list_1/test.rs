@@ -0,0 +1,41 @@
+use liblumen_alloc::atom;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_list_1::native;
+
+#[test]
+fn with_utf8_binary_returns_code_points() {
+    with_process(|process| {
+        let data = process.binary_from_str("héllo").unwrap();
+
+        assert_eq!(
+            native(process, data),
+            Ok(process.charlist_from_str("héllo").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_invalid_byte_returns_error_with_converted_list() {
+    with_process(|process| {
+        let data = process
+            .list_from_slice(&[
+                process.binary_from_str("ok").unwrap(),
+                process.binary_from_bytes(&[0xC0, b'x']).unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            native(process, data),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("error"),
+                    process.charlist_from_str("ok").unwrap(),
+                    process
+                        .list_from_slice(&[process.binary_from_bytes(&[0xC0, b'x']).unwrap()])
+                        .unwrap(),
+                ])
+                .unwrap())
+        );
+    });
+}
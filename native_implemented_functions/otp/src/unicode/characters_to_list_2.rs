@@ -0,0 +1,25 @@
+// wasm32 proptest cannot be compiled at the same time as non-wasm32 proptest, so disable tests that
+// use proptest completely for wasm32
+//
+// See https://github.com/rust-lang/cargo/issues/4866
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use native_implemented_function::native_implemented_function;
+
+use crate::unicode::characters_to;
+
+/// Converts `data`, whose binaries are in `in_encoding`, to a list of code points.
+///
+/// Invalid input returns `{error, Converted, RestData}` and input ending in a partial sequence
+/// returns `{incomplete, Converted, RestBinary}` instead of raising.
+#[native_implemented_function(characters_to_list/2)]
+pub fn native(process: &Process, data: Term, in_encoding: Term) -> exception::Result<Term> {
+    characters_to(process, data, in_encoding, |process, s| {
+        process.charlist_from_str(s).map_err(From::from)
+    })
+}
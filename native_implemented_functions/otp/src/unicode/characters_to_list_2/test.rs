@@ -0,0 +1,44 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_list_2::native;
+
+#[test]
+fn with_utf32_little_returns_code_points() {
+    with_process(|process| {
+        let data = process
+            .binary_from_bytes(&[0x00, 0xF6, 0x01, 0x00, b'a', 0x00, 0x00, 0x00])
+            .unwrap();
+
+        assert_eq!(
+            native(
+                process,
+                data,
+                process
+                    .tuple_from_slice(&[atom!("utf32"), atom!("little")])
+                    .unwrap()
+            ),
+            Ok(process.charlist_from_str("\u{1F600}a").unwrap())
+        );
+    });
+}
+
+#[test]
+fn with_latin1_code_point_above_255_returns_error() {
+    with_process(|process| {
+        let invalid = process.integer(256).unwrap();
+        let data = process.list_from_slice(&[invalid]).unwrap();
+
+        assert_eq!(
+            native(process, data, atom!("latin1")),
+            Ok(process
+                .tuple_from_slice(&[
+                    atom!("error"),
+                    Term::NIL,
+                    process.list_from_slice(&[invalid]).unwrap(),
+                ])
+                .unwrap())
+        );
+    });
+}
@@ -23,6 +23,11 @@ pub fn atom() -> Atom {
     ARC_NODE.name()
 }
 
+/// Whether this node has a name other than `nonode@nohost`, so it can take part in distribution
+pub fn is_alive() -> bool {
+    atom() != dead_atom()
+}
+
 pub fn id() -> usize {
     ARC_NODE.id()
 }
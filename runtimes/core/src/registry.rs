@@ -51,6 +51,14 @@ pub fn pid_to_self_or_process(pid: Pid, process_arc: &Arc<Process>) -> Option<Ar
     }
 }
 
+/// All processes that are still alive
+pub fn processes() -> Vec<Arc<Process>> {
+    WEAK_PROCESS_CONTROL_BLOCK_BY_PID
+        .iter()
+        .filter_map(|entry| entry.value().upgrade())
+        .collect()
+}
+
 pub fn put_atom_to_process(name: Atom, arc_process: Arc<Process>) -> bool {
     if !REGISTERED_BY_NAME.contains_key(&name) {
         register_in(arc_process, name)
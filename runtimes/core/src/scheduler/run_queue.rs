@@ -56,10 +56,18 @@ impl Queues {
         self.waiting.len() + self.normal_low.len() + self.high.len() + self.max.len()
     }
 
+    /// Returns whether `arc_process` was in any of the queues
+    pub fn remove(&mut self, arc_process: &Arc<Process>) -> bool {
+        self.waiting.remove(arc_process)
+            || self.normal_low.remove(arc_process)
+            || self.high.remove(arc_process)
+            || self.max.remove(arc_process)
+    }
+
     /// Returns the process is not pushed back because it is exiting
     #[must_use]
     pub fn requeue(&mut self, arc_process: Arc<Process>) -> Option<Arc<Process>> {
-        let next = Next::from_status(&arc_process.status.read());
+        let next = Next::from_process(&arc_process);
 
         // has to be separate so that `arc_process` can be moved
         match next {
@@ -97,14 +105,17 @@ enum Next {
 }
 
 impl Next {
-    fn from_status(status: &Status) -> Next {
-        match status {
-            Status::Runnable => Next::PushBack,
-            Status::Waiting => Next::Wait,
+    fn from_process(process: &Process) -> Next {
+        match *process.status.read() {
             Status::Exiting(_) => Next::Exit,
             Status::Running => {
                 unreachable!("Process.stop_running() should have been called before this")
             }
+            // Suspended processes wait until resumed, even when runnable, so that they are not
+            // dequeued again
+            _ if process.is_suspended() => Next::Wait,
+            Status::Runnable => Next::PushBack,
+            Status::Waiting => Next::Wait,
         }
     }
 }
@@ -174,6 +185,17 @@ impl Immediate {
     pub fn enqueue(&mut self, process: Arc<Process>) {
         self.0.push_back(process);
     }
+
+    pub fn remove(&mut self, value: &Arc<Process>) -> bool {
+        match self.0.iter().position(|arc_process| arc_process == value) {
+            Some(index) => {
+                self.0.remove(index);
+
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 /// A run queue where the `Arc<Process` is run only when its delay is `0`.  This allows
@@ -213,6 +235,21 @@ impl Delayed {
         let delayed_process = DelayedProcess::new(arc_process);
         self.0.push_back(delayed_process);
     }
+
+    pub fn remove(&mut self, value: &Arc<Process>) -> bool {
+        match self
+            .0
+            .iter()
+            .position(|delayed_process| &delayed_process.arc_process == value)
+        {
+            Some(index) => {
+                self.0.remove(index);
+
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 type Delay = u8;
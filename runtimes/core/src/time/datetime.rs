@@ -16,9 +16,30 @@ pub fn local_time() -> [usize; 3] {
     [datetime[3], datetime[4], datetime[5]]
 }
 
+/// Converts `local` to UTC.  Like `mktime` with `tm_isdst`, `Some(true)` converts with the DST
+/// offset and `Some(false)` with the standard offset, even when `local` is not in that part of the
+/// year, while `None` uses whichever offset is in effect at `local`, and the DST one when `local`
+/// occurs twice because clocks were turned back.
+///
+/// Returns `None` if `local` is not a valid date and time.
+pub fn local_to_utc(local: [usize; 6], is_dst: Option<bool>) -> Option<[usize; 6]> {
+    get_local_to_utc(local, is_dst)
+}
+
+/// Converts `utc` to local time.
+///
+/// Returns `None` if `utc` is not a valid date and time.
+pub fn utc_to_local(utc: [usize; 6]) -> Option<[usize; 6]> {
+    get_utc_to_local(utc)
+}
+
 #[cfg(not(all(target_arch = "wasm32", feature = "time_web_sys")))]
 mod sys {
+    use std::convert::TryFrom;
+
+    use chrono::offset::LocalResult;
     use chrono::prelude::*;
+    use chrono::Duration;
 
     pub fn get_local_now() -> [usize; 6] {
         datetime_to_array(Local::now())
@@ -28,6 +49,70 @@ mod sys {
         datetime_to_array(Utc::now())
     }
 
+    pub fn get_local_to_utc(local: [usize; 6], is_dst: Option<bool>) -> Option<[usize; 6]> {
+        let naive = array_to_naive_datetime(local)?;
+
+        let utc = match is_dst {
+            Some(is_dst) => {
+                let (standard_offset, dst_offset) = standard_and_dst_offsets(naive.year());
+                let offset = if is_dst { dst_offset } else { standard_offset };
+
+                Utc.from_utc_datetime(&(naive - offset))
+            }
+            None => match Local.from_local_datetime(&naive) {
+                LocalResult::Single(datetime) => datetime.with_timezone(&Utc),
+                // Clocks were turned back, so the first occurrence is still in DST
+                LocalResult::Ambiguous(dst, _) => dst.with_timezone(&Utc),
+                // Clocks were turned forward past `local`, so keep the offset from before the gap
+                LocalResult::None => {
+                    let offset = Local.offset_from_utc_datetime(&naive);
+                    let utc_naive = naive - Duration::seconds(offset.local_minus_utc() as i64);
+
+                    Utc.from_utc_datetime(&utc_naive)
+                }
+            },
+        };
+
+        Some(datetime_to_array(utc))
+    }
+
+    pub fn get_utc_to_local(utc: [usize; 6]) -> Option<[usize; 6]> {
+        let naive = array_to_naive_datetime(utc)?;
+
+        Some(datetime_to_array(Local.from_utc_datetime(&naive)))
+    }
+
+    fn array_to_naive_datetime(array: [usize; 6]) -> Option<NaiveDateTime> {
+        NaiveDate::from_ymd_opt(
+            i32::try_from(array[0]).ok()?,
+            u32::try_from(array[1]).ok()?,
+            u32::try_from(array[2]).ok()?,
+        )?
+        .and_hms_opt(
+            u32::try_from(array[3]).ok()?,
+            u32::try_from(array[4]).ok()?,
+            u32::try_from(array[5]).ok()?,
+        )
+    }
+
+    /// The offsets from UTC at the start of January and July of `year`, the smaller being the
+    /// standard offset and the bigger the DST one, so that it doesn't matter which hemisphere's
+    /// summer DST is in.  Without DST, they are the same.
+    fn standard_and_dst_offsets(year: i32) -> (Duration, Duration) {
+        let local_minus_utc = |month| {
+            let naive = NaiveDate::from_ymd(year, month, 1).and_hms(0, 0, 0);
+
+            Local.offset_from_utc_datetime(&naive).local_minus_utc() as i64
+        };
+        let january = local_minus_utc(1);
+        let july = local_minus_utc(7);
+
+        (
+            Duration::seconds(january.min(july)),
+            Duration::seconds(january.max(july)),
+        )
+    }
+
     fn datetime_to_array<Tz: TimeZone>(datetime: DateTime<Tz>) -> [usize; 6] {
         [
             datetime.year() as usize,
@@ -45,30 +130,117 @@ mod sys {
     use js_sys::Date;
 
     pub fn get_local_now() -> [usize; 6] {
-        let now = Date::new_0();
+        date_to_local_array(&Date::new_0())
+    }
 
+    pub fn get_utc_now() -> [usize; 6] {
+        date_to_utc_array(&Date::new_0())
+    }
+
+    pub fn get_local_to_utc(local: [usize; 6], is_dst: Option<bool>) -> Option<[usize; 6]> {
+        if is_valid(local) {
+            let date = match is_dst {
+                Some(is_dst) => {
+                    let (standard_offset_minutes, dst_offset_minutes) =
+                        standard_and_dst_offset_minutes(local[0]);
+                    let offset_minutes = if is_dst {
+                        dst_offset_minutes
+                    } else {
+                        standard_offset_minutes
+                    };
+
+                    let date = Date::new_0();
+                    date.set_time(
+                        array_to_utc_milliseconds(local) - (offset_minutes as f64) * 60_000.0,
+                    );
+
+                    date
+                }
+                // `Date` has no way to disambiguate repeated local times, so it picks one
+                None => Date::new_with_year_month_day_hr_min_sec(
+                    local[0] as u32,
+                    // Since months in javascript are 0-based
+                    (local[1] as i32) - 1,
+                    local[2] as i32,
+                    local[3] as i32,
+                    local[4] as i32,
+                    local[5] as i32,
+                ),
+            };
+
+            Some(date_to_utc_array(&date))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_utc_to_local(utc: [usize; 6]) -> Option<[usize; 6]> {
+        if is_valid(utc) {
+            let date = Date::new_0();
+            date.set_time(array_to_utc_milliseconds(utc));
+
+            Some(date_to_local_array(&date))
+        } else {
+            None
+        }
+    }
+
+    /// The milliseconds since the epoch of `array` as if it were UTC
+    fn array_to_utc_milliseconds(array: [usize; 6]) -> f64 {
+        // Since months in javascript are 0-based
+        let month_milliseconds = Date::utc(array[0] as f64, (array[1] as f64) - 1.0);
+        let seconds = (array[2] - 1) * 86_400 + array[3] * 3_600 + array[4] * 60 + array[5];
+
+        month_milliseconds + (seconds as f64) * 1_000.0
+    }
+
+    fn date_to_local_array(date: &Date) -> [usize; 6] {
         [
-            now.get_full_year() as usize,
-            (now.get_month() as usize) + 1, // Since months in javascript are 0-based
-            now.get_date() as usize,
-            now.get_hours() as usize,
-            now.get_minutes() as usize,
-            now.get_seconds() as usize,
+            date.get_full_year() as usize,
+            (date.get_month() as usize) + 1, // Since months in javascript are 0-based
+            date.get_date() as usize,
+            date.get_hours() as usize,
+            date.get_minutes() as usize,
+            date.get_seconds() as usize,
         ]
     }
 
-    pub fn get_utc_now() -> [usize; 6] {
-        let now = Date::new_0();
-
+    fn date_to_utc_array(date: &Date) -> [usize; 6] {
         [
-            now.get_utc_full_year() as usize,
-            (now.get_utc_month() as usize) + 1, // Since months in javascript are 0-based
-            now.get_utc_date() as usize,
-            now.get_utc_hours() as usize,
-            now.get_utc_minutes() as usize,
-            now.get_utc_seconds() as usize,
+            date.get_utc_full_year() as usize,
+            (date.get_utc_month() as usize) + 1, // Since months in javascript are 0-based
+            date.get_utc_date() as usize,
+            date.get_utc_hours() as usize,
+            date.get_utc_minutes() as usize,
+            date.get_utc_seconds() as usize,
         ]
     }
+
+    /// Like the native `standard_and_dst_offsets`, but in minutes, as that is what `Date` uses
+    fn standard_and_dst_offset_minutes(year: usize) -> (i32, i32) {
+        // `getTimezoneOffset` is UTC minus local time
+        let local_minus_utc = |month| {
+            -(Date::new_with_year_month_day(year as u32, month, 1).get_timezone_offset() as i32)
+        };
+        let january = local_minus_utc(0);
+        let july = local_minus_utc(6);
+
+        (january.min(july), january.max(july))
+    }
+
+    fn is_valid(array: [usize; 6]) -> bool {
+        let [year, month, day, hour, minute, second] = array;
+        let is_leap_year = (year % 4 == 0) && ((year % 100 != 0) || (year % 400 == 0));
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return false,
+        };
+
+        1 <= day && day <= days_in_month && hour < 24 && minute < 60 && second < 60
+    }
 }
 
 pub use self::sys::*;
@@ -38,7 +38,7 @@ use anyhow::*;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use thiserror::Error;
 
-use liblumen_alloc::erts::exception::{ArcError, InternalException, InternalResult};
+use liblumen_alloc::erts::exception::{AllocResult, ArcError, InternalException, InternalResult};
 use liblumen_alloc::erts::term::closure::Creator;
 use liblumen_alloc::erts::term::prelude::{Pid as LocalPid, *};
 use liblumen_alloc::erts::{Node, Process};
@@ -169,9 +169,9 @@ impl Pid {
         Ok(pid)
     }
 
-    fn clone_to_process(&self, process: &Process) -> Term {
+    fn clone_to_process(&self, process: &Process) -> AllocResult<Term> {
         match self {
-            Pid::Local(local_pid) => local_pid.clone().into(),
+            Pid::Local(local_pid) => Ok(local_pid.clone().into()),
             Pid::External(external_pid) => external_pid.clone_to_process(process),
         }
     }
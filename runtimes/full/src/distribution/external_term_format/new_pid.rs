@@ -21,6 +21,7 @@ pub fn decode_term<'a>(
     safe: bool,
     bytes: &'a [u8],
 ) -> InternalResult<(Term, &'a [u8])> {
-    decode_pid(safe, bytes)
-        .map(|(pid, after_pid_bytes)| (pid.clone_to_process(process), after_pid_bytes))
+    let (pid, after_pid_bytes) = decode_pid(safe, bytes)?;
+
+    Ok((pid.clone_to_process(process)?, after_pid_bytes))
 }
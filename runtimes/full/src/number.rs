@@ -1,8 +1,31 @@
 use num_bigint::BigInt;
 
+use liblumen_alloc::erts::term::prelude::Integer;
+
 pub enum Operands {
     Bad,
     ISizes(isize, isize),
     Floats(f64, f64),
     BigInts(BigInt, BigInt),
 }
+
+/// Applies `checked` to `left` and `right`, falling back to `big` on `BigInt`s when the result
+/// overflows `isize`, so that it is promoted instead of wrapping.
+///
+/// Results that fit in an `isize`, but not in a `SmallInteger`, are promoted by the conversion to
+/// `Integer`.
+pub fn isizes_to_integer<C, B>(left: isize, right: isize, checked: C, big: B) -> Integer
+where
+    C: FnOnce(isize, isize) -> Option<isize>,
+    B: FnOnce(BigInt, BigInt) -> BigInt,
+{
+    match checked(left, right) {
+        Some(output_isize) => output_isize.into(),
+        None => {
+            let left_big_int: BigInt = left.into();
+            let right_big_int: BigInt = right.into();
+
+            big(left_big_int, right_big_int).into()
+        }
+    }
+}
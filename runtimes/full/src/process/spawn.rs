@@ -29,7 +29,7 @@ pub fn apply_3(
 
     let module_term = module.encode()?;
     let function_term = function.encode()?;
-    let heap_arguments = arguments.clone_to_process(&child_process);
+    let heap_arguments = arguments.clone_to_process(&child_process)?;
 
     let erlang_atom = Atom::try_from_str("erlang").unwrap();
     let apply_atom = Atom::try_from_str("apply").unwrap();
@@ -71,7 +71,7 @@ pub fn code(
     let child_process = options.spawn(parent_process, module, function, arity)?;
 
     for argument in arguments.iter().rev() {
-        let process_argument = argument.clone_to_process(&child_process);
+        let process_argument = argument.clone_to_process(&child_process)?;
         child_process.stack_push(process_argument)?;
     }
 
@@ -12,7 +12,7 @@ use liblumen_core::locks::{Mutex, RwLock};
 
 use liblumen_alloc::erts::exception::{Result, SystemException};
 use liblumen_alloc::erts::process::code::Code;
-use liblumen_alloc::erts::process::{Priority, Process, Status};
+use liblumen_alloc::erts::process::{Priority, Process, ProcessFlags, Status};
 pub use liblumen_alloc::erts::scheduler::{id, ID};
 use liblumen_alloc::erts::term::prelude::*;
 
@@ -57,6 +57,12 @@ impl Scheduler {
         SCHEDULER.with(|thread_local_scheduler| thread_local_scheduler.clone())
     }
 
+    /// The number of schedulers, which is the number of threads that have run a scheduler and
+    /// have not exited
+    pub fn count() -> usize {
+        SCHEDULER_BY_ID.lock().len()
+    }
+
     pub fn from_id(id: &ID) -> Option<Arc<Scheduler>> {
         Self::current_from_id(id).or_else(|| {
             SCHEDULER_BY_ID
@@ -135,11 +141,33 @@ impl Scheduler {
 
             match run {
                 Run::Now(arc_process) => {
+                    // Suspended processes must not run until resumed, so move them to waiting
+                    // without counting as a run.
+                    if arc_process.is_suspended() && !arc_process.is_exiting() {
+                        let none = self.run_queues.write().requeue(arc_process);
+                        debug_assert!(none.is_none());
+
+                        continue;
+                    }
+
                     // Don't allow exiting processes to run again.
                     //
                     // Without this check, a process.exit() from outside the process during WAITING
                     // will return to the Frame that called `process.wait()`
                     if !arc_process.is_exiting() {
+                        // Another process requested a collection with `garbage_collect/1`, which
+                        // is only safe to do while this process is not running.
+                        if arc_process.are_flags_set(ProcessFlags::ForceGC) {
+                            match arc_process.garbage_collect(0, &mut []) {
+                                Ok(_freed) => (),
+                                Err(gc_err) => {
+                                    panic!("fatal garbage collection error: {:?}", gc_err)
+                                }
+                            }
+
+                            arc_process.clear_flags(ProcessFlags::ForceGC);
+                        }
+
                         match Process::run(&arc_process) {
                             Ok(()) => (),
                             Err(exception) => match exception {
@@ -190,6 +218,12 @@ impl Scheduler {
         self.run_queues.read().contains(value)
     }
 
+    /// Takes `arc_process` out of the run queues without exiting it, so it is not run again, even
+    /// when sent a message, but it still receives messages.  Returns whether it was run queued.
+    pub fn unschedule(&self, arc_process: &Arc<Process>) -> bool {
+        self.run_queues.write().remove(arc_process)
+    }
+
     /// Returns `true` if `arc_process` was run; otherwise, `false`.
     #[must_use]
     pub fn run_through(&self, arc_process: &Arc<Process>) -> bool {
@@ -210,6 +244,25 @@ impl Scheduler {
         }
     }
 
+    /// Runs each process that is runnable when the round starts once, in run queue order.
+    /// Processes that become runnable during the round, such as those spawned or sent a message,
+    /// are at the back of the run queue, so they wait for the next round.  This lets tests step
+    /// through interleavings of processes in a reproducible order.
+    ///
+    /// Returns the number of processes that were run.
+    pub fn run_round(&self) -> usize {
+        let runnable_len: usize = {
+            let run_queues = self.run_queues.read();
+
+            [Priority::Normal, Priority::High, Priority::Max]
+                .iter()
+                .map(|priority| run_queues.run_queue_len(*priority))
+                .sum()
+        };
+
+        (0..runnable_len).take_while(|_| self.run_once()).count()
+    }
+
     pub fn schedule(self: Arc<Scheduler>, process: Process) -> Arc<Process> {
         let mut writable_run_queues = self.run_queues.write();
 
@@ -301,6 +354,14 @@ impl Scheduler {
         self.run_queues.write().stop_waiting(process);
     }
 
+    /// Puts a process that was suspended back in its run queue after its last matching resume,
+    /// unless it is still waiting for a message.
+    pub fn resume(&self, process: &Process) {
+        if *process.status.read() != Status::Waiting {
+            self.stop_waiting(process);
+        }
+    }
+
     // Private
 
     fn new() -> Scheduler {
@@ -51,6 +51,17 @@ fn scheduler_does_run_exiting_process() {
     assert!(!scheduler.is_run_queued(&arc_process));
 }
 
+#[test]
+fn scheduler_does_not_run_unscheduled_process() {
+    let arc_process = test::process::default();
+    let scheduler = Scheduler::current();
+
+    assert!(scheduler.unschedule(&arc_process));
+    assert!(!scheduler.is_run_queued(&arc_process));
+    assert!(!scheduler.run_through(&arc_process));
+    assert!(!scheduler.unschedule(&arc_process));
+}
+
 fn exit_1_place_frame_with_arguments(
     process: &Process,
     placement: Placement,
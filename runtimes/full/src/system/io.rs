@@ -27,8 +27,36 @@ pub fn puts(s: &str) {
     println!("{}", s);
 }
 
+/// Like [puts], but does not append a newline, so `s` must contain its own line endings.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn print(s: &str) {
+    use std::io::Write;
+
+    let stdout = std::io::stdout();
+    let mut stdout_lock = stdout.lock();
+
+    // Without a trailing newline, stdout won't flush on its own.  Errors, such as stdout being
+    // closed, are ignored, as there is nowhere left to print them.
+    let _ = stdout_lock
+        .write_all(s.as_bytes())
+        .and_then(|()| stdout_lock.flush());
+}
+
 #[cfg(target_arch = "wasm32")]
 #[allow(dead_code)]
 pub fn puts(s: &str) {
     console_log(s);
 }
+
+/// `console.log` always ends the line, so a trailing newline is dropped to not double it.
+#[cfg(target_arch = "wasm32")]
+#[allow(dead_code)]
+pub fn print(s: &str) {
+    let line = if s.ends_with('\n') {
+        &s[..s.len() - 1]
+    } else {
+        s
+    };
+
+    console_log(line);
+}